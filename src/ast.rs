@@ -20,16 +20,37 @@
 //! - statement expressions
 //! - `typeof` type specifiers
 
+use std::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use astutil::decode_ucn;
 use span::Node;
 
+pub use intern::Name;
+
 // From 6.4 Lexical elements
 
 /// Variable, function and other names that are not type names
 ///
 /// (C11 6.4.2)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Identifier {
-    pub name: String,
+    /// Name as spelled in the source, including any universal character names
+    pub name: Name,
+}
+
+impl Identifier {
+    /// Name with universal character names replaced by the characters they designate
+    ///
+    /// `caf\u00e9` and `café` are the same identifier and have the same normalized name.
+    ///
+    /// (C11 6.4.3)
+    pub fn normalized(&self) -> Cow<'_, str> {
+        decode_ucn(&self.name).unwrap_or(Cow::Borrowed(self.name.as_str()))
+    }
 }
 
 /// Constant literals
@@ -39,6 +60,7 @@ pub struct Identifier {
 ///
 /// (C11 6.4.4)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Constant {
     Integer(Integer),
     Float(Float),
@@ -49,6 +71,7 @@ pub enum Constant {
 ///
 /// (C11 6.4.4.1)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Integer {
     pub base: IntegerBase,
     pub number: Box<str>,
@@ -59,6 +82,7 @@ pub struct Integer {
 ///
 /// (C11 6.4.4.1)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum IntegerBase {
     Decimal,
     Octal,
@@ -71,6 +95,7 @@ pub enum IntegerBase {
 ///
 /// (C11 6.4.4.1)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IntegerSuffix {
     /// Minimum size of the integer literal
     pub size: IntegerSize,
@@ -86,6 +111,7 @@ pub struct IntegerSuffix {
 ///
 /// (C11 6.4.4.1)
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum IntegerSize {
     /// no `l` or `ll`
     Int = 0,
@@ -99,6 +125,7 @@ pub enum IntegerSize {
 ///
 /// (C11 6.4.4.2)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Float {
     pub base: FloatBase,
     pub number: Box<str>,
@@ -109,6 +136,7 @@ pub struct Float {
 ///
 /// (C11 6.4.4.2)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FloatBase {
     Decimal,
     Hexadecimal,
@@ -118,6 +146,7 @@ pub enum FloatBase {
 ///
 /// (C11 6.4.4.2)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FloatSuffix {
     pub format: FloatFormat,
     /// Integer literal is an imaginary part of a complex number
@@ -130,6 +159,7 @@ pub struct FloatSuffix {
 ///
 /// (C11 6.4.4.2)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FloatFormat {
     /// `f` suffix
     Float,
@@ -155,7 +185,8 @@ pub type StringLiteral = Vec<String>;
 /// Expressions
 ///
 /// (C11 6.5)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Expression {
     /// Identifier
     ///
@@ -262,10 +293,15 @@ pub enum Expression {
     ///
     /// [GNU extension](https://gcc.gnu.org/onlinedocs/gcc/Statement-Exprs.html)
     Statement(Box<Node<Statement>>),
+
+    /// `(a) -b`, `(a) &b`, `(a) *b`, `(a) +b` or `(a)(b)` with `a` of unknown kind when inferring
+    /// typedef names
+    Ambiguous(Box<Node<AmbiguousExpression>>),
 }
 
 /// Struct or union member access
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MemberOperator {
     /// `expression.identifier`
     Direct,
@@ -276,7 +312,8 @@ pub enum MemberOperator {
 /// Generic selection expression
 ///
 /// (C11 6.5.1.1)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GenericSelection {
     pub expression: Box<Node<Expression>>,
     pub associations: Vec<Node<GenericAssociation>>,
@@ -285,7 +322,9 @@ pub struct GenericSelection {
 /// Single element of a generic selection expression
 ///
 /// (C11 6.5.1.1)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(clippy::large_enum_variant)]
 pub enum GenericAssociation {
     Type(Node<GenericAssociationType>),
     Default(Box<Node<Expression>>),
@@ -294,7 +333,8 @@ pub enum GenericAssociation {
 /// Type match case in a generic selection expression
 ///
 /// (C11 6.5.1.1)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GenericAssociationType {
     pub type_name: Node<TypeName>,
     pub expression: Box<Node<Expression>>,
@@ -305,7 +345,8 @@ pub struct GenericAssociationType {
 /// Both direct (`.`) and indirect (`->`) access.
 ///
 /// (C11 6.5.2)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MemberExpression {
     pub operator: Node<MemberOperator>,
     pub expression: Box<Node<Expression>>,
@@ -315,7 +356,8 @@ pub struct MemberExpression {
 /// Function call expression
 ///
 /// (C11 6.5.2)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CallExpression {
     pub callee: Box<Node<Expression>>,
     pub arguments: Vec<Node<Expression>>,
@@ -324,7 +366,8 @@ pub struct CallExpression {
 /// Compound literal
 ///
 /// (C11 6.5.2)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CompoundLiteral {
     pub type_name: Node<TypeName>,
     pub initializer_list: Vec<Node<InitializerListItem>>,
@@ -334,6 +377,7 @@ pub struct CompoundLiteral {
 ///
 /// (C11 6.5)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UnaryOperator {
     /// `operand++`
     PostIncrement,
@@ -365,7 +409,8 @@ pub enum UnaryOperator {
 /// additional operands are represented by a separate entry in this enum.
 ///
 /// (C11 6.5.2, c11 6.5.3)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UnaryOperatorExpression {
     pub operator: Node<UnaryOperator>,
     pub operand: Box<Node<Expression>>,
@@ -376,7 +421,8 @@ pub struct UnaryOperatorExpression {
 /// `(type) expr`
 ///
 /// (C11 6.5.4)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CastExpression {
     pub type_name: Node<TypeName>,
     pub expression: Box<Node<Expression>>,
@@ -386,6 +432,7 @@ pub struct CastExpression {
 ///
 /// (C11 6.5)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BinaryOperator {
     /// `lhs[rhs]`
     Index,
@@ -454,7 +501,8 @@ pub enum BinaryOperator {
 /// All of C binary operators that can be applied to two expressions.
 ///
 /// (C11 6.5.5 -- 6.5.16)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinaryOperatorExpression {
     pub operator: Node<BinaryOperator>,
     pub lhs: Box<Node<Expression>>,
@@ -464,7 +512,8 @@ pub struct BinaryOperatorExpression {
 /// Conditional operator
 ///
 /// (C11 6.5.15)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConditionalExpression {
     pub condition: Box<Node<Expression>>,
     pub then_expression: Box<Node<Expression>>,
@@ -476,7 +525,8 @@ pub struct ConditionalExpression {
 /// Result of expansion of `va_arg` macro.
 ///
 /// (C11 7.16.1.1).
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VaArgExpression {
     pub va_list: Box<Node<Expression>>,
     pub type_name: Node<TypeName>,
@@ -487,7 +537,8 @@ pub struct VaArgExpression {
 /// Result of expansion of `offsetof` macro.
 ///
 /// (C11 7.19 §3).
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OffsetOfExpression {
     pub type_name: Node<TypeName>,
     pub designator: Node<OffsetDesignator>,
@@ -496,7 +547,8 @@ pub struct OffsetOfExpression {
 /// Offset designator in a `offsetof` macro expansion
 ///
 /// (C11 7.19 §3).
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OffsetDesignator {
     pub base: Node<Identifier>,
     pub members: Vec<Node<OffsetMember>>,
@@ -505,19 +557,34 @@ pub struct OffsetDesignator {
 /// Single element of an offset designator
 ///
 /// (C11 7.19 §3).
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum OffsetMember {
     Member(Node<Identifier>),
     IndirectMember(Node<Identifier>),
     Index(Node<Expression>),
 }
 
+/// Expression that is a cast if an unknown parenthesized identifier is a typedef name
+///
+/// Only produced when parsing with `Config::infer_typedefs`, for a binary expression starting with
+/// the parenthesized identifier.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AmbiguousExpression {
+    /// `(a) -b` negating `b` and casting it to type `a`
+    pub cast: Node<Expression>,
+    /// `(a) -b` subtracting `b` from `a`
+    pub expression: Node<Expression>,
+}
+
 // From 6.7 Declarations
 
 /// Variable, function or type declaration
 ///
 /// (C11 6.7)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Declaration {
     pub specifiers: Vec<Node<DeclarationSpecifier>>,
     pub declarators: Vec<Node<InitDeclarator>>,
@@ -528,7 +595,8 @@ pub struct Declaration {
 /// These apply to all declarators in a declaration.
 ///
 /// (C11 6.7)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DeclarationSpecifier {
     StorageClass(Node<StorageClassSpecifier>),
     TypeSpecifier(Node<TypeSpecifier>),
@@ -542,7 +610,8 @@ pub enum DeclarationSpecifier {
 /// Defines a single name in a declaration
 ///
 /// (C11 6.7.6)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InitDeclarator {
     pub declarator: Node<Declarator>,
     pub initializer: Option<Node<Initializer>>,
@@ -554,6 +623,7 @@ pub struct InitDeclarator {
 ///
 /// (C11 6.7.1)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StorageClassSpecifier {
     /// `typedef`
     Typedef,
@@ -574,7 +644,8 @@ pub enum StorageClassSpecifier {
 /// Type specifier
 ///
 /// (C11 6.7.2)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TypeSpecifier {
     /// `void`
     Void,
@@ -628,12 +699,25 @@ pub enum TypeSpecifier {
     ///
     /// [ISO/IEC TS 18661-3:2015](http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1945.pdf)
     TS18661Float(TS18661FloatType),
+    /// Fixed-point fractional type `_Fract`
+    ///
+    /// [ISO/IEC TR 18037:2008](http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1169.pdf)
+    Fract,
+    /// Fixed-point type with an integral part `_Accum`
+    ///
+    /// [ISO/IEC TR 18037:2008](http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1169.pdf)
+    Accum,
+    /// Saturating fixed-point type `_Sat`
+    ///
+    /// [ISO/IEC TR 18037:2008](http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1169.pdf)
+    Sat,
 }
 
 /// Floating point type with guaranteed width and format
 ///
 /// [ISO/IEC TS 18661-3:2015](http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1945.pdf)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TS18661FloatType {
     pub format: TS18661FloatFormat,
     pub width: usize,
@@ -643,6 +727,7 @@ pub struct TS18661FloatType {
 ///
 /// [ISO/IEC TS 18661-3:2015](http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1945.pdf)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TS18661FloatFormat {
     BinaryInterchange,
     BinaryExtended,
@@ -655,7 +740,8 @@ pub enum TS18661FloatFormat {
 /// Structure or union type specifier
 ///
 /// (C11 6.7.2.1)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StructType {
     pub kind: Node<StructKind>,
     pub identifier: Option<Node<Identifier>>,
@@ -669,6 +755,7 @@ pub struct StructType {
 ///
 /// (C11 6.7.2.1)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StructKind {
     Struct,
     Union,
@@ -677,14 +764,16 @@ pub enum StructKind {
 /// Single declaration in a struct or a union
 ///
 /// (C11 6.7.2.1)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StructDeclaration {
     Field(Node<StructField>),
     StaticAssert(Node<StaticAssert>),
 }
 
 /// Struct field declaration
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StructField {
     pub specifiers: Vec<Node<SpecifierQualifier>>,
     pub declarators: Vec<Node<StructDeclarator>>,
@@ -695,16 +784,21 @@ pub struct StructField {
 /// C11 also uses this type in a few other places.
 ///
 /// (C11 6.7.2.1)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(clippy::large_enum_variant)]
 pub enum SpecifierQualifier {
     TypeSpecifier(Node<TypeSpecifier>),
     TypeQualifier(Node<TypeQualifier>),
+    /// Vendor-specific extensions that can be mixed with standard specifiers
+    Extension(Vec<Node<Extension>>),
 }
 
 /// Field declarator for a struct or a union
 ///
 /// (C11 6.7.2.1)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StructDeclarator {
     pub declarator: Option<Node<Declarator>>,
     pub bit_width: Option<Box<Node<Expression>>>,
@@ -715,7 +809,8 @@ pub struct StructDeclarator {
 /// Enumeration type specifier
 ///
 /// (C11 6.7.2.2)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EnumType {
     pub identifier: Option<Node<Identifier>>,
     pub enumerators: Vec<Node<Enumerator>>,
@@ -724,7 +819,8 @@ pub struct EnumType {
 /// Single constant inside a `enum` definition
 ///
 /// (C11 6.7.2.2)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Enumerator {
     pub identifier: Node<Identifier>,
     pub expression: Option<Box<Node<Expression>>>,
@@ -736,6 +832,7 @@ pub struct Enumerator {
 ///
 /// (C11 6.7.3)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TypeQualifier {
     /// `const`
     ///
@@ -771,6 +868,7 @@ pub enum TypeQualifier {
 ///
 /// (C11 6.7.4)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FunctionSpecifier {
     /// `inline`
     ///
@@ -785,7 +883,8 @@ pub enum FunctionSpecifier {
 /// Alignment specifier
 ///
 /// (C11 6.7.5)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AlignmentSpecifier {
     /// `_Alignas(typename)`
     Type(Node<TypeName>),
@@ -800,7 +899,8 @@ pub enum AlignmentSpecifier {
 /// Represents both normal and abstract declarators.
 ///
 /// (C11 6.7.6, 6.7.7)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Declarator {
     /// What is being declared
     pub kind: Node<DeclaratorKind>,
@@ -813,7 +913,8 @@ pub struct Declarator {
 /// Name of a declarator
 ///
 /// (C11 6.7.6, 6.7.7)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DeclaratorKind {
     /// Unnamed declarator
     ///
@@ -833,7 +934,8 @@ pub enum DeclaratorKind {
 /// Modifies declarator type
 ///
 /// (C11 6.7.6)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DerivedDeclarator {
     /// `* qualifiers …`
     Pointer(Vec<Node<PointerQualifier>>),
@@ -846,14 +948,16 @@ pub enum DerivedDeclarator {
 }
 
 /// Array part of a declarator
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ArrayDeclarator {
     pub qualifiers: Vec<Node<TypeQualifier>>,
     pub size: ArraySize,
 }
 
 /// Function parameter part of a declarator
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FunctionDeclarator {
     pub parameters: Vec<Node<ParameterDeclaration>>,
     pub ellipsis: Ellipsis,
@@ -862,7 +966,8 @@ pub struct FunctionDeclarator {
 /// List of qualifiers that can follow a `*` in a declaration
 ///
 /// (C11 6.7.6.1)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PointerQualifier {
     TypeQualifier(Node<TypeQualifier>),
     Extension(Vec<Node<Extension>>),
@@ -871,7 +976,8 @@ pub enum PointerQualifier {
 /// Size of an array in a declaration
 ///
 /// (C11 6.7.6.2)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ArraySize {
     /// `[]`
     Unknown,
@@ -891,7 +997,8 @@ pub enum ArraySize {
 /// `FunctionDefinition::declarations` field.
 ///
 /// (C11 6.7.6.3)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParameterDeclaration {
     pub specifiers: Vec<Node<DeclarationSpecifier>>,
     pub declarator: Option<Node<Declarator>>,
@@ -900,6 +1007,7 @@ pub struct ParameterDeclaration {
 
 /// Whether function signature ends with a `...`
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Ellipsis {
     Some,
     None,
@@ -912,7 +1020,8 @@ pub enum Ellipsis {
 /// Type names contain only abstract declarators.
 ///
 /// (C11 6.7.7)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TypeName {
     pub specifiers: Vec<Node<SpecifierQualifier>>,
     pub declarator: Option<Node<Declarator>>,
@@ -923,7 +1032,8 @@ pub struct TypeName {
 /// Value that is assigned immediately in a declaration
 ///
 /// (C11 6.7.9)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Initializer {
     Expression(Box<Node<Expression>>),
     List(Vec<Node<InitializerListItem>>),
@@ -932,14 +1042,16 @@ pub enum Initializer {
 /// Initializes one field or array element in a initializer list
 ///
 /// (C11 6.7.9)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InitializerListItem {
     pub designation: Vec<Node<Designator>>,
     pub initializer: Box<Node<Initializer>>,
 }
 
 /// Single element of an designation in an initializer
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Designator {
     /// Array element
     ///
@@ -967,7 +1079,8 @@ pub enum Designator {
 /// `[from ... to]`
 ///
 /// ([GNU extension](https://gcc.gnu.org/onlinedocs/gcc/Designated-Inits.html#Designated-Inits))
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RangeDesignator {
     pub from: Node<Expression>,
     pub to: Node<Expression>,
@@ -978,7 +1091,8 @@ pub struct RangeDesignator {
 /// Static assertion
 ///
 /// (C11 6.7.10)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StaticAssert {
     pub expression: Box<Node<Expression>>,
     pub message: Node<StringLiteral>,
@@ -989,7 +1103,8 @@ pub struct StaticAssert {
 /// Element of a function body
 ///
 /// (C11 6.8)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Statement {
     Labeled(Node<LabeledStatement>),
     Compound(Vec<Node<BlockItem>>),
@@ -1010,7 +1125,8 @@ pub enum Statement {
 /// Labeled statement
 ///
 /// (C11 6.8.1)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LabeledStatement {
     pub label: Node<Label>,
     pub statement: Box<Node<Statement>>,
@@ -1019,7 +1135,8 @@ pub struct LabeledStatement {
 /// If statement
 ///
 /// (C11 6.8.4)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IfStatement {
     pub condition: Box<Node<Expression>>,
     pub then_statement: Box<Node<Statement>>,
@@ -1029,7 +1146,8 @@ pub struct IfStatement {
 /// Switch statement
 ///
 /// (C11 6.8.4)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SwitchStatement {
     pub expression: Box<Node<Expression>>,
     pub statement: Box<Node<Statement>>,
@@ -1038,7 +1156,8 @@ pub struct SwitchStatement {
 /// While statement
 ///
 /// (C11 6.8.5)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WhileStatement {
     pub expression: Box<Node<Expression>>,
     pub statement: Box<Node<Statement>>,
@@ -1047,7 +1166,8 @@ pub struct WhileStatement {
 /// Do statement
 ///
 /// (C11 6.8.5)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DoWhileStatement {
     pub statement: Box<Node<Statement>>,
     pub expression: Box<Node<Expression>>,
@@ -1056,7 +1176,8 @@ pub struct DoWhileStatement {
 /// For statement
 ///
 /// (C11 6.8.5)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ForStatement {
     pub initializer: Node<ForInitializer>,
     pub condition: Option<Box<Node<Expression>>>,
@@ -1065,7 +1186,8 @@ pub struct ForStatement {
 }
 
 /// Statement labels for `goto` and `switch`
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Label {
     /// Goto label
     ///
@@ -1082,7 +1204,8 @@ pub enum Label {
 }
 
 /// First element of a `for` statement
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ForInitializer {
     /// `for(; …)`
     Empty,
@@ -1097,11 +1220,26 @@ pub enum ForInitializer {
 // From 6.8.2
 
 /// Element of a compound statement
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BlockItem {
     Declaration(Node<Declaration>),
     StaticAssert(Node<StaticAssert>),
     Statement(Node<Statement>),
+    /// `a * b;` with `a` of unknown kind when inferring typedef names
+    Ambiguous(Box<Node<AmbiguousBlockItem>>),
+}
+
+/// Block item that is a declaration if an unknown identifier is a typedef name
+///
+/// Only produced when parsing with `Config::infer_typedefs`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AmbiguousBlockItem {
+    /// `a * b;` declaring a pointer `b` to type `a`
+    pub declaration: Node<Declaration>,
+    /// `a * b;` multiplying `a` and `b`
+    pub statement: Node<Statement>,
 }
 
 // From 6.9 External definitions
@@ -1109,13 +1247,16 @@ pub enum BlockItem {
 /// Entire C source file after preprocessing
 ///
 /// (C11 6.9)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TranslationUnit(pub Vec<Node<ExternalDeclaration>>);
 
 /// Top-level elements of a C program
 ///
 /// (C11 6.9)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(clippy::large_enum_variant)]
 pub enum ExternalDeclaration {
     Declaration(Node<Declaration>),
     StaticAssert(Node<StaticAssert>),
@@ -1125,7 +1266,8 @@ pub enum ExternalDeclaration {
 /// Function definition
 ///
 /// (C11 6.9.1)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FunctionDefinition {
     /// Return type of the function, possibly mixed with other specifiers
     pub specifiers: Vec<Node<DeclarationSpecifier>>,
@@ -1140,7 +1282,8 @@ pub struct FunctionDefinition {
 // Syntax extensions
 
 /// Extended vendor-specific syntax that does not fit elsewhere
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Extension {
     /// Attributes
     ///
@@ -1154,21 +1297,39 @@ pub enum Extension {
     ///
     /// [Clang extension](https://clang.llvm.org/docs/AttributeReference.html#availability)
     AvailabilityAttribute(Node<AvailabilityAttribute>),
+    /// Vendor-specific qualifier keyword
+    ///
+    /// Keywords like `__far`, `__near`, `__interrupt` or `__at(address)` used by embedded
+    /// compilers, including [ISO/IEC TR 18037](http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1169.pdf)
+    /// named address spaces.
+    VendorQualifier(VendorQualifier),
 }
 
 /// Attributes
 ///
 /// [GNU extension](https://gcc.gnu.org/onlinedocs/gcc/Attribute-Syntax.html)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Attribute {
     pub name: Node<String>,
     pub arguments: Vec<Node<Expression>>,
 }
 
+/// Vendor-specific qualifier keyword
+///
+/// Recognized keywords are configured with `driver::Config::vendor_keywords`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VendorQualifier {
+    pub name: Node<String>,
+    pub arguments: Vec<Node<Expression>>,
+}
+
 /// Platform availability attribute
 ///
 /// [Clang extension](https://clang.llvm.org/docs/AttributeReference.html#availability)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AvailabilityAttribute {
     pub platform: Node<Identifier>,
     pub clauses: Vec<Node<AvailabilityClause>>,
@@ -1177,7 +1338,8 @@ pub struct AvailabilityAttribute {
 /// Platfrom availability attribute clause
 ///
 /// [Clang extension](https://clang.llvm.org/docs/AttributeReference.html#availability)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AvailabilityClause {
     Introduced(Node<AvailabilityVersion>),
     Deprecated(Node<AvailabilityVersion>),
@@ -1191,6 +1353,7 @@ pub enum AvailabilityClause {
 ///
 /// [Clang extension](https://clang.llvm.org/docs/AttributeReference.html#availability)
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AvailabilityVersion {
     pub major: String,
     pub minor: Option<String>,
@@ -1198,7 +1361,8 @@ pub struct AvailabilityVersion {
 }
 
 /// Inline assembler
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AsmStatement {
     /// Basic asm statement with just source code
     ///
@@ -1214,7 +1378,8 @@ pub enum AsmStatement {
 /// Extended statement that has access to C variables
 ///
 /// [GNU extension](https://gcc.gnu.org/onlinedocs/gcc/Extended-Asm.html)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GnuExtendedAsmStatement {
     pub qualifier: Option<Node<TypeQualifier>>,
     pub template: Node<StringLiteral>,
@@ -1226,7 +1391,8 @@ pub struct GnuExtendedAsmStatement {
 /// Single input or output operand specifier for GNU extended asm statement
 ///
 /// [GNU extension](https://gcc.gnu.org/onlinedocs/gcc/Extended-Asm.html#Output-Operands)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GnuAsmOperand {
     pub symbolic_name: Option<Node<Identifier>>,
     pub constraints: Node<StringLiteral>,
@@ -1236,8 +1402,105 @@ pub struct GnuAsmOperand {
 /// Type of an expression or type
 ///
 /// [GNU extension](https://gcc.gnu.org/onlinedocs/gcc/Typeof.html)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TypeOf {
     Expression(Node<Expression>),
     Type(Node<TypeName>),
 }
+
+/// Invoke a macro with every node type that has its own visitor hooks
+///
+/// Each entry is `Type: visit_hook, visit_mut_hook;`. The translation unit, whose hooks take no
+/// span, and availability clauses, whose hooks have no default traversal, are left out. Lists
+/// of node types elsewhere in the crate are generated from this one.
+macro_rules! for_each_node_type {
+    ($callback:ident) => {
+        $callback! {
+            Identifier: visit_identifier, visit_identifier_mut;
+            Constant: visit_constant, visit_constant_mut;
+            Integer: visit_integer, visit_integer_mut;
+            IntegerBase: visit_integer_base, visit_integer_base_mut;
+            IntegerSuffix: visit_integer_suffix, visit_integer_suffix_mut;
+            IntegerSize: visit_integer_size, visit_integer_size_mut;
+            Float: visit_float, visit_float_mut;
+            FloatBase: visit_float_base, visit_float_base_mut;
+            FloatSuffix: visit_float_suffix, visit_float_suffix_mut;
+            FloatFormat: visit_float_format, visit_float_format_mut;
+            StringLiteral: visit_string_literal, visit_string_literal_mut;
+            Expression: visit_expression, visit_expression_mut;
+            MemberOperator: visit_member_operator, visit_member_operator_mut;
+            GenericSelection: visit_generic_selection, visit_generic_selection_mut;
+            GenericAssociation: visit_generic_association, visit_generic_association_mut;
+            GenericAssociationType: visit_generic_association_type, visit_generic_association_type_mut;
+            MemberExpression: visit_member_expression, visit_member_expression_mut;
+            CallExpression: visit_call_expression, visit_call_expression_mut;
+            CompoundLiteral: visit_compound_literal, visit_compound_literal_mut;
+            UnaryOperator: visit_unary_operator, visit_unary_operator_mut;
+            UnaryOperatorExpression: visit_unary_operator_expression, visit_unary_operator_expression_mut;
+            CastExpression: visit_cast_expression, visit_cast_expression_mut;
+            BinaryOperator: visit_binary_operator, visit_binary_operator_mut;
+            BinaryOperatorExpression: visit_binary_operator_expression, visit_binary_operator_expression_mut;
+            ConditionalExpression: visit_conditional_expression, visit_conditional_expression_mut;
+            VaArgExpression: visit_va_arg_expression, visit_va_arg_expression_mut;
+            OffsetOfExpression: visit_offset_of_expression, visit_offset_of_expression_mut;
+            OffsetDesignator: visit_offset_designator, visit_offset_designator_mut;
+            OffsetMember: visit_offset_member, visit_offset_member_mut;
+            AmbiguousExpression: visit_ambiguous_expression, visit_ambiguous_expression_mut;
+            Declaration: visit_declaration, visit_declaration_mut;
+            DeclarationSpecifier: visit_declaration_specifier, visit_declaration_specifier_mut;
+            InitDeclarator: visit_init_declarator, visit_init_declarator_mut;
+            StorageClassSpecifier: visit_storage_class_specifier, visit_storage_class_specifier_mut;
+            TypeSpecifier: visit_type_specifier, visit_type_specifier_mut;
+            TS18661FloatType: visit_ts18661_float_type, visit_ts18661_float_type_mut;
+            TS18661FloatFormat: visit_ts18661_float_format, visit_ts18661_float_format_mut;
+            StructType: visit_struct_type, visit_struct_type_mut;
+            StructKind: visit_struct_kind, visit_struct_kind_mut;
+            StructDeclaration: visit_struct_declaration, visit_struct_declaration_mut;
+            StructField: visit_struct_field, visit_struct_field_mut;
+            SpecifierQualifier: visit_specifier_qualifier, visit_specifier_qualifier_mut;
+            StructDeclarator: visit_struct_declarator, visit_struct_declarator_mut;
+            EnumType: visit_enum_type, visit_enum_type_mut;
+            Enumerator: visit_enumerator, visit_enumerator_mut;
+            TypeQualifier: visit_type_qualifier, visit_type_qualifier_mut;
+            FunctionSpecifier: visit_function_specifier, visit_function_specifier_mut;
+            AlignmentSpecifier: visit_alignment_specifier, visit_alignment_specifier_mut;
+            Declarator: visit_declarator, visit_declarator_mut;
+            DeclaratorKind: visit_declarator_kind, visit_declarator_kind_mut;
+            DerivedDeclarator: visit_derived_declarator, visit_derived_declarator_mut;
+            ArrayDeclarator: visit_array_declarator, visit_array_declarator_mut;
+            FunctionDeclarator: visit_function_declarator, visit_function_declarator_mut;
+            PointerQualifier: visit_pointer_qualifier, visit_pointer_qualifier_mut;
+            ArraySize: visit_array_size, visit_array_size_mut;
+            ParameterDeclaration: visit_parameter_declaration, visit_parameter_declaration_mut;
+            Ellipsis: visit_ellipsis, visit_ellipsis_mut;
+            TypeName: visit_type_name, visit_type_name_mut;
+            Initializer: visit_initializer, visit_initializer_mut;
+            InitializerListItem: visit_initializer_list_item, visit_initializer_list_item_mut;
+            Designator: visit_designator, visit_designator_mut;
+            RangeDesignator: visit_range_designator, visit_range_designator_mut;
+            StaticAssert: visit_static_assert, visit_static_assert_mut;
+            Statement: visit_statement, visit_statement_mut;
+            LabeledStatement: visit_labeled_statement, visit_labeled_statement_mut;
+            IfStatement: visit_if_statement, visit_if_statement_mut;
+            SwitchStatement: visit_switch_statement, visit_switch_statement_mut;
+            WhileStatement: visit_while_statement, visit_while_statement_mut;
+            DoWhileStatement: visit_do_while_statement, visit_do_while_statement_mut;
+            ForStatement: visit_for_statement, visit_for_statement_mut;
+            Label: visit_label, visit_label_mut;
+            ForInitializer: visit_for_initializer, visit_for_initializer_mut;
+            BlockItem: visit_block_item, visit_block_item_mut;
+            AmbiguousBlockItem: visit_ambiguous_block_item, visit_ambiguous_block_item_mut;
+            ExternalDeclaration: visit_external_declaration, visit_external_declaration_mut;
+            FunctionDefinition: visit_function_definition, visit_function_definition_mut;
+            Extension: visit_extension, visit_extension_mut;
+            Attribute: visit_attribute, visit_attribute_mut;
+            VendorQualifier: visit_vendor_qualifier, visit_vendor_qualifier_mut;
+            AsmStatement: visit_asm_statement, visit_asm_statement_mut;
+            AvailabilityAttribute: visit_availability_attribute, visit_availability_attribute_mut;
+            GnuExtendedAsmStatement: visit_gnu_extended_asm_statement, visit_gnu_extended_asm_statement_mut;
+            GnuAsmOperand: visit_gnu_asm_operand, visit_gnu_asm_operand_mut;
+            TypeOf: visit_type_of, visit_type_of_mut;
+        }
+    };
+}
@@ -1,5 +1,14 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
 use ast::*;
+use cst::TokenKind;
+use env::Env;
+use lexer::{pieces, Piece};
 use span::{Node, Span};
+use strings;
+use visit::{self, Visit};
+use visit_mut::{self, VisitMut};
 
 #[cfg_attr(test, derive(Debug, PartialEq, Clone))]
 pub enum Operation {
@@ -91,7 +100,7 @@ pub fn ts18661_float(binary: bool, width: usize, extended: bool) -> TS18661Float
             (false, false) => TS18661FloatFormat::DecimalInterchange,
             (false, true) => TS18661FloatFormat::DecimalExtended,
         },
-        width: width,
+        width,
     }
 }
 
@@ -100,7 +109,7 @@ pub fn int_suffix(mut s: &str) -> Result<IntegerSuffix, &'static str> {
     let mut u = false;
     let mut i = false;
 
-    while s.len() > 0 {
+    while !s.is_empty() {
         if l == IntegerSize::Int && (s.starts_with("ll") || s.starts_with("LL")) {
             l = IntegerSize::LongLong;
             s = &s[2..];
@@ -129,3 +138,309 @@ pub fn int_suffix(mut s: &str) -> Result<IntegerSuffix, &'static str> {
         imaginary: i,
     })
 }
+
+/// Replace universal character names with characters they designate
+///
+/// Returns `None` if any of the UCNs is malformed or designates a character not allowed by C11
+/// 6.4.3 §2.
+pub fn decode_ucn(s: &str) -> Option<Cow<'_, str>> {
+    if !s.contains('\\') {
+        return Some(Cow::Borrowed(s));
+    }
+
+    let mut res = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(pos) = rest.find('\\') {
+        res.push_str(&rest[..pos]);
+        let len = match rest[pos + 1..].chars().next() {
+            Some('u') => 4,
+            Some('U') => 8,
+            _ => return None,
+        };
+        let digits = rest.get(pos + 2..pos + 2 + len)?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let code = u32::from_str_radix(digits, 16).ok()?;
+        if code < 0xa0 && code != 0x24 && code != 0x40 && code != 0x60 {
+            return None;
+        }
+        res.push(char::from_u32(code)?);
+        rest = &rest[pos + 2 + len..];
+    }
+    res.push_str(rest);
+
+    Some(Cow::Owned(res))
+}
+
+/// Check that an identifier spelling only contains characters allowed by C11 Annex D
+pub fn check_identifier(s: &str) -> Result<(), &'static str> {
+    let name = match decode_ucn(s) {
+        Some(name) => name,
+        None => return Err("universal character name"),
+    };
+
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii() {
+            continue;
+        }
+        let c = c as u32;
+        if !in_ranges(c, strings::IDENTIFIER_RANGES) {
+            return Err("identifier");
+        }
+        if i == 0 && in_ranges(c, strings::IDENTIFIER_NONSTART_RANGES) {
+            return Err("identifier");
+        }
+    }
+
+    Ok(())
+}
+
+fn in_ranges(c: u32, ranges: &[(u32, u32)]) -> bool {
+    ranges
+        .binary_search_by(|&(lo, hi)| {
+            if hi < c {
+                Ordering::Less
+            } else if lo > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Identifier prefix that metavariables of query and quote patterns are rewritten to
+const META: &str = "__lang_c_meta_";
+
+/// Offsets of the `$` of metavariables in `text`
+///
+/// A `$` followed by a name starts a metavariable, unless it is in a string or character literal
+/// or in a comment.
+fn metavariables(text: &str) -> Vec<usize> {
+    let mut found = Vec::new();
+    for piece in pieces(text) {
+        if let Piece::Token(TokenKind::Identifier, span) = piece {
+            let token = &text[span.start..span.end];
+            for (pos, _) in token.match_indices('$') {
+                let named =
+                    token[pos + 1..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_');
+                if named {
+                    found.push(span.start + pos);
+                }
+            }
+        }
+    }
+    found
+}
+
+/// Replace `$name` with an identifier that parses anywhere an identifier may be
+pub fn replace_metavariables(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    for pos in metavariables(text) {
+        out.push_str(&text[copied..pos]);
+        out.push_str(META);
+        copied = pos + 1;
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// Offset in `text` of an offset in the text `replace_metavariables` makes of it
+///
+/// Offsets within a rewritten metavariable map to its `$`.
+pub fn original_offset(text: &str, offset: usize) -> usize {
+    // Growth of the text before the current position
+    let mut shift = 0;
+    for pos in metavariables(text) {
+        if offset < pos + shift + META.len() {
+            return if offset < pos + shift { offset - shift } else { pos };
+        }
+        shift += META.len() - 1;
+    }
+    offset - shift
+}
+
+/// Name of a metavariable, if an identifier is one written by `replace_metavariables`
+pub fn metavariable(ident: &Identifier) -> Option<&str> {
+    ident.name.strip_prefix(META)
+}
+
+/// Identifier a metavariable is rewritten to
+pub fn metavariable_identifier(name: &str) -> String {
+    format!("{}{}", META, name)
+}
+
+/// Typedef names used outside of nested blocks, which are committed separately
+struct TypedefNames(Vec<Node<Identifier>>);
+
+impl<'ast> Visit<'ast> for TypedefNames {
+    fn visit_type_specifier(&mut self, type_specifier: &'ast TypeSpecifier, span: &'ast Span) {
+        if let TypeSpecifier::TypedefName(ref name) = *type_specifier {
+            self.0.push(name.clone());
+        }
+        visit::visit_type_specifier(self, type_specifier, span)
+    }
+
+    fn visit_statement(&mut self, statement: &'ast Statement, span: &'ast Span) {
+        if let Statement::Compound(_) = *statement {
+            return;
+        }
+        visit::visit_statement(self, statement, span)
+    }
+
+    fn visit_ambiguous_expression(&mut self, a: &'ast AmbiguousExpression, _: &'ast Span) {
+        // The cast reading is only a guess
+        self.visit_expression(&a.expression.node, &a.expression.span)
+    }
+}
+
+pub fn declaration_typedef_names(d: &Node<Declaration>) -> Vec<Node<Identifier>> {
+    let mut names = TypedefNames(Vec::new());
+    names.visit_declaration(&d.node, &d.span);
+    names.0
+}
+
+pub fn statement_typedef_names(s: &Node<Statement>) -> Vec<Node<Identifier>> {
+    let mut names = TypedefNames(Vec::new());
+    visit::visit_statement(&mut names, &s.node, &s.span);
+    names.0
+}
+
+pub fn function_typedef_names(f: &Node<FunctionDefinition>) -> Vec<Node<Identifier>> {
+    let mut names = TypedefNames(Vec::new());
+    names.visit_function_definition(&f.node, &f.span);
+    names.0
+}
+
+/// Statement block item, or an ambiguous one if it is also a declaration of an unknown type
+pub fn statement_block_item(s: Node<Statement>, env: &Env) -> BlockItem {
+    match ambiguous_declaration(&s, env) {
+        Some(declaration) => {
+            let span = s.span;
+            let item = AmbiguousBlockItem {
+                declaration,
+                statement: s,
+            };
+            BlockItem::Ambiguous(Box::new(Node::new(item, span)))
+        }
+        None => BlockItem::Statement(s),
+    }
+}
+
+/// Expression read both as a cast and as `expression`, if both readings span the same text
+pub fn ambiguous_expression(
+    mut cast: Node<Expression>,
+    expression: Option<Node<Expression>>,
+) -> Result<Node<Expression>, &'static str> {
+    match expression {
+        Some(expression) if expression.span.end == cast.span.end => {
+            // Both readings would hold every nested ambiguity, doubling the tree at each level
+            Unambiguous.visit_expression_mut(&mut cast.node, &mut cast.span);
+            let span = cast.span;
+            let ambiguous = AmbiguousExpression { cast, expression };
+            let node = Node::new(ambiguous, span);
+            Ok(Node::new(Expression::Ambiguous(Box::new(node)), span))
+        }
+        _ => Err("<unused>"),
+    }
+}
+
+// Keeps the expression reading of nested ambiguous expressions
+struct Unambiguous;
+
+impl VisitMut for Unambiguous {
+    fn visit_expression_mut(&mut self, expression: &mut Expression, span: &mut Span) {
+        if let Expression::Ambiguous(ref a) = *expression {
+            let reading = a.node.expression.node.clone();
+            *expression = reading;
+        }
+        visit_mut::visit_expression_mut(self, expression, span)
+    }
+}
+
+/// `T * x;` or `T(x);` read as a declaration, if neither `T` nor `x` is declared
+fn ambiguous_declaration(s: &Node<Statement>, env: &Env) -> Option<Node<Declaration>> {
+    let e = match s.node {
+        Statement::Expression(Some(ref e)) => e,
+        _ => return None,
+    };
+    let (ty, declarator) = match e.node {
+        Expression::BinaryOperator(ref b) if b.node.operator.node == BinaryOperator::Multiply => {
+            let mut declarator = pointer_declarator(&b.node.rhs)?;
+            let pointer = Node::new(DerivedDeclarator::Pointer(Vec::new()), b.node.operator.span);
+            declarator.node.derived.insert(0, pointer);
+            declarator.span = Span::span(b.node.operator.span.start, declarator.span.end);
+            (&b.node.lhs, declarator)
+        }
+        Expression::Call(ref c) if c.node.arguments.len() == 1 => {
+            let inner = pointer_declarator(&c.node.arguments[0])?;
+            let span = Span::span(c.node.callee.span.end, c.span.end);
+            let kind = Node::new(DeclaratorKind::Declarator(Box::new(inner)), span);
+            (&c.node.callee, declarator(kind, Vec::new(), span))
+        }
+        _ => return None,
+    };
+    let ty = match ty.node {
+        Expression::Identifier(ref i) if env.is_unknown(&i.node.name) => i,
+        _ => return None,
+    };
+    match declarator_name(&declarator.node.kind.node) {
+        Some(name) if env.is_unknown(name) => {}
+        _ => return None,
+    }
+
+    let ty_span = ty.span;
+    let ty = Node::new(TypeSpecifier::TypedefName((**ty).clone()), ty_span);
+    let specifier = Node::new(DeclarationSpecifier::TypeSpecifier(ty), ty_span);
+    let init_declarator = InitDeclarator {
+        declarator,
+        initializer: None,
+    };
+    let span = init_declarator.declarator.span;
+    let declaration = Declaration {
+        specifiers: vec![specifier],
+        declarators: vec![Node::new(init_declarator, span)],
+    };
+    Some(Node::new(declaration, s.span))
+}
+
+/// Declarator for `x`, `*x` or `**x` read as an expression
+fn pointer_declarator(e: &Node<Expression>) -> Option<Node<Declarator>> {
+    match e.node {
+        Expression::Identifier(ref i) => {
+            let kind = Node::new(DeclaratorKind::Identifier((**i).clone()), i.span);
+            Some(declarator(kind, Vec::new(), e.span))
+        }
+        Expression::UnaryOperator(ref u) if u.node.operator.node == UnaryOperator::Indirection => {
+            let mut d = pointer_declarator(&u.node.operand)?;
+            let pointer = Node::new(DerivedDeclarator::Pointer(Vec::new()), u.node.operator.span);
+            d.node.derived.insert(0, pointer);
+            d.span = e.span;
+            Some(d)
+        }
+        _ => None,
+    }
+}
+
+fn declarator_name(kind: &DeclaratorKind) -> Option<&str> {
+    match *kind {
+        DeclaratorKind::Abstract => None,
+        DeclaratorKind::Identifier(ref i) => Some(&i.node.name),
+        DeclaratorKind::Declarator(ref d) => declarator_name(&d.node.kind.node),
+    }
+}
+
+fn declarator(
+    kind: Node<DeclaratorKind>,
+    derived: Vec<Node<DerivedDeclarator>>,
+    span: Span,
+) -> Node<Declarator> {
+    let declarator = Declarator {
+        kind,
+        derived,
+        extensions: Vec::new(),
+    };
+    Node::new(declarator, span)
+}
@@ -1,52 +1,317 @@
 //! Parse a C file and dump the AST.
+//!
+//! ```text
+//! dump [--flavor std|gnu|clang] [--format debug|json|sexp] [--compact]
+//!      [--only functions|declarations] [--main-file] [-q] [cpp options] file.c
+//! ```
+//!
+//! JSON and S-expression output require the `json` feature.
 
 extern crate lang_c;
+#[cfg(feature = "json")]
+extern crate serde_json;
 
 use std::process::exit;
 
-use lang_c::driver::Config;
+use lang_c::ast::{ExternalDeclaration, TranslationUnit};
+use lang_c::driver::{Config, Flavor};
+use lang_c::source_map::SourceMap;
+use lang_c::span::Compact;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Format {
+    Debug,
+    Json,
+    Sexp,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Only {
+    Everything,
+    Functions,
+    Declarations,
+}
+
+fn usage(message: &str) -> ! {
+    println!("{}", message);
+    exit(1);
+}
 
 fn main() {
-    let mut config = Config::default();
+    let mut flavor = None;
+    let mut cpp_options = Vec::new();
     let mut source = None;
     let mut quiet = false;
+    let mut format = Format::Debug;
+    let mut compact = false;
+    let mut only = Only::Everything;
+    let mut main_file = false;
 
-    for opt in std::env::args().skip(1) {
-        if opt == "-use-gcc" {
-            config = Config::with_gcc();
-        } else if opt == "-use-clang" {
-            config = Config::with_clang();
-        } else if opt == "-q" {
-            quiet = true;
-        } else if opt.starts_with("-") {
-            config.cpp_options.push(opt);
-        } else {
-            if source.is_none() {
-                source = Some(opt);
-            } else {
-                println!("multiple input files given");
-                exit(1);
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let (opt, inline) = match arg.find('=') {
+            Some(pos) if arg.starts_with("--") => {
+                (arg[..pos].to_owned(), Some(arg[pos + 1..].to_owned()))
+            }
+            _ => (arg.clone(), None),
+        };
+        let mut value = |name: &str| match inline.clone().or_else(|| args.next()) {
+            Some(v) => v,
+            None => usage(&format!("{} requires a value", name)),
+        };
+
+        match &opt[..] {
+            "--flavor" => {
+                flavor = match &value("--flavor")[..] {
+                    "std" => Some(Flavor::StdC11),
+                    "gnu" => Some(Flavor::GnuC11),
+                    "clang" => Some(Flavor::ClangC11),
+                    f => usage(&format!("unknown flavor: {}", f)),
+                }
+            }
+            "--format" => {
+                format = match &value("--format")[..] {
+                    "debug" => Format::Debug,
+                    "json" => Format::Json,
+                    "sexp" => Format::Sexp,
+                    f => usage(&format!("unknown format: {}", f)),
+                }
+            }
+            "--only" => {
+                only = match &value("--only")[..] {
+                    "functions" => Only::Functions,
+                    "declarations" => Only::Declarations,
+                    o => usage(&format!("unknown filter: {}", o)),
+                }
             }
+            "--compact" => compact = true,
+            "--main-file" => main_file = true,
+            "-q" => quiet = true,
+            _ if opt.starts_with('-') => cpp_options.push(arg),
+            _ if source.is_none() => source = Some(arg),
+            _ => usage("multiple input files given"),
         }
     }
 
     let source = match source {
         Some(s) => s,
-        None => {
-            println!("input file required");
+        None => usage("input file required"),
+    };
+
+    let mut config = match flavor {
+        Some(Flavor::ClangC11) => Config::with_clang(),
+        Some(Flavor::GnuC11) => Config::with_gcc(),
+        Some(Flavor::StdC11) => Config::default().flavor(Flavor::StdC11),
+        None => Config::default(),
+    };
+    config.cpp_options.extend(cpp_options);
+
+    let parse = match lang_c::driver::parse(&config, &source) {
+        Ok(parse) => parse,
+        Err(err) => {
+            println!("{}", err);
             exit(1);
         }
     };
 
-    match lang_c::driver::parse(&config, &source) {
-        Ok(parse) => {
-            if !quiet {
-                println!("{:#?}", parse.unit);
+    if quiet {
+        return;
+    }
+
+    let source_map = SourceMap::new(&parse.source);
+
+    let unit = TranslationUnit(
+        parse
+            .unit
+            .0
+            .into_iter()
+            .filter(|d| {
+                matches!(
+                    (only, &d.node),
+                    (Only::Functions, ExternalDeclaration::FunctionDefinition(_))
+                        | (Only::Declarations, ExternalDeclaration::Declaration(_))
+                        | (Only::Everything, _)
+                )
+            })
+            .filter(|d| !main_file || source_map.is_main_file(d.span.start))
+            .collect(),
+    );
+
+    match format {
+        Format::Debug => print_debug(&unit, compact),
+        Format::Json => print_json(&unit, compact),
+        Format::Sexp => print_sexp(&unit, compact),
+    }
+}
+
+fn print_debug(unit: &TranslationUnit, compact: bool) {
+    if compact {
+        println!("{:#?}", Compact(unit));
+    } else {
+        println!("{:#?}", unit);
+    }
+}
+
+#[cfg(not(feature = "json"))]
+fn print_json(_unit: &TranslationUnit, _compact: bool) {
+    usage("JSON output requires the `json` feature");
+}
+
+#[cfg(not(feature = "json"))]
+fn print_sexp(_unit: &TranslationUnit, _compact: bool) {
+    usage("S-expression output requires the `json` feature");
+}
+
+#[cfg(feature = "json")]
+fn to_value(unit: &TranslationUnit, compact: bool) -> serde_json::Value {
+    let value = serde_json::to_value(unit).expect("AST is serializable");
+    if compact {
+        sexp::strip_spans(value)
+    } else {
+        value
+    }
+}
+
+#[cfg(feature = "json")]
+fn print_json(unit: &TranslationUnit, compact: bool) {
+    let value = to_value(unit, compact);
+    println!("{}", serde_json::to_string_pretty(&value).unwrap());
+}
+
+#[cfg(feature = "json")]
+fn print_sexp(unit: &TranslationUnit, compact: bool) {
+    if let serde_json::Value::Array(decls) = to_value(unit, compact) {
+        for decl in decls {
+            println!("{}", sexp::Sexp::from_value(decl).pretty(0));
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+mod sexp {
+    use serde_json::{Map, Value};
+
+    const WIDTH: usize = 100;
+
+    /// Replace `Node` objects by their contents
+    pub fn strip_spans(value: Value) -> Value {
+        match value {
+            Value::Object(map) => match node_parts(map) {
+                Ok((node, _)) => strip_spans(node),
+                Err(map) => {
+                    Value::Object(map.into_iter().map(|(k, v)| (k, strip_spans(v))).collect())
+                }
+            },
+            Value::Array(items) => Value::Array(items.into_iter().map(strip_spans).collect()),
+            v => v,
+        }
+    }
+
+    /// Split serialized `Node` into its value and span, dropping the identifier
+    fn node_parts(mut map: Map<String, Value>) -> Result<(Value, Value), Map<String, Value>> {
+        let fields = if map.contains_key("id") { 3 } else { 2 };
+        if map.len() == fields && map.contains_key("node") && map.contains_key("span") {
+            let node = map.remove("node").unwrap();
+            let span = map.remove("span").unwrap();
+            Ok((node, span))
+        } else {
+            Err(map)
+        }
+    }
+
+    pub enum Sexp {
+        Atom(String),
+        List(Vec<Sexp>),
+        /// Sequence, kept apart from lists so that a one-element sequence differs from its item
+        Vector(Vec<Sexp>),
+    }
+
+    impl Sexp {
+        /// Convert serialized AST into an S-expression
+        ///
+        /// Enum variants with data and struct fields become lists headed by their name, spans
+        /// of nodes become `(span start end)` lists and sequences become `[...]` vectors.
+        /// Strings, including names of unit enum variants, are quoted.
+        pub fn from_value(value: Value) -> Sexp {
+            let mut items = items(value);
+            if items.len() == 1 {
+                items.pop().unwrap()
+            } else {
+                Sexp::List(items)
             }
         }
-        Err(err) => {
-            println!("{}", err);
-            exit(1);
+
+        fn flat(&self) -> String {
+            match *self {
+                Sexp::Atom(ref a) => a.clone(),
+                Sexp::List(ref items) => format!("({})", flat_items(items)),
+                Sexp::Vector(ref items) => format!("[{}]", flat_items(items)),
+            }
+        }
+
+        pub fn pretty(&self, indent: usize) -> String {
+            let flat = self.flat();
+            // Lists are indented past their head, vectors align all items
+            let (open, close, items, step) = match *self {
+                Sexp::List(ref items) => ("(", ")", items, 2),
+                Sexp::Vector(ref items) => ("[", "]", items, 1),
+                Sexp::Atom(_) => return flat,
+            };
+            if indent + flat.len() <= WIDTH || items.len() < 2 {
+                return flat;
+            }
+
+            let mut out = format!("{}{}", open, items[0].pretty(indent + 1));
+            for item in &items[1..] {
+                out.push('\n');
+                out.push_str(&" ".repeat(indent + step));
+                out.push_str(&item.pretty(indent + step));
+            }
+            out.push_str(close);
+            out
+        }
+    }
+
+    fn flat_items(items: &[Sexp]) -> String {
+        let items: Vec<_> = items.iter().map(Sexp::flat).collect();
+        items.join(" ")
+    }
+
+    /// Items a value contributes to the list of its parent
+    fn items(value: Value) -> Vec<Sexp> {
+        match value {
+            Value::Null => vec![Sexp::Atom("nil".into())],
+            Value::Bool(b) => vec![Sexp::Atom(b.to_string())],
+            Value::Number(n) => vec![Sexp::Atom(n.to_string())],
+            Value::String(s) => vec![Sexp::Atom(Value::String(s).to_string())],
+            Value::Array(values) => {
+                vec![Sexp::Vector(
+                    values.into_iter().map(Sexp::from_value).collect(),
+                )]
+            }
+            Value::Object(map) => match node_parts(map) {
+                Ok((node, span)) => {
+                    let mut items = items(node);
+                    items.push(span_sexp(span));
+                    items
+                }
+                Err(map) => map
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let mut list = vec![Sexp::Atom(name)];
+                        list.extend(items(value));
+                        Sexp::List(list)
+                    })
+                    .collect(),
+            },
+        }
+    }
+
+    fn span_sexp(span: Value) -> Sexp {
+        let mut list = vec![Sexp::Atom("span".into())];
+        for key in &["start", "end"] {
+            list.push(Sexp::Atom(span[*key].to_string()));
         }
+        Sexp::List(list)
     }
 }
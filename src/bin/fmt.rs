@@ -0,0 +1,147 @@
+//! Format C files.
+//!
+//! ```text
+//! fmt [--flavor std|gnu|clang] [--indent N] [--braces attach|linux|allman]
+//!     [--line-width N] [--pointer left|right|middle] [--check | -i] file.c...
+//! ```
+//!
+//! Formatted source is written to standard output, or back to the files with `-i`. With
+//! `--check` nothing is written, names of files that are not formatted are listed instead.
+//!
+//! Errors are reported on standard error. The exit code is 1 if a file could not be read, written
+//! or formatted, or is not formatted with `--check`, and 2 for invalid arguments.
+
+extern crate lang_c;
+
+use std::fs;
+use std::process::exit;
+
+use lang_c::driver::{Config, Flavor};
+use lang_c::format::format;
+use lang_c::print::{BraceStyle, PointerAlignment, Style};
+
+#[derive(Copy, Clone, PartialEq)]
+enum Mode {
+    Print,
+    InPlace,
+    Check,
+}
+
+fn usage(message: &str) -> ! {
+    eprintln!("{}", message);
+    exit(2);
+}
+
+fn number(name: &str, value: &str) -> usize {
+    match value.parse() {
+        Ok(n) => n,
+        Err(_) => usage(&format!("{} requires a number", name)),
+    }
+}
+
+fn main() {
+    let mut config = Config::default();
+    let mut style = Style {
+        line_width: Some(100),
+        ..Style::default()
+    };
+    let mut mode = Mode::Print;
+    let mut files = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let (opt, inline) = match arg.find('=') {
+            Some(pos) if arg.starts_with("--") => {
+                (arg[..pos].to_owned(), Some(arg[pos + 1..].to_owned()))
+            }
+            _ => (arg.clone(), None),
+        };
+        let mut value = |name: &str| match inline.clone().or_else(|| args.next()) {
+            Some(v) => v,
+            None => usage(&format!("{} requires a value", name)),
+        };
+
+        match &opt[..] {
+            "--flavor" => {
+                config.flavor = match &value("--flavor")[..] {
+                    "std" => Flavor::StdC11,
+                    "gnu" => Flavor::GnuC11,
+                    "clang" => Flavor::ClangC11,
+                    f => usage(&format!("unknown flavor: {}", f)),
+                }
+            }
+            "--indent" => style.indent_width = number("--indent", &value("--indent")),
+            "--braces" => {
+                style.braces = match &value("--braces")[..] {
+                    "attach" => BraceStyle::Attach,
+                    "linux" => BraceStyle::Linux,
+                    "allman" => BraceStyle::Allman,
+                    b => usage(&format!("unknown brace style: {}", b)),
+                }
+            }
+            "--line-width" => {
+                style.line_width = match number("--line-width", &value("--line-width")) {
+                    0 => None,
+                    n => Some(n),
+                }
+            }
+            "--pointer" => {
+                style.pointer_alignment = match &value("--pointer")[..] {
+                    "left" => PointerAlignment::Left,
+                    "right" => PointerAlignment::Right,
+                    "middle" => PointerAlignment::Middle,
+                    p => usage(&format!("unknown pointer alignment: {}", p)),
+                }
+            }
+            "--check" => mode = Mode::Check,
+            "-i" | "--in-place" => mode = Mode::InPlace,
+            _ if opt.starts_with('-') => usage(&format!("unknown option: {}", arg)),
+            _ => files.push(arg),
+        }
+    }
+
+    if files.is_empty() {
+        usage("input file required");
+    }
+
+    let mut failed = false;
+    for file in &files {
+        let source = match fs::read_to_string(file) {
+            Ok(s) => s,
+            Err(err) => {
+                eprintln!("{}: {}", file, err);
+                failed = true;
+                continue;
+            }
+        };
+
+        let formatted = match format(&config, &style, &source) {
+            Ok(s) => s,
+            Err(err) => {
+                eprintln!("{}: syntax error: {}", file, err);
+                failed = true;
+                continue;
+            }
+        };
+
+        match mode {
+            Mode::Print => print!("{}", formatted),
+            Mode::InPlace if formatted != source => {
+                if let Err(err) = fs::write(file, formatted) {
+                    eprintln!("{}: {}", file, err);
+                    failed = true;
+                }
+            }
+            Mode::InPlace => {}
+            Mode::Check if formatted != source => {
+                println!("{}", file);
+                failed = true;
+            }
+            Mode::Check => {}
+        }
+    }
+
+    if failed {
+        exit(1);
+    }
+}
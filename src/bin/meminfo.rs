@@ -20,6 +20,7 @@ fn main() {
         BinaryOperator
         OffsetDesignator
         OffsetMember
+        AmbiguousExpression
         Declaration
         DeclarationSpecifier
         InitDeclarator
@@ -49,10 +50,12 @@ fn main() {
         Label
         ForInitializer
         BlockItem
+        AmbiguousBlockItem
         TranslationUnit
         ExternalDeclaration
         FunctionDefinition
         Extension
+        VendorQualifier
         AsmStatement
         GnuAsmOperand
         TypeOf
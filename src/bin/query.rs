@@ -0,0 +1,125 @@
+//! Search C files for code matching a pattern.
+//!
+//! ```text
+//! query [--flavor std|gnu|clang] [--bindings] [cpp options] 'pattern' file.c...
+//! ```
+//!
+//! Each match is printed as `file:line:column: code`, with the position in the original file
+//! the code comes from, which may be a header. With `--bindings`, code bound to each
+//! metavariable of the pattern follows on separate lines. See `lang_c::query` for the pattern
+//! syntax.
+
+extern crate lang_c;
+
+use std::process::exit;
+
+use lang_c::driver::{Config, Flavor};
+use lang_c::line_index::LineIndex;
+use lang_c::query::Pattern;
+use lang_c::source_map::SourceMap;
+use lang_c::span::Span;
+
+fn usage(message: &str) -> ! {
+    println!("{}", message);
+    exit(1);
+}
+
+/// Matched code on a single line
+fn snippet(source: &str, span: Span) -> String {
+    let text = source[span.start..span.end].trim_end();
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn main() {
+    let mut flavor = None;
+    let mut cpp_options = Vec::new();
+    let mut bindings = false;
+    let mut pattern = None;
+    let mut files = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let (opt, inline) = match arg.find('=') {
+            Some(pos) if arg.starts_with("--") => {
+                (arg[..pos].to_owned(), Some(arg[pos + 1..].to_owned()))
+            }
+            _ => (arg.clone(), None),
+        };
+
+        match &opt[..] {
+            "--flavor" => {
+                flavor = match inline.or_else(|| args.next()).as_ref().map(|s| &s[..]) {
+                    Some("std") => Some(Flavor::StdC11),
+                    Some("gnu") => Some(Flavor::GnuC11),
+                    Some("clang") => Some(Flavor::ClangC11),
+                    _ => usage("--flavor requires std, gnu or clang"),
+                }
+            }
+            "--bindings" => bindings = true,
+            _ if opt.starts_with('-') => cpp_options.push(arg),
+            _ if pattern.is_none() => pattern = Some(arg),
+            _ => files.push(arg),
+        }
+    }
+
+    let pattern = match pattern {
+        Some(p) => p,
+        None => usage("pattern required"),
+    };
+    if files.is_empty() {
+        usage("input file required");
+    }
+
+    let mut config = match flavor {
+        Some(Flavor::ClangC11) => Config::with_clang(),
+        Some(Flavor::GnuC11) => Config::with_gcc(),
+        Some(Flavor::StdC11) => Config::default().flavor(Flavor::StdC11),
+        None => Config::default(),
+    };
+    config.cpp_options.extend(cpp_options);
+
+    let pattern = match Pattern::new(&config, &pattern) {
+        Ok(p) => p,
+        Err(err) => usage(&format!("invalid pattern: {}", err)),
+    };
+
+    let mut failed = false;
+    for file in &files {
+        let parse = match lang_c::driver::parse(&config, file) {
+            Ok(parse) => parse,
+            Err(err) => {
+                println!("{}: {}", file, err);
+                failed = true;
+                continue;
+            }
+        };
+
+        let source_map = SourceMap::new(&parse.source);
+        let lines = LineIndex::new(&parse.source);
+        for m in pattern.find(&parse.unit) {
+            let (name, line, column) = match source_map.location(m.span.start) {
+                Some(loc) => (source_map.file_name(loc.file), loc.line, loc.column),
+                None => {
+                    let pos = lines.line_col(m.span.start);
+                    (&file[..], pos.line, pos.column)
+                }
+            };
+            println!(
+                "{}:{}:{}: {}",
+                name,
+                line,
+                column,
+                snippet(&parse.source, m.span)
+            );
+            if bindings {
+                for (var, binding) in &m.bindings {
+                    println!("    ${} = {}", var, snippet(&parse.source, binding.span));
+                }
+            }
+        }
+    }
+
+    if failed {
+        exit(1);
+    }
+}
@@ -0,0 +1,326 @@
+//! Lossless concrete syntax tree
+//!
+//! ```rust
+//! # use lang_c::cst;
+//! # use lang_c::driver::Config;
+//! let source = "int x = f( 1 ); // one\n".to_string();
+//! let (_, tree) = cst::parse(&Config::default(), source.clone()).unwrap();
+//! assert_eq!(tree.to_string(), source);
+//!
+//! let call = tree.root.descendants().into_iter().find(|n| n.kind == "CallExpression").unwrap();
+//! let tokens: Vec<_> = call.tokens().iter().map(|t| tree.text(t.span)).collect();
+//! assert_eq!(tokens, ["f", "(", "1", ")"]);
+//! ```
+//!
+//! The abstract syntax tree drops whitespace, comments, preprocessor directives and tokens that
+//! carry no meaning after parsing, like parentheses and separators. A `SyntaxTree` keeps all of
+//! them: every byte of the source belongs to a token or to a trivia piece attached to one, and
+//! printing the tree gives back the source text unchanged.
+//!
+//! The syntax tree is built after parsing, by matching spans of AST nodes against the token
+//! stream. It is a view of the source next to the AST, not the parser's own output: the AST is
+//! parsed from the source text as usual and is not derived from the tree, and editing the tree
+//! does not change the AST. Tokens come from the same tokenizer `format` uses to find comments and
+//! directives, which only splits text and knows no grammar. Each `SyntaxNode` corresponds to an AST node with a non-empty span, and is named after
+//! its type (as in `visit_path::NodeRef::kind`). Tokens belong to the innermost node that covers
+//! them, so a node holds, in source order, its child nodes and the tokens not covered by any
+//! child, such as operators, keywords and punctuation.
+//!
+//! Trivia is attached to tokens: comments and whitespace up to the end of the line following a
+//! token are its trailing trivia, anything else is the leading trivia of the next token. Trivia
+//! after the last token is kept in `SyntaxTree::trailing`.
+
+use std::fmt;
+use std::mem;
+
+use ast::*;
+use driver::{parse_preprocessed, Config, SyntaxError};
+use lexer::{pieces, Piece};
+use span::Span;
+use visit::{self, Visit};
+
+/// Kind of a token
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TokenKind {
+    /// Identifier or keyword
+    Identifier,
+    /// Integer or floating point constant
+    Number,
+    /// Character constant
+    Character,
+    /// String literal
+    String,
+    /// Operator or punctuator, or a character that does not start any other token
+    Punctuator,
+}
+
+/// Kind of source text that is not a token
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TriviaKind {
+    /// Spaces and tabs, possibly followed by a single line break
+    Whitespace,
+    /// Block or line comment
+    Comment,
+    /// Preprocessor directive or line marker, without the final line break
+    Directive,
+}
+
+/// Piece of source text that is not a token
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+/// Token with attached trivia
+#[derive(Clone, Debug, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+    /// Trivia between the previous token's trailing trivia and this token
+    pub leading: Vec<Trivia>,
+    /// Trivia after this token up to and including the end of the line
+    pub trailing: Vec<Trivia>,
+}
+
+/// Child of a syntax node
+#[derive(Clone, Debug, PartialEq)]
+pub enum Element {
+    Node(SyntaxNode),
+    Token(Token),
+}
+
+/// Syntax tree node corresponding to an AST node
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyntaxNode {
+    /// Name of the AST node type
+    pub kind: &'static str,
+    /// Span of the AST node, not including trivia
+    pub span: Span,
+    /// Child nodes and tokens in source order
+    pub children: Vec<Element>,
+}
+
+impl SyntaxNode {
+    /// All nodes inside this one, in source order
+    pub fn descendants(&self) -> Vec<&SyntaxNode> {
+        let mut nodes = Vec::new();
+        self.collect(&mut nodes, &mut Vec::new());
+        nodes
+    }
+
+    /// All tokens inside this node, in source order
+    pub fn tokens(&self) -> Vec<&Token> {
+        let mut tokens = Vec::new();
+        self.collect(&mut Vec::new(), &mut tokens);
+        tokens
+    }
+
+    fn collect<'a>(&'a self, nodes: &mut Vec<&'a SyntaxNode>, tokens: &mut Vec<&'a Token>) {
+        for child in &self.children {
+            match *child {
+                Element::Node(ref n) => {
+                    nodes.push(n);
+                    n.collect(nodes, tokens);
+                }
+                Element::Token(ref t) => tokens.push(t),
+            }
+        }
+    }
+}
+
+/// Lossless syntax tree of a translation unit
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyntaxTree {
+    /// Source text the tree was built from
+    pub source: String,
+    /// Node of the translation unit, spanning the whole source
+    pub root: SyntaxNode,
+    /// Trivia after the last token
+    pub trailing: Vec<Trivia>,
+}
+
+impl SyntaxTree {
+    /// Build a syntax tree from the source text and the translation unit parsed from it
+    pub fn new(source: String, unit: &TranslationUnit) -> SyntaxTree {
+        let (tokens, trailing) = lex(&source);
+
+        let mut collector = Collector(Vec::new());
+        collector.visit_translation_unit(unit);
+        let mut nodes = collector.0;
+        nodes.retain(|&(_, span)| !span.is_none() && span.start < span.end);
+        nodes.sort_by(|a, b| a.1.start.cmp(&b.1.start).then(b.1.end.cmp(&a.1.end)));
+        let mut nodes = nodes.into_iter().peekable();
+
+        let root = SyntaxNode {
+            kind: "TranslationUnit",
+            span: Span::span(0, source.len()),
+            children: Vec::new(),
+        };
+        let mut stack = vec![root];
+        for token in tokens {
+            let pos = token.span.start;
+            while stack.len() > 1 && stack[stack.len() - 1].span.end <= pos {
+                close(&mut stack);
+            }
+            while let Some(&(kind, span)) = nodes.peek() {
+                if span.start > pos {
+                    break;
+                }
+                nodes.next();
+                if span.end <= pos {
+                    continue;
+                }
+                while stack.len() > 1 && stack[stack.len() - 1].span.end < span.end {
+                    close(&mut stack);
+                }
+                stack.push(SyntaxNode {
+                    kind,
+                    span,
+                    children: Vec::new(),
+                });
+            }
+            let top = stack.len() - 1;
+            stack[top].children.push(Element::Token(token));
+        }
+        while stack.len() > 1 {
+            close(&mut stack);
+        }
+
+        SyntaxTree {
+            root: stack.pop().expect("root node is never closed"),
+            source,
+            trailing,
+        }
+    }
+
+    /// Source text of a span
+    pub fn text(&self, span: Span) -> &str {
+        &self.source[span.start..span.end]
+    }
+
+    fn write_node(&self, fmt: &mut fmt::Formatter, node: &SyntaxNode) -> fmt::Result {
+        for child in &node.children {
+            match *child {
+                Element::Node(ref n) => self.write_node(fmt, n)?,
+                Element::Token(ref t) => {
+                    self.write_trivia(fmt, &t.leading)?;
+                    fmt.write_str(self.text(t.span))?;
+                    self.write_trivia(fmt, &t.trailing)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_trivia(&self, fmt: &mut fmt::Formatter, trivia: &[Trivia]) -> fmt::Result {
+        for t in trivia {
+            fmt.write_str(self.text(t.span))?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the original source text
+impl fmt::Display for SyntaxTree {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        self.write_node(fmt, &self.root)?;
+        self.write_trivia(fmt, &self.trailing)
+    }
+}
+
+/// Parse source and build its syntax tree
+///
+/// Source must be preprocessed, save for comments, which are skipped.
+pub fn parse(
+    config: &Config,
+    source: String,
+) -> Result<(TranslationUnit, SyntaxTree), SyntaxError> {
+    let mut code = source.clone().into_bytes();
+    for piece in pieces(&source) {
+        if let Piece::Trivia(Trivia {
+            kind: TriviaKind::Comment,
+            span,
+        }) = piece
+        {
+            for b in &mut code[span.start..span.end] {
+                if *b != b'\n' {
+                    *b = b' ';
+                }
+            }
+        }
+    }
+    let code = String::from_utf8(code).expect("only whole characters are blanked");
+
+    match parse_preprocessed(config, code) {
+        Ok(parse) => {
+            let tree = SyntaxTree::new(source, &parse.unit);
+            Ok((parse.unit, tree))
+        }
+        Err(err) => Err(SyntaxError { source, ..err }),
+    }
+}
+
+fn close(stack: &mut Vec<SyntaxNode>) {
+    let node = stack.pop().expect("stack is not empty");
+    let top = stack.len() - 1;
+    stack[top].children.push(Element::Node(node));
+}
+
+/// Records kinds and spans of all AST nodes
+struct Collector(Vec<(&'static str, Span)>);
+
+macro_rules! collect_nodes {
+    ($($ty:ident: $method:ident, $_method_mut:ident;)*) => {
+        impl<'ast> Visit<'ast> for Collector {
+            $(fn $method(&mut self, node: &'ast $ty, span: &'ast Span) {
+                self.0.push((stringify!($ty), *span));
+                visit::$method(self, node, span);
+            })*
+
+            fn visit_availability_clause(&mut self, _: &'ast AvailabilityClause, span: &'ast Span) {
+                self.0.push(("AvailabilityClause", *span));
+            }
+        }
+    };
+}
+
+for_each_node_type!(collect_nodes);
+
+/// Tokenize source, attaching trivia to tokens, and return trivia after the last token
+fn lex(source: &str) -> (Vec<Token>, Vec<Trivia>) {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut pending = Vec::new();
+    let mut trailing = false;
+
+    for piece in pieces(source) {
+        match piece {
+            Piece::Token(kind, span) => {
+                tokens.push(Token {
+                    kind,
+                    span,
+                    leading: mem::take(&mut pending),
+                    trailing: Vec::new(),
+                });
+                trailing = true;
+            }
+            Piece::Trivia(t) => {
+                let last = tokens
+                    .last_mut()
+                    .filter(|_| trailing && t.kind != TriviaKind::Directive);
+                match last {
+                    Some(token) => {
+                        trailing = !source[t.span.start..t.span.end].contains('\n');
+                        token.trailing.push(t);
+                    }
+                    None => {
+                        trailing = false;
+                        pending.push(t);
+                    }
+                }
+            }
+        }
+    }
+
+    (tokens, pending)
+}
@@ -1,17 +1,35 @@
 //! Preprocess and parse C source file into an abstract syntax tree
 
-use std::collections::HashSet;
+use std::collections::{hash_set, HashSet};
 use std::error;
 use std::fmt;
 use std::io;
+use std::iter::FromIterator;
 use std::path::Path;
 use std::process::Command;
 
-use ast::TranslationUnit;
+use ast::{Declaration, Expression, Identifier, Name, Statement, TranslationUnit, TypeName};
 use env::Env;
-use parser::translation_unit;
+use intern::Interner;
+use line_index::LineIndex;
+use parser::{self, translation_unit, ParseResult};
+use source_map::SourceMap;
+use span::Node;
 
 /// Parser configuration
+///
+/// Start from `Config::default()`, `with_gcc` or `with_clang`, then set fields or chain the
+/// builder methods:
+///
+/// ```rust
+/// # use lang_c::driver::{Config, Flavor};
+/// let config = Config::with_gcc()
+///     .cpp_command("arm-none-eabi-gcc")
+///     .cpp_option("-DNDEBUG")
+///     .flavor(Flavor::StdC11)
+///     .infer_typedefs(true);
+/// assert_eq!(config.cpp_options, ["-E", "-DNDEBUG"]);
+/// ```
 #[derive(Clone, Debug)]
 pub struct Config {
     /// Command used to invoke C preprocessor
@@ -20,6 +38,21 @@ pub struct Config {
     pub cpp_options: Vec<String>,
     /// Language flavor to parse
     pub flavor: Flavor,
+    /// Enable ISO/IEC TR 18037 embedded C extensions
+    pub embedded: bool,
+    /// Vendor-specific keywords to parse as qualifiers
+    pub vendor_keywords: Vec<VendorKeyword>,
+    /// Typedef names to treat as declared before the parsed code
+    pub typedefs: Typedefs,
+    /// Assume unknown identifiers are typedef names where only a type name would parse
+    ///
+    /// Meant for code whose headers are unavailable. Assumed names are reported in
+    /// `Parse::assumed_typedefs`, and statements such as `a * b;` that are also declarations
+    /// if an unknown `a` is a type are kept as `BlockItem::Ambiguous`. Likewise, expressions such
+    /// as `(a) -b` that are also casts are kept as `Expression::Ambiguous`.
+    pub infer_typedefs: bool,
+    /// Table identifiers are interned in, shared by all parses with this configuration
+    pub interner: Interner,
 }
 
 impl Config {
@@ -29,6 +62,11 @@ impl Config {
             cpp_command: "gcc".into(),
             cpp_options: vec!["-E".into()],
             flavor: Flavor::GnuC11,
+            embedded: false,
+            vendor_keywords: Vec::new(),
+            typedefs: Typedefs::new(),
+            infer_typedefs: false,
+            interner: Interner::new(),
         }
     }
 
@@ -38,8 +76,61 @@ impl Config {
             cpp_command: "clang".into(),
             cpp_options: vec!["-E".into()],
             flavor: Flavor::ClangC11,
+            embedded: false,
+            vendor_keywords: Vec::new(),
+            typedefs: Typedefs::new(),
+            infer_typedefs: false,
+            interner: Interner::new(),
         }
     }
+
+    /// Invoke the given command as the preprocessor
+    pub fn cpp_command<S: Into<String>>(mut self, command: S) -> Config {
+        self.cpp_command = command.into();
+        self
+    }
+
+    /// Pass another option to the preprocessor
+    pub fn cpp_option<S: Into<String>>(mut self, option: S) -> Config {
+        self.cpp_options.push(option.into());
+        self
+    }
+
+    /// Parse the given language flavor
+    pub fn flavor(mut self, flavor: Flavor) -> Config {
+        self.flavor = flavor;
+        self
+    }
+
+    /// Enable or disable ISO/IEC TR 18037 embedded C extensions
+    pub fn embedded(mut self, embedded: bool) -> Config {
+        self.embedded = embedded;
+        self
+    }
+
+    /// Parse a vendor-specific keyword as a qualifier
+    pub fn vendor_keyword(mut self, keyword: VendorKeyword) -> Config {
+        self.vendor_keywords.push(keyword);
+        self
+    }
+
+    /// Treat the given typedef names as declared before the parsed code
+    pub fn typedefs(mut self, typedefs: Typedefs) -> Config {
+        self.typedefs = typedefs;
+        self
+    }
+
+    /// Enable or disable inference of unknown typedef names
+    pub fn infer_typedefs(mut self, infer_typedefs: bool) -> Config {
+        self.infer_typedefs = infer_typedefs;
+        self
+    }
+
+    /// Intern identifiers in the given table, to share names with other configurations
+    pub fn interner(mut self, interner: Interner) -> Config {
+        self.interner = interner;
+        self
+    }
 }
 
 impl Default for Config {
@@ -65,6 +156,127 @@ pub enum Flavor {
     ClangC11,
 }
 
+/// Vendor-specific qualifier keyword
+///
+/// Embedded compilers accept keywords like `__far`, `__near` or `__interrupt` wherever a type
+/// qualifier may appear, and after a declarator. These are parsed into
+/// `ast::Extension::VendorQualifier` nodes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VendorKeyword {
+    /// Spelling of the keyword
+    pub name: String,
+    /// Keyword is followed by a parenthesized argument list, like `__at(0x20)`
+    pub arguments: bool,
+}
+
+impl VendorKeyword {
+    /// Keyword without arguments
+    pub fn new<S: Into<String>>(name: S) -> VendorKeyword {
+        VendorKeyword {
+            name: name.into(),
+            arguments: false,
+        }
+    }
+
+    /// Keyword followed by a parenthesized argument list
+    pub fn with_arguments<S: Into<String>>(name: S) -> VendorKeyword {
+        VendorKeyword {
+            name: name.into(),
+            arguments: true,
+        }
+    }
+}
+
+/// Set of typedef names declared at file scope
+///
+/// Code that uses typedef names parses only if their declarations are known. Names given in
+/// `Config::typedefs` are treated as if declared by a header included before the parsed code,
+/// and `Parse::typedefs` holds the names known at the end of a parse. Together they let a
+/// header be parsed once and reused for many files that include it:
+///
+/// ```rust
+/// # use lang_c::driver::{parse_preprocessed, Config};
+/// let header = "typedef unsigned long size_t; typedef struct file FILE;";
+/// let mut config = Config::default();
+/// config.typedefs = parse_preprocessed(&config, header.to_string()).unwrap().typedefs;
+/// assert!(config.typedefs.contains("FILE"));
+///
+/// let parse = parse_preprocessed(&config, "size_t n; FILE *f;".to_string());
+/// assert!(parse.is_ok());
+/// ```
+///
+/// Typedef names built into a flavor, like `__builtin_va_list`, are known without being listed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Typedefs {
+    names: HashSet<Name>,
+}
+
+impl Typedefs {
+    pub fn new() -> Typedefs {
+        Typedefs::default()
+    }
+
+    /// Add a name, returns `false` if it was already present
+    pub fn insert(&mut self, name: &str) -> bool {
+        self.names.insert(Name::new(name))
+    }
+
+    /// Remove a name, returns `false` if it was not present
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.names.remove(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Names in arbitrary order
+    pub fn iter(&self) -> Iter<'_> {
+        Iter(self.names.iter())
+    }
+}
+
+impl<'a> Extend<&'a str> for Typedefs {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, names: I) {
+        self.names.extend(names.into_iter().map(Name::new))
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Typedefs {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(names: I) -> Typedefs {
+        let mut typedefs = Typedefs::new();
+        typedefs.extend(names);
+        typedefs
+    }
+}
+
+impl FromIterator<Name> for Typedefs {
+    fn from_iter<I: IntoIterator<Item = Name>>(names: I) -> Typedefs {
+        Typedefs {
+            names: names.into_iter().collect(),
+        }
+    }
+}
+
+/// Iterator over names of `Typedefs`
+pub struct Iter<'a>(hash_set::Iter<'a, Name>);
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.0.next().map(|name| name.as_str())
+    }
+}
+
 /// Result of a successful parse
 #[derive(Clone, Debug)]
 pub struct Parse {
@@ -72,6 +284,23 @@ pub struct Parse {
     pub source: String,
     /// Root of the abstract syntax tree
     pub unit: TranslationUnit,
+    /// Typedef names known at the end of the source, including the ones from `Config::typedefs`
+    /// and the assumed ones
+    pub typedefs: Typedefs,
+    /// Identifiers assumed to be typedef names with `Config::infer_typedefs`, at their first use
+    pub assumed_typedefs: Vec<Node<Identifier>>,
+}
+
+impl Parse {
+    /// Index for converting spans into lines and columns of the source
+    pub fn line_index(&self) -> LineIndex<'_> {
+        LineIndex::new(&self.source)
+    }
+
+    /// Map from spans to the files the source was preprocessed from
+    pub fn source_map(&self) -> SourceMap<'_> {
+        SourceMap::new(&self.source)
+    }
 }
 
 #[derive(Debug)]
@@ -89,18 +318,18 @@ impl From<SyntaxError> for Error {
 
 impl fmt::Display for Error {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            &Error::PreprocessorError(ref e) => write!(fmt, "preprocessor error: {}", e),
-            &Error::SyntaxError(ref e) => write!(fmt, "syntax error: {}", e),
+        match *self {
+            Error::PreprocessorError(ref e) => write!(fmt, "preprocessor error: {}", e),
+            Error::SyntaxError(ref e) => write!(fmt, "syntax error: {}", e),
         }
     }
 }
 
 impl error::Error for Error {
     fn description(&self) -> &str {
-        match self {
-            &Error::PreprocessorError(_) => "preprocessor error",
-            &Error::SyntaxError(_) => "syntax error",
+        match *self {
+            Error::PreprocessorError(_) => "preprocessor error",
+            Error::SyntaxError(_) => "syntax error",
         }
     }
 }
@@ -158,19 +387,17 @@ pub fn parse<P: AsRef<Path>>(config: &Config, source: P) -> Result<Parse, Error>
 }
 
 pub fn parse_preprocessed(config: &Config, source: String) -> Result<Parse, SyntaxError> {
-    let mut env = match config.flavor {
-        Flavor::StdC11 => Env::with_core(),
-        Flavor::GnuC11 => Env::with_gnu(),
-        Flavor::ClangC11 => Env::with_clang(),
-    };
+    let mut env = Env::with_config(config);
 
     match translation_unit(&source, &mut env) {
         Ok(unit) => Ok(Parse {
-            source: source,
-            unit: unit,
+            source,
+            unit,
+            typedefs: env.typedefs(),
+            assumed_typedefs: env.assumed_typedefs(),
         }),
         Err(err) => Err(SyntaxError {
-            source: source,
+            source,
             line: err.line,
             column: err.column,
             offset: err.offset,
@@ -179,6 +406,53 @@ pub fn parse_preprocessed(config: &Config, source: String) -> Result<Parse, Synt
     }
 }
 
+/// Parse a single expression, such as `a[i] + 1`
+///
+/// The flavor, extensions and typedef names of `config` apply, as if the typedefs were declared
+/// by a header. Unlike translation units, fragments are not preprocessed, and spans are byte
+/// offsets in `source`. Whitespace and comments around the fragment are allowed.
+pub fn parse_expression(
+    config: &Config,
+    source: &str,
+) -> Result<Box<Node<Expression>>, SyntaxError> {
+    parse_fragment(config, source, parser::expression_fragment)
+}
+
+/// Parse a single statement, such as `if (x) return;`
+///
+/// See `parse_expression` for the use of `config`.
+pub fn parse_statement(config: &Config, source: &str) -> Result<Box<Node<Statement>>, SyntaxError> {
+    parse_fragment(config, source, parser::statement_fragment)
+}
+
+/// Parse a single declaration, such as `size_t n = 0;`
+///
+/// See `parse_expression` for the use of `config`.
+pub fn parse_declaration(config: &Config, source: &str) -> Result<Node<Declaration>, SyntaxError> {
+    parse_fragment(config, source, parser::declaration_fragment)
+}
+
+/// Parse a type name, such as `const char *[4]`
+///
+/// See `parse_expression` for the use of `config`.
+pub fn parse_type_name(config: &Config, source: &str) -> Result<Node<TypeName>, SyntaxError> {
+    parse_fragment(config, source, parser::type_name_fragment)
+}
+
+fn parse_fragment<T, F>(config: &Config, source: &str, parse: F) -> Result<T, SyntaxError>
+where
+    F: FnOnce(&str, &mut Env) -> ParseResult<T>,
+{
+    let mut env = Env::with_config(config);
+    parse(source, &mut env).map_err(|err| SyntaxError {
+        source: source.to_owned(),
+        line: err.line,
+        column: err.column,
+        offset: err.offset,
+        expected: err.expected,
+    })
+}
+
 fn preprocess(config: &Config, source: &Path) -> io::Result<String> {
     let mut cmd = Command::new(&config.cpp_command);
 
@@ -193,15 +467,12 @@ fn preprocess(config: &Config, source: &Path) -> io::Result<String> {
     if output.status.success() {
         match String::from_utf8(output.stdout) {
             Ok(s) => Ok(s),
-            Err(e) => Err(io::Error::new(io::ErrorKind::Other, e)),
+            Err(e) => Err(io::Error::other(e)),
         }
     } else {
         match String::from_utf8(output.stderr) {
-            Ok(s) => Err(io::Error::new(io::ErrorKind::Other, s)),
-            Err(_) => Err(io::Error::new(
-                io::ErrorKind::Other,
-                "cpp error contains invalid utf-8",
-            )),
+            Ok(s) => Err(io::Error::other(s)),
+            Err(_) => Err(io::Error::other("cpp error contains invalid utf-8")),
         }
     }
 }
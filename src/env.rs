@@ -1,20 +1,60 @@
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
 use ast::*;
-use span::Node;
+use astutil::decode_ucn;
+use driver::{Config, Flavor, Typedefs};
+use intern::Interner;
+use span::{Node, Span};
 use strings;
 
-#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Symbol {
     Typename,
     Identifier,
 }
 
 pub struct Env {
-    symbols: Vec<HashMap<String, Symbol>>,
+    symbols: Vec<HashMap<Name, Symbol>>,
     pub extensions_gnu: bool,
     pub extensions_clang: bool,
+    pub extensions_embedded: bool,
     pub reserved: HashSet<&'static str>,
+    pub vendor_keywords: HashMap<String, bool>,
+    pub infer_typedefs: bool,
+    /// Offset of the parsed text in the source, added to all spans
+    pub offset: usize,
+    inference: Vec<bool>,
+    assumed: Vec<Node<Identifier>>,
+    cast_guesses: Vec<usize>,
+    casts: Vec<Node<Expression>>,
+    names: Interner,
+    attempts: Vec<Attempt>,
+    journal: Vec<Change>,
+    state: usize,
+    states: HashMap<(usize, Transition), usize>,
+    failed_blocks: HashSet<(usize, usize, bool)>,
+}
+
+/// Point to return to if an alternative of the grammar fails
+struct Attempt {
+    journal: usize,
+    assumed: usize,
+    state: usize,
+}
+
+/// Symbol table entry replaced during an attempt
+struct Change {
+    scope: usize,
+    name: Name,
+    previous: Option<Symbol>,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+enum Transition {
+    Enter,
+    Leave,
+    Insert(usize, Name, Symbol),
 }
 
 impl Env {
@@ -30,28 +70,62 @@ impl Env {
             extensions_gnu: false,
             extensions_clang: false,
             symbols: vec![HashMap::default()],
-            reserved: reserved,
+            extensions_embedded: false,
+            reserved,
+            vendor_keywords: HashMap::default(),
+            infer_typedefs: false,
+            offset: 0,
+            inference: Vec::new(),
+            assumed: Vec::new(),
+            cast_guesses: Vec::new(),
+            casts: Vec::new(),
+            attempts: Vec::new(),
+            journal: Vec::new(),
+            state: 0,
+            states: HashMap::new(),
+            failed_blocks: HashSet::new(),
+            names: Interner::new(),
         }
     }
 
     pub fn with_gnu() -> Env {
+        let names = Interner::new();
         let mut symbols = HashMap::default();
         let mut reserved = HashSet::default();
-        symbols.insert("__builtin_va_list".to_owned(), Symbol::Typename);
+        for name in strings::TYPEDEFS_GNU {
+            symbols.insert(names.intern(name), Symbol::Typename);
+        }
         reserved.extend(strings::RESERVED_C11.iter());
         reserved.extend(strings::RESERVED_GNU.iter());
         Env {
             extensions_gnu: true,
             extensions_clang: false,
             symbols: vec![symbols],
-            reserved: reserved,
+            extensions_embedded: false,
+            reserved,
+            vendor_keywords: HashMap::default(),
+            infer_typedefs: false,
+            offset: 0,
+            inference: Vec::new(),
+            assumed: Vec::new(),
+            cast_guesses: Vec::new(),
+            casts: Vec::new(),
+            attempts: Vec::new(),
+            journal: Vec::new(),
+            state: 0,
+            states: HashMap::new(),
+            failed_blocks: HashSet::new(),
+            names,
         }
     }
 
     pub fn with_clang() -> Env {
+        let names = Interner::new();
         let mut symbols = HashMap::default();
         let mut reserved = HashSet::default();
-        symbols.insert("__builtin_va_list".to_owned(), Symbol::Typename);
+        for name in strings::TYPEDEFS_GNU {
+            symbols.insert(names.intern(name), Symbol::Typename);
+        }
         reserved.extend(strings::RESERVED_C11.iter());
         reserved.extend(strings::RESERVED_GNU.iter());
         reserved.extend(strings::RESERVED_CLANG.iter());
@@ -59,27 +133,256 @@ impl Env {
             extensions_gnu: true,
             extensions_clang: true,
             symbols: vec![symbols],
-            reserved: reserved,
+            extensions_embedded: false,
+            reserved,
+            vendor_keywords: HashMap::default(),
+            infer_typedefs: false,
+            offset: 0,
+            inference: Vec::new(),
+            assumed: Vec::new(),
+            cast_guesses: Vec::new(),
+            casts: Vec::new(),
+            attempts: Vec::new(),
+            journal: Vec::new(),
+            state: 0,
+            states: HashMap::new(),
+            failed_blocks: HashSet::new(),
+            names,
+        }
+    }
+
+    /// Environment for a language flavor
+    pub fn with_flavor(flavor: Flavor) -> Env {
+        match flavor {
+            Flavor::StdC11 => Env::with_core(),
+            Flavor::GnuC11 => Env::with_gnu(),
+            Flavor::ClangC11 => Env::with_clang(),
+        }
+    }
+
+    /// Environment for the flavor and extensions selected in `config`
+    pub fn with_config(config: &Config) -> Env {
+        let mut env = Env::with_flavor(config.flavor);
+        env.use_interner(config.interner.clone());
+
+        if config.embedded {
+            env.enable_embedded();
+        }
+
+        for keyword in &config.vendor_keywords {
+            env.add_vendor_keyword(&keyword.name, keyword.arguments);
+        }
+
+        for name in config.typedefs.iter() {
+            env.add_typename(name);
+        }
+
+        env.infer_typedefs = config.infer_typedefs;
+
+        env
+    }
+
+    pub fn enable_embedded(&mut self) {
+        self.extensions_embedded = true;
+        self.reserved.extend(strings::RESERVED_EMBEDDED.iter());
+    }
+
+    pub fn add_vendor_keyword(&mut self, name: &str, arguments: bool) {
+        self.vendor_keywords.insert(name.to_owned(), arguments);
+    }
+
+    /// Intern names in the given table from now on, including the predeclared ones
+    pub fn use_interner(&mut self, names: Interner) {
+        for scope in &mut self.symbols {
+            *scope = scope.drain().map(|(k, v)| (names.intern(&k), v)).collect();
         }
+        self.names = names;
+    }
+
+    /// Name shared with other identifiers interned in the same table
+    pub fn intern(&mut self, text: &str) -> Name {
+        self.names.intern(text)
+    }
+
+    pub fn span(&self, start: usize, end: usize) -> Span {
+        Span::span(self.offset + start, self.offset + end)
+    }
+
+    pub fn is_reserved(&self, ident: &str) -> bool {
+        self.reserved.contains(ident) || self.vendor_keywords.contains_key(ident)
     }
 
     pub fn enter_scope(&mut self) {
         self.symbols.push(HashMap::new());
+        self.advance(Transition::Enter);
     }
 
     pub fn leave_scope(&mut self) {
         self.symbols.pop().expect("more scope pops than pushes");
+        self.advance(Transition::Leave);
+    }
+
+    /// Record changes to the symbol table until the matching `end_attempt`
+    pub fn begin_attempt(&mut self) {
+        if self.attempts.is_empty() {
+            self.failed_blocks.clear();
+        }
+        self.attempts.push(Attempt {
+            journal: self.journal.len(),
+            assumed: self.assumed.len(),
+            state: self.state,
+        });
+    }
+
+    /// Keep the changes made since the matching `begin_attempt`, or undo them if it failed
+    pub fn end_attempt(&mut self, success: bool) {
+        let attempt = self.attempts.pop().expect("more attempt ends than begins");
+        if !success {
+            // Scopes entered during the attempt are gone already
+            for change in self.journal.drain(attempt.journal..).rev() {
+                if let Some(scope) = self.symbols.get_mut(change.scope) {
+                    match change.previous {
+                        Some(symbol) => scope.insert(change.name, symbol),
+                        None => scope.remove(&change.name),
+                    };
+                }
+            }
+            self.assumed.truncate(attempt.assumed);
+            self.state = attempt.state;
+        }
+        if self.attempts.is_empty() {
+            self.journal.clear();
+        }
+    }
+
+    // Equal states are reached only by the same changes to the symbol table
+    fn advance(&mut self, transition: Transition) {
+        let next = self.states.len() + 1;
+        self.state = *self.states.entry((self.state, transition)).or_insert(next);
+    }
+
+    fn insert_symbol(&mut self, scope: usize, name: Name, symbol: Symbol) {
+        let previous = self.symbols[scope].insert(name.clone(), symbol);
+        if !self.attempts.is_empty() {
+            self.journal.push(Change {
+                scope,
+                name: name.clone(),
+                previous,
+            });
+        }
+        self.advance(Transition::Insert(scope, name, symbol));
+    }
+
+    /// Key of the compound statement at `position`, unless it is known to fail there
+    pub fn block_key(&self, position: usize) -> Option<(usize, usize, bool)> {
+        let key = (
+            self.offset + position,
+            self.state,
+            self.cast_guesses.is_empty(),
+        );
+        if self.failed_blocks.contains(&key) {
+            None
+        } else {
+            Some(key)
+        }
+    }
+
+    /// Fail the compound statement at once when it is parsed again in the same state
+    pub fn fail_block(&mut self, key: (usize, usize, bool)) {
+        if !self.attempts.is_empty() {
+            self.failed_blocks.insert(key);
+        }
     }
 
     pub fn is_typename(&self, ident: &str) -> bool {
+        let ident = normalize(ident);
         for scope in self.symbols.iter().rev() {
-            if let Some(symbol) = scope.get(ident) {
+            if let Some(symbol) = scope.get(&*ident) {
                 return *symbol == Symbol::Typename;
             }
         }
         false
     }
 
+    fn is_declared(&self, ident: &str) -> bool {
+        let ident = normalize(ident);
+        self.symbols.iter().any(|scope| scope.contains_key(&*ident))
+    }
+
+    /// Identifier neither declared nor known to be a type name, that may be assumed to be one
+    pub fn is_unknown(&self, ident: &str) -> bool {
+        self.infer_typedefs && !self.is_declared(ident)
+    }
+
+    /// Unknown identifier that may start a cast reading, which is never split again
+    pub fn can_guess_cast(&self, ident: &str) -> bool {
+        self.cast_guesses.is_empty() && self.is_unknown(ident)
+    }
+
+    /// Allow or forbid typedef name inference until the matching `pop_inference`
+    pub fn push_inference(&mut self, enabled: bool) {
+        self.inference.push(enabled);
+    }
+
+    pub fn pop_inference(&mut self) {
+        self.inference
+            .pop()
+            .expect("more inference pops than pushes");
+    }
+
+    /// Unknown identifiers may be parsed as typedef names at this point
+    pub fn is_inferring(&self) -> bool {
+        self.infer_typedefs && self.inference.last() == Some(&true)
+    }
+
+    pub fn can_infer(&self, ident: &str) -> bool {
+        self.is_inferring() && !self.is_declared(ident)
+    }
+
+    /// Read the unknown identifier at `offset` as a typedef name until the matching
+    /// `pop_cast_guess`
+    pub fn push_cast_guess(&mut self, offset: usize) {
+        self.cast_guesses.push(offset);
+    }
+
+    pub fn pop_cast_guess(&mut self) {
+        self.cast_guesses
+            .pop()
+            .expect("more cast guess pops than pushes");
+    }
+
+    pub fn is_cast_guess(&self, offset: usize) -> bool {
+        self.cast_guesses.last() == Some(&offset)
+    }
+
+    /// Keep the cast reading of an ambiguous expression while the other reading is parsed
+    pub fn stash_cast(&mut self, cast: Node<Expression>) {
+        self.casts.push(cast);
+    }
+
+    pub fn take_cast(&mut self) -> Node<Expression> {
+        self.casts.pop().expect("more cast takes than stashes")
+    }
+
+    /// Declare inferred typedef names for the rest of the translation unit
+    pub fn assume_typedefs(&mut self, names: Vec<Node<Identifier>>) {
+        for name in names {
+            if !self.is_typename(&name.node.name) {
+                let ident = self.names.intern(&normalize(&name.node.name));
+                self.insert_symbol(0, ident, Symbol::Typename);
+                self.assumed.push(name);
+            }
+        }
+    }
+
+    /// Identifiers assumed to be typedef names, at their first use
+    pub fn assumed_typedefs(&self) -> Vec<Node<Identifier>> {
+        // Function definitions are committed after the declarations in their body
+        let mut assumed = self.assumed.clone();
+        assumed.sort_by_key(|name| name.span.start);
+        assumed
+    }
+
     pub fn handle_declarator(&mut self, d: &Node<Declarator>, sym: Symbol) {
         if let Some(name) = find_declarator_name(&d.node.kind.node) {
             self.add_symbol(name, sym)
@@ -87,23 +390,39 @@ impl Env {
     }
 
     pub fn add_symbol(&mut self, s: &str, symbol: Symbol) {
-        let scope = self
-            .symbols
-            .last_mut()
-            .expect("at least one scope should be always present");
-        scope.insert(s.to_string(), symbol);
+        let name = self.names.intern(&normalize(s));
+        let scope = self.symbols.len() - 1;
+        self.insert_symbol(scope, name, symbol);
     }
 
-    #[cfg(test)]
     pub fn add_typename(&mut self, s: &str) {
         self.add_symbol(s, Symbol::Typename)
     }
+
+    /// Typedef names declared in the outermost scope, except for the predeclared ones
+    pub fn typedefs(&self) -> Typedefs {
+        let predeclared = if self.extensions_gnu {
+            strings::TYPEDEFS_GNU
+        } else {
+            &[]
+        };
+        let symbols = self.symbols[0].iter();
+        let names = symbols.filter(|(name, symbol)| {
+            **symbol == Symbol::Typename && !predeclared.contains(&name.as_str())
+        });
+        names.map(|(name, _)| name.clone()).collect()
+    }
+}
+
+// Identifiers spelled with and without universal character names refer to the same symbol
+fn normalize(ident: &str) -> Cow<'_, str> {
+    decode_ucn(ident).unwrap_or(Cow::Borrowed(ident))
 }
 
 fn find_declarator_name(d: &DeclaratorKind) -> Option<&str> {
-    match d {
-        &DeclaratorKind::Abstract => None,
-        &DeclaratorKind::Identifier(ref i) => Some(&i.node.name),
-        &DeclaratorKind::Declarator(ref d) => find_declarator_name(&d.node.kind.node),
+    match *d {
+        DeclaratorKind::Abstract => None,
+        DeclaratorKind::Identifier(ref i) => Some(i.node.name.as_str()),
+        DeclaratorKind::Declarator(ref d) => find_declarator_name(&d.node.kind.node),
     }
 }
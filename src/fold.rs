@@ -0,0 +1,1854 @@
+//! Recursive abstract syntax tree transformation
+//!
+//! ```rust
+//! # use lang_c::{ast, fold, span};
+//! struct NegateConstants;
+//!
+//! impl fold::Fold for NegateConstants {
+//!     fn fold_expression(&mut self, expr: ast::Expression, span: span::Span) -> ast::Expression {
+//!         let expr = fold::fold_expression(self, expr, span);
+//!         match expr {
+//!             ast::Expression::Constant(_) => {
+//!                 ast::Expression::UnaryOperator(Box::new(span::Node::new(
+//!                     ast::UnaryOperatorExpression {
+//!                         operator: span::Node::new(ast::UnaryOperator::Minus, span),
+//!                         operand: Box::new(span::Node::new(expr, span)),
+//!                     },
+//!                     span,
+//!                 )))
+//!             }
+//!             expr => expr,
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The `Fold` trait is a collection of hooks, one for each type of node in the AST, much like
+//! `visit::Visit`. Hooks take the node by value and return its replacement, which may be a node
+//! of a different kind. Default implementations rebuild the node from its folded sub-nodes (by
+//! calling a corresponding free function in this module), so a pass needs to override only the
+//! hooks for nodes it changes.
+//!
+//! Sub-nodes keep their spans and identifiers, the span passed to a hook is that of the node being
+//! folded.
+
+use ast::*;
+use span::{Node, Span};
+
+pub trait Fold {
+    fn fold_identifier(&mut self, identifier: Identifier, span: Span) -> Identifier {
+        fold_identifier(self, identifier, span)
+    }
+
+    fn fold_constant(&mut self, constant: Constant, span: Span) -> Constant {
+        fold_constant(self, constant, span)
+    }
+
+    fn fold_integer(&mut self, integer: Integer, span: Span) -> Integer {
+        fold_integer(self, integer, span)
+    }
+
+    fn fold_integer_base(&mut self, integer_base: IntegerBase, span: Span) -> IntegerBase {
+        fold_integer_base(self, integer_base, span)
+    }
+
+    fn fold_integer_suffix(&mut self, integer_suffix: IntegerSuffix, span: Span) -> IntegerSuffix {
+        fold_integer_suffix(self, integer_suffix, span)
+    }
+
+    fn fold_integer_size(&mut self, integer_size: IntegerSize, span: Span) -> IntegerSize {
+        fold_integer_size(self, integer_size, span)
+    }
+
+    fn fold_float(&mut self, float: Float, span: Span) -> Float {
+        fold_float(self, float, span)
+    }
+
+    fn fold_float_base(&mut self, float_base: FloatBase, span: Span) -> FloatBase {
+        fold_float_base(self, float_base, span)
+    }
+
+    fn fold_float_suffix(&mut self, float_suffix: FloatSuffix, span: Span) -> FloatSuffix {
+        fold_float_suffix(self, float_suffix, span)
+    }
+
+    fn fold_float_format(&mut self, float_format: FloatFormat, span: Span) -> FloatFormat {
+        fold_float_format(self, float_format, span)
+    }
+
+    fn fold_string_literal(&mut self, string_literal: StringLiteral, span: Span) -> StringLiteral {
+        fold_string_literal(self, string_literal, span)
+    }
+
+    fn fold_expression(&mut self, expression: Expression, span: Span) -> Expression {
+        fold_expression(self, expression, span)
+    }
+
+    fn fold_member_operator(
+        &mut self,
+        member_operator: MemberOperator,
+        span: Span,
+    ) -> MemberOperator {
+        fold_member_operator(self, member_operator, span)
+    }
+
+    fn fold_generic_selection(
+        &mut self,
+        generic_selection: GenericSelection,
+        span: Span,
+    ) -> GenericSelection {
+        fold_generic_selection(self, generic_selection, span)
+    }
+
+    fn fold_generic_association(
+        &mut self,
+        generic_association: GenericAssociation,
+        span: Span,
+    ) -> GenericAssociation {
+        fold_generic_association(self, generic_association, span)
+    }
+
+    fn fold_generic_association_type(
+        &mut self,
+        generic_association_type: GenericAssociationType,
+        span: Span,
+    ) -> GenericAssociationType {
+        fold_generic_association_type(self, generic_association_type, span)
+    }
+
+    fn fold_member_expression(
+        &mut self,
+        member_expression: MemberExpression,
+        span: Span,
+    ) -> MemberExpression {
+        fold_member_expression(self, member_expression, span)
+    }
+
+    fn fold_call_expression(
+        &mut self,
+        call_expression: CallExpression,
+        span: Span,
+    ) -> CallExpression {
+        fold_call_expression(self, call_expression, span)
+    }
+
+    fn fold_compound_literal(
+        &mut self,
+        compound_literal: CompoundLiteral,
+        span: Span,
+    ) -> CompoundLiteral {
+        fold_compound_literal(self, compound_literal, span)
+    }
+
+    fn fold_unary_operator(&mut self, unary_operator: UnaryOperator, span: Span) -> UnaryOperator {
+        fold_unary_operator(self, unary_operator, span)
+    }
+
+    fn fold_unary_operator_expression(
+        &mut self,
+        unary_operator_expression: UnaryOperatorExpression,
+        span: Span,
+    ) -> UnaryOperatorExpression {
+        fold_unary_operator_expression(self, unary_operator_expression, span)
+    }
+
+    fn fold_cast_expression(
+        &mut self,
+        cast_expression: CastExpression,
+        span: Span,
+    ) -> CastExpression {
+        fold_cast_expression(self, cast_expression, span)
+    }
+
+    fn fold_binary_operator(
+        &mut self,
+        binary_operator: BinaryOperator,
+        span: Span,
+    ) -> BinaryOperator {
+        fold_binary_operator(self, binary_operator, span)
+    }
+
+    fn fold_binary_operator_expression(
+        &mut self,
+        binary_operator_expression: BinaryOperatorExpression,
+        span: Span,
+    ) -> BinaryOperatorExpression {
+        fold_binary_operator_expression(self, binary_operator_expression, span)
+    }
+
+    fn fold_conditional_expression(
+        &mut self,
+        conditional_expression: ConditionalExpression,
+        span: Span,
+    ) -> ConditionalExpression {
+        fold_conditional_expression(self, conditional_expression, span)
+    }
+
+    fn fold_va_arg_expression(
+        &mut self,
+        va_arg_expression: VaArgExpression,
+        span: Span,
+    ) -> VaArgExpression {
+        fold_va_arg_expression(self, va_arg_expression, span)
+    }
+
+    fn fold_offset_of_expression(
+        &mut self,
+        offset_of_expression: OffsetOfExpression,
+        span: Span,
+    ) -> OffsetOfExpression {
+        fold_offset_of_expression(self, offset_of_expression, span)
+    }
+
+    fn fold_offset_designator(
+        &mut self,
+        offset_designator: OffsetDesignator,
+        span: Span,
+    ) -> OffsetDesignator {
+        fold_offset_designator(self, offset_designator, span)
+    }
+
+    fn fold_offset_member(&mut self, offset_member: OffsetMember, span: Span) -> OffsetMember {
+        fold_offset_member(self, offset_member, span)
+    }
+
+    fn fold_ambiguous_expression(
+        &mut self,
+        ambiguous_expression: AmbiguousExpression,
+        span: Span,
+    ) -> AmbiguousExpression {
+        fold_ambiguous_expression(self, ambiguous_expression, span)
+    }
+
+    fn fold_declaration(&mut self, declaration: Declaration, span: Span) -> Declaration {
+        fold_declaration(self, declaration, span)
+    }
+
+    fn fold_declaration_specifier(
+        &mut self,
+        declaration_specifier: DeclarationSpecifier,
+        span: Span,
+    ) -> DeclarationSpecifier {
+        fold_declaration_specifier(self, declaration_specifier, span)
+    }
+
+    fn fold_init_declarator(
+        &mut self,
+        init_declarator: InitDeclarator,
+        span: Span,
+    ) -> InitDeclarator {
+        fold_init_declarator(self, init_declarator, span)
+    }
+
+    fn fold_storage_class_specifier(
+        &mut self,
+        storage_class_specifier: StorageClassSpecifier,
+        span: Span,
+    ) -> StorageClassSpecifier {
+        fold_storage_class_specifier(self, storage_class_specifier, span)
+    }
+
+    fn fold_type_specifier(&mut self, type_specifier: TypeSpecifier, span: Span) -> TypeSpecifier {
+        fold_type_specifier(self, type_specifier, span)
+    }
+
+    fn fold_ts18661_float_type(
+        &mut self,
+        ts18661_float_type: TS18661FloatType,
+        span: Span,
+    ) -> TS18661FloatType {
+        fold_ts18661_float_type(self, ts18661_float_type, span)
+    }
+
+    fn fold_ts18661_float_format(
+        &mut self,
+        ts18661_float_format: TS18661FloatFormat,
+        span: Span,
+    ) -> TS18661FloatFormat {
+        fold_ts18661_float_format(self, ts18661_float_format, span)
+    }
+
+    fn fold_struct_type(&mut self, struct_type: StructType, span: Span) -> StructType {
+        fold_struct_type(self, struct_type, span)
+    }
+
+    fn fold_struct_kind(&mut self, struct_kind: StructKind, span: Span) -> StructKind {
+        fold_struct_kind(self, struct_kind, span)
+    }
+
+    fn fold_struct_declaration(
+        &mut self,
+        struct_declaration: StructDeclaration,
+        span: Span,
+    ) -> StructDeclaration {
+        fold_struct_declaration(self, struct_declaration, span)
+    }
+
+    fn fold_struct_field(&mut self, struct_field: StructField, span: Span) -> StructField {
+        fold_struct_field(self, struct_field, span)
+    }
+
+    fn fold_specifier_qualifier(
+        &mut self,
+        specifier_qualifier: SpecifierQualifier,
+        span: Span,
+    ) -> SpecifierQualifier {
+        fold_specifier_qualifier(self, specifier_qualifier, span)
+    }
+
+    fn fold_struct_declarator(
+        &mut self,
+        struct_declarator: StructDeclarator,
+        span: Span,
+    ) -> StructDeclarator {
+        fold_struct_declarator(self, struct_declarator, span)
+    }
+
+    fn fold_enum_type(&mut self, enum_type: EnumType, span: Span) -> EnumType {
+        fold_enum_type(self, enum_type, span)
+    }
+
+    fn fold_enumerator(&mut self, enumerator: Enumerator, span: Span) -> Enumerator {
+        fold_enumerator(self, enumerator, span)
+    }
+
+    fn fold_type_qualifier(&mut self, type_qualifier: TypeQualifier, span: Span) -> TypeQualifier {
+        fold_type_qualifier(self, type_qualifier, span)
+    }
+
+    fn fold_function_specifier(
+        &mut self,
+        function_specifier: FunctionSpecifier,
+        span: Span,
+    ) -> FunctionSpecifier {
+        fold_function_specifier(self, function_specifier, span)
+    }
+
+    fn fold_alignment_specifier(
+        &mut self,
+        alignment_specifier: AlignmentSpecifier,
+        span: Span,
+    ) -> AlignmentSpecifier {
+        fold_alignment_specifier(self, alignment_specifier, span)
+    }
+
+    fn fold_declarator(&mut self, declarator: Declarator, span: Span) -> Declarator {
+        fold_declarator(self, declarator, span)
+    }
+
+    fn fold_declarator_kind(
+        &mut self,
+        declarator_kind: DeclaratorKind,
+        span: Span,
+    ) -> DeclaratorKind {
+        fold_declarator_kind(self, declarator_kind, span)
+    }
+
+    fn fold_derived_declarator(
+        &mut self,
+        derived_declarator: DerivedDeclarator,
+        span: Span,
+    ) -> DerivedDeclarator {
+        fold_derived_declarator(self, derived_declarator, span)
+    }
+
+    fn fold_array_declarator(
+        &mut self,
+        array_declarator: ArrayDeclarator,
+        span: Span,
+    ) -> ArrayDeclarator {
+        fold_array_declarator(self, array_declarator, span)
+    }
+
+    fn fold_function_declarator(
+        &mut self,
+        function_declarator: FunctionDeclarator,
+        span: Span,
+    ) -> FunctionDeclarator {
+        fold_function_declarator(self, function_declarator, span)
+    }
+
+    fn fold_pointer_qualifier(
+        &mut self,
+        pointer_qualifier: PointerQualifier,
+        span: Span,
+    ) -> PointerQualifier {
+        fold_pointer_qualifier(self, pointer_qualifier, span)
+    }
+
+    fn fold_array_size(&mut self, array_size: ArraySize, span: Span) -> ArraySize {
+        fold_array_size(self, array_size, span)
+    }
+
+    fn fold_parameter_declaration(
+        &mut self,
+        parameter_declaration: ParameterDeclaration,
+        span: Span,
+    ) -> ParameterDeclaration {
+        fold_parameter_declaration(self, parameter_declaration, span)
+    }
+
+    fn fold_ellipsis(&mut self, ellipsis: Ellipsis, span: Span) -> Ellipsis {
+        fold_ellipsis(self, ellipsis, span)
+    }
+
+    fn fold_type_name(&mut self, type_name: TypeName, span: Span) -> TypeName {
+        fold_type_name(self, type_name, span)
+    }
+
+    fn fold_initializer(&mut self, initializer: Initializer, span: Span) -> Initializer {
+        fold_initializer(self, initializer, span)
+    }
+
+    fn fold_initializer_list_item(
+        &mut self,
+        initializer_list_item: InitializerListItem,
+        span: Span,
+    ) -> InitializerListItem {
+        fold_initializer_list_item(self, initializer_list_item, span)
+    }
+
+    fn fold_designator(&mut self, designator: Designator, span: Span) -> Designator {
+        fold_designator(self, designator, span)
+    }
+
+    fn fold_range_designator(
+        &mut self,
+        range_designator: RangeDesignator,
+        span: Span,
+    ) -> RangeDesignator {
+        fold_range_designator(self, range_designator, span)
+    }
+
+    fn fold_static_assert(&mut self, static_assert: StaticAssert, span: Span) -> StaticAssert {
+        fold_static_assert(self, static_assert, span)
+    }
+
+    fn fold_statement(&mut self, statement: Statement, span: Span) -> Statement {
+        fold_statement(self, statement, span)
+    }
+
+    fn fold_labeled_statement(
+        &mut self,
+        labeled_statement: LabeledStatement,
+        span: Span,
+    ) -> LabeledStatement {
+        fold_labeled_statement(self, labeled_statement, span)
+    }
+
+    fn fold_if_statement(&mut self, if_statement: IfStatement, span: Span) -> IfStatement {
+        fold_if_statement(self, if_statement, span)
+    }
+
+    fn fold_switch_statement(
+        &mut self,
+        switch_statement: SwitchStatement,
+        span: Span,
+    ) -> SwitchStatement {
+        fold_switch_statement(self, switch_statement, span)
+    }
+
+    fn fold_while_statement(
+        &mut self,
+        while_statement: WhileStatement,
+        span: Span,
+    ) -> WhileStatement {
+        fold_while_statement(self, while_statement, span)
+    }
+
+    fn fold_do_while_statement(
+        &mut self,
+        do_while_statement: DoWhileStatement,
+        span: Span,
+    ) -> DoWhileStatement {
+        fold_do_while_statement(self, do_while_statement, span)
+    }
+
+    fn fold_for_statement(&mut self, for_statement: ForStatement, span: Span) -> ForStatement {
+        fold_for_statement(self, for_statement, span)
+    }
+
+    fn fold_label(&mut self, label: Label, span: Span) -> Label {
+        fold_label(self, label, span)
+    }
+
+    fn fold_for_initializer(
+        &mut self,
+        for_initializer: ForInitializer,
+        span: Span,
+    ) -> ForInitializer {
+        fold_for_initializer(self, for_initializer, span)
+    }
+
+    fn fold_block_item(&mut self, block_item: BlockItem, span: Span) -> BlockItem {
+        fold_block_item(self, block_item, span)
+    }
+
+    fn fold_ambiguous_block_item(
+        &mut self,
+        ambiguous_block_item: AmbiguousBlockItem,
+        span: Span,
+    ) -> AmbiguousBlockItem {
+        fold_ambiguous_block_item(self, ambiguous_block_item, span)
+    }
+
+    fn fold_translation_unit(&mut self, translation_unit: TranslationUnit) -> TranslationUnit {
+        fold_translation_unit(self, translation_unit)
+    }
+
+    fn fold_external_declaration(
+        &mut self,
+        external_declaration: ExternalDeclaration,
+        span: Span,
+    ) -> ExternalDeclaration {
+        fold_external_declaration(self, external_declaration, span)
+    }
+
+    fn fold_function_definition(
+        &mut self,
+        function_definition: FunctionDefinition,
+        span: Span,
+    ) -> FunctionDefinition {
+        fold_function_definition(self, function_definition, span)
+    }
+
+    fn fold_extension(&mut self, extension: Extension, span: Span) -> Extension {
+        fold_extension(self, extension, span)
+    }
+
+    fn fold_attribute(&mut self, attribute: Attribute, span: Span) -> Attribute {
+        fold_attribute(self, attribute, span)
+    }
+
+    fn fold_vendor_qualifier(
+        &mut self,
+        vendor_qualifier: VendorQualifier,
+        span: Span,
+    ) -> VendorQualifier {
+        fold_vendor_qualifier(self, vendor_qualifier, span)
+    }
+
+    fn fold_asm_statement(&mut self, asm_statement: AsmStatement, span: Span) -> AsmStatement {
+        fold_asm_statement(self, asm_statement, span)
+    }
+
+    fn fold_availability_attribute(
+        &mut self,
+        availability_attribute: AvailabilityAttribute,
+        span: Span,
+    ) -> AvailabilityAttribute {
+        fold_availability_attribute(self, availability_attribute, span)
+    }
+
+    fn fold_availability_clause(
+        &mut self,
+        availability_clause: AvailabilityClause,
+        span: Span,
+    ) -> AvailabilityClause {
+        fold_availability_clause(self, availability_clause, span)
+    }
+
+    fn fold_gnu_extended_asm_statement(
+        &mut self,
+        gnu_extended_asm_statement: GnuExtendedAsmStatement,
+        span: Span,
+    ) -> GnuExtendedAsmStatement {
+        fold_gnu_extended_asm_statement(self, gnu_extended_asm_statement, span)
+    }
+
+    fn fold_gnu_asm_operand(
+        &mut self,
+        gnu_asm_operand: GnuAsmOperand,
+        span: Span,
+    ) -> GnuAsmOperand {
+        fold_gnu_asm_operand(self, gnu_asm_operand, span)
+    }
+
+    fn fold_type_of(&mut self, type_of: TypeOf, span: Span) -> TypeOf {
+        fold_type_of(self, type_of, span)
+    }
+}
+
+fn node<T, F: FnOnce(T, Span) -> T>(node: Node<T>, fold: F) -> Node<T> {
+    Node {
+        node: fold(node.node, node.span),
+        span: node.span,
+        id: node.id,
+    }
+}
+
+fn boxed<T, F: FnOnce(T, Span) -> T>(mut boxed: Box<Node<T>>, fold: F) -> Box<Node<T>> {
+    *boxed = node(*boxed, fold);
+    boxed
+}
+
+fn nodes<T, F: FnMut(T, Span) -> T>(nodes: Vec<Node<T>>, mut fold: F) -> Vec<Node<T>> {
+    nodes.into_iter().map(|n| node(n, &mut fold)).collect()
+}
+
+pub fn fold_identifier<V: Fold + ?Sized>(
+    _folder: &mut V,
+    identifier: Identifier,
+    _span: Span,
+) -> Identifier {
+    identifier
+}
+
+pub fn fold_constant<V: Fold + ?Sized>(folder: &mut V, constant: Constant, span: Span) -> Constant {
+    match constant {
+        Constant::Integer(x) => Constant::Integer(folder.fold_integer(x, span)),
+        Constant::Float(x) => Constant::Float(folder.fold_float(x, span)),
+        x => x,
+    }
+}
+
+pub fn fold_integer<V: Fold + ?Sized>(folder: &mut V, integer: Integer, span: Span) -> Integer {
+    Integer {
+        base: folder.fold_integer_base(integer.base, span),
+        number: integer.number,
+        suffix: folder.fold_integer_suffix(integer.suffix, span),
+    }
+}
+
+pub fn fold_integer_base<V: Fold + ?Sized>(
+    _folder: &mut V,
+    integer_base: IntegerBase,
+    _span: Span,
+) -> IntegerBase {
+    integer_base
+}
+
+pub fn fold_integer_suffix<V: Fold + ?Sized>(
+    folder: &mut V,
+    integer_suffix: IntegerSuffix,
+    span: Span,
+) -> IntegerSuffix {
+    IntegerSuffix {
+        size: folder.fold_integer_size(integer_suffix.size, span),
+        unsigned: integer_suffix.unsigned,
+        imaginary: integer_suffix.imaginary,
+    }
+}
+
+pub fn fold_integer_size<V: Fold + ?Sized>(
+    _folder: &mut V,
+    integer_size: IntegerSize,
+    _span: Span,
+) -> IntegerSize {
+    integer_size
+}
+
+pub fn fold_float<V: Fold + ?Sized>(folder: &mut V, float: Float, span: Span) -> Float {
+    Float {
+        base: folder.fold_float_base(float.base, span),
+        number: float.number,
+        suffix: folder.fold_float_suffix(float.suffix, span),
+    }
+}
+
+pub fn fold_float_base<V: Fold + ?Sized>(
+    _folder: &mut V,
+    float_base: FloatBase,
+    _span: Span,
+) -> FloatBase {
+    float_base
+}
+
+pub fn fold_float_suffix<V: Fold + ?Sized>(
+    folder: &mut V,
+    float_suffix: FloatSuffix,
+    span: Span,
+) -> FloatSuffix {
+    FloatSuffix {
+        format: folder.fold_float_format(float_suffix.format, span),
+        imaginary: float_suffix.imaginary,
+    }
+}
+
+pub fn fold_float_format<V: Fold + ?Sized>(
+    folder: &mut V,
+    float_format: FloatFormat,
+    span: Span,
+) -> FloatFormat {
+    match float_format {
+        FloatFormat::TS18661Format(x) => {
+            FloatFormat::TS18661Format(folder.fold_ts18661_float_type(x, span))
+        }
+        x => x,
+    }
+}
+
+pub fn fold_string_literal<V: Fold + ?Sized>(
+    _folder: &mut V,
+    string_literal: StringLiteral,
+    _span: Span,
+) -> StringLiteral {
+    string_literal
+}
+
+pub fn fold_expression<V: Fold + ?Sized>(
+    folder: &mut V,
+    expression: Expression,
+    _span: Span,
+) -> Expression {
+    match expression {
+        Expression::Identifier(x) => {
+            Expression::Identifier(boxed(x, |n, s| folder.fold_identifier(n, s)))
+        }
+        Expression::Constant(x) => {
+            Expression::Constant(boxed(x, |n, s| folder.fold_constant(n, s)))
+        }
+        Expression::StringLiteral(x) => {
+            Expression::StringLiteral(boxed(x, |n, s| folder.fold_string_literal(n, s)))
+        }
+        Expression::GenericSelection(x) => {
+            Expression::GenericSelection(boxed(x, |n, s| folder.fold_generic_selection(n, s)))
+        }
+        Expression::Member(x) => {
+            Expression::Member(boxed(x, |n, s| folder.fold_member_expression(n, s)))
+        }
+        Expression::Call(x) => Expression::Call(boxed(x, |n, s| folder.fold_call_expression(n, s))),
+        Expression::CompoundLiteral(x) => {
+            Expression::CompoundLiteral(boxed(x, |n, s| folder.fold_compound_literal(n, s)))
+        }
+        Expression::SizeOf(x) => Expression::SizeOf(boxed(x, |n, s| folder.fold_type_name(n, s))),
+        Expression::AlignOf(x) => Expression::AlignOf(boxed(x, |n, s| folder.fold_type_name(n, s))),
+        Expression::UnaryOperator(x) => {
+            Expression::UnaryOperator(boxed(x, |n, s| folder.fold_unary_operator_expression(n, s)))
+        }
+        Expression::Cast(x) => Expression::Cast(boxed(x, |n, s| folder.fold_cast_expression(n, s))),
+        Expression::BinaryOperator(x) => Expression::BinaryOperator(boxed(x, |n, s| {
+            folder.fold_binary_operator_expression(n, s)
+        })),
+        Expression::Conditional(x) => {
+            Expression::Conditional(boxed(x, |n, s| folder.fold_conditional_expression(n, s)))
+        }
+        Expression::Comma(x) => {
+            Expression::Comma(Box::new(nodes(*x, |n, s| folder.fold_expression(n, s))))
+        }
+        Expression::OffsetOf(x) => {
+            Expression::OffsetOf(boxed(x, |n, s| folder.fold_offset_of_expression(n, s)))
+        }
+        Expression::VaArg(x) => {
+            Expression::VaArg(boxed(x, |n, s| folder.fold_va_arg_expression(n, s)))
+        }
+        Expression::Statement(x) => {
+            Expression::Statement(boxed(x, |n, s| folder.fold_statement(n, s)))
+        }
+        Expression::Ambiguous(x) => {
+            Expression::Ambiguous(boxed(x, |n, s| folder.fold_ambiguous_expression(n, s)))
+        }
+    }
+}
+
+pub fn fold_member_operator<V: Fold + ?Sized>(
+    _folder: &mut V,
+    member_operator: MemberOperator,
+    _span: Span,
+) -> MemberOperator {
+    member_operator
+}
+
+pub fn fold_generic_selection<V: Fold + ?Sized>(
+    folder: &mut V,
+    generic_selection: GenericSelection,
+    _span: Span,
+) -> GenericSelection {
+    GenericSelection {
+        expression: boxed(generic_selection.expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+        associations: nodes(generic_selection.associations, |n, s| {
+            folder.fold_generic_association(n, s)
+        }),
+    }
+}
+
+pub fn fold_generic_association<V: Fold + ?Sized>(
+    folder: &mut V,
+    generic_association: GenericAssociation,
+    _span: Span,
+) -> GenericAssociation {
+    match generic_association {
+        GenericAssociation::Type(x) => {
+            GenericAssociation::Type(node(x, |n, s| folder.fold_generic_association_type(n, s)))
+        }
+        GenericAssociation::Default(x) => {
+            GenericAssociation::Default(boxed(x, |n, s| folder.fold_expression(n, s)))
+        }
+    }
+}
+
+pub fn fold_generic_association_type<V: Fold + ?Sized>(
+    folder: &mut V,
+    generic_association_type: GenericAssociationType,
+    _span: Span,
+) -> GenericAssociationType {
+    GenericAssociationType {
+        type_name: node(generic_association_type.type_name, |n, s| {
+            folder.fold_type_name(n, s)
+        }),
+        expression: boxed(generic_association_type.expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+    }
+}
+
+pub fn fold_member_expression<V: Fold + ?Sized>(
+    folder: &mut V,
+    member_expression: MemberExpression,
+    _span: Span,
+) -> MemberExpression {
+    MemberExpression {
+        operator: node(member_expression.operator, |n, s| {
+            folder.fold_member_operator(n, s)
+        }),
+        expression: boxed(member_expression.expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+        identifier: node(member_expression.identifier, |n, s| {
+            folder.fold_identifier(n, s)
+        }),
+    }
+}
+
+pub fn fold_call_expression<V: Fold + ?Sized>(
+    folder: &mut V,
+    call_expression: CallExpression,
+    _span: Span,
+) -> CallExpression {
+    CallExpression {
+        callee: boxed(call_expression.callee, |n, s| folder.fold_expression(n, s)),
+        arguments: nodes(call_expression.arguments, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+    }
+}
+
+pub fn fold_compound_literal<V: Fold + ?Sized>(
+    folder: &mut V,
+    compound_literal: CompoundLiteral,
+    _span: Span,
+) -> CompoundLiteral {
+    CompoundLiteral {
+        type_name: node(compound_literal.type_name, |n, s| {
+            folder.fold_type_name(n, s)
+        }),
+        initializer_list: nodes(compound_literal.initializer_list, |n, s| {
+            folder.fold_initializer_list_item(n, s)
+        }),
+    }
+}
+
+pub fn fold_unary_operator<V: Fold + ?Sized>(
+    _folder: &mut V,
+    unary_operator: UnaryOperator,
+    _span: Span,
+) -> UnaryOperator {
+    unary_operator
+}
+
+pub fn fold_unary_operator_expression<V: Fold + ?Sized>(
+    folder: &mut V,
+    unary_operator_expression: UnaryOperatorExpression,
+    _span: Span,
+) -> UnaryOperatorExpression {
+    let UnaryOperatorExpression { operator, operand } = unary_operator_expression;
+    match operator.node {
+        UnaryOperator::PostIncrement | UnaryOperator::PostDecrement => {
+            let operand = boxed(operand, |n, s| folder.fold_expression(n, s));
+            let operator = node(operator, |n, s| folder.fold_unary_operator(n, s));
+            UnaryOperatorExpression { operator, operand }
+        }
+        _ => {
+            let operator = node(operator, |n, s| folder.fold_unary_operator(n, s));
+            let operand = boxed(operand, |n, s| folder.fold_expression(n, s));
+            UnaryOperatorExpression { operator, operand }
+        }
+    }
+}
+
+pub fn fold_cast_expression<V: Fold + ?Sized>(
+    folder: &mut V,
+    cast_expression: CastExpression,
+    _span: Span,
+) -> CastExpression {
+    CastExpression {
+        type_name: node(cast_expression.type_name, |n, s| {
+            folder.fold_type_name(n, s)
+        }),
+        expression: boxed(cast_expression.expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+    }
+}
+
+pub fn fold_binary_operator<V: Fold + ?Sized>(
+    _folder: &mut V,
+    binary_operator: BinaryOperator,
+    _span: Span,
+) -> BinaryOperator {
+    binary_operator
+}
+
+pub fn fold_binary_operator_expression<V: Fold + ?Sized>(
+    folder: &mut V,
+    binary_operator_expression: BinaryOperatorExpression,
+    _span: Span,
+) -> BinaryOperatorExpression {
+    BinaryOperatorExpression {
+        operator: node(binary_operator_expression.operator, |n, s| {
+            folder.fold_binary_operator(n, s)
+        }),
+        lhs: boxed(binary_operator_expression.lhs, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+        rhs: boxed(binary_operator_expression.rhs, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+    }
+}
+
+pub fn fold_conditional_expression<V: Fold + ?Sized>(
+    folder: &mut V,
+    conditional_expression: ConditionalExpression,
+    _span: Span,
+) -> ConditionalExpression {
+    ConditionalExpression {
+        condition: boxed(conditional_expression.condition, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+        then_expression: boxed(conditional_expression.then_expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+        else_expression: boxed(conditional_expression.else_expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+    }
+}
+
+pub fn fold_va_arg_expression<V: Fold + ?Sized>(
+    folder: &mut V,
+    va_arg_expression: VaArgExpression,
+    _span: Span,
+) -> VaArgExpression {
+    VaArgExpression {
+        va_list: boxed(va_arg_expression.va_list, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+        type_name: node(va_arg_expression.type_name, |n, s| {
+            folder.fold_type_name(n, s)
+        }),
+    }
+}
+
+pub fn fold_offset_of_expression<V: Fold + ?Sized>(
+    folder: &mut V,
+    offset_of_expression: OffsetOfExpression,
+    _span: Span,
+) -> OffsetOfExpression {
+    OffsetOfExpression {
+        type_name: node(offset_of_expression.type_name, |n, s| {
+            folder.fold_type_name(n, s)
+        }),
+        designator: node(offset_of_expression.designator, |n, s| {
+            folder.fold_offset_designator(n, s)
+        }),
+    }
+}
+
+pub fn fold_offset_designator<V: Fold + ?Sized>(
+    folder: &mut V,
+    offset_designator: OffsetDesignator,
+    _span: Span,
+) -> OffsetDesignator {
+    OffsetDesignator {
+        base: node(offset_designator.base, |n, s| folder.fold_identifier(n, s)),
+        members: nodes(offset_designator.members, |n, s| {
+            folder.fold_offset_member(n, s)
+        }),
+    }
+}
+
+pub fn fold_offset_member<V: Fold + ?Sized>(
+    folder: &mut V,
+    offset_member: OffsetMember,
+    _span: Span,
+) -> OffsetMember {
+    match offset_member {
+        OffsetMember::Member(x) => {
+            OffsetMember::Member(node(x, |n, s| folder.fold_identifier(n, s)))
+        }
+        OffsetMember::IndirectMember(x) => {
+            OffsetMember::IndirectMember(node(x, |n, s| folder.fold_identifier(n, s)))
+        }
+        OffsetMember::Index(x) => OffsetMember::Index(node(x, |n, s| folder.fold_expression(n, s))),
+    }
+}
+
+pub fn fold_ambiguous_expression<V: Fold + ?Sized>(
+    folder: &mut V,
+    ambiguous_expression: AmbiguousExpression,
+    _span: Span,
+) -> AmbiguousExpression {
+    AmbiguousExpression {
+        cast: node(ambiguous_expression.cast, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+        expression: node(ambiguous_expression.expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+    }
+}
+
+pub fn fold_declaration<V: Fold + ?Sized>(
+    folder: &mut V,
+    declaration: Declaration,
+    _span: Span,
+) -> Declaration {
+    Declaration {
+        specifiers: nodes(declaration.specifiers, |n, s| {
+            folder.fold_declaration_specifier(n, s)
+        }),
+        declarators: nodes(declaration.declarators, |n, s| {
+            folder.fold_init_declarator(n, s)
+        }),
+    }
+}
+
+pub fn fold_declaration_specifier<V: Fold + ?Sized>(
+    folder: &mut V,
+    declaration_specifier: DeclarationSpecifier,
+    _span: Span,
+) -> DeclarationSpecifier {
+    match declaration_specifier {
+        DeclarationSpecifier::StorageClass(x) => {
+            DeclarationSpecifier::StorageClass(node(x, |n, s| {
+                folder.fold_storage_class_specifier(n, s)
+            }))
+        }
+        DeclarationSpecifier::TypeSpecifier(x) => {
+            DeclarationSpecifier::TypeSpecifier(node(x, |n, s| folder.fold_type_specifier(n, s)))
+        }
+        DeclarationSpecifier::TypeQualifier(x) => {
+            DeclarationSpecifier::TypeQualifier(node(x, |n, s| folder.fold_type_qualifier(n, s)))
+        }
+        DeclarationSpecifier::Function(x) => {
+            DeclarationSpecifier::Function(node(x, |n, s| folder.fold_function_specifier(n, s)))
+        }
+        DeclarationSpecifier::Alignment(x) => {
+            DeclarationSpecifier::Alignment(node(x, |n, s| folder.fold_alignment_specifier(n, s)))
+        }
+        DeclarationSpecifier::Extension(x) => {
+            DeclarationSpecifier::Extension(nodes(x, |n, s| folder.fold_extension(n, s)))
+        }
+    }
+}
+
+pub fn fold_init_declarator<V: Fold + ?Sized>(
+    folder: &mut V,
+    init_declarator: InitDeclarator,
+    _span: Span,
+) -> InitDeclarator {
+    InitDeclarator {
+        declarator: node(init_declarator.declarator, |n, s| {
+            folder.fold_declarator(n, s)
+        }),
+        initializer: init_declarator
+            .initializer
+            .map(|x| node(x, |n, s| folder.fold_initializer(n, s))),
+    }
+}
+
+pub fn fold_storage_class_specifier<V: Fold + ?Sized>(
+    _folder: &mut V,
+    storage_class_specifier: StorageClassSpecifier,
+    _span: Span,
+) -> StorageClassSpecifier {
+    storage_class_specifier
+}
+
+pub fn fold_type_specifier<V: Fold + ?Sized>(
+    folder: &mut V,
+    type_specifier: TypeSpecifier,
+    span: Span,
+) -> TypeSpecifier {
+    match type_specifier {
+        TypeSpecifier::Atomic(x) => {
+            TypeSpecifier::Atomic(node(x, |n, s| folder.fold_type_name(n, s)))
+        }
+        TypeSpecifier::Struct(x) => {
+            TypeSpecifier::Struct(node(x, |n, s| folder.fold_struct_type(n, s)))
+        }
+        TypeSpecifier::Enum(x) => TypeSpecifier::Enum(node(x, |n, s| folder.fold_enum_type(n, s))),
+        TypeSpecifier::TypedefName(x) => {
+            TypeSpecifier::TypedefName(node(x, |n, s| folder.fold_identifier(n, s)))
+        }
+        TypeSpecifier::TypeOf(x) => {
+            TypeSpecifier::TypeOf(node(x, |n, s| folder.fold_type_of(n, s)))
+        }
+        TypeSpecifier::TS18661Float(x) => {
+            TypeSpecifier::TS18661Float(folder.fold_ts18661_float_type(x, span))
+        }
+        x => x,
+    }
+}
+
+pub fn fold_ts18661_float_type<V: Fold + ?Sized>(
+    folder: &mut V,
+    ts18661_float_type: TS18661FloatType,
+    span: Span,
+) -> TS18661FloatType {
+    TS18661FloatType {
+        format: folder.fold_ts18661_float_format(ts18661_float_type.format, span),
+        width: ts18661_float_type.width,
+    }
+}
+
+pub fn fold_ts18661_float_format<V: Fold + ?Sized>(
+    _folder: &mut V,
+    ts18661_float_format: TS18661FloatFormat,
+    _span: Span,
+) -> TS18661FloatFormat {
+    ts18661_float_format
+}
+
+pub fn fold_struct_type<V: Fold + ?Sized>(
+    folder: &mut V,
+    struct_type: StructType,
+    _span: Span,
+) -> StructType {
+    StructType {
+        kind: node(struct_type.kind, |n, s| folder.fold_struct_kind(n, s)),
+        identifier: struct_type
+            .identifier
+            .map(|x| node(x, |n, s| folder.fold_identifier(n, s))),
+        declarations: struct_type
+            .declarations
+            .map(|x| nodes(x, |n, s| folder.fold_struct_declaration(n, s))),
+    }
+}
+
+pub fn fold_struct_kind<V: Fold + ?Sized>(
+    _folder: &mut V,
+    struct_kind: StructKind,
+    _span: Span,
+) -> StructKind {
+    struct_kind
+}
+
+pub fn fold_struct_declaration<V: Fold + ?Sized>(
+    folder: &mut V,
+    struct_declaration: StructDeclaration,
+    _span: Span,
+) -> StructDeclaration {
+    match struct_declaration {
+        StructDeclaration::Field(x) => {
+            StructDeclaration::Field(node(x, |n, s| folder.fold_struct_field(n, s)))
+        }
+        StructDeclaration::StaticAssert(x) => {
+            StructDeclaration::StaticAssert(node(x, |n, s| folder.fold_static_assert(n, s)))
+        }
+    }
+}
+
+pub fn fold_struct_field<V: Fold + ?Sized>(
+    folder: &mut V,
+    struct_field: StructField,
+    _span: Span,
+) -> StructField {
+    StructField {
+        specifiers: nodes(struct_field.specifiers, |n, s| {
+            folder.fold_specifier_qualifier(n, s)
+        }),
+        declarators: nodes(struct_field.declarators, |n, s| {
+            folder.fold_struct_declarator(n, s)
+        }),
+    }
+}
+
+pub fn fold_specifier_qualifier<V: Fold + ?Sized>(
+    folder: &mut V,
+    specifier_qualifier: SpecifierQualifier,
+    _span: Span,
+) -> SpecifierQualifier {
+    match specifier_qualifier {
+        SpecifierQualifier::TypeSpecifier(x) => {
+            SpecifierQualifier::TypeSpecifier(node(x, |n, s| folder.fold_type_specifier(n, s)))
+        }
+        SpecifierQualifier::TypeQualifier(x) => {
+            SpecifierQualifier::TypeQualifier(node(x, |n, s| folder.fold_type_qualifier(n, s)))
+        }
+        SpecifierQualifier::Extension(x) => {
+            SpecifierQualifier::Extension(nodes(x, |n, s| folder.fold_extension(n, s)))
+        }
+    }
+}
+
+pub fn fold_struct_declarator<V: Fold + ?Sized>(
+    folder: &mut V,
+    struct_declarator: StructDeclarator,
+    _span: Span,
+) -> StructDeclarator {
+    StructDeclarator {
+        declarator: struct_declarator
+            .declarator
+            .map(|x| node(x, |n, s| folder.fold_declarator(n, s))),
+        bit_width: struct_declarator
+            .bit_width
+            .map(|x| boxed(x, |n, s| folder.fold_expression(n, s))),
+    }
+}
+
+pub fn fold_enum_type<V: Fold + ?Sized>(
+    folder: &mut V,
+    enum_type: EnumType,
+    _span: Span,
+) -> EnumType {
+    EnumType {
+        identifier: enum_type
+            .identifier
+            .map(|x| node(x, |n, s| folder.fold_identifier(n, s))),
+        enumerators: nodes(enum_type.enumerators, |n, s| folder.fold_enumerator(n, s)),
+    }
+}
+
+pub fn fold_enumerator<V: Fold + ?Sized>(
+    folder: &mut V,
+    enumerator: Enumerator,
+    _span: Span,
+) -> Enumerator {
+    Enumerator {
+        identifier: node(enumerator.identifier, |n, s| folder.fold_identifier(n, s)),
+        expression: enumerator
+            .expression
+            .map(|x| boxed(x, |n, s| folder.fold_expression(n, s))),
+    }
+}
+
+pub fn fold_type_qualifier<V: Fold + ?Sized>(
+    _folder: &mut V,
+    type_qualifier: TypeQualifier,
+    _span: Span,
+) -> TypeQualifier {
+    type_qualifier
+}
+
+pub fn fold_function_specifier<V: Fold + ?Sized>(
+    _folder: &mut V,
+    function_specifier: FunctionSpecifier,
+    _span: Span,
+) -> FunctionSpecifier {
+    function_specifier
+}
+
+pub fn fold_alignment_specifier<V: Fold + ?Sized>(
+    folder: &mut V,
+    alignment_specifier: AlignmentSpecifier,
+    _span: Span,
+) -> AlignmentSpecifier {
+    match alignment_specifier {
+        AlignmentSpecifier::Type(x) => {
+            AlignmentSpecifier::Type(node(x, |n, s| folder.fold_type_name(n, s)))
+        }
+        AlignmentSpecifier::Constant(x) => {
+            AlignmentSpecifier::Constant(boxed(x, |n, s| folder.fold_expression(n, s)))
+        }
+    }
+}
+
+pub fn fold_declarator<V: Fold + ?Sized>(
+    folder: &mut V,
+    declarator: Declarator,
+    _span: Span,
+) -> Declarator {
+    Declarator {
+        kind: node(declarator.kind, |n, s| folder.fold_declarator_kind(n, s)),
+        derived: nodes(declarator.derived, |n, s| {
+            folder.fold_derived_declarator(n, s)
+        }),
+        extensions: nodes(declarator.extensions, |n, s| folder.fold_extension(n, s)),
+    }
+}
+
+pub fn fold_declarator_kind<V: Fold + ?Sized>(
+    folder: &mut V,
+    declarator_kind: DeclaratorKind,
+    _span: Span,
+) -> DeclaratorKind {
+    match declarator_kind {
+        DeclaratorKind::Identifier(x) => {
+            DeclaratorKind::Identifier(node(x, |n, s| folder.fold_identifier(n, s)))
+        }
+        DeclaratorKind::Declarator(x) => {
+            DeclaratorKind::Declarator(boxed(x, |n, s| folder.fold_declarator(n, s)))
+        }
+        x => x,
+    }
+}
+
+pub fn fold_derived_declarator<V: Fold + ?Sized>(
+    folder: &mut V,
+    derived_declarator: DerivedDeclarator,
+    _span: Span,
+) -> DerivedDeclarator {
+    match derived_declarator {
+        DerivedDeclarator::Pointer(x) => {
+            DerivedDeclarator::Pointer(nodes(x, |n, s| folder.fold_pointer_qualifier(n, s)))
+        }
+        DerivedDeclarator::Array(x) => {
+            DerivedDeclarator::Array(node(x, |n, s| folder.fold_array_declarator(n, s)))
+        }
+        DerivedDeclarator::Function(x) => {
+            DerivedDeclarator::Function(node(x, |n, s| folder.fold_function_declarator(n, s)))
+        }
+        DerivedDeclarator::KRFunction(x) => {
+            DerivedDeclarator::KRFunction(nodes(x, |n, s| folder.fold_identifier(n, s)))
+        }
+    }
+}
+
+pub fn fold_array_declarator<V: Fold + ?Sized>(
+    folder: &mut V,
+    array_declarator: ArrayDeclarator,
+    span: Span,
+) -> ArrayDeclarator {
+    ArrayDeclarator {
+        qualifiers: nodes(array_declarator.qualifiers, |n, s| {
+            folder.fold_type_qualifier(n, s)
+        }),
+        size: folder.fold_array_size(array_declarator.size, span),
+    }
+}
+
+pub fn fold_function_declarator<V: Fold + ?Sized>(
+    folder: &mut V,
+    function_declarator: FunctionDeclarator,
+    span: Span,
+) -> FunctionDeclarator {
+    FunctionDeclarator {
+        parameters: nodes(function_declarator.parameters, |n, s| {
+            folder.fold_parameter_declaration(n, s)
+        }),
+        ellipsis: folder.fold_ellipsis(function_declarator.ellipsis, span),
+    }
+}
+
+pub fn fold_pointer_qualifier<V: Fold + ?Sized>(
+    folder: &mut V,
+    pointer_qualifier: PointerQualifier,
+    _span: Span,
+) -> PointerQualifier {
+    match pointer_qualifier {
+        PointerQualifier::TypeQualifier(x) => {
+            PointerQualifier::TypeQualifier(node(x, |n, s| folder.fold_type_qualifier(n, s)))
+        }
+        PointerQualifier::Extension(x) => {
+            PointerQualifier::Extension(nodes(x, |n, s| folder.fold_extension(n, s)))
+        }
+    }
+}
+
+pub fn fold_array_size<V: Fold + ?Sized>(
+    folder: &mut V,
+    array_size: ArraySize,
+    _span: Span,
+) -> ArraySize {
+    match array_size {
+        ArraySize::VariableExpression(x) => {
+            ArraySize::VariableExpression(boxed(x, |n, s| folder.fold_expression(n, s)))
+        }
+        ArraySize::StaticExpression(x) => {
+            ArraySize::StaticExpression(boxed(x, |n, s| folder.fold_expression(n, s)))
+        }
+        x => x,
+    }
+}
+
+pub fn fold_parameter_declaration<V: Fold + ?Sized>(
+    folder: &mut V,
+    parameter_declaration: ParameterDeclaration,
+    _span: Span,
+) -> ParameterDeclaration {
+    ParameterDeclaration {
+        specifiers: nodes(parameter_declaration.specifiers, |n, s| {
+            folder.fold_declaration_specifier(n, s)
+        }),
+        declarator: parameter_declaration
+            .declarator
+            .map(|x| node(x, |n, s| folder.fold_declarator(n, s))),
+        extensions: nodes(parameter_declaration.extensions, |n, s| {
+            folder.fold_extension(n, s)
+        }),
+    }
+}
+
+pub fn fold_ellipsis<V: Fold + ?Sized>(
+    _folder: &mut V,
+    ellipsis: Ellipsis,
+    _span: Span,
+) -> Ellipsis {
+    ellipsis
+}
+
+pub fn fold_type_name<V: Fold + ?Sized>(
+    folder: &mut V,
+    type_name: TypeName,
+    _span: Span,
+) -> TypeName {
+    TypeName {
+        specifiers: nodes(type_name.specifiers, |n, s| {
+            folder.fold_specifier_qualifier(n, s)
+        }),
+        declarator: type_name
+            .declarator
+            .map(|x| node(x, |n, s| folder.fold_declarator(n, s))),
+    }
+}
+
+pub fn fold_initializer<V: Fold + ?Sized>(
+    folder: &mut V,
+    initializer: Initializer,
+    _span: Span,
+) -> Initializer {
+    match initializer {
+        Initializer::Expression(x) => {
+            Initializer::Expression(boxed(x, |n, s| folder.fold_expression(n, s)))
+        }
+        Initializer::List(x) => {
+            Initializer::List(nodes(x, |n, s| folder.fold_initializer_list_item(n, s)))
+        }
+    }
+}
+
+pub fn fold_initializer_list_item<V: Fold + ?Sized>(
+    folder: &mut V,
+    initializer_list_item: InitializerListItem,
+    _span: Span,
+) -> InitializerListItem {
+    InitializerListItem {
+        designation: nodes(initializer_list_item.designation, |n, s| {
+            folder.fold_designator(n, s)
+        }),
+        initializer: boxed(initializer_list_item.initializer, |n, s| {
+            folder.fold_initializer(n, s)
+        }),
+    }
+}
+
+pub fn fold_designator<V: Fold + ?Sized>(
+    folder: &mut V,
+    designator: Designator,
+    _span: Span,
+) -> Designator {
+    match designator {
+        Designator::Index(x) => Designator::Index(node(x, |n, s| folder.fold_expression(n, s))),
+        Designator::Member(x) => Designator::Member(node(x, |n, s| folder.fold_identifier(n, s))),
+        Designator::Range(x) => {
+            Designator::Range(node(x, |n, s| folder.fold_range_designator(n, s)))
+        }
+    }
+}
+
+pub fn fold_range_designator<V: Fold + ?Sized>(
+    folder: &mut V,
+    range_designator: RangeDesignator,
+    _span: Span,
+) -> RangeDesignator {
+    RangeDesignator {
+        from: node(range_designator.from, |n, s| folder.fold_expression(n, s)),
+        to: node(range_designator.to, |n, s| folder.fold_expression(n, s)),
+    }
+}
+
+pub fn fold_static_assert<V: Fold + ?Sized>(
+    folder: &mut V,
+    static_assert: StaticAssert,
+    _span: Span,
+) -> StaticAssert {
+    StaticAssert {
+        expression: boxed(static_assert.expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+        message: node(static_assert.message, |n, s| {
+            folder.fold_string_literal(n, s)
+        }),
+    }
+}
+
+pub fn fold_statement<V: Fold + ?Sized>(
+    folder: &mut V,
+    statement: Statement,
+    _span: Span,
+) -> Statement {
+    match statement {
+        Statement::Labeled(x) => {
+            Statement::Labeled(node(x, |n, s| folder.fold_labeled_statement(n, s)))
+        }
+        Statement::Compound(x) => {
+            Statement::Compound(nodes(x, |n, s| folder.fold_block_item(n, s)))
+        }
+        Statement::Expression(x) => {
+            Statement::Expression(x.map(|x| boxed(x, |n, s| folder.fold_expression(n, s))))
+        }
+        Statement::If(x) => Statement::If(node(x, |n, s| folder.fold_if_statement(n, s))),
+        Statement::Switch(x) => {
+            Statement::Switch(node(x, |n, s| folder.fold_switch_statement(n, s)))
+        }
+        Statement::While(x) => Statement::While(node(x, |n, s| folder.fold_while_statement(n, s))),
+        Statement::DoWhile(x) => {
+            Statement::DoWhile(node(x, |n, s| folder.fold_do_while_statement(n, s)))
+        }
+        Statement::For(x) => Statement::For(node(x, |n, s| folder.fold_for_statement(n, s))),
+        Statement::Goto(x) => Statement::Goto(node(x, |n, s| folder.fold_identifier(n, s))),
+        Statement::Return(x) => {
+            Statement::Return(x.map(|x| boxed(x, |n, s| folder.fold_expression(n, s))))
+        }
+        Statement::Asm(x) => Statement::Asm(node(x, |n, s| folder.fold_asm_statement(n, s))),
+        x => x,
+    }
+}
+
+pub fn fold_labeled_statement<V: Fold + ?Sized>(
+    folder: &mut V,
+    labeled_statement: LabeledStatement,
+    _span: Span,
+) -> LabeledStatement {
+    LabeledStatement {
+        label: node(labeled_statement.label, |n, s| folder.fold_label(n, s)),
+        statement: boxed(labeled_statement.statement, |n, s| {
+            folder.fold_statement(n, s)
+        }),
+    }
+}
+
+pub fn fold_if_statement<V: Fold + ?Sized>(
+    folder: &mut V,
+    if_statement: IfStatement,
+    _span: Span,
+) -> IfStatement {
+    IfStatement {
+        condition: boxed(if_statement.condition, |n, s| folder.fold_expression(n, s)),
+        then_statement: boxed(if_statement.then_statement, |n, s| {
+            folder.fold_statement(n, s)
+        }),
+        else_statement: if_statement
+            .else_statement
+            .map(|x| boxed(x, |n, s| folder.fold_statement(n, s))),
+    }
+}
+
+pub fn fold_switch_statement<V: Fold + ?Sized>(
+    folder: &mut V,
+    switch_statement: SwitchStatement,
+    _span: Span,
+) -> SwitchStatement {
+    SwitchStatement {
+        expression: boxed(switch_statement.expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+        statement: boxed(switch_statement.statement, |n, s| {
+            folder.fold_statement(n, s)
+        }),
+    }
+}
+
+pub fn fold_while_statement<V: Fold + ?Sized>(
+    folder: &mut V,
+    while_statement: WhileStatement,
+    _span: Span,
+) -> WhileStatement {
+    WhileStatement {
+        expression: boxed(while_statement.expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+        statement: boxed(while_statement.statement, |n, s| {
+            folder.fold_statement(n, s)
+        }),
+    }
+}
+
+pub fn fold_do_while_statement<V: Fold + ?Sized>(
+    folder: &mut V,
+    do_while_statement: DoWhileStatement,
+    _span: Span,
+) -> DoWhileStatement {
+    DoWhileStatement {
+        statement: boxed(do_while_statement.statement, |n, s| {
+            folder.fold_statement(n, s)
+        }),
+        expression: boxed(do_while_statement.expression, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+    }
+}
+
+pub fn fold_for_statement<V: Fold + ?Sized>(
+    folder: &mut V,
+    for_statement: ForStatement,
+    _span: Span,
+) -> ForStatement {
+    ForStatement {
+        initializer: node(for_statement.initializer, |n, s| {
+            folder.fold_for_initializer(n, s)
+        }),
+        condition: for_statement
+            .condition
+            .map(|x| boxed(x, |n, s| folder.fold_expression(n, s))),
+        step: for_statement
+            .step
+            .map(|x| boxed(x, |n, s| folder.fold_expression(n, s))),
+        statement: boxed(for_statement.statement, |n, s| folder.fold_statement(n, s)),
+    }
+}
+
+pub fn fold_label<V: Fold + ?Sized>(folder: &mut V, label: Label, _span: Span) -> Label {
+    match label {
+        Label::Identifier(x) => Label::Identifier(node(x, |n, s| folder.fold_identifier(n, s))),
+        Label::Case(x) => Label::Case(boxed(x, |n, s| folder.fold_expression(n, s))),
+        x => x,
+    }
+}
+
+pub fn fold_for_initializer<V: Fold + ?Sized>(
+    folder: &mut V,
+    for_initializer: ForInitializer,
+    _span: Span,
+) -> ForInitializer {
+    match for_initializer {
+        ForInitializer::Expression(x) => {
+            ForInitializer::Expression(boxed(x, |n, s| folder.fold_expression(n, s)))
+        }
+        ForInitializer::Declaration(x) => {
+            ForInitializer::Declaration(node(x, |n, s| folder.fold_declaration(n, s)))
+        }
+        ForInitializer::StaticAssert(x) => {
+            ForInitializer::StaticAssert(node(x, |n, s| folder.fold_static_assert(n, s)))
+        }
+        x => x,
+    }
+}
+
+pub fn fold_block_item<V: Fold + ?Sized>(
+    folder: &mut V,
+    block_item: BlockItem,
+    _span: Span,
+) -> BlockItem {
+    match block_item {
+        BlockItem::Declaration(x) => {
+            BlockItem::Declaration(node(x, |n, s| folder.fold_declaration(n, s)))
+        }
+        BlockItem::StaticAssert(x) => {
+            BlockItem::StaticAssert(node(x, |n, s| folder.fold_static_assert(n, s)))
+        }
+        BlockItem::Statement(x) => {
+            BlockItem::Statement(node(x, |n, s| folder.fold_statement(n, s)))
+        }
+        BlockItem::Ambiguous(x) => {
+            BlockItem::Ambiguous(boxed(x, |n, s| folder.fold_ambiguous_block_item(n, s)))
+        }
+    }
+}
+
+pub fn fold_ambiguous_block_item<V: Fold + ?Sized>(
+    folder: &mut V,
+    ambiguous_block_item: AmbiguousBlockItem,
+    _span: Span,
+) -> AmbiguousBlockItem {
+    AmbiguousBlockItem {
+        declaration: node(ambiguous_block_item.declaration, |n, s| {
+            folder.fold_declaration(n, s)
+        }),
+        statement: node(ambiguous_block_item.statement, |n, s| {
+            folder.fold_statement(n, s)
+        }),
+    }
+}
+
+pub fn fold_translation_unit<V: Fold + ?Sized>(
+    folder: &mut V,
+    translation_unit: TranslationUnit,
+) -> TranslationUnit {
+    TranslationUnit(nodes(translation_unit.0, |n, s| {
+        folder.fold_external_declaration(n, s)
+    }))
+}
+
+pub fn fold_external_declaration<V: Fold + ?Sized>(
+    folder: &mut V,
+    external_declaration: ExternalDeclaration,
+    _span: Span,
+) -> ExternalDeclaration {
+    match external_declaration {
+        ExternalDeclaration::Declaration(x) => {
+            ExternalDeclaration::Declaration(node(x, |n, s| folder.fold_declaration(n, s)))
+        }
+        ExternalDeclaration::StaticAssert(x) => {
+            ExternalDeclaration::StaticAssert(node(x, |n, s| folder.fold_static_assert(n, s)))
+        }
+        ExternalDeclaration::FunctionDefinition(x) => {
+            ExternalDeclaration::FunctionDefinition(node(x, |n, s| {
+                folder.fold_function_definition(n, s)
+            }))
+        }
+    }
+}
+
+pub fn fold_function_definition<V: Fold + ?Sized>(
+    folder: &mut V,
+    function_definition: FunctionDefinition,
+    _span: Span,
+) -> FunctionDefinition {
+    FunctionDefinition {
+        specifiers: nodes(function_definition.specifiers, |n, s| {
+            folder.fold_declaration_specifier(n, s)
+        }),
+        declarator: node(function_definition.declarator, |n, s| {
+            folder.fold_declarator(n, s)
+        }),
+        declarations: nodes(function_definition.declarations, |n, s| {
+            folder.fold_declaration(n, s)
+        }),
+        statement: node(function_definition.statement, |n, s| {
+            folder.fold_statement(n, s)
+        }),
+    }
+}
+
+pub fn fold_extension<V: Fold + ?Sized>(
+    folder: &mut V,
+    extension: Extension,
+    span: Span,
+) -> Extension {
+    match extension {
+        Extension::Attribute(x) => Extension::Attribute(folder.fold_attribute(x, span)),
+        Extension::AsmLabel(x) => {
+            Extension::AsmLabel(node(x, |n, s| folder.fold_string_literal(n, s)))
+        }
+        Extension::AvailabilityAttribute(x) => Extension::AvailabilityAttribute(node(x, |n, s| {
+            folder.fold_availability_attribute(n, s)
+        })),
+        Extension::VendorQualifier(x) => {
+            Extension::VendorQualifier(folder.fold_vendor_qualifier(x, span))
+        }
+    }
+}
+
+pub fn fold_attribute<V: Fold + ?Sized>(
+    folder: &mut V,
+    attribute: Attribute,
+    _span: Span,
+) -> Attribute {
+    Attribute {
+        name: attribute.name,
+        arguments: nodes(attribute.arguments, |n, s| folder.fold_expression(n, s)),
+    }
+}
+
+pub fn fold_vendor_qualifier<V: Fold + ?Sized>(
+    folder: &mut V,
+    vendor_qualifier: VendorQualifier,
+    _span: Span,
+) -> VendorQualifier {
+    VendorQualifier {
+        name: vendor_qualifier.name,
+        arguments: nodes(vendor_qualifier.arguments, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+    }
+}
+
+pub fn fold_asm_statement<V: Fold + ?Sized>(
+    folder: &mut V,
+    asm_statement: AsmStatement,
+    span: Span,
+) -> AsmStatement {
+    match asm_statement {
+        AsmStatement::GnuBasic(x) => {
+            AsmStatement::GnuBasic(node(x, |n, s| folder.fold_string_literal(n, s)))
+        }
+        AsmStatement::GnuExtended(x) => {
+            AsmStatement::GnuExtended(folder.fold_gnu_extended_asm_statement(x, span))
+        }
+    }
+}
+
+pub fn fold_availability_attribute<V: Fold + ?Sized>(
+    folder: &mut V,
+    availability_attribute: AvailabilityAttribute,
+    _span: Span,
+) -> AvailabilityAttribute {
+    AvailabilityAttribute {
+        platform: node(availability_attribute.platform, |n, s| {
+            folder.fold_identifier(n, s)
+        }),
+        clauses: nodes(availability_attribute.clauses, |n, s| {
+            folder.fold_availability_clause(n, s)
+        }),
+    }
+}
+
+pub fn fold_availability_clause<V: Fold + ?Sized>(
+    folder: &mut V,
+    availability_clause: AvailabilityClause,
+    _span: Span,
+) -> AvailabilityClause {
+    match availability_clause {
+        AvailabilityClause::Message(x) => {
+            AvailabilityClause::Message(node(x, |n, s| folder.fold_string_literal(n, s)))
+        }
+        AvailabilityClause::Replacement(x) => {
+            AvailabilityClause::Replacement(node(x, |n, s| folder.fold_string_literal(n, s)))
+        }
+        x => x,
+    }
+}
+
+pub fn fold_gnu_extended_asm_statement<V: Fold + ?Sized>(
+    folder: &mut V,
+    gnu_extended_asm_statement: GnuExtendedAsmStatement,
+    _span: Span,
+) -> GnuExtendedAsmStatement {
+    GnuExtendedAsmStatement {
+        qualifier: gnu_extended_asm_statement
+            .qualifier
+            .map(|x| node(x, |n, s| folder.fold_type_qualifier(n, s))),
+        template: node(gnu_extended_asm_statement.template, |n, s| {
+            folder.fold_string_literal(n, s)
+        }),
+        outputs: nodes(gnu_extended_asm_statement.outputs, |n, s| {
+            folder.fold_gnu_asm_operand(n, s)
+        }),
+        inputs: nodes(gnu_extended_asm_statement.inputs, |n, s| {
+            folder.fold_gnu_asm_operand(n, s)
+        }),
+        clobbers: nodes(gnu_extended_asm_statement.clobbers, |n, s| {
+            folder.fold_string_literal(n, s)
+        }),
+    }
+}
+
+pub fn fold_gnu_asm_operand<V: Fold + ?Sized>(
+    folder: &mut V,
+    gnu_asm_operand: GnuAsmOperand,
+    _span: Span,
+) -> GnuAsmOperand {
+    GnuAsmOperand {
+        symbolic_name: gnu_asm_operand
+            .symbolic_name
+            .map(|x| node(x, |n, s| folder.fold_identifier(n, s))),
+        constraints: node(gnu_asm_operand.constraints, |n, s| {
+            folder.fold_string_literal(n, s)
+        }),
+        variable_name: node(gnu_asm_operand.variable_name, |n, s| {
+            folder.fold_expression(n, s)
+        }),
+    }
+}
+
+pub fn fold_type_of<V: Fold + ?Sized>(folder: &mut V, type_of: TypeOf, _span: Span) -> TypeOf {
+    match type_of {
+        TypeOf::Expression(x) => TypeOf::Expression(node(x, |n, s| folder.fold_expression(n, s))),
+        TypeOf::Type(x) => TypeOf::Type(node(x, |n, s| folder.fold_type_name(n, s))),
+    }
+}
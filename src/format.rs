@@ -0,0 +1,272 @@
+//! Format C source code
+//!
+//! ```rust
+//! # use lang_c::driver::Config;
+//! # use lang_c::format::format;
+//! # use lang_c::print::Style;
+//! let source = "#include <stdio.h>\nint main(){puts(\"hi\"); // greet\nreturn 0;}\n";
+//! let formatted = format(&Config::default(), &Style::default(), source).unwrap();
+//! assert_eq!(
+//!     formatted,
+//!     "#include <stdio.h>\nint main() {\n    puts(\"hi\"); // greet\n    return 0;\n}\n"
+//! );
+//! ```
+//!
+//! Unlike `driver::parse`, source is not preprocessed. Comments and preprocessor directives are
+//! kept as they are and placed between the surrounding declarations and statements. Macro
+//! invocations are parsed like any other code, which works as long as they expand to and look
+//! like a C expression or statement. Top-level declarations that fail to parse, typically
+//! because they use a macro in place of a type or a statement, are copied to the output
+//! unchanged. A macro invocation on its own line without a semicolon, like `DECLARE(x)`, is
+//! copied alone, and the code after it is still formatted.
+//!
+//! Typedef names declared in included headers are not known, so declarations using them do not
+//! parse and are copied verbatim as well.
+//!
+//! Top-level items are parsed one at a time, so a declaration that fails to parse only costs
+//! one more parse of itself. Typedef names declared by earlier items are known to later ones.
+
+use ast::TranslationUnit;
+use cst::{self, TokenKind};
+use driver::{Config, SyntaxError};
+use env::Env;
+use lexer::{pieces, Piece};
+use line_index::LineIndex;
+use parser::translation_unit;
+use print::{Printer, Style, Trivia, TriviaKind};
+use span::Span;
+
+/// Format a translation unit
+pub fn format(config: &Config, style: &Style, source: &str) -> Result<String, SyntaxError> {
+    let (code, tokens, mut trivia) = scan(source);
+    let mut items = top_level_items(source, &tokens);
+    let mut env = Env::with_config(config);
+    let mut unit = Vec::new();
+
+    let mut i = 0;
+    while i < items.len() {
+        // Input that ends too early, like a K&R function definition split at its parameter
+        // declarations, is parsed again together with the next item
+        let start = item_span(&tokens, items[i]).start;
+        let mut last = i;
+        let err = loop {
+            let end = item_span(&tokens, items[last]).end;
+            env.offset = start;
+            match translation_unit(&code[start..end], &mut env) {
+                Ok(parsed) => {
+                    unit.extend(parsed.0);
+                    break None;
+                }
+                Err(ref err) if err.offset == end - start && last + 1 < items.len() => last += 1,
+                Err(err) => break Some((err, end)),
+            }
+        };
+        let (err, end) = match err {
+            Some(failed) => failed,
+            None => {
+                i = last + 1;
+                continue;
+            }
+        };
+
+        // An unfinished last item is a syntax error, as it would be in a parse of the whole file
+        if err.offset == end - start {
+            let pos = LineIndex::new(source).line_col(end);
+            return Err(SyntaxError {
+                source: source.to_owned(),
+                line: pos.line,
+                column: pos.column,
+                offset: end,
+                expected: err.expected,
+            });
+        }
+
+        if let Some(split) = macro_invocation_end(source, &tokens[items[i].0..items[i].1]) {
+            let split = items[i].0 + split;
+            items.insert(i + 1, (split, items[i].1));
+            items[i].1 = split;
+        }
+        let Span { start, end } = item_span(&tokens, items[i]);
+        trivia.retain(|t| t.offset < start || t.offset >= end);
+        let pos = trivia.iter().take_while(|t| t.offset < start).count();
+        trivia.insert(
+            pos,
+            Trivia {
+                offset: start,
+                kind: TriviaKind::Verbatim,
+                text: source[start..end].trim_end().to_owned(),
+            },
+        );
+        i += 1;
+    }
+
+    let mut printer = Printer::with_style(style.clone());
+    printer.set_trivia(trivia);
+    printer.print(&TranslationUnit(unit));
+    Ok(printer.finish())
+}
+
+/// Source span of the tokens in range
+fn item_span(tokens: &[(TokenKind, Span)], (first, last): (usize, usize)) -> Span {
+    Span::span(tokens[first].1.start, tokens[last - 1].1.end)
+}
+
+/// Split source into code with comments and directives blanked out, its tokens, and the removed
+/// trivia
+fn scan(source: &str) -> (String, Vec<(TokenKind, Span)>, Vec<Trivia>) {
+    let mut code = source.as_bytes().to_vec();
+    let mut tokens = Vec::new();
+    let mut trivia = Vec::new();
+    // Code or trivia seen on the current line
+    let mut line_used = false;
+    let mut line_code = false;
+    let mut blank_before = false;
+    let mut line_start = 0;
+
+    for piece in pieces(source) {
+        let (kind, span) = match piece {
+            Piece::Token(kind, span) => {
+                tokens.push((kind, span));
+                line_used = true;
+                line_code = true;
+                continue;
+            }
+            Piece::Trivia(cst::Trivia {
+                kind: cst::TriviaKind::Whitespace,
+                span,
+            }) => {
+                if source[span.start..span.end].ends_with('\n') {
+                    if !line_used && !blank_before {
+                        trivia.push(Trivia {
+                            offset: line_start,
+                            kind: TriviaKind::BlankLine,
+                            text: String::new(),
+                        });
+                    }
+                    blank_before = !line_used;
+                    line_used = false;
+                    line_code = false;
+                    line_start = span.end;
+                }
+                continue;
+            }
+            Piece::Trivia(cst::Trivia {
+                kind: cst::TriviaKind::Directive,
+                span,
+            }) => (TriviaKind::Verbatim, span),
+            Piece::Trivia(cst::Trivia { span, .. }) if line_code => {
+                (TriviaKind::TrailingComment, span)
+            }
+            Piece::Trivia(cst::Trivia { span, .. }) => (TriviaKind::Comment, span),
+        };
+
+        let text = &source[span.start..span.end];
+        line_used = true;
+        trivia.push(Trivia {
+            offset: span.start,
+            kind,
+            text: text.trim_end().to_owned(),
+        });
+        for b in &mut code[span.start..span.end] {
+            if *b != b'\n' {
+                *b = b' ';
+            }
+        }
+        if text.contains('\n') {
+            line_code = false;
+        }
+    }
+
+    let code = String::from_utf8(code).expect("only whole characters are blanked");
+    (code, tokens, trivia)
+}
+
+/// Token ranges of top-level declarations and function definitions
+///
+/// A declaration ends at a semicolon outside of any brackets, a function definition ends at the
+/// closing brace of a block that follows a closing parenthesis. A block after a semicolon is the
+/// body of a K&R function definition and ends an item of its own.
+fn top_level_items(source: &str, tokens: &[(TokenKind, Span)]) -> Vec<(usize, usize)> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut function_body = false;
+
+    for (i, &token) in tokens.iter().enumerate() {
+        let mut end = false;
+        match punctuator(source, token) {
+            "(" | "[" | "<:" => depth += 1,
+            "{" | "<%" => {
+                if depth == 0 {
+                    let last = if i > 0 {
+                        punctuator(source, tokens[i - 1])
+                    } else {
+                        ""
+                    };
+                    function_body = last == ")" || last == ";";
+                }
+                depth += 1;
+            }
+            ")" | "]" | ":>" => depth = depth.saturating_sub(1),
+            "}" | "%>" => {
+                depth = depth.saturating_sub(1);
+                end = depth == 0 && function_body;
+            }
+            ";" => end = depth == 0,
+            _ => {}
+        }
+        if end {
+            items.push((start, i + 1));
+            start = i + 1;
+            function_body = false;
+        }
+    }
+
+    if start < tokens.len() {
+        items.push((start, tokens.len()));
+    }
+    items
+}
+
+/// End of a macro invocation without a semicolon at the start of an item, like `DECLARE(x)`
+///
+/// Returns the index of the first token on the following lines, which is parsed on its own if
+/// the item as a whole does not parse.
+fn macro_invocation_end(source: &str, tokens: &[(TokenKind, Span)]) -> Option<usize> {
+    match tokens {
+        [(TokenKind::Identifier, _), paren, ..] if punctuator(source, *paren) == "(" => {}
+        _ => return None,
+    }
+
+    let mut depth = 0;
+    let mut i = 1;
+    while i < tokens.len() {
+        match punctuator(source, tokens[i]) {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+        if depth == 0 {
+            break;
+        }
+    }
+    if depth != 0 || i == tokens.len() {
+        return None;
+    }
+
+    let between = &source[tokens[i - 1].1.end..tokens[i].1.start];
+    if between.contains('\n') {
+        Some(i)
+    } else {
+        None
+    }
+}
+
+/// Text of a punctuator token, or an empty string for other tokens
+fn punctuator(source: &str, (kind, span): (TokenKind, Span)) -> &str {
+    match kind {
+        TokenKind::Punctuator => &source[span.start..span.end],
+        _ => "",
+    }
+}
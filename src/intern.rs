@@ -0,0 +1,196 @@
+//! Interned names
+//!
+//! ```rust
+//! # use lang_c::intern::{Interner, Name};
+//! let names = Interner::new();
+//! let a = names.intern("count");
+//! let b = names.clone().intern("count");
+//! assert!(Name::ptr_eq(&a, &b));
+//! assert_eq!(a, Name::from("count"));
+//! assert_eq!(a, "count");
+//! ```
+//!
+//! Names are cheap to clone. Identifiers are interned in the table held by `driver::Config`,
+//! which clones of the configuration share, so all names with the same text from parses with
+//! one configuration share a single allocation. Two names from the same table compare by
+//! address only; other names compare by text. Text stays allocated as long as the table or
+//! any name taken from it, so a table shared by many parses keeps every name seen in them.
+//!
+//! Only identifiers are interned. String literals and constants keep their own copy of the
+//! source text, as borrowing it would tie every AST type to the lifetime of the source.
+
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Shared immutable string
+#[derive(Clone)]
+pub struct Name {
+    text: Arc<str>,
+    /// Identity of the table the name was interned in, or zero
+    table: usize,
+}
+
+/// Table of names, keeping one copy of each distinct text
+///
+/// Clones share the table.
+#[derive(Clone, Debug)]
+pub struct Interner {
+    names: Arc<Mutex<HashSet<Name>>>,
+    table: usize,
+}
+
+impl Interner {
+    pub fn new() -> Interner {
+        static NEXT_TABLE: AtomicUsize = AtomicUsize::new(1);
+        Interner {
+            names: Arc::default(),
+            table: NEXT_TABLE.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// Find or add a name in the table
+    pub fn intern(&self, text: &str) -> Name {
+        let mut names = self.names.lock().expect("interner is not poisoned");
+        if let Some(name) = names.get(text) {
+            return name.clone();
+        }
+        let name = Name {
+            text: Arc::from(text),
+            table: self.table,
+        };
+        names.insert(name.clone());
+        name
+    }
+
+    /// Number of distinct names in the table
+    pub fn len(&self) -> usize {
+        self.names.lock().expect("interner is not poisoned").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for Interner {
+    fn default() -> Interner {
+        Interner::new()
+    }
+}
+
+impl Name {
+    /// Create a name not shared with any other
+    pub fn new(text: &str) -> Name {
+        Name {
+            text: Arc::from(text),
+            table: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Test if two names share their text, as equal names from the same `Interner` do
+    pub fn ptr_eq(a: &Name, b: &Name) -> bool {
+        Arc::ptr_eq(&a.text, &b.text)
+    }
+}
+
+impl Deref for Name {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Hashes the same as the text, so that maps keyed by names can be searched by `&str`
+impl Borrow<str> for Name {
+    fn borrow(&self) -> &str {
+        &self.text
+    }
+}
+
+impl<'a> From<&'a str> for Name {
+    fn from(text: &'a str) -> Name {
+        Name::new(text)
+    }
+}
+
+impl From<String> for Name {
+    fn from(text: String) -> Name {
+        Name::new(&text)
+    }
+}
+
+/// Names from the same table are equal only if they share their text
+impl PartialEq for Name {
+    fn eq(&self, other: &Name) -> bool {
+        if self.table != 0 && self.table == other.table {
+            Name::ptr_eq(self, other)
+        } else {
+            Name::ptr_eq(self, other) || self.text == other.text
+        }
+    }
+}
+
+impl Eq for Name {}
+
+impl PartialEq<str> for Name {
+    fn eq(&self, other: &str) -> bool {
+        &*self.text == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Name {
+    fn eq(&self, other: &&'a str) -> bool {
+        &*self.text == *other
+    }
+}
+
+impl Hash for Name {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state)
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&*self.text, fmt)
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&*self.text, fmt)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Name {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.text)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Name {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Name, D::Error> {
+        String::deserialize(deserializer).map(Name::from)
+    }
+}
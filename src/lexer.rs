@@ -0,0 +1,157 @@
+//! Tokenizer shared by the `cst` and `format` modules
+//!
+//! Splits source text into tokens and trivia without parsing it. Tokens are only classified as
+//! far as needed to find their ends, keywords are identifiers and all operators are punctuators.
+
+use cst::{TokenKind, Trivia, TriviaKind};
+use span::Span;
+
+const PUNCTUATORS: &[&str] = &[
+    "%:%:", "...", "<<=", ">>=", "->", "++", "--", "<<", ">>", "<=", ">=", "==", "!=", "&&", "||",
+    "*=", "/=", "%=", "+=", "-=", "&=", "^=", "|=", "##", "<:", ":>", "<%", "%>", "%:",
+];
+
+fn is_identifier_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || b >= 0x80
+}
+
+/// End of a string or character literal starting at `pos`
+fn quoted_end(bytes: &[u8], pos: usize) -> usize {
+    let quote = bytes[pos];
+    let mut i = pos + 1;
+    while i < bytes.len() && bytes[i] != quote && bytes[i] != b'\n' {
+        if bytes[i] == b'\\' {
+            i += 1;
+        }
+        i += 1;
+    }
+    (i + 1).min(bytes.len())
+}
+
+pub enum Piece {
+    Token(TokenKind, Span),
+    Trivia(Trivia),
+}
+
+/// Split source into tokens and trivia
+pub fn pieces(source: &str) -> Vec<Piece> {
+    let bytes = source.as_bytes();
+    let mut pieces = Vec::new();
+    let mut line_start = true;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+        let b = bytes[i];
+        let next = bytes.get(i + 1).cloned();
+        let trivia = match b {
+            b' ' | b'\t' | b'\r' | b'\x0b' | b'\x0c' | b'\n' => {
+                while i < bytes.len() && b" \t\r\x0b\x0c".contains(&bytes[i]) {
+                    i += 1;
+                }
+                if i < bytes.len() && bytes[i] == b'\n' {
+                    i += 1;
+                    line_start = true;
+                }
+                Some(TriviaKind::Whitespace)
+            }
+            b'#' if line_start => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    if bytes[i] == b'\\' && bytes.get(i + 1) == Some(&b'\n') {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                Some(TriviaKind::Directive)
+            }
+            b'/' if next == Some(b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+                Some(TriviaKind::Comment)
+            }
+            b'/' if next == Some(b'*') => {
+                i = match source[i + 2..].find("*/") {
+                    Some(pos) => i + 2 + pos + 2,
+                    None => bytes.len(),
+                };
+                Some(TriviaKind::Comment)
+            }
+            _ => None,
+        };
+        if let Some(kind) = trivia {
+            pieces.push(Piece::Trivia(Trivia {
+                kind,
+                span: Span::span(start, i),
+            }));
+            continue;
+        }
+
+        line_start = false;
+        let kind = match b {
+            b'"' | b'\'' => {
+                i = quoted_end(bytes, i);
+                if b == b'"' {
+                    TokenKind::String
+                } else {
+                    TokenKind::Character
+                }
+            }
+            b'0'..=b'9' | b'.' if b != b'.' || next.is_some_and(|n| n.is_ascii_digit()) => {
+                while i < bytes.len() {
+                    if b"eEpP".contains(&bytes[i])
+                        && bytes.get(i + 1).is_some_and(|&n| n == b'+' || n == b'-')
+                    {
+                        i += 2;
+                    } else if is_identifier_byte(bytes[i]) || bytes[i] == b'.' {
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+                TokenKind::Number
+            }
+            _ if is_identifier_byte(b)
+                || (b == b'\\' && next.is_some_and(|n| n == b'u' || n == b'U')) =>
+            {
+                while i < bytes.len() {
+                    if bytes[i] == b'\\'
+                        && bytes.get(i + 1).is_some_and(|&n| n == b'u' || n == b'U')
+                    {
+                        i += 2;
+                    } else if is_identifier_byte(bytes[i]) {
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+                let prefix = matches!(&source[start..i], "L" | "u" | "U" | "u8");
+                match bytes.get(i) {
+                    Some(&q) if prefix && (q == b'"' || q == b'\'') => {
+                        i = quoted_end(bytes, i);
+                        if q == b'"' {
+                            TokenKind::String
+                        } else {
+                            TokenKind::Character
+                        }
+                    }
+                    _ => TokenKind::Identifier,
+                }
+            }
+            _ => {
+                let len = PUNCTUATORS
+                    .iter()
+                    .find(|p| source[i..].starts_with(*p))
+                    .map_or(1, |p| p.len());
+                i += len;
+                while !source.is_char_boundary(i) {
+                    i += 1;
+                }
+                TokenKind::Punctuator
+            }
+        };
+        pieces.push(Piece::Token(kind, Span::span(start, i)));
+    }
+
+    pieces
+}
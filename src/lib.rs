@@ -11,15 +11,39 @@
 
 #![allow(deprecated)]
 
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
+#[macro_use]
 pub mod ast;
+pub mod cst;
 pub mod driver;
+pub mod fold;
+pub mod format;
+pub mod intern;
+pub mod line_index;
+pub mod literal;
+pub mod print;
+pub mod query;
+#[macro_use]
+pub mod quote;
+pub mod rewrite;
+pub mod side_table;
+pub mod source_map;
 pub mod span;
 pub mod visit;
+pub mod visit_mut;
+pub mod visit_path;
 
 mod astutil;
 mod env;
+mod lexer;
+#[allow(clippy::all)]
 mod parser;
 mod strings;
+mod structural;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,132 @@
+//! Conversion between byte offsets and line and column numbers
+//!
+//! ```rust
+//! # use lang_c::line_index::{LineCol, LineIndex};
+//! # use lang_c::span::Span;
+//! let index = LineIndex::new("int x;\nchar *s = \"é\"; int y;\n");
+//! assert_eq!(index.line_col(7), LineCol { line: 2, column: 1 });
+//! assert_eq!(index.line_col(23), LineCol { line: 2, column: 17 });
+//! assert_eq!(index.line_col_utf16(23), LineCol { line: 2, column: 16 });
+//! assert_eq!(index.offset_utf16(LineCol { line: 2, column: 16 }), Some(23));
+//! assert_eq!(index.text(Span::span(23, 29)), "int y;");
+//! ```
+//!
+//! Lines and columns are numbered from one, as in `driver::SyntaxError`. Columns count bytes or,
+//! for editors and protocols that need them, UTF-16 code units from the start of the line. Lines
+//! are separated by `\n`, a preceding `\r` belongs to the line it ends.
+
+use span::Span;
+
+/// Line and column of a position
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Start offsets of lines in a text
+#[derive(Clone, Debug)]
+pub struct LineIndex<'a> {
+    text: &'a str,
+    starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    /// Index lines of a text, such as `Parse::source`
+    pub fn new(text: &'a str) -> LineIndex<'a> {
+        let mut starts = vec![0];
+        starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        LineIndex { text, starts }
+    }
+
+    /// Number of lines, counting the empty one after a final line break
+    pub fn line_count(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Text of a line, without the line break
+    pub fn line(&self, line: usize) -> Option<&'a str> {
+        let start = *self.starts.get(line.checked_sub(1)?)?;
+        let end = match self.starts.get(line) {
+            Some(&next) => next - 1,
+            None => self.text.len(),
+        };
+        Some(&self.text[start..end])
+    }
+
+    /// Text covered by a span
+    ///
+    /// Panics if the span is undefined or not within the text.
+    pub fn text(&self, span: Span) -> &'a str {
+        &self.text[span.start..span.end]
+    }
+
+    /// Line and byte column of an offset
+    ///
+    /// Panics if the offset is past the end of the text.
+    pub fn line_col(&self, offset: usize) -> LineCol {
+        let line = self.line_of(offset);
+        LineCol {
+            line: line + 1,
+            column: offset - self.starts[line] + 1,
+        }
+    }
+
+    /// Line and UTF-16 column of an offset
+    ///
+    /// Panics if the offset is past the end of the text or not at a character boundary.
+    pub fn line_col_utf16(&self, offset: usize) -> LineCol {
+        let line = self.line_of(offset);
+        let prefix = &self.text[self.starts[line]..offset];
+        LineCol {
+            line: line + 1,
+            column: prefix.encode_utf16().count() + 1,
+        }
+    }
+
+    /// Positions of the start and the end of a span
+    pub fn span_line_cols(&self, span: Span) -> (LineCol, LineCol) {
+        (self.line_col(span.start), self.line_col(span.end))
+    }
+
+    /// Offset of a line and byte column
+    ///
+    /// Returns `None` if the position is not within the line or not at a character boundary. The
+    /// end of the line, where its line break is, is within the line.
+    pub fn offset(&self, pos: LineCol) -> Option<usize> {
+        let text = self.line(pos.line)?;
+        let column = pos.column.checked_sub(1)?;
+        let offset = self.starts[pos.line - 1] + column;
+        if column > text.len() || !self.text.is_char_boundary(offset) {
+            return None;
+        }
+        Some(offset)
+    }
+
+    /// Offset of a line and UTF-16 column
+    ///
+    /// Returns `None` if the position is not within the line or splits a surrogate pair.
+    pub fn offset_utf16(&self, pos: LineCol) -> Option<usize> {
+        let text = self.line(pos.line)?;
+        let mut units = pos.column.checked_sub(1)?;
+        for (i, c) in text.char_indices() {
+            if units == 0 {
+                return Some(self.starts[pos.line - 1] + i);
+            }
+            units = units.checked_sub(c.len_utf16())?;
+        }
+        match units {
+            0 => Some(self.starts[pos.line - 1] + text.len()),
+            _ => None,
+        }
+    }
+
+    /// Index of the line containing an offset, from zero
+    fn line_of(&self, offset: usize) -> usize {
+        assert!(offset <= self.text.len(), "offset past the end of text");
+        match self.starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next) => next - 1,
+        }
+    }
+}
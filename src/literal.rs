@@ -0,0 +1,770 @@
+//! Values of literal constants
+//!
+//! The parser keeps constants as they are spelled in the source. Methods and functions in this
+//! module compute their values: numeric values and types of integer constants, exact values of
+//! floating constants, code points of character constants and contents of string literals.
+//!
+//! ```
+//! use lang_c::ast::{Integer, IntegerBase, IntegerSize, IntegerSuffix};
+//! use lang_c::literal::{IntegerType, Target};
+//!
+//! let i = Integer {
+//!     base: IntegerBase::Hexadecimal,
+//!     number: "ffffffff".into(),
+//!     suffix: IntegerSuffix {
+//!         size: IntegerSize::Int,
+//!         unsigned: false,
+//!         imaginary: false,
+//!     },
+//! };
+//! assert_eq!(i.value(), Ok(0xffffffff));
+//! assert_eq!(i.integer_type(&Target::lp64()), Ok(IntegerType::UnsignedInt));
+//! ```
+
+use std::error;
+use std::fmt;
+
+use ast::*;
+use astutil::decode_ucn;
+
+/// Properties of the target platform that affect values of constants
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Target {
+    /// Width of `int` in bits
+    pub int_width: u32,
+    /// Width of `long` in bits
+    pub long_width: u32,
+    /// Width of `long long` in bits
+    pub long_long_width: u32,
+    /// Width of `wchar_t` in bits
+    pub wchar_width: u32,
+    /// Plain `char` is a signed type
+    pub char_signed: bool,
+}
+
+impl Target {
+    /// 64-bit Unix-like platforms (x86-64 Linux, macOS)
+    pub fn lp64() -> Target {
+        Target {
+            int_width: 32,
+            long_width: 64,
+            long_long_width: 64,
+            wchar_width: 32,
+            char_signed: true,
+        }
+    }
+
+    /// 64-bit Windows
+    pub fn llp64() -> Target {
+        Target {
+            int_width: 32,
+            long_width: 32,
+            long_long_width: 64,
+            wchar_width: 16,
+            char_signed: true,
+        }
+    }
+
+    /// 32-bit Unix-like platforms
+    pub fn ilp32() -> Target {
+        Target {
+            int_width: 32,
+            long_width: 32,
+            long_long_width: 64,
+            wchar_width: 32,
+            char_signed: true,
+        }
+    }
+}
+
+impl Default for Target {
+    fn default() -> Target {
+        Target::lp64()
+    }
+}
+
+/// Error decoding a literal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Integer constant does not fit into any type it can have
+    IntegerOverflow,
+    /// Unknown escape sequence
+    InvalidEscape,
+    /// Universal character name is malformed or designates a character it may not
+    InvalidUniversalCharacterName,
+    /// Octal or hexadecimal escape sequence value does not fit into the character type
+    EscapeOutOfRange,
+    /// Literal text is not a valid constant
+    Malformed,
+    /// Concatenated string literals have different encoding prefixes
+    IncompatibleEncodings,
+    /// Character constant with an encoding prefix contains more than one character
+    PrefixedMultiCharacter,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(error::Error::description(self))
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::IntegerOverflow => "integer constant is too large for its type",
+            Error::InvalidEscape => "unknown escape sequence",
+            Error::InvalidUniversalCharacterName => "invalid universal character name",
+            Error::EscapeOutOfRange => "escape sequence out of range",
+            Error::Malformed => "malformed literal",
+            Error::IncompatibleEncodings => {
+                "concatenation of string literals with different prefixes"
+            }
+            Error::PrefixedMultiCharacter => "multi-character constant with an encoding prefix",
+        }
+    }
+}
+
+// From 6.4.4.1 Integer constants
+
+/// Type of an integer constant
+///
+/// (C11 6.4.4.1 §5)
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum IntegerType {
+    Int,
+    UnsignedInt,
+    Long,
+    UnsignedLong,
+    LongLong,
+    UnsignedLongLong,
+}
+
+impl IntegerType {
+    /// Width of the type in bits
+    pub fn width(&self, target: &Target) -> u32 {
+        match *self {
+            IntegerType::Int | IntegerType::UnsignedInt => target.int_width,
+            IntegerType::Long | IntegerType::UnsignedLong => target.long_width,
+            IntegerType::LongLong | IntegerType::UnsignedLongLong => target.long_long_width,
+        }
+    }
+
+    /// Type is a signed integer type
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            *self,
+            IntegerType::Int | IntegerType::Long | IntegerType::LongLong
+        )
+    }
+
+    /// Largest value representable by the type
+    pub fn max_value(&self, target: &Target) -> u64 {
+        let width = self.width(target) - if self.is_signed() { 1 } else { 0 };
+        if width >= 64 {
+            u64::MAX
+        } else {
+            (1 << width) - 1
+        }
+    }
+}
+
+impl Integer {
+    /// Numeric value of the constant
+    ///
+    /// Fails if the value does not fit into 64 bits.
+    pub fn value(&self) -> Result<u64, Error> {
+        let radix = match self.base {
+            IntegerBase::Decimal => 10,
+            IntegerBase::Octal => 8,
+            IntegerBase::Hexadecimal => 16,
+            IntegerBase::Binary => 2,
+        };
+
+        let mut value: u64 = 0;
+        for c in self.number.chars() {
+            let digit = match c.to_digit(radix) {
+                Some(d) => d as u64,
+                None => return Err(Error::Malformed),
+            };
+            value = match value.checked_mul(radix as u64) {
+                Some(v) => v,
+                None => return Err(Error::IntegerOverflow),
+            };
+            value = match value.checked_add(digit) {
+                Some(v) => v,
+                None => return Err(Error::IntegerOverflow),
+            };
+        }
+
+        Ok(value)
+    }
+
+    /// Type of the constant on the given target
+    ///
+    /// The type is the first one from the list in C11 6.4.4.1 §5 that can represent the value.
+    /// Fails if there is no such type.
+    pub fn integer_type(&self, target: &Target) -> Result<IntegerType, Error> {
+        use self::IntegerType::*;
+
+        let value = self.value()?;
+        let decimal = self.base == IntegerBase::Decimal;
+
+        let candidates: &[IntegerType] = match (self.suffix.unsigned, &self.suffix.size, decimal) {
+            (false, &IntegerSize::Int, true) => &[Int, Long, LongLong],
+            (false, &IntegerSize::Int, false) => &[
+                Int,
+                UnsignedInt,
+                Long,
+                UnsignedLong,
+                LongLong,
+                UnsignedLongLong,
+            ],
+            (true, &IntegerSize::Int, _) => &[UnsignedInt, UnsignedLong, UnsignedLongLong],
+            (false, &IntegerSize::Long, true) => &[Long, LongLong],
+            (false, &IntegerSize::Long, false) => &[Long, UnsignedLong, LongLong, UnsignedLongLong],
+            (true, &IntegerSize::Long, _) => &[UnsignedLong, UnsignedLongLong],
+            (false, &IntegerSize::LongLong, true) => &[LongLong],
+            (false, &IntegerSize::LongLong, false) => &[LongLong, UnsignedLongLong],
+            (true, &IntegerSize::LongLong, _) => &[UnsignedLongLong],
+        };
+
+        candidates
+            .iter()
+            .find(|t| value <= t.max_value(target))
+            .cloned()
+            .ok_or(Error::IntegerOverflow)
+    }
+}
+
+// From 6.4.4.2 Floating constants
+
+/// Exact value of a floating constant
+///
+/// The value is `significand × 10^exponent` for decimal constants and
+/// `significand × 2^exponent` for hexadecimal constants. Values are normalized, so two
+/// constants are equal exactly when their values are equal.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct FloatValue {
+    pub base: FloatBase,
+    /// Digits of the significand in the base of the constant, without leading or trailing zeros
+    ///
+    /// Empty for zero.
+    pub significand: String,
+    pub exponent: i64,
+}
+
+impl Float {
+    /// Exact value of the constant
+    pub fn value(&self) -> FloatValue {
+        let (markers, digit_exponent) = match self.base {
+            FloatBase::Decimal => (['e', 'E'], 1),
+            FloatBase::Hexadecimal => (['p', 'P'], 4),
+        };
+
+        let (mantissa, mut exponent) = match self.number.find(&markers[..]) {
+            Some(pos) => (&self.number[..pos], parse_exponent(&self.number[pos + 1..])),
+            None => (&self.number[..], 0),
+        };
+
+        let mut significand = String::new();
+        let mut fraction = false;
+        for c in mantissa.chars() {
+            if c == '.' {
+                fraction = true;
+                continue;
+            }
+            if fraction {
+                exponent = exponent.saturating_sub(digit_exponent);
+            }
+            if c != '0' || !significand.is_empty() {
+                significand.push(c.to_ascii_lowercase());
+            }
+        }
+
+        while significand.ends_with('0') {
+            significand.pop();
+            exponent = exponent.saturating_add(digit_exponent);
+        }
+
+        if significand.is_empty() {
+            exponent = 0;
+        }
+
+        FloatValue {
+            base: self.base.clone(),
+            significand,
+            exponent,
+        }
+    }
+
+    /// Value of the constant correctly rounded to a double precision number
+    pub fn to_f64(&self) -> f64 {
+        let value = self.value();
+        match value.base {
+            FloatBase::Decimal => decimal_to_float(&value).parse().unwrap_or(0.0),
+            FloatBase::Hexadecimal => f64::from_bits(binary_to_float(&value, &DOUBLE)),
+        }
+    }
+
+    /// Value of the constant correctly rounded to a single precision number
+    pub fn to_f32(&self) -> f32 {
+        let value = self.value();
+        match value.base {
+            FloatBase::Decimal => decimal_to_float(&value).parse().unwrap_or(0.0),
+            FloatBase::Hexadecimal => f32::from_bits(binary_to_float(&value, &SINGLE) as u32),
+        }
+    }
+}
+
+fn parse_exponent(s: &str) -> i64 {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let mut value: i64 = 0;
+    for c in digits.chars() {
+        let digit = c.to_digit(10).unwrap_or(0) as i64;
+        value = value.saturating_mul(10).saturating_add(digit);
+    }
+
+    if negative {
+        -value
+    } else {
+        value
+    }
+}
+
+fn decimal_to_float(value: &FloatValue) -> String {
+    if value.significand.is_empty() {
+        "0".to_owned()
+    } else {
+        format!("{}e{}", value.significand, value.exponent)
+    }
+}
+
+struct BinaryFormat {
+    /// Number of significand bits, including the implicit one
+    precision: u32,
+    /// Exponent of the smallest normal number
+    min_exponent: i64,
+    /// Exponent of the largest finite number
+    max_exponent: i64,
+}
+
+const DOUBLE: BinaryFormat = BinaryFormat {
+    precision: 53,
+    min_exponent: -1022,
+    max_exponent: 1023,
+};
+
+const SINGLE: BinaryFormat = BinaryFormat {
+    precision: 24,
+    min_exponent: -126,
+    max_exponent: 127,
+};
+
+/// Round hexadecimal significand to the nearest value representable in a binary format
+///
+/// Returns bit pattern of the resulting number.
+fn binary_to_float(value: &FloatValue, format: &BinaryFormat) -> u64 {
+    let frac_bits = format.precision - 1;
+    let infinity = ((format.max_exponent * 2 + 1) as u64) << frac_bits;
+
+    // Keep as many leading bits as fit, remember if any of the dropped ones were set.
+    let mut significand: u128 = 0;
+    let mut exponent = value.exponent;
+    let mut sticky = false;
+    for c in value.significand.chars() {
+        let digit = c.to_digit(16).unwrap_or(0) as u128;
+        if significand >> 124 == 0 {
+            significand = significand << 4 | digit;
+        } else {
+            exponent = exponent.saturating_add(4);
+            sticky |= digit != 0;
+        }
+    }
+
+    if significand == 0 {
+        return 0;
+    }
+
+    let length = 128 - significand.leading_zeros() as i64;
+    let top = exponent.saturating_add(length - 1);
+    if top > format.max_exponent {
+        return infinity;
+    }
+
+    let keep = if top >= format.min_exponent {
+        format.precision as i64
+    } else {
+        format.precision as i64 - (format.min_exponent - top)
+    };
+    if keep < 0 {
+        return 0;
+    }
+
+    let shift = length - keep;
+    let mut result = if shift > 0 {
+        let shift = shift as u32;
+        let rest = significand & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        let truncated = (significand >> shift) as u64;
+        if rest > half || (rest == half && (sticky || truncated & 1 == 1)) {
+            truncated + 1
+        } else {
+            truncated
+        }
+    } else {
+        (significand as u64) << -shift
+    };
+
+    if top < format.min_exponent {
+        // Subnormal numbers have no implicit bit, rounding up may produce the smallest normal.
+        return result;
+    }
+
+    let mut top = top;
+    if result >> format.precision != 0 {
+        result >>= 1;
+        top += 1;
+        if top > format.max_exponent {
+            return infinity;
+        }
+    }
+
+    let biased = (top + format.max_exponent) as u64;
+    biased << frac_bits | (result & ((1 << frac_bits) - 1))
+}
+
+// From 6.4.4.4 Character constants
+
+/// Encoding prefix of a character constant or a string literal
+///
+/// (C11 6.4.4.4, 6.4.5)
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Encoding {
+    /// No prefix
+    Plain,
+    /// `u8`
+    Utf8,
+    /// `u`, `char16_t`
+    Char16,
+    /// `U`, `char32_t`
+    Char32,
+    /// `L`, `wchar_t`
+    Wide,
+}
+
+impl Encoding {
+    /// Split encoding prefix from a literal
+    fn split(literal: &str) -> (Encoding, &str) {
+        let prefixes = [
+            ("u8", Encoding::Utf8),
+            ("u", Encoding::Char16),
+            ("U", Encoding::Char32),
+            ("L", Encoding::Wide),
+        ];
+        for &(prefix, encoding) in &prefixes {
+            if let Some(rest) = literal.strip_prefix(prefix) {
+                return (encoding, rest);
+            }
+        }
+        (Encoding::Plain, literal)
+    }
+
+    /// Width of a code unit in bits
+    pub fn unit_width(&self, target: &Target) -> u32 {
+        match *self {
+            Encoding::Plain | Encoding::Utf8 => 8,
+            Encoding::Char16 => 16,
+            Encoding::Char32 => 32,
+            Encoding::Wide => target.wchar_width,
+        }
+    }
+}
+
+/// Decoded character constant
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct CharacterValue {
+    pub encoding: Encoding,
+    /// Code units of all characters in the constant
+    ///
+    /// Plain constants are encoded in UTF-8, prefixed ones in UTF-16 or UTF-32 depending on the
+    /// width of the character type.
+    pub units: Vec<u32>,
+}
+
+impl CharacterValue {
+    /// Value of the constant as an integer
+    ///
+    /// Values of multi-character constants are implementation-defined (C11 6.4.4.4 §10-11).
+    /// Here they follow GCC: code units of plain constants are concatenated into an `int`.
+    /// Prefixed constants hold a single character, and take the value of its last code unit if
+    /// it needs more than one.
+    pub fn value(&self, target: &Target) -> i64 {
+        match self.encoding {
+            Encoding::Plain | Encoding::Utf8 => {
+                let mask = match target.int_width {
+                    64 => u64::MAX,
+                    w => (1 << w) - 1,
+                };
+                let value = self
+                    .units
+                    .iter()
+                    .fold(0u64, |v, &u| (v << 8 | u as u64) & mask);
+                if self.units.len() == 1 {
+                    if target.char_signed {
+                        value as u8 as i8 as i64
+                    } else {
+                        value as i64
+                    }
+                } else {
+                    sign_extend(value, target.int_width)
+                }
+            }
+            _ => self.units.last().map(|&u| u as i64).unwrap_or(0),
+        }
+    }
+
+    /// Character designated by a constant consisting of exactly one character
+    pub fn code_point(&self) -> Option<char> {
+        let units = self.units.iter().map(|&u| u as u16).collect::<Vec<_>>();
+        let decoded = match self.encoding {
+            Encoding::Plain | Encoding::Utf8 => {
+                let bytes = self.units.iter().map(|&u| u as u8).collect::<Vec<_>>();
+                String::from_utf8(bytes).ok()
+            }
+            Encoding::Char16 => String::from_utf16(&units).ok(),
+            Encoding::Char32 | Encoding::Wide => self
+                .units
+                .iter()
+                .map(|&u| ::std::char::from_u32(u))
+                .collect(),
+        };
+        let decoded = decoded?;
+        let mut chars = decoded.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+}
+
+fn sign_extend(value: u64, width: u32) -> i64 {
+    let shift = 64 - width;
+    ((value << shift) as i64) >> shift
+}
+
+/// Decode a character constant
+///
+/// Takes the text of `Constant::Character`, including the encoding prefix and quotes. Only
+/// plain constants may contain more than one character.
+pub fn character_value(constant: &str, target: &Target) -> Result<CharacterValue, Error> {
+    let (encoding, quoted) = Encoding::split(constant);
+    if quoted.len() < 3 || !quoted.starts_with('\'') || !quoted.ends_with('\'') {
+        return Err(Error::Malformed);
+    }
+
+    let mut units = Vec::new();
+    let chars = decode_into(&quoted[1..quoted.len() - 1], encoding, target, &mut units)?;
+    if chars > 1 && encoding != Encoding::Plain {
+        return Err(Error::PrefixedMultiCharacter);
+    }
+
+    Ok(CharacterValue { encoding, units })
+}
+
+// From 6.4.5 String literals
+
+/// Code units of a decoded string literal
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum CodeUnits {
+    Bytes(Vec<u8>),
+    Utf16(Vec<u16>),
+    Utf32(Vec<u32>),
+}
+
+impl CodeUnits {
+    /// Number of code units
+    pub fn len(&self) -> usize {
+        match *self {
+            CodeUnits::Bytes(ref u) => u.len(),
+            CodeUnits::Utf16(ref u) => u.len(),
+            CodeUnits::Utf32(ref u) => u.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Decoded string literal
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct StringValue {
+    /// Encoding of the whole literal after concatenation
+    pub encoding: Encoding,
+    /// Code units of the literal, without the terminating null character
+    ///
+    /// Plain and `u8` literals are encoded in UTF-8, prefixed ones in UTF-16 or UTF-32 depending
+    /// on the width of the character type.
+    pub units: CodeUnits,
+}
+
+impl StringValue {
+    /// Length of the array initialized by the literal, including the terminating null character
+    pub fn array_len(&self) -> usize {
+        self.units.len() + 1
+    }
+
+    /// Contents of the literal as text, if it is valid in its encoding
+    pub fn text(&self) -> Option<String> {
+        match self.units {
+            CodeUnits::Bytes(ref u) => String::from_utf8(u.clone()).ok(),
+            CodeUnits::Utf16(ref u) => String::from_utf16(u).ok(),
+            CodeUnits::Utf32(ref u) => u.iter().map(|&u| ::std::char::from_u32(u)).collect(),
+        }
+    }
+}
+
+/// Decode a string literal
+///
+/// Adjacent pieces are concatenated following C11 6.4.5 §5: if any piece has an encoding
+/// prefix, the whole literal has it. Pieces with different prefixes are rejected.
+pub fn string_value(literal: &StringLiteral, target: &Target) -> Result<StringValue, Error> {
+    let mut encoding = Encoding::Plain;
+    let mut pieces = Vec::with_capacity(literal.len());
+
+    for piece in literal {
+        let (prefix, quoted) = Encoding::split(piece);
+        if quoted.len() < 2 || !quoted.starts_with('"') || !quoted.ends_with('"') {
+            return Err(Error::Malformed);
+        }
+        if prefix != Encoding::Plain {
+            if encoding != Encoding::Plain && encoding != prefix {
+                return Err(Error::IncompatibleEncodings);
+            }
+            encoding = prefix;
+        }
+        pieces.push(&quoted[1..quoted.len() - 1]);
+    }
+
+    let mut units = Vec::new();
+    for piece in pieces {
+        decode_into(piece, encoding, target, &mut units)?;
+    }
+
+    let units = match encoding.unit_width(target) {
+        8 => CodeUnits::Bytes(units.into_iter().map(|u| u as u8).collect()),
+        16 => CodeUnits::Utf16(units.into_iter().map(|u| u as u16).collect()),
+        _ => CodeUnits::Utf32(units),
+    };
+
+    Ok(StringValue { encoding, units })
+}
+
+/// Decode escape sequences and encode characters into code units of the given encoding
+///
+/// Returns the number of characters and escape sequences decoded.
+fn decode_into(
+    text: &str,
+    encoding: Encoding,
+    target: &Target,
+    units: &mut Vec<u32>,
+) -> Result<usize, Error> {
+    let width = encoding.unit_width(target);
+    let mut chars = text.char_indices().peekable();
+    let mut count = 0;
+
+    while let Some((pos, c)) = chars.next() {
+        count += 1;
+        if c != '\\' {
+            encode(c, width, units);
+            continue;
+        }
+
+        let e = match chars.next() {
+            Some((_, e)) => e,
+            None => return Err(Error::Malformed),
+        };
+
+        let simple = match e {
+            '\'' | '"' | '?' | '\\' => Some(e as u32),
+            'a' => Some(7),
+            'b' => Some(8),
+            'f' => Some(12),
+            'n' => Some(10),
+            'r' => Some(13),
+            't' => Some(9),
+            'v' => Some(11),
+            _ => None,
+        };
+
+        let unit = if let Some(unit) = simple {
+            unit
+        } else if e.is_digit(8) {
+            let mut value = e.to_digit(8).unwrap();
+            for _ in 0..2 {
+                match chars.peek().and_then(|&(_, c)| c.to_digit(8)) {
+                    Some(d) => value = value * 8 + d,
+                    None => break,
+                }
+                chars.next();
+            }
+            value
+        } else if e == 'x' {
+            let mut value: u32 = 0;
+            let mut digits = 0;
+            while let Some(d) = chars.peek().and_then(|&(_, c)| c.to_digit(16)) {
+                value = value
+                    .checked_mul(16)
+                    .and_then(|v| v.checked_add(d))
+                    .ok_or(Error::EscapeOutOfRange)?;
+                digits += 1;
+                chars.next();
+            }
+            if digits == 0 {
+                return Err(Error::InvalidEscape);
+            }
+            value
+        } else if e == 'u' || e == 'U' {
+            let len = if e == 'u' { 6 } else { 10 };
+            let ucn = text
+                .get(pos..pos + len)
+                .ok_or(Error::InvalidUniversalCharacterName)?;
+            let c = decode_ucn(ucn)
+                .and_then(|s| s.chars().next())
+                .ok_or(Error::InvalidUniversalCharacterName)?;
+            for _ in 2..len {
+                chars.next();
+            }
+            encode(c, width, units);
+            continue;
+        } else {
+            return Err(Error::InvalidEscape);
+        };
+
+        if width < 32 && unit >> width != 0 {
+            return Err(Error::EscapeOutOfRange);
+        }
+        units.push(unit);
+    }
+
+    Ok(count)
+}
+
+/// Encode a character in UTF-8, UTF-16 or UTF-32, depending on the width of the code unit
+fn encode(c: char, width: u32, units: &mut Vec<u32>) {
+    match width {
+        8 => {
+            let mut buf = [0; 4];
+            units.extend(c.encode_utf8(&mut buf).bytes().map(|b| b as u32));
+        }
+        16 => {
+            let mut buf = [0; 2];
+            units.extend(c.encode_utf16(&mut buf).iter().map(|&u| u as u32));
+        }
+        _ => units.push(c as u32),
+    }
+}
@@ -210,6 +210,121 @@ fn __parse_directive<'input>(__input: &'input str, __state: &mut ParseState<'inp
     }
 }
 
+fn __parse_fragment_space<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    {
+        __state.suppress_fail += 1;
+        let res = {
+            let mut __repeat_pos = __pos;
+            loop {
+                let __pos = __repeat_pos;
+                let __step_res = {
+                    let __choice_res = if __input.len() > __pos {
+                        let (__ch, __next) = char_range_at(__input, __pos);
+                        match __ch {
+                            ' ' | '\t' | '\r' | '\n' => Matched(__next, ()),
+                            _ => __state.mark_failure(__pos, "[ \t\r\n]"),
+                        }
+                    } else {
+                        __state.mark_failure(__pos, "[ \t\r\n]")
+                    };
+                    match __choice_res {
+                        Matched(__pos, __value) => Matched(__pos, __value),
+                        Failed => {
+                            let __choice_res = {
+                                let __seq_res = slice_eq(__input, __state, __pos, "/*");
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = {
+                                            let mut __repeat_pos = __pos;
+                                            loop {
+                                                let __pos = __repeat_pos;
+                                                let __step_res = {
+                                                    let __seq_res = {
+                                                        __state.suppress_fail += 1;
+                                                        let __assert_res = slice_eq(__input, __state, __pos, "*/");
+                                                        __state.suppress_fail -= 1;
+                                                        match __assert_res {
+                                                            Failed => Matched(__pos, ()),
+                                                            Matched(..) => Failed,
+                                                        }
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => any_char(__input, __state, __pos),
+                                                        Failed => Failed,
+                                                    }
+                                                };
+                                                match __step_res {
+                                                    Matched(__newpos, __value) => {
+                                                        __repeat_pos = __newpos;
+                                                    }
+                                                    Failed => {
+                                                        break;
+                                                    }
+                                                }
+                                            }
+                                            Matched(__repeat_pos, ())
+                                        };
+                                        match __seq_res {
+                                            Matched(__pos, _) => slice_eq(__input, __state, __pos, "*/"),
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            };
+                            match __choice_res {
+                                Matched(__pos, __value) => Matched(__pos, __value),
+                                Failed => {
+                                    let __seq_res = slice_eq(__input, __state, __pos, "//");
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let mut __repeat_pos = __pos;
+                                            loop {
+                                                let __pos = __repeat_pos;
+                                                let __step_res = if __input.len() > __pos {
+                                                    let (__ch, __next) = char_range_at(__input, __pos);
+                                                    match __ch {
+                                                        '\n' => __state.mark_failure(__pos, "[^\n]"),
+                                                        _ => Matched(__next, ()),
+                                                    }
+                                                } else {
+                                                    __state.mark_failure(__pos, "[^\n]")
+                                                };
+                                                match __step_res {
+                                                    Matched(__newpos, __value) => {
+                                                        __repeat_pos = __newpos;
+                                                    }
+                                                    Failed => {
+                                                        break;
+                                                    }
+                                                }
+                                            }
+                                            Matched(__repeat_pos, ())
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            }
+                        }
+                    }
+                };
+                match __step_res {
+                    Matched(__newpos, __value) => {
+                        __repeat_pos = __newpos;
+                    }
+                    Failed => {
+                        break;
+                    }
+                }
+            }
+            Matched(__repeat_pos, ())
+        };
+        __state.suppress_fail -= 1;
+        res
+    }
+}
+
 fn __parse_identifier<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Identifier>> {
     #![allow(non_snake_case, unused)]
     {
@@ -221,7 +336,7 @@ fn __parse_identifier<'input>(__input: &'input str, __state: &mut ParseState<'in
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -239,29 +354,13 @@ fn __parse_identifier0<'input>(__input: &'input str, __state: &mut ParseState<'i
         let __seq_res = {
             let str_start = __pos;
             match {
-                let __seq_res = if __input.len() > __pos {
-                    let (__ch, __next) = char_range_at(__input, __pos);
-                    match __ch {
-                        '_' | 'a'...'z' | 'A'...'Z' => Matched(__next, ()),
-                        _ => __state.mark_failure(__pos, "[_a-zA-Z]"),
-                    }
-                } else {
-                    __state.mark_failure(__pos, "[_a-zA-Z]")
-                };
+                let __seq_res = __parse_identifier_nondigit(__input, __state, __pos, env);
                 match __seq_res {
                     Matched(__pos, _) => {
                         let mut __repeat_pos = __pos;
                         loop {
                             let __pos = __repeat_pos;
-                            let __step_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __step_res = __parse_identifier_char(__input, __state, __pos, env);
                             match __step_res {
                                 Matched(__newpos, __value) => {
                                     __repeat_pos = __newpos;
@@ -283,8 +382,8 @@ fn __parse_identifier0<'input>(__input: &'input str, __state: &mut ParseState<'i
         match __seq_res {
             Matched(__pos, n) => {
                 match {
-                    if !env.reserved.contains(n) {
-                        Ok(Identifier { name: n.into() })
+                    if !env.is_reserved(n) {
+                        check_identifier(n).map(|_| Identifier { name: env.intern(n) })
                     } else {
                         Err("identifier")
                     }
@@ -301,6 +400,117 @@ fn __parse_identifier0<'input>(__input: &'input str, __state: &mut ParseState<'i
     }
 }
 
+fn __parse_identifier_nondigit<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = if __input.len() > __pos {
+            let (__ch, __next) = char_range_at(__input, __pos);
+            match __ch {
+                '_' | 'a'...'z' | 'A'...'Z' | '\u{80}'...'\u{10ffff}' => Matched(__next, ()),
+                _ => __state.mark_failure(__pos, "[_a-zA-Z\u{80}-\u{10ffff}]"),
+            }
+        } else {
+            __state.mark_failure(__pos, "[_a-zA-Z\u{80}-\u{10ffff}]")
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => __parse_universal_character_name(__input, __state, __pos, env),
+        }
+    }
+}
+
+fn __parse_identifier_char<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = __parse_identifier_nondigit(__input, __state, __pos, env);
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                if __input.len() > __pos {
+                    let (__ch, __next) = char_range_at(__input, __pos);
+                    match __ch {
+                        '0'...'9' => Matched(__next, ()),
+                        _ => __state.mark_failure(__pos, "[0-9]"),
+                    }
+                } else {
+                    __state.mark_failure(__pos, "[0-9]")
+                }
+            }
+        }
+    }
+}
+
+fn __parse_universal_character_name<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __seq_res = slice_eq(__input, __state, __pos, "\\u");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let mut __repeat_pos = __pos;
+                    let mut __repeat_value = vec![];
+                    loop {
+                        let __pos = __repeat_pos;
+                        if __repeat_value.len() >= 4 {
+                            break;
+                        }
+                        let __step_res = __parse_hex(__input, __state, __pos, env);
+                        match __step_res {
+                            Matched(__newpos, __value) => {
+                                __repeat_pos = __newpos;
+                                __repeat_value.push(__value);
+                            }
+                            Failed => {
+                                break;
+                            }
+                        }
+                    }
+                    if __repeat_value.len() >= 4 {
+                        Matched(__repeat_pos, ())
+                    } else {
+                        Failed
+                    }
+                }
+                Failed => Failed,
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __seq_res = slice_eq(__input, __state, __pos, "\\U");
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            if __repeat_value.len() >= 8 {
+                                break;
+                            }
+                            let __step_res = __parse_hex(__input, __state, __pos, env);
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        if __repeat_value.len() >= 8 {
+                            Matched(__repeat_pos, ())
+                        } else {
+                            Failed
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+        }
+    }
+}
+
 fn __parse_ohx<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
     #![allow(non_snake_case, unused)]
     {
@@ -1525,81 +1735,87 @@ fn __parse_character<'input>(__input: &'input str, __state: &mut ParseState<'inp
 fn __parse_escape_sequence<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = slice_eq(__input, __state, __pos, "\\");
-        match __seq_res {
-            Matched(__pos, _) => {
-                let __choice_res = if __input.len() > __pos {
-                    let (__ch, __next) = char_range_at(__input, __pos);
-                    match __ch {
-                        '\'' | '"' | '?' | '\\' | 'a' | 'b' | 'c' | 'f' | 'n' | 'r' | 't' | 'v' => Matched(__next, ()),
-                        _ => __state.mark_failure(__pos, "['\"?\\abcfnrtv]"),
-                    }
-                } else {
-                    __state.mark_failure(__pos, "['\"?\\abcfnrtv]")
-                };
-                match __choice_res {
-                    Matched(__pos, __value) => Matched(__pos, __value),
-                    Failed => {
-                        let __choice_res = {
-                            let mut __repeat_pos = __pos;
-                            let mut __repeat_value = vec![];
-                            loop {
-                                let __pos = __repeat_pos;
-                                if __repeat_value.len() >= 3 {
-                                    break;
-                                }
-                                let __step_res = __parse_oct(__input, __state, __pos, env);
-                                match __step_res {
-                                    Matched(__newpos, __value) => {
-                                        __repeat_pos = __newpos;
-                                        __repeat_value.push(__value);
-                                    }
-                                    Failed => {
+        let __choice_res = {
+            let __seq_res = slice_eq(__input, __state, __pos, "\\");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __choice_res = if __input.len() > __pos {
+                        let (__ch, __next) = char_range_at(__input, __pos);
+                        match __ch {
+                            '\'' | '"' | '?' | '\\' | 'a' | 'b' | 'c' | 'f' | 'n' | 'r' | 't' | 'v' => Matched(__next, ()),
+                            _ => __state.mark_failure(__pos, "['\"?\\abcfnrtv]"),
+                        }
+                    } else {
+                        __state.mark_failure(__pos, "['\"?\\abcfnrtv]")
+                    };
+                    match __choice_res {
+                        Matched(__pos, __value) => Matched(__pos, __value),
+                        Failed => {
+                            let __choice_res = {
+                                let mut __repeat_pos = __pos;
+                                let mut __repeat_value = vec![];
+                                loop {
+                                    let __pos = __repeat_pos;
+                                    if __repeat_value.len() >= 3 {
                                         break;
                                     }
+                                    let __step_res = __parse_oct(__input, __state, __pos, env);
+                                    match __step_res {
+                                        Matched(__newpos, __value) => {
+                                            __repeat_pos = __newpos;
+                                            __repeat_value.push(__value);
+                                        }
+                                        Failed => {
+                                            break;
+                                        }
+                                    }
                                 }
-                            }
-                            if __repeat_value.len() >= 1 {
-                                Matched(__repeat_pos, ())
-                            } else {
-                                Failed
-                            }
-                        };
-                        match __choice_res {
-                            Matched(__pos, __value) => Matched(__pos, __value),
-                            Failed => {
-                                let __seq_res = slice_eq(__input, __state, __pos, "x");
-                                match __seq_res {
-                                    Matched(__pos, _) => {
-                                        let mut __repeat_pos = __pos;
-                                        let mut __repeat_value = vec![];
-                                        loop {
-                                            let __pos = __repeat_pos;
-                                            let __step_res = __parse_hex(__input, __state, __pos, env);
-                                            match __step_res {
-                                                Matched(__newpos, __value) => {
-                                                    __repeat_pos = __newpos;
-                                                    __repeat_value.push(__value);
-                                                }
-                                                Failed => {
-                                                    break;
+                                if __repeat_value.len() >= 1 {
+                                    Matched(__repeat_pos, ())
+                                } else {
+                                    Failed
+                                }
+                            };
+                            match __choice_res {
+                                Matched(__pos, __value) => Matched(__pos, __value),
+                                Failed => {
+                                    let __seq_res = slice_eq(__input, __state, __pos, "x");
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let mut __repeat_pos = __pos;
+                                            let mut __repeat_value = vec![];
+                                            loop {
+                                                let __pos = __repeat_pos;
+                                                let __step_res = __parse_hex(__input, __state, __pos, env);
+                                                match __step_res {
+                                                    Matched(__newpos, __value) => {
+                                                        __repeat_pos = __newpos;
+                                                        __repeat_value.push(__value);
+                                                    }
+                                                    Failed => {
+                                                        break;
+                                                    }
                                                 }
                                             }
+                                            if __repeat_value.len() >= 1 {
+                                                Matched(__repeat_pos, ())
+                                            } else {
+                                                Failed
+                                            }
                                         }
-                                        if __repeat_value.len() >= 1 {
-                                            Matched(__repeat_pos, ())
-                                        } else {
-                                            Failed
-                                        }
+                                        Failed => Failed,
                                     }
-                                    Failed => Failed,
                                 }
                             }
                         }
                     }
                 }
+                Failed => Failed,
             }
-            Failed => Failed,
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => __parse_universal_character_name(__input, __state, __pos, env),
         }
     }
 }
@@ -1652,7 +1868,7 @@ fn __parse_string_literal<'input>(__input: &'input str, __state: &mut ParseState
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -1762,6 +1978,106 @@ fn __parse_string_char<'input>(__input: &'input str, __state: &mut ParseState<'i
     }
 }
 
+fn __parse_lbrack<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            __state.suppress_fail += 1;
+            let res = {
+                let __choice_res = slice_eq(__input, __state, __pos, "[");
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => slice_eq(__input, __state, __pos, "<:"),
+                }
+            };
+            __state.suppress_fail -= 1;
+            res
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                __state.mark_failure(__pos, "[");
+                Failed
+            }
+        }
+    }
+}
+
+fn __parse_rbrack<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            __state.suppress_fail += 1;
+            let res = {
+                let __choice_res = slice_eq(__input, __state, __pos, "]");
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => slice_eq(__input, __state, __pos, ":>"),
+                }
+            };
+            __state.suppress_fail -= 1;
+            res
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                __state.mark_failure(__pos, "]");
+                Failed
+            }
+        }
+    }
+}
+
+fn __parse_lbrace<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            __state.suppress_fail += 1;
+            let res = {
+                let __choice_res = slice_eq(__input, __state, __pos, "{");
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => slice_eq(__input, __state, __pos, "<%"),
+                }
+            };
+            __state.suppress_fail -= 1;
+            res
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                __state.mark_failure(__pos, "{");
+                Failed
+            }
+        }
+    }
+}
+
+fn __parse_rbrace<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            __state.suppress_fail += 1;
+            let res = {
+                let __choice_res = slice_eq(__input, __state, __pos, "}");
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => slice_eq(__input, __state, __pos, "%>"),
+                }
+            };
+            __state.suppress_fail -= 1;
+            res
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                __state.mark_failure(__pos, "}");
+                Failed
+            }
+        }
+    }
+}
+
 fn __parse_primary_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Box<Node<Expression>>> {
     #![allow(non_snake_case, unused)]
     {
@@ -1774,7 +2090,7 @@ fn __parse_primary_expression<'input>(__input: &'input str, __state: &mut ParseS
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -1795,9 +2111,27 @@ fn __parse_primary_expression0<'input>(__input: &'input str, __state: &mut Parse
     #![allow(non_snake_case, unused)]
     {
         let __choice_res = {
-            let __seq_res = __parse_identifier(__input, __state, __pos, env);
+            let __seq_res = {
+                let __seq_res = Matched(__pos, __pos);
+                match __seq_res {
+                    Matched(__pos, l) => {
+                        let __seq_res = __parse_constant(__input, __state, __pos, env);
+                        match __seq_res {
+                            Matched(__pos, e) => {
+                                let __seq_res = Matched(__pos, __pos);
+                                match __seq_res {
+                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            };
             match __seq_res {
-                Matched(__pos, a) => Matched(__pos, { Expression::Identifier(Box::new(a)) }),
+                Matched(__pos, a) => Matched(__pos, { Expression::Constant(Box::new(a)) }),
                 Failed => Failed,
             }
         };
@@ -1805,27 +2139,9 @@ fn __parse_primary_expression0<'input>(__input: &'input str, __state: &mut Parse
             Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
                 let __choice_res = {
-                    let __seq_res = {
-                        let __seq_res = Matched(__pos, __pos);
-                        match __seq_res {
-                            Matched(__pos, l) => {
-                                let __seq_res = __parse_constant(__input, __state, __pos, env);
-                                match __seq_res {
-                                    Matched(__pos, e) => {
-                                        let __seq_res = Matched(__pos, __pos);
-                                        match __seq_res {
-                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                            Failed => Failed,
-                                        }
-                                    }
-                                    Failed => Failed,
-                                }
-                            }
-                            Failed => Failed,
-                        }
-                    };
+                    let __seq_res = __parse_string_literal(__input, __state, __pos, env);
                     match __seq_res {
-                        Matched(__pos, a) => Matched(__pos, { Expression::Constant(Box::new(a)) }),
+                        Matched(__pos, a) => Matched(__pos, { Expression::StringLiteral(Box::new(a)) }),
                         Failed => Failed,
                     }
                 };
@@ -1833,9 +2149,9 @@ fn __parse_primary_expression0<'input>(__input: &'input str, __state: &mut Parse
                     Matched(__pos, __value) => Matched(__pos, __value),
                     Failed => {
                         let __choice_res = {
-                            let __seq_res = __parse_string_literal(__input, __state, __pos, env);
+                            let __seq_res = __parse_identifier(__input, __state, __pos, env);
                             match __seq_res {
-                                Matched(__pos, a) => Matched(__pos, { Expression::StringLiteral(Box::new(a)) }),
+                                Matched(__pos, a) => Matched(__pos, { Expression::Identifier(Box::new(a)) }),
                                 Failed => Failed,
                             }
                         };
@@ -1886,7 +2202,7 @@ fn __parse_primary_expression0<'input>(__input: &'input str, __state: &mut Parse
                                                             Matched(__pos, e) => {
                                                                 let __seq_res = Matched(__pos, __pos);
                                                                 match __seq_res {
-                                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                     Failed => Failed,
                                                                 }
                                                             }
@@ -1947,15 +2263,7 @@ fn __parse_generic_selection<'input>(__input: &'input str, __state: &mut ParseSt
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -2032,7 +2340,7 @@ fn __parse_generic_selection<'input>(__input: &'input str, __state: &mut ParseSt
                                                                                                     Matched(__pos, e) => {
                                                                                                         let __seq_res = Matched(__pos, __pos);
                                                                                                         match __seq_res {
-                                                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                                             Failed => Failed,
                                                                                                         }
                                                                                                     }
@@ -2154,15 +2462,7 @@ fn __parse_generic_association<'input>(__input: &'input str, __state: &mut Parse
                             Matched(__pos, e) => {
                                 let __seq_res = {
                                     __state.suppress_fail += 1;
-                                    let __assert_res = if __input.len() > __pos {
-                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                        match __ch {
-                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                        }
-                                    } else {
-                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                    };
+                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                     __state.suppress_fail -= 1;
                                     match __assert_res {
                                         Failed => Matched(__pos, ()),
@@ -2225,7 +2525,7 @@ fn __parse_postfix_expression<'input>(__input: &'input str, __state: &mut ParseS
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -2257,7 +2557,7 @@ fn __parse_postfix_expression0<'input>(__input: &'input str, __state: &mut Parse
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -2296,7 +2596,7 @@ fn __parse_postfix_expression0<'input>(__input: &'input str, __state: &mut Parse
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -2399,7 +2699,7 @@ fn __parse_postfix_expressionT<'input>(__input: &'input str, __state: &mut Parse
                                                                 Matched(__pos, e) => {
                                                                     let __seq_res = Matched(__pos, __pos);
                                                                     match __seq_res {
-                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                         Failed => Failed,
                                                                     }
                                                                 }
@@ -2462,7 +2762,7 @@ fn __parse_postfix_expressionT<'input>(__input: &'input str, __state: &mut Parse
                                             Matched(__pos, e) => {
                                                 let __seq_res = Matched(__pos, __pos);
                                                 match __seq_res {
-                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                     Failed => Failed,
                                                 }
                                             }
@@ -2501,7 +2801,7 @@ fn __parse_postfix_expressionT<'input>(__input: &'input str, __state: &mut Parse
                                                 Matched(__pos, e) => {
                                                     let __seq_res = Matched(__pos, __pos);
                                                     match __seq_res {
-                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                         Failed => Failed,
                                                     }
                                                 }
@@ -2536,7 +2836,7 @@ fn __parse_index_operator<'input>(__input: &'input str, __state: &mut ParseState
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -2556,7 +2856,7 @@ fn __parse_index_operator<'input>(__input: &'input str, __state: &mut ParseState
 fn __parse_index_operator0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Expression>> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = slice_eq(__input, __state, __pos, "[");
+        let __seq_res = __parse_lbrack(__input, __state, __pos, env);
         match __seq_res {
             Matched(__pos, _) => {
                 let __seq_res = __parse__(__input, __state, __pos, env);
@@ -2571,7 +2871,7 @@ fn __parse_index_operator0<'input>(__input: &'input str, __state: &mut ParseStat
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -2586,7 +2886,7 @@ fn __parse_index_operator0<'input>(__input: &'input str, __state: &mut ParseStat
                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                 match __seq_res {
                                     Matched(__pos, _) => {
-                                        let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                        let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                         match __seq_res {
                                             Matched(__pos, _) => Matched(__pos, { e }),
                                             Failed => Failed,
@@ -2664,7 +2964,7 @@ fn __parse_compound_literal<'input>(__input: &'input str, __state: &mut ParseSta
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -2702,7 +3002,7 @@ fn __parse_compound_literal_inner<'input>(__input: &'input str, __state: &mut Pa
                                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                                 match __seq_res {
                                                     Matched(__pos, _) => {
-                                                        let __seq_res = slice_eq(__input, __state, __pos, "{");
+                                                        let __seq_res = __parse_lbrace(__input, __state, __pos, env);
                                                         match __seq_res {
                                                             Matched(__pos, _) => {
                                                                 let __seq_res = __parse__(__input, __state, __pos, env);
@@ -2744,7 +3044,7 @@ fn __parse_compound_literal_inner<'input>(__input: &'input str, __state: &mut Pa
                                                                                                     Matched(__pos, e) => {
                                                                                                         let __seq_res = Matched(__pos, __pos);
                                                                                                         match __seq_res {
-                                                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                                             Failed => Failed,
                                                                                                         }
                                                                                                     }
@@ -2789,7 +3089,7 @@ fn __parse_compound_literal_inner<'input>(__input: &'input str, __state: &mut Pa
                                                                                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                                                                                 match __seq_res {
                                                                                                     Matched(__pos, _) => {
-                                                                                                        let __seq_res = slice_eq(__input, __state, __pos, "}");
+                                                                                                        let __seq_res = __parse_rbrace(__input, __state, __pos, env);
                                                                                                         match __seq_res {
                                                                                                             Matched(__pos, _) => Matched(__pos, { CompoundLiteral { type_name: t, initializer_list: i } }),
                                                                                                             Failed => Failed,
@@ -2845,7 +3145,7 @@ fn __parse_unary_expression<'input>(__input: &'input str, __state: &mut ParseSta
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -2905,15 +3205,7 @@ fn __parse_unary_expression0<'input>(__input: &'input str, __state: &mut ParseSt
                                                                         Matched(__pos, e) => {
                                                                             let __seq_res = {
                                                                                 __state.suppress_fail += 1;
-                                                                                let __assert_res = if __input.len() > __pos {
-                                                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                                                    match __ch {
-                                                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                                    }
-                                                                                } else {
-                                                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                                };
+                                                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                                 __state.suppress_fail -= 1;
                                                                                 match __assert_res {
                                                                                     Failed => Matched(__pos, ()),
@@ -2980,7 +3272,7 @@ fn __parse_unary_prefix<'input>(__input: &'input str, __state: &mut ParseState<'
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -3009,7 +3301,7 @@ fn __parse_unary_prefix_inner<'input>(__input: &'input str, __state: &mut ParseS
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -3069,15 +3361,7 @@ fn __parse_prefix_operator<'input>(__input: &'input str, __state: &mut ParseStat
                                     Matched(__pos, e) => {
                                         let __seq_res = {
                                             __state.suppress_fail += 1;
-                                            let __assert_res = if __input.len() > __pos {
-                                                let (__ch, __next) = char_range_at(__input, __pos);
-                                                match __ch {
-                                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                }
-                                            } else {
-                                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                            };
+                                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                             __state.suppress_fail -= 1;
                                             match __assert_res {
                                                 Failed => Matched(__pos, ()),
@@ -3118,7 +3402,7 @@ fn __parse_unary_cast<'input>(__input: &'input str, __state: &mut ParseState<'in
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -3147,7 +3431,7 @@ fn __parse_unary_cast_inner<'input>(__input: &'input str, __state: &mut ParseSta
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -3272,15 +3556,7 @@ fn __parse_sizeof_expression<'input>(__input: &'input str, __state: &mut ParseSt
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -3387,15 +3663,7 @@ fn __parse_alignof_expression<'input>(__input: &'input str, __state: &mut ParseS
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -3468,7 +3736,7 @@ fn __parse_cast_expression<'input>(__input: &'input str, __state: &mut ParseStat
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -3498,7 +3766,7 @@ fn __parse_cast_expression0<'input>(__input: &'input str, __state: &mut ParseSta
                             Matched(__pos, e) => {
                                 let __seq_res = Matched(__pos, __pos);
                                 match __seq_res {
-                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                     Failed => Failed,
                                 }
                             }
@@ -3579,6 +3847,17 @@ fn __parse_binary_expression<'input>(__input: &'input str, __state: &mut ParseSt
 }
 
 fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Expression>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = __parse_ambiguous_binary_expression(__input, __state, __pos, env);
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => __parse_binary_expression1(__input, __state, __pos, env),
+        }
+    }
+}
+
+fn __parse_binary_expression1<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Expression>> {
     #![allow(non_snake_case, unused)]
     {
         fn __infix_parse<'input>(__min_prec: i32, __input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Expression>> {
@@ -3600,7 +3879,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -3646,7 +3925,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -3692,7 +3971,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -3738,7 +4017,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -3798,7 +4077,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -3844,7 +4123,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -3888,7 +4167,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -3934,7 +4213,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -3978,7 +4257,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -4022,7 +4301,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -4066,7 +4345,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -4112,7 +4391,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -4156,7 +4435,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -4202,7 +4481,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -4246,7 +4525,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -4292,7 +4571,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -4331,12 +4610,26 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                         let __seq_res = Matched(__pos, __pos);
                                         match __seq_res {
                                             Matched(__pos, l) => {
-                                                let __seq_res = slice_eq(__input, __state, __pos, "/");
+                                                let __seq_res = {
+                                                    let __seq_res = slice_eq(__input, __state, __pos, "/");
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            __state.suppress_fail += 1;
+                                                            let __assert_res = slice_eq(__input, __state, __pos, "*");
+                                                            __state.suppress_fail -= 1;
+                                                            match __assert_res {
+                                                                Failed => Matched(__pos, ()),
+                                                                Matched(..) => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                };
                                                 match __seq_res {
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -4380,7 +4673,7 @@ fn __parse_binary_expression0<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -4434,7 +4727,7 @@ fn __parse_binary_operand<'input>(__input: &'input str, __state: &mut ParseState
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -4446,39 +4739,253 @@ fn __parse_binary_operand<'input>(__input: &'input str, __state: &mut ParseState
     }
 }
 
-fn __parse_conditional_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Box<Node<Expression>>> {
+fn __parse_ambiguous_binary_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Expression>> {
     #![allow(non_snake_case, unused)]
     {
         let __seq_res = {
-            let __seq_res = Matched(__pos, __pos);
-            match __seq_res {
-                Matched(__pos, l) => {
-                    let __seq_res = __parse_conditional_expression0(__input, __state, __pos, env);
-                    match __seq_res {
-                        Matched(__pos, e) => {
-                            let __seq_res = Matched(__pos, __pos);
-                            match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                Failed => Failed,
-                            }
-                        }
-                        Failed => Failed,
-                    }
+            __state.suppress_fail += 1;
+            let __assert_res = {
+                let __seq_res = __parse_cast_reading(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, c) => Matched(__pos, {
+                        env.stash_cast(c);
+                    }),
+                    Failed => Failed,
                 }
+            };
+            __state.suppress_fail -= 1;
+            match __assert_res {
+                Matched(_, __value) => Matched(__pos, __value),
                 Failed => Failed,
             }
         };
         match __seq_res {
-            Matched(__pos, e) => Matched(__pos, { Box::new(e) }),
+            Matched(__pos, _) => {
+                let __seq_res = match __parse_binary_expression1(__input, __state, __pos, env) {
+                    Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                    Failed => Matched(__pos, None),
+                };
+                match __seq_res {
+                    Matched(__pos, e) => match { ambiguous_expression(env.take_cast(), e) } {
+                        Ok(res) => Matched(__pos, res),
+                        Err(expected) => {
+                            __state.mark_failure(__pos, expected);
+                            Failed
+                        }
+                    },
+                    Failed => Failed,
+                }
+            }
             Failed => Failed,
         }
     }
 }
 
-fn __parse_conditional_expression0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
+fn __parse_cast_reading<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Expression>> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = __parse_binary_expression0(__input, __state, __pos, env);
+        let __seq_res = Matched(__pos, {
+            env.begin_attempt();
+        });
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = match __parse_cast_reading0(__input, __state, __pos, env) {
+                    Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                    Failed => Matched(__pos, None),
+                };
+                match __seq_res {
+                    Matched(__pos, e) => {
+                        match {
+                            env.end_attempt(e.is_some());
+                            e.ok_or("")
+                        } {
+                            Ok(res) => Matched(__pos, res),
+                            Err(expected) => {
+                                __state.mark_failure(__pos, expected);
+                                Failed
+                            }
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_cast_reading0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Expression>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = __parse_cast_guess(__input, __state, __pos, env);
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = match __parse_binary_expression1(__input, __state, __pos, env) {
+                    Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                    Failed => Matched(__pos, None),
+                };
+                match __seq_res {
+                    Matched(__pos, c) => {
+                        match {
+                            env.pop_cast_guess();
+                            c.ok_or("<unused>")
+                        } {
+                            Ok(res) => Matched(__pos, res),
+                            Err(expected) => {
+                                __state.mark_failure(__pos, expected);
+                                Failed
+                            }
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_cast_guess<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    {
+        __state.suppress_fail += 1;
+        let __assert_res = {
+            let __seq_res = slice_eq(__input, __state, __pos, "(");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse__(__input, __state, __pos, env);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse_identifier(__input, __state, __pos, env);
+                            match __seq_res {
+                                Matched(__pos, i) => {
+                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, ")");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = {
+                                                                let __choice_res = slice_eq(__input, __state, __pos, "-");
+                                                                match __choice_res {
+                                                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                                                    Failed => {
+                                                                        let __choice_res = slice_eq(__input, __state, __pos, "+");
+                                                                        match __choice_res {
+                                                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                                                            Failed => {
+                                                                                let __choice_res = {
+                                                                                    let __seq_res = slice_eq(__input, __state, __pos, "&");
+                                                                                    match __seq_res {
+                                                                                        Matched(__pos, _) => {
+                                                                                            __state.suppress_fail += 1;
+                                                                                            let __assert_res = slice_eq(__input, __state, __pos, "&");
+                                                                                            __state.suppress_fail -= 1;
+                                                                                            match __assert_res {
+                                                                                                Failed => Matched(__pos, ()),
+                                                                                                Matched(..) => Failed,
+                                                                                            }
+                                                                                        }
+                                                                                        Failed => Failed,
+                                                                                    }
+                                                                                };
+                                                                                match __choice_res {
+                                                                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                                                                    Failed => {
+                                                                                        let __choice_res = slice_eq(__input, __state, __pos, "*");
+                                                                                        match __choice_res {
+                                                                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                                                                            Failed => slice_eq(__input, __state, __pos, "("),
+                                                                                        }
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                        }
+                                                                    }
+                                                                }
+                                                            };
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    match {
+                                                                        if env.can_guess_cast(&i.node.name) {
+                                                                            env.push_cast_guess(i.span.start);
+                                                                            Ok(())
+                                                                        } else {
+                                                                            Err("<unused>")
+                                                                        }
+                                                                    } {
+                                                                        Ok(res) => Matched(__pos, res),
+                                                                        Err(expected) => {
+                                                                            __state.mark_failure(__pos, expected);
+                                                                            Failed
+                                                                        }
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        };
+        __state.suppress_fail -= 1;
+        match __assert_res {
+            Matched(_, __value) => Matched(__pos, __value),
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_conditional_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Box<Node<Expression>>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = {
+            let __seq_res = Matched(__pos, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = __parse_conditional_expression0(__input, __state, __pos, env);
+                    match __seq_res {
+                        Matched(__pos, e) => {
+                            let __seq_res = Matched(__pos, __pos);
+                            match __seq_res {
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        };
+        match __seq_res {
+            Matched(__pos, e) => Matched(__pos, { Box::new(e) }),
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_conditional_expression0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = __parse_binary_expression0(__input, __state, __pos, env);
         match __seq_res {
             Matched(__pos, a) => {
                 let __seq_res = __parse__(__input, __state, __pos, env);
@@ -4526,7 +5033,7 @@ fn __parse_conditional_expressionT<'input>(__input: &'input str, __state: &mut P
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -4556,7 +5063,7 @@ fn __parse_conditional_expressionT<'input>(__input: &'input str, __state: &mut P
                                                                         Matched(__pos, e) => {
                                                                             let __seq_res = Matched(__pos, __pos);
                                                                             match __seq_res {
-                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                 Failed => Failed,
                                                                             }
                                                                         }
@@ -4603,7 +5110,7 @@ fn __parse_assignment_expression<'input>(__input: &'input str, __state: &mut Par
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -4633,7 +5140,7 @@ fn __parse_assignment_expression0<'input>(__input: &'input str, __state: &mut Pa
                             Matched(__pos, e) => {
                                 let __seq_res = Matched(__pos, __pos);
                                 match __seq_res {
-                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                     Failed => Failed,
                                 }
                             }
@@ -4673,7 +5180,7 @@ fn __parse_assignment_expression_inner<'input>(__input: &'input str, __state: &m
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -4851,7 +5358,7 @@ fn __parse_expression<'input>(__input: &'input str, __state: &mut ParseState<'in
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -4880,7 +5387,7 @@ fn __parse_expression0<'input>(__input: &'input str, __state: &mut ParseState<'i
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -4967,7 +5474,7 @@ fn __parse_expressionT<'input>(__input: &'input str, __state: &mut ParseState<'i
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -5011,7 +5518,7 @@ fn __parse_declaration<'input>(__input: &'input str, __state: &mut ParseState<'i
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -5046,15 +5553,7 @@ fn __parse_declaration0<'input>(__input: &'input str, __state: &mut ParseState<'
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -5458,7 +5957,7 @@ fn __parse_declaration_unique_type<'input>(__input: &'input str, __state: &mut P
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -5487,7 +5986,7 @@ fn __parse_declaration_nonunique_type<'input>(__input: &'input str, __state: &mu
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -5600,7 +6099,7 @@ fn __parse_declaration_specifiers_unique<'input>(__input: &'input str, __state:
                                 Matched(__pos, e) => {
                                     let __seq_res = Matched(__pos, __pos);
                                     match __seq_res {
-                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                         Failed => Failed,
                                     }
                                 }
@@ -5661,7 +6160,7 @@ fn __parse_declaration_specifiers_nonunique<'input>(__input: &'input str, __stat
                                 Matched(__pos, e) => {
                                     let __seq_res = Matched(__pos, __pos);
                                     match __seq_res {
-                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                         Failed => Failed,
                                     }
                                 }
@@ -5733,30 +6232,42 @@ fn __parse_declaration_specifier_nontype<'input>(__input: &'input str, __state:
                                 match __choice_res {
                                     Matched(__pos, __value) => Matched(__pos, __value),
                                     Failed => {
-                                        let __seq_res = {
+                                        let __choice_res = {
                                             let __seq_res = {
-                                                __state.suppress_fail += 1;
-                                                let __assert_res = __parse_gnu_guard(__input, __state, __pos, env);
-                                                __state.suppress_fail -= 1;
-                                                match __assert_res {
-                                                    Matched(_, __value) => Matched(__pos, __value),
+                                                let __seq_res = {
+                                                    __state.suppress_fail += 1;
+                                                    let __assert_res = __parse_gnu_guard(__input, __state, __pos, env);
+                                                    __state.suppress_fail -= 1;
+                                                    match __assert_res {
+                                                        Matched(_, __value) => Matched(__pos, __value),
+                                                        Failed => Failed,
+                                                    }
+                                                };
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res = __parse_attribute_specifier(__input, __state, __pos, env);
+                                                        match __seq_res {
+                                                            Matched(__pos, e) => Matched(__pos, { e }),
+                                                            Failed => Failed,
+                                                        }
+                                                    }
                                                     Failed => Failed,
                                                 }
                                             };
                                             match __seq_res {
-                                                Matched(__pos, _) => {
-                                                    let __seq_res = __parse_attribute_specifier(__input, __state, __pos, env);
-                                                    match __seq_res {
-                                                        Matched(__pos, e) => Matched(__pos, { e }),
-                                                        Failed => Failed,
-                                                    }
-                                                }
+                                                Matched(__pos, s) => Matched(__pos, { DeclarationSpecifier::Extension(s) }),
                                                 Failed => Failed,
                                             }
                                         };
-                                        match __seq_res {
-                                            Matched(__pos, s) => Matched(__pos, { DeclarationSpecifier::Extension(s) }),
-                                            Failed => Failed,
+                                        match __choice_res {
+                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                            Failed => {
+                                                let __seq_res = __parse_vendor_qualifier(__input, __state, __pos, env);
+                                                match __seq_res {
+                                                    Matched(__pos, s) => Matched(__pos, { DeclarationSpecifier::Extension(vec![s]) }),
+                                                    Failed => Failed,
+                                                }
+                                            }
                                         }
                                     }
                                 }
@@ -5781,7 +6292,7 @@ fn __parse_declaration_typedef<'input>(__input: &'input str, __state: &mut Parse
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -5821,7 +6332,7 @@ fn __parse_declaration_specifier_unique_type0<'input>(__input: &'input str, __st
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -5850,7 +6361,7 @@ fn __parse_declaration_specifier_nonunique_type0<'input>(__input: &'input str, _
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -5905,7 +6416,7 @@ fn __parse_declaration_init_declarators<'input>(__input: &'input str, __state: &
                                 Matched(__pos, e) => {
                                     let __seq_res = Matched(__pos, __pos);
                                     match __seq_res {
-                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                         Failed => Failed,
                                     }
                                 }
@@ -5972,7 +6483,7 @@ fn __parse_declaration_type_declarators<'input>(__input: &'input str, __state: &
                                 Matched(__pos, e) => {
                                     let __seq_res = Matched(__pos, __pos);
                                     match __seq_res {
-                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                         Failed => Failed,
                                     }
                                 }
@@ -6048,7 +6559,7 @@ fn __parse_init_declarator<'input>(__input: &'input str, __state: &mut ParseStat
                                                         Matched(__pos, e) => {
                                                             let __seq_res = Matched(__pos, __pos);
                                                             match __seq_res {
-                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                 Failed => Failed,
                                                             }
                                                         }
@@ -6203,7 +6714,7 @@ fn __parse_storage_class_specifier<'input>(__input: &'input str, __state: &mut P
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -6227,15 +6738,7 @@ fn __parse_storage_class_specifier0<'input>(__input: &'input str, __state: &mut
                         Matched(__pos, e) => {
                             let __seq_res = {
                                 __state.suppress_fail += 1;
-                                let __assert_res = if __input.len() > __pos {
-                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                    match __ch {
-                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                    }
-                                } else {
-                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                };
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                 __state.suppress_fail -= 1;
                                 match __assert_res {
                                     Failed => Matched(__pos, ()),
@@ -6270,15 +6773,7 @@ fn __parse_storage_class_specifier0<'input>(__input: &'input str, __state: &mut
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -6313,15 +6808,7 @@ fn __parse_storage_class_specifier0<'input>(__input: &'input str, __state: &mut
                                         Matched(__pos, e) => {
                                             let __seq_res = {
                                                 __state.suppress_fail += 1;
-                                                let __assert_res = if __input.len() > __pos {
-                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                    match __ch {
-                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                    }
-                                                } else {
-                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                };
+                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                 __state.suppress_fail -= 1;
                                                 match __assert_res {
                                                     Failed => Matched(__pos, ()),
@@ -6356,15 +6843,7 @@ fn __parse_storage_class_specifier0<'input>(__input: &'input str, __state: &mut
                                                 Matched(__pos, e) => {
                                                     let __seq_res = {
                                                         __state.suppress_fail += 1;
-                                                        let __assert_res = if __input.len() > __pos {
-                                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                                            match __ch {
-                                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                            }
-                                                        } else {
-                                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                        };
+                                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                         __state.suppress_fail -= 1;
                                                         match __assert_res {
                                                             Failed => Matched(__pos, ()),
@@ -6398,15 +6877,7 @@ fn __parse_storage_class_specifier0<'input>(__input: &'input str, __state: &mut
                                                     Matched(__pos, e) => {
                                                         let __seq_res = {
                                                             __state.suppress_fail += 1;
-                                                            let __assert_res = if __input.len() > __pos {
-                                                                let (__ch, __next) = char_range_at(__input, __pos);
-                                                                match __ch {
-                                                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                }
-                                                            } else {
-                                                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                            };
+                                                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                             __state.suppress_fail -= 1;
                                                             match __assert_res {
                                                                 Failed => Matched(__pos, ()),
@@ -6450,7 +6921,7 @@ fn __parse_storage_class_typedef<'input>(__input: &'input str, __state: &mut Par
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -6473,15 +6944,7 @@ fn __parse_storage_class_typedef0<'input>(__input: &'input str, __state: &mut Pa
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -6518,15 +6981,7 @@ fn __parse_type_specifier_unique<'input>(__input: &'input str, __state: &mut Par
                         Matched(__pos, e) => {
                             let __seq_res = {
                                 __state.suppress_fail += 1;
-                                let __assert_res = if __input.len() > __pos {
-                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                    match __ch {
-                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                    }
-                                } else {
-                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                };
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                 __state.suppress_fail -= 1;
                                 match __assert_res {
                                     Failed => Matched(__pos, ()),
@@ -6561,15 +7016,7 @@ fn __parse_type_specifier_unique<'input>(__input: &'input str, __state: &mut Par
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -6604,15 +7051,7 @@ fn __parse_type_specifier_unique<'input>(__input: &'input str, __state: &mut Par
                                         Matched(__pos, e) => {
                                             let __seq_res = {
                                                 __state.suppress_fail += 1;
-                                                let __assert_res = if __input.len() > __pos {
-                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                    match __ch {
-                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                    }
-                                                } else {
-                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                };
+                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                 __state.suppress_fail -= 1;
                                                 match __assert_res {
                                                     Failed => Matched(__pos, ()),
@@ -6684,7 +7123,7 @@ fn __parse_type_specifier_unique<'input>(__input: &'input str, __state: &mut Par
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -6712,7 +7151,7 @@ fn __parse_type_specifier_unique<'input>(__input: &'input str, __state: &mut Par
                                                             Matched(__pos, e) => {
                                                                 let __seq_res = Matched(__pos, __pos);
                                                                 match __seq_res {
-                                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                     Failed => Failed,
                                                                 }
                                                             }
@@ -6760,15 +7199,7 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                         Matched(__pos, e) => {
                             let __seq_res = {
                                 __state.suppress_fail += 1;
-                                let __assert_res = if __input.len() > __pos {
-                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                    match __ch {
-                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                    }
-                                } else {
-                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                };
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                 __state.suppress_fail -= 1;
                                 match __assert_res {
                                     Failed => Matched(__pos, ()),
@@ -6803,15 +7234,7 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -6846,15 +7269,7 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                                         Matched(__pos, e) => {
                                             let __seq_res = {
                                                 __state.suppress_fail += 1;
-                                                let __assert_res = if __input.len() > __pos {
-                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                    match __ch {
-                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                    }
-                                                } else {
-                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                };
+                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                 __state.suppress_fail -= 1;
                                                 match __assert_res {
                                                     Failed => Matched(__pos, ()),
@@ -6889,15 +7304,7 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                                                 Matched(__pos, e) => {
                                                     let __seq_res = {
                                                         __state.suppress_fail += 1;
-                                                        let __assert_res = if __input.len() > __pos {
-                                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                                            match __ch {
-                                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                            }
-                                                        } else {
-                                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                        };
+                                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                         __state.suppress_fail -= 1;
                                                         match __assert_res {
                                                             Failed => Matched(__pos, ()),
@@ -6932,15 +7339,7 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                                                         Matched(__pos, e) => {
                                                             let __seq_res = {
                                                                 __state.suppress_fail += 1;
-                                                                let __assert_res = if __input.len() > __pos {
-                                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                                    match __ch {
-                                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                    }
-                                                                } else {
-                                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                };
+                                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                 __state.suppress_fail -= 1;
                                                                 match __assert_res {
                                                                     Failed => Matched(__pos, ()),
@@ -6975,15 +7374,7 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                                                                 Matched(__pos, e) => {
                                                                     let __seq_res = {
                                                                         __state.suppress_fail += 1;
-                                                                        let __assert_res = if __input.len() > __pos {
-                                                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                                                            match __ch {
-                                                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                            }
-                                                                        } else {
-                                                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                        };
+                                                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                         __state.suppress_fail -= 1;
                                                                         match __assert_res {
                                                                             Failed => Matched(__pos, ()),
@@ -7053,15 +7444,7 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                                                                         Matched(__pos, e) => {
                                                                             let __seq_res = {
                                                                                 __state.suppress_fail += 1;
-                                                                                let __assert_res = if __input.len() > __pos {
-                                                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                                                    match __ch {
-                                                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                                    }
-                                                                                } else {
-                                                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                                };
+                                                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                                 __state.suppress_fail -= 1;
                                                                                 match __assert_res {
                                                                                     Failed => Matched(__pos, ()),
@@ -7096,15 +7479,7 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                                                                                 Matched(__pos, e) => {
                                                                                     let __seq_res = {
                                                                                         __state.suppress_fail += 1;
-                                                                                        let __assert_res = if __input.len() > __pos {
-                                                                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                                                                            match __ch {
-                                                                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                                            }
-                                                                                        } else {
-                                                                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                                        };
+                                                                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                                         __state.suppress_fail -= 1;
                                                                                         match __assert_res {
                                                                                             Failed => Matched(__pos, ()),
@@ -7174,15 +7549,7 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                                                                                         Matched(__pos, e) => {
                                                                                             let __seq_res = {
                                                                                                 __state.suppress_fail += 1;
-                                                                                                let __assert_res = if __input.len() > __pos {
-                                                                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                                                                    match __ch {
-                                                                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                                                    }
-                                                                                                } else {
-                                                                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                                                };
+                                                                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                                                 __state.suppress_fail -= 1;
                                                                                                 match __assert_res {
                                                                                                     Failed => Matched(__pos, ()),
@@ -7217,15 +7584,7 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                                                                                                 Matched(__pos, e) => {
                                                                                                     let __seq_res = {
                                                                                                         __state.suppress_fail += 1;
-                                                                                                        let __assert_res = if __input.len() > __pos {
-                                                                                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                                                                                            match __ch {
-                                                                                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                                                            }
-                                                                                                        } else {
-                                                                                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                                                        };
+                                                                                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                                                         __state.suppress_fail -= 1;
                                                                                                         match __assert_res {
                                                                                                             Failed => Matched(__pos, ()),
@@ -7251,24 +7610,50 @@ fn __parse_type_specifier_nonunique<'input>(__input: &'input str, __state: &mut
                                                                                 match __choice_res {
                                                                                     Matched(__pos, __value) => Matched(__pos, __value),
                                                                                     Failed => {
-                                                                                        let __seq_res = {
-                                                                                            __state.suppress_fail += 1;
-                                                                                            let __assert_res = __parse_gnu_guard(__input, __state, __pos, env);
-                                                                                            __state.suppress_fail -= 1;
-                                                                                            match __assert_res {
-                                                                                                Matched(_, __value) => Matched(__pos, __value),
+                                                                                        let __choice_res = {
+                                                                                            let __seq_res = {
+                                                                                                __state.suppress_fail += 1;
+                                                                                                let __assert_res = __parse_gnu_guard(__input, __state, __pos, env);
+                                                                                                __state.suppress_fail -= 1;
+                                                                                                match __assert_res {
+                                                                                                    Matched(_, __value) => Matched(__pos, __value),
+                                                                                                    Failed => Failed,
+                                                                                                }
+                                                                                            };
+                                                                                            match __seq_res {
+                                                                                                Matched(__pos, _) => {
+                                                                                                    let __seq_res = __parse_typeof_specifier(__input, __state, __pos, env);
+                                                                                                    match __seq_res {
+                                                                                                        Matched(__pos, e) => Matched(__pos, { e }),
+                                                                                                        Failed => Failed,
+                                                                                                    }
+                                                                                                }
                                                                                                 Failed => Failed,
                                                                                             }
                                                                                         };
-                                                                                        match __seq_res {
-                                                                                            Matched(__pos, _) => {
-                                                                                                let __seq_res = __parse_typeof_specifier(__input, __state, __pos, env);
+                                                                                        match __choice_res {
+                                                                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                                                                            Failed => {
+                                                                                                let __seq_res = {
+                                                                                                    __state.suppress_fail += 1;
+                                                                                                    let __assert_res = __parse_embedded_guard(__input, __state, __pos, env);
+                                                                                                    __state.suppress_fail -= 1;
+                                                                                                    match __assert_res {
+                                                                                                        Matched(_, __value) => Matched(__pos, __value),
+                                                                                                        Failed => Failed,
+                                                                                                    }
+                                                                                                };
                                                                                                 match __seq_res {
-                                                                                                    Matched(__pos, e) => Matched(__pos, { e }),
+                                                                                                    Matched(__pos, _) => {
+                                                                                                        let __seq_res = __parse_tr18037_type_specifier(__input, __state, __pos, env);
+                                                                                                        match __seq_res {
+                                                                                                            Matched(__pos, e) => Matched(__pos, { e }),
+                                                                                                            Failed => Failed,
+                                                                                                        }
+                                                                                                    }
                                                                                                     Failed => Failed,
                                                                                                 }
                                                                                             }
-                                                                                            Failed => Failed,
                                                                                         }
                                                                                     }
                                                                                 }
@@ -7306,7 +7691,7 @@ fn __parse_struct_or_union_specifier<'input>(__input: &'input str, __state: &mut
                             Matched(__pos, e) => {
                                 let __seq_res = Matched(__pos, __pos);
                                 match __seq_res {
-                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                     Failed => Failed,
                                 }
                             }
@@ -7360,7 +7745,7 @@ fn __parse_struct_or_union_specifier<'input>(__input: &'input str, __state: &mut
                                 Matched(__pos, e) => {
                                     let __seq_res = Matched(__pos, __pos);
                                     match __seq_res {
-                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                         Failed => Failed,
                                     }
                                 }
@@ -7395,7 +7780,7 @@ fn __parse_struct_or_union_body<'input>(__input: &'input str, __state: &mut Pars
     #![allow(non_snake_case, unused)]
     {
         let __choice_res = {
-            let __seq_res = slice_eq(__input, __state, __pos, "{");
+            let __seq_res = __parse_lbrace(__input, __state, __pos, env);
             match __seq_res {
                 Matched(__pos, _) => {
                     let __seq_res = __parse__(__input, __state, __pos, env);
@@ -7425,7 +7810,7 @@ fn __parse_struct_or_union_body<'input>(__input: &'input str, __state: &mut Pars
                                                         Matched(__pos, e) => {
                                                             let __seq_res = Matched(__pos, __pos);
                                                             match __seq_res {
-                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                 Failed => Failed,
                                                             }
                                                         }
@@ -7461,7 +7846,7 @@ fn __parse_struct_or_union_body<'input>(__input: &'input str, __state: &mut Pars
                                     let __seq_res = __parse__(__input, __state, __pos, env);
                                     match __seq_res {
                                         Matched(__pos, _) => {
-                                            let __seq_res = slice_eq(__input, __state, __pos, "}");
+                                            let __seq_res = __parse_rbrace(__input, __state, __pos, env);
                                             match __seq_res {
                                                 Matched(__pos, _) => Matched(__pos, { Some(d) }),
                                                 Failed => Failed,
@@ -7496,12 +7881,12 @@ fn __parse_struct_or_union_body<'input>(__input: &'input str, __state: &mut Pars
                         match __seq_res {
                             Matched(__pos, _) => {
                                 let __seq_res = {
-                                    let __seq_res = slice_eq(__input, __state, __pos, "{");
+                                    let __seq_res = __parse_lbrace(__input, __state, __pos, env);
                                     match __seq_res {
                                         Matched(__pos, _) => {
                                             let __seq_res = __parse__(__input, __state, __pos, env);
                                             match __seq_res {
-                                                Matched(__pos, _) => slice_eq(__input, __state, __pos, "}"),
+                                                Matched(__pos, _) => __parse_rbrace(__input, __state, __pos, env),
                                                 Failed => Failed,
                                             }
                                         }
@@ -7542,15 +7927,7 @@ fn __parse_struct_or_union<'input>(__input: &'input str, __state: &mut ParseStat
                         Matched(__pos, e) => {
                             let __seq_res = {
                                 __state.suppress_fail += 1;
-                                let __assert_res = if __input.len() > __pos {
-                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                    match __ch {
-                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                    }
-                                } else {
-                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                };
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                 __state.suppress_fail -= 1;
                                 match __assert_res {
                                     Failed => Matched(__pos, ()),
@@ -7584,15 +7961,7 @@ fn __parse_struct_or_union<'input>(__input: &'input str, __state: &mut ParseStat
                             Matched(__pos, e) => {
                                 let __seq_res = {
                                     __state.suppress_fail += 1;
-                                    let __assert_res = if __input.len() > __pos {
-                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                        match __ch {
-                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                        }
-                                    } else {
-                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                    };
+                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                     __state.suppress_fail -= 1;
                                     match __assert_res {
                                         Failed => Matched(__pos, ()),
@@ -7632,7 +8001,7 @@ fn __parse_struct_declaration<'input>(__input: &'input str, __state: &mut ParseS
                             Matched(__pos, e) => {
                                 let __seq_res = Matched(__pos, __pos);
                                 match __seq_res {
-                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                     Failed => Failed,
                                 }
                             }
@@ -7680,15 +8049,7 @@ fn __parse_struct_declaration<'input>(__input: &'input str, __state: &mut ParseS
                                                 Matched(__pos, e) => {
                                                     let __seq_res = {
                                                         __state.suppress_fail += 1;
-                                                        let __assert_res = if __input.len() > __pos {
-                                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                                            match __ch {
-                                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                            }
-                                                        } else {
-                                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                        };
+                                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                         __state.suppress_fail -= 1;
                                                         match __assert_res {
                                                             Failed => Matched(__pos, ()),
@@ -7782,7 +8143,7 @@ fn __parse_struct_field<'input>(__input: &'input str, __state: &mut ParseState<'
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -7862,7 +8223,7 @@ fn __parse_specifier_qualifiers<'input>(__input: &'input str, __state: &mut Pars
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -7903,7 +8264,7 @@ fn __parse_specifier_qualifiers<'input>(__input: &'input str, __state: &mut Pars
                                             Matched(__pos, e) => {
                                                 let __seq_res = Matched(__pos, __pos);
                                                 match __seq_res {
-                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                     Failed => Failed,
                                                 }
                                             }
@@ -7942,7 +8303,7 @@ fn __parse_specifier_qualifiers<'input>(__input: &'input str, __state: &mut Pars
                                                                         Matched(__pos, e) => {
                                                                             let __seq_res = Matched(__pos, __pos);
                                                                             match __seq_res {
-                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                 Failed => Failed,
                                                                             }
                                                                         }
@@ -8018,7 +8379,7 @@ fn __parse_specifier_qualifiers<'input>(__input: &'input str, __state: &mut Pars
                                             Matched(__pos, e) => {
                                                 let __seq_res = Matched(__pos, __pos);
                                                 match __seq_res {
-                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                     Failed => Failed,
                                                 }
                                             }
@@ -8059,7 +8420,7 @@ fn __parse_specifier_qualifiers<'input>(__input: &'input str, __state: &mut Pars
                                                 Matched(__pos, e) => {
                                                     let __seq_res = Matched(__pos, __pos);
                                                     match __seq_res {
-                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                         Failed => Failed,
                                                     }
                                                 }
@@ -8099,7 +8460,7 @@ fn __parse_specifier_qualifiers<'input>(__input: &'input str, __state: &mut Pars
                                                                                 Matched(__pos, e) => {
                                                                                     let __seq_res = Matched(__pos, __pos);
                                                                                     match __seq_res {
-                                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                         Failed => Failed,
                                                                                     }
                                                                                 }
@@ -8120,7 +8481,7 @@ fn __parse_specifier_qualifiers<'input>(__input: &'input str, __state: &mut Pars
                                                                                     Matched(__pos, e) => {
                                                                                         let __seq_res = Matched(__pos, __pos);
                                                                                         match __seq_res {
-                                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                             Failed => Failed,
                                                                                         }
                                                                                     }
@@ -8187,7 +8548,7 @@ fn __parse_specifier_qualifier_unique_type0<'input>(__input: &'input str, __stat
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -8216,7 +8577,7 @@ fn __parse_specifier_qualifier_nonunique_type0<'input>(__input: &'input str, __s
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -8236,10 +8597,22 @@ fn __parse_specifier_qualifier_nonunique_type0<'input>(__input: &'input str, __s
 fn __parse_specifier_qualifier_qualifier0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<SpecifierQualifier> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = __parse_type_qualifier(__input, __state, __pos, env);
-        match __seq_res {
-            Matched(__pos, q) => Matched(__pos, { SpecifierQualifier::TypeQualifier(q) }),
-            Failed => Failed,
+        let __choice_res = {
+            let __seq_res = __parse_type_qualifier(__input, __state, __pos, env);
+            match __seq_res {
+                Matched(__pos, q) => Matched(__pos, { SpecifierQualifier::TypeQualifier(q) }),
+                Failed => Failed,
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __seq_res = __parse_vendor_qualifier(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, e) => Matched(__pos, { SpecifierQualifier::Extension(vec![e]) }),
+                    Failed => Failed,
+                }
+            }
         }
     }
 }
@@ -8370,15 +8743,7 @@ fn __parse_enum_specifier<'input>(__input: &'input str, __state: &mut ParseState
                         Matched(__pos, e) => {
                             let __seq_res = {
                                 __state.suppress_fail += 1;
-                                let __assert_res = if __input.len() > __pos {
-                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                    match __ch {
-                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                    }
-                                } else {
-                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                };
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                 __state.suppress_fail -= 1;
                                 match __assert_res {
                                     Failed => Matched(__pos, ()),
@@ -8410,7 +8775,7 @@ fn __parse_enum_specifier<'input>(__input: &'input str, __state: &mut ParseState
                                     let __seq_res = __parse__(__input, __state, __pos, env);
                                     match __seq_res {
                                         Matched(__pos, _) => {
-                                            let __seq_res = slice_eq(__input, __state, __pos, "{");
+                                            let __seq_res = __parse_lbrace(__input, __state, __pos, env);
                                             match __seq_res {
                                                 Matched(__pos, _) => {
                                                     let __seq_res = __parse__(__input, __state, __pos, env);
@@ -8452,7 +8817,7 @@ fn __parse_enum_specifier<'input>(__input: &'input str, __state: &mut ParseState
                                                                                         Matched(__pos, e) => {
                                                                                             let __seq_res = Matched(__pos, __pos);
                                                                                             match __seq_res {
-                                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                                 Failed => Failed,
                                                                                             }
                                                                                         }
@@ -8497,7 +8862,7 @@ fn __parse_enum_specifier<'input>(__input: &'input str, __state: &mut ParseState
                                                                                     let __seq_res = __parse__(__input, __state, __pos, env);
                                                                                     match __seq_res {
                                                                                         Matched(__pos, _) => {
-                                                                                            let __seq_res = slice_eq(__input, __state, __pos, "}");
+                                                                                            let __seq_res = __parse_rbrace(__input, __state, __pos, env);
                                                                                             match __seq_res {
                                                                                                 Matched(__pos, _) => Matched(__pos, { EnumType { identifier: i, enumerators: e } }),
                                                                                                 Failed => Failed,
@@ -8544,15 +8909,7 @@ fn __parse_enum_specifier<'input>(__input: &'input str, __state: &mut ParseState
                             Matched(__pos, e) => {
                                 let __seq_res = {
                                     __state.suppress_fail += 1;
-                                    let __assert_res = if __input.len() > __pos {
-                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                        match __ch {
-                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                        }
-                                    } else {
-                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                    };
+                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                     __state.suppress_fail -= 1;
                                     match __assert_res {
                                         Failed => Matched(__pos, ()),
@@ -8654,7 +9011,7 @@ fn __parse_type_qualifier<'input>(__input: &'input str, __state: &mut ParseState
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -8704,15 +9061,7 @@ fn __parse_type_qualifier0<'input>(__input: &'input str, __state: &mut ParseStat
                         Matched(__pos, e) => {
                             let __seq_res = {
                                 __state.suppress_fail += 1;
-                                let __assert_res = if __input.len() > __pos {
-                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                    match __ch {
-                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                    }
-                                } else {
-                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                };
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                 __state.suppress_fail -= 1;
                                 match __assert_res {
                                     Failed => Matched(__pos, ()),
@@ -8782,15 +9131,7 @@ fn __parse_type_qualifier0<'input>(__input: &'input str, __state: &mut ParseStat
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -8860,15 +9201,7 @@ fn __parse_type_qualifier0<'input>(__input: &'input str, __state: &mut ParseStat
                                         Matched(__pos, e) => {
                                             let __seq_res = {
                                                 __state.suppress_fail += 1;
-                                                let __assert_res = if __input.len() > __pos {
-                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                    match __ch {
-                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                    }
-                                                } else {
-                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                };
+                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                 __state.suppress_fail -= 1;
                                                 match __assert_res {
                                                     Failed => Matched(__pos, ()),
@@ -8915,15 +9248,7 @@ fn __parse_type_qualifier0<'input>(__input: &'input str, __state: &mut ParseStat
                                                             Matched(__pos, e) => {
                                                                 let __seq_res = {
                                                                     __state.suppress_fail += 1;
-                                                                    let __assert_res = if __input.len() > __pos {
-                                                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                                                        match __ch {
-                                                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                        }
-                                                                    } else {
-                                                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                    };
+                                                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                     __state.suppress_fail -= 1;
                                                                     match __assert_res {
                                                                         Failed => Matched(__pos, ()),
@@ -8978,15 +9303,7 @@ fn __parse_type_qualifier0<'input>(__input: &'input str, __state: &mut ParseStat
                                                                     Matched(__pos, e) => {
                                                                         let __seq_res = {
                                                                             __state.suppress_fail += 1;
-                                                                            let __assert_res = if __input.len() > __pos {
-                                                                                let (__ch, __next) = char_range_at(__input, __pos);
-                                                                                match __ch {
-                                                                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                                }
-                                                                            } else {
-                                                                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                            };
+                                                                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                             __state.suppress_fail -= 1;
                                                                             match __assert_res {
                                                                                 Failed => Matched(__pos, ()),
@@ -9041,15 +9358,7 @@ fn __parse_type_qualifier0<'input>(__input: &'input str, __state: &mut ParseStat
                                                                             Matched(__pos, e) => {
                                                                                 let __seq_res = {
                                                                                     __state.suppress_fail += 1;
-                                                                                    let __assert_res = if __input.len() > __pos {
-                                                                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                                                                        match __ch {
-                                                                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                                        }
-                                                                                    } else {
-                                                                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                                    };
+                                                                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                                     __state.suppress_fail -= 1;
                                                                                     match __assert_res {
                                                                                         Failed => Matched(__pos, ()),
@@ -9091,15 +9400,7 @@ fn __parse_type_qualifier0<'input>(__input: &'input str, __state: &mut ParseStat
                                                                     Matched(__pos, e) => {
                                                                         let __seq_res = {
                                                                             __state.suppress_fail += 1;
-                                                                            let __assert_res = if __input.len() > __pos {
-                                                                                let (__ch, __next) = char_range_at(__input, __pos);
-                                                                                match __ch {
-                                                                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                                }
-                                                                            } else {
-                                                                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                            };
+                                                                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                             __state.suppress_fail -= 1;
                                                                             match __assert_res {
                                                                                 Failed => Matched(__pos, ()),
@@ -9167,7 +9468,7 @@ fn __parse_function_specifier<'input>(__input: &'input str, __state: &mut ParseS
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -9226,15 +9527,7 @@ fn __parse_function_specifier0<'input>(__input: &'input str, __state: &mut Parse
                         Matched(__pos, e) => {
                             let __seq_res = {
                                 __state.suppress_fail += 1;
-                                let __assert_res = if __input.len() > __pos {
-                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                    match __ch {
-                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                    }
-                                } else {
-                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                };
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                 __state.suppress_fail -= 1;
                                 match __assert_res {
                                     Failed => Matched(__pos, ()),
@@ -9268,15 +9561,7 @@ fn __parse_function_specifier0<'input>(__input: &'input str, __state: &mut Parse
                             Matched(__pos, e) => {
                                 let __seq_res = {
                                     __state.suppress_fail += 1;
-                                    let __assert_res = if __input.len() > __pos {
-                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                        match __ch {
-                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                        }
-                                    } else {
-                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                    };
+                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                     __state.suppress_fail -= 1;
                                     match __assert_res {
                                         Failed => Matched(__pos, ()),
@@ -9314,7 +9599,7 @@ fn __parse_alignment_specifier<'input>(__input: &'input str, __state: &mut Parse
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -9338,15 +9623,7 @@ fn __parse_alignment_specifier0<'input>(__input: &'input str, __state: &mut Pars
                         Matched(__pos, e) => {
                             let __seq_res = {
                                 __state.suppress_fail += 1;
-                                let __assert_res = if __input.len() > __pos {
-                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                    match __ch {
-                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                    }
-                                } else {
-                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                };
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                 __state.suppress_fail -= 1;
                                 match __assert_res {
                                     Failed => Matched(__pos, ()),
@@ -9416,15 +9693,7 @@ fn __parse_alignment_specifier0<'input>(__input: &'input str, __state: &mut Pars
                             Matched(__pos, e) => {
                                 let __seq_res = {
                                     __state.suppress_fail += 1;
-                                    let __assert_res = if __input.len() > __pos {
-                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                        match __ch {
-                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                        }
-                                    } else {
-                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                    };
+                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                     __state.suppress_fail -= 1;
                                     match __assert_res {
                                         Failed => Matched(__pos, ()),
@@ -9498,7 +9767,7 @@ fn __parse_declarator<'input>(__input: &'input str, __state: &mut ParseState<'in
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -9539,121 +9808,162 @@ fn __parse_declarator0<'input>(__input: &'input str, __state: &mut ParseState<'i
         };
         match __seq_res {
             Matched(__pos, attr) => {
-                let __seq_res = {
-                    let __seq_res = {
-                        let mut __repeat_pos = __pos;
-                        let mut __repeat_value = vec![];
-                        loop {
-                            let __pos = __repeat_pos;
-                            let __pos = if __repeat_value.len() > 0 {
-                                let __sep_res = __parse__(__input, __state, __pos, env);
-                                match __sep_res {
-                                    Matched(__newpos, _) => __newpos,
-                                    Failed => break,
+                let __seq_res = __parse__(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = {
+                            let __seq_res = {
+                                let mut __repeat_pos = __pos;
+                                let mut __repeat_value = vec![];
+                                loop {
+                                    let __pos = __repeat_pos;
+                                    let __pos = if __repeat_value.len() > 0 {
+                                        let __sep_res = __parse__(__input, __state, __pos, env);
+                                        match __sep_res {
+                                            Matched(__newpos, _) => __newpos,
+                                            Failed => break,
+                                        }
+                                    } else {
+                                        __pos
+                                    };
+                                    let __step_res = __parse_pointer(__input, __state, __pos, env);
+                                    match __step_res {
+                                        Matched(__newpos, __value) => {
+                                            __repeat_pos = __newpos;
+                                            __repeat_value.push(__value);
+                                        }
+                                        Failed => {
+                                            break;
+                                        }
+                                    }
                                 }
-                            } else {
-                                __pos
+                                Matched(__repeat_pos, __repeat_value)
                             };
-                            let __step_res = __parse_pointer(__input, __state, __pos, env);
-                            match __step_res {
-                                Matched(__newpos, __value) => {
-                                    __repeat_pos = __newpos;
-                                    __repeat_value.push(__value);
-                                }
-                                Failed => {
-                                    break;
-                                }
+                            match __seq_res {
+                                Matched(__pos, e) => Matched(__pos, { e }),
+                                Failed => Failed,
                             }
-                        }
-                        Matched(__repeat_pos, __repeat_value)
-                    };
-                    match __seq_res {
-                        Matched(__pos, e) => Matched(__pos, { e }),
-                        Failed => Failed,
-                    }
-                };
-                match __seq_res {
-                    Matched(__pos, pointer) => {
-                        let __seq_res = __parse__(__input, __state, __pos, env);
+                        };
                         match __seq_res {
-                            Matched(__pos, _) => {
-                                let __seq_res = {
-                                    let __seq_res = Matched(__pos, __pos);
-                                    match __seq_res {
-                                        Matched(__pos, l) => {
-                                            let __seq_res = __parse_direct_declarator(__input, __state, __pos, env);
+                            Matched(__pos, pointer) => {
+                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = {
+                                            let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, e) => {
-                                                    let __seq_res = Matched(__pos, __pos);
+                                                Matched(__pos, l) => {
+                                                    let __seq_res = __parse_direct_declarator(__input, __state, __pos, env);
                                                     match __seq_res {
-                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                                        Failed => Failed,
-                                                    }
-                                                }
-                                                Failed => Failed,
-                                            }
-                                        }
-                                        Failed => Failed,
-                                    }
-                                };
-                                match __seq_res {
-                                    Matched(__pos, kind) => {
-                                        let __seq_res = __parse__(__input, __state, __pos, env);
+                                                        Matched(__pos, e) => {
+                                                            let __seq_res = Matched(__pos, __pos);
+                                                            match __seq_res {
+                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        };
                                         match __seq_res {
-                                            Matched(__pos, _) => {
-                                                let __seq_res = {
-                                                    let __seq_res = {
-                                                        let mut __repeat_pos = __pos;
-                                                        let mut __repeat_value = vec![];
-                                                        loop {
-                                                            let __pos = __repeat_pos;
-                                                            let __pos = if __repeat_value.len() > 0 {
-                                                                let __sep_res = __parse__(__input, __state, __pos, env);
-                                                                match __sep_res {
-                                                                    Matched(__newpos, _) => __newpos,
-                                                                    Failed => break,
-                                                                }
-                                                            } else {
-                                                                __pos
-                                                            };
-                                                            let __step_res = {
-                                                                let __seq_res = Matched(__pos, __pos);
-                                                                match __seq_res {
-                                                                    Matched(__pos, l) => {
-                                                                        let __seq_res = __parse_derived_declarator(__input, __state, __pos, env);
+                                            Matched(__pos, kind) => {
+                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res = {
+                                                            let __seq_res = {
+                                                                let mut __repeat_pos = __pos;
+                                                                let mut __repeat_value = vec![];
+                                                                loop {
+                                                                    let __pos = __repeat_pos;
+                                                                    let __pos = if __repeat_value.len() > 0 {
+                                                                        let __sep_res = __parse__(__input, __state, __pos, env);
+                                                                        match __sep_res {
+                                                                            Matched(__newpos, _) => __newpos,
+                                                                            Failed => break,
+                                                                        }
+                                                                    } else {
+                                                                        __pos
+                                                                    };
+                                                                    let __step_res = {
+                                                                        let __seq_res = Matched(__pos, __pos);
                                                                         match __seq_res {
-                                                                            Matched(__pos, e) => {
-                                                                                let __seq_res = Matched(__pos, __pos);
+                                                                            Matched(__pos, l) => {
+                                                                                let __seq_res = __parse_derived_declarator(__input, __state, __pos, env);
                                                                                 match __seq_res {
-                                                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                    Matched(__pos, e) => {
+                                                                                        let __seq_res = Matched(__pos, __pos);
+                                                                                        match __seq_res {
+                                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                                                            Failed => Failed,
+                                                                                        }
+                                                                                    }
                                                                                     Failed => Failed,
                                                                                 }
                                                                             }
                                                                             Failed => Failed,
                                                                         }
+                                                                    };
+                                                                    match __step_res {
+                                                                        Matched(__newpos, __value) => {
+                                                                            __repeat_pos = __newpos;
+                                                                            __repeat_value.push(__value);
+                                                                        }
+                                                                        Failed => {
+                                                                            break;
+                                                                        }
                                                                     }
-                                                                    Failed => Failed,
                                                                 }
+                                                                Matched(__repeat_pos, __repeat_value)
                                                             };
-                                                            match __step_res {
-                                                                Matched(__newpos, __value) => {
-                                                                    __repeat_pos = __newpos;
-                                                                    __repeat_value.push(__value);
-                                                                }
-                                                                Failed => {
-                                                                    break;
+                                                            match __seq_res {
+                                                                Matched(__pos, e) => Matched(__pos, { e }),
+                                                                Failed => Failed,
+                                                            }
+                                                        };
+                                                        match __seq_res {
+                                                            Matched(__pos, derived) => {
+                                                                let __seq_res = {
+                                                                    let mut __repeat_pos = __pos;
+                                                                    let mut __repeat_value = vec![];
+                                                                    loop {
+                                                                        let __pos = __repeat_pos;
+                                                                        let __step_res = {
+                                                                            let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                            match __seq_res {
+                                                                                Matched(__pos, _) => {
+                                                                                    let __seq_res = __parse_vendor_qualifier(__input, __state, __pos, env);
+                                                                                    match __seq_res {
+                                                                                        Matched(__pos, v) => Matched(__pos, { v }),
+                                                                                        Failed => Failed,
+                                                                                    }
+                                                                                }
+                                                                                Failed => Failed,
+                                                                            }
+                                                                        };
+                                                                        match __step_res {
+                                                                            Matched(__newpos, __value) => {
+                                                                                __repeat_pos = __newpos;
+                                                                                __repeat_value.push(__value);
+                                                                            }
+                                                                            Failed => {
+                                                                                break;
+                                                                            }
+                                                                        }
+                                                                    }
+                                                                    Matched(__repeat_pos, __repeat_value)
+                                                                };
+                                                                match __seq_res {
+                                                                    Matched(__pos, vendor) => Matched(__pos, { Declarator { kind: kind, derived: concat(pointer, derived), extensions: concat(attr.unwrap_or_default(), vendor) } }),
+                                                                    Failed => Failed,
                                                                 }
                                                             }
+                                                            Failed => Failed,
                                                         }
-                                                        Matched(__repeat_pos, __repeat_value)
-                                                    };
-                                                    match __seq_res {
-                                                        Matched(__pos, e) => Matched(__pos, { e }),
-                                                        Failed => Failed,
                                                     }
-                                                };
-                                                match __seq_res {
-                                                    Matched(__pos, derived) => Matched(__pos, { Declarator { kind: kind, derived: concat(pointer, derived), extensions: attr.unwrap_or_default() } }),
                                                     Failed => Failed,
                                                 }
                                             }
@@ -9725,7 +10035,7 @@ fn __parse_derived_declarator<'input>(__input: &'input str, __state: &mut ParseS
     #![allow(non_snake_case, unused)]
     {
         let __choice_res = {
-            let __seq_res = slice_eq(__input, __state, __pos, "[");
+            let __seq_res = __parse_lbrack(__input, __state, __pos, env);
             match __seq_res {
                 Matched(__pos, _) => {
                     let __seq_res = __parse__(__input, __state, __pos, env);
@@ -9740,7 +10050,7 @@ fn __parse_derived_declarator<'input>(__input: &'input str, __state: &mut ParseS
                                             Matched(__pos, e) => {
                                                 let __seq_res = Matched(__pos, __pos);
                                                 match __seq_res {
-                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                     Failed => Failed,
                                                 }
                                             }
@@ -9786,7 +10096,7 @@ fn __parse_derived_declarator<'input>(__input: &'input str, __state: &mut ParseS
                                                                 Matched(__pos, e) => {
                                                                     let __seq_res = Matched(__pos, __pos);
                                                                     match __seq_res {
-                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                         Failed => Failed,
                                                                     }
                                                                 }
@@ -9966,7 +10276,7 @@ fn __parse_array_declarator<'input>(__input: &'input str, __state: &mut ParseSta
                     let __seq_res = __parse__(__input, __state, __pos, env);
                     match __seq_res {
                         Matched(__pos, _) => {
-                            let __seq_res = slice_eq(__input, __state, __pos, "]");
+                            let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                             match __seq_res {
                                 Matched(__pos, _) => Matched(__pos, { ArrayDeclarator { qualifiers: q, size: ArraySize::Unknown } }),
                                 Failed => Failed,
@@ -10026,7 +10336,7 @@ fn __parse_array_declarator<'input>(__input: &'input str, __state: &mut ParseSta
                                             let __seq_res = __parse__(__input, __state, __pos, env);
                                             match __seq_res {
                                                 Matched(__pos, _) => {
-                                                    let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                                    let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                                     match __seq_res {
                                                         Matched(__pos, _) => Matched(__pos, { ArrayDeclarator { qualifiers: q, size: ArraySize::VariableExpression(e) } }),
                                                         Failed => Failed,
@@ -10056,15 +10366,7 @@ fn __parse_array_declarator<'input>(__input: &'input str, __state: &mut ParseSta
                                         Matched(__pos, e) => {
                                             let __seq_res = {
                                                 __state.suppress_fail += 1;
-                                                let __assert_res = if __input.len() > __pos {
-                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                    match __ch {
-                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                    }
-                                                } else {
-                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                };
+                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                 __state.suppress_fail -= 1;
                                                 match __assert_res {
                                                     Failed => Matched(__pos, ()),
@@ -10131,7 +10433,7 @@ fn __parse_array_declarator<'input>(__input: &'input str, __state: &mut ParseSta
                                                                     let __seq_res = __parse__(__input, __state, __pos, env);
                                                                     match __seq_res {
                                                                         Matched(__pos, _) => {
-                                                                            let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                                                            let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                                                             match __seq_res {
                                                                                 Matched(__pos, _) => Matched(__pos, { ArrayDeclarator { qualifiers: q, size: ArraySize::StaticExpression(e) } }),
                                                                                 Failed => Failed,
@@ -10209,15 +10511,7 @@ fn __parse_array_declarator<'input>(__input: &'input str, __state: &mut ParseSta
                                                                 Matched(__pos, e) => {
                                                                     let __seq_res = {
                                                                         __state.suppress_fail += 1;
-                                                                        let __assert_res = if __input.len() > __pos {
-                                                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                                                            match __ch {
-                                                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                            }
-                                                                        } else {
-                                                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                        };
+                                                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                         __state.suppress_fail -= 1;
                                                                         match __assert_res {
                                                                             Failed => Matched(__pos, ()),
@@ -10246,7 +10540,7 @@ fn __parse_array_declarator<'input>(__input: &'input str, __state: &mut ParseSta
                                                                             let __seq_res = __parse__(__input, __state, __pos, env);
                                                                             match __seq_res {
                                                                                 Matched(__pos, _) => {
-                                                                                    let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                                                                    let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                                                                     match __seq_res {
                                                                                         Matched(__pos, _) => Matched(__pos, { ArrayDeclarator { qualifiers: q, size: ArraySize::StaticExpression(e) } }),
                                                                                         Failed => Failed,
@@ -10317,7 +10611,7 @@ fn __parse_array_declarator<'input>(__input: &'input str, __state: &mut ParseSta
                                                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                                                 match __seq_res {
                                                                     Matched(__pos, _) => {
-                                                                        let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                                                        let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                                                         match __seq_res {
                                                                             Matched(__pos, _) => Matched(__pos, { ArrayDeclarator { qualifiers: q, size: ArraySize::VariableUnknown } }),
                                                                             Failed => Failed,
@@ -10427,7 +10721,7 @@ fn __parse_pointer<'input>(__input: &'input str, __state: &mut ParseState<'input
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -10472,7 +10766,7 @@ fn __parse_pointer0<'input>(__input: &'input str, __state: &mut ParseState<'inpu
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -10525,30 +10819,42 @@ fn __parse_pointer_qualifier<'input>(__input: &'input str, __state: &mut ParseSt
         match __choice_res {
             Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let __seq_res = {
+                let __choice_res = {
                     let __seq_res = {
-                        __state.suppress_fail += 1;
-                        let __assert_res = __parse_gnu_guard(__input, __state, __pos, env);
-                        __state.suppress_fail -= 1;
-                        match __assert_res {
-                            Matched(_, __value) => Matched(__pos, __value),
+                        let __seq_res = {
+                            __state.suppress_fail += 1;
+                            let __assert_res = __parse_gnu_guard(__input, __state, __pos, env);
+                            __state.suppress_fail -= 1;
+                            match __assert_res {
+                                Matched(_, __value) => Matched(__pos, __value),
+                                Failed => Failed,
+                            }
+                        };
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = __parse_attribute_specifier(__input, __state, __pos, env);
+                                match __seq_res {
+                                    Matched(__pos, e) => Matched(__pos, { e }),
+                                    Failed => Failed,
+                                }
+                            }
                             Failed => Failed,
                         }
                     };
                     match __seq_res {
-                        Matched(__pos, _) => {
-                            let __seq_res = __parse_attribute_specifier(__input, __state, __pos, env);
-                            match __seq_res {
-                                Matched(__pos, e) => Matched(__pos, { e }),
-                                Failed => Failed,
-                            }
-                        }
+                        Matched(__pos, e) => Matched(__pos, { PointerQualifier::Extension(e) }),
                         Failed => Failed,
                     }
                 };
-                match __seq_res {
-                    Matched(__pos, e) => Matched(__pos, { PointerQualifier::Extension(e) }),
-                    Failed => Failed,
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __seq_res = __parse_vendor_qualifier(__input, __state, __pos, env);
+                        match __seq_res {
+                            Matched(__pos, e) => Matched(__pos, { PointerQualifier::Extension(vec![e]) }),
+                            Failed => Failed,
+                        }
+                    }
                 }
             }
         }
@@ -10595,7 +10901,7 @@ fn __parse_parameter_declaration<'input>(__input: &'input str, __state: &mut Par
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -10708,7 +11014,7 @@ fn __parse_type_name<'input>(__input: &'input str, __state: &mut ParseState<'inp
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -10757,7 +11063,7 @@ fn __parse_abstract_declarator<'input>(__input: &'input str, __state: &mut Parse
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -10820,7 +11126,7 @@ fn __parse_abstract_declarator0<'input>(__input: &'input str, __state: &mut Pars
                                             Matched(__pos, e) => {
                                                 let __seq_res = Matched(__pos, __pos);
                                                 match __seq_res {
-                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                     Failed => Failed,
                                                 }
                                             }
@@ -10968,7 +11274,7 @@ fn __parse_abstract_declarator0<'input>(__input: &'input str, __state: &mut Pars
                                                 }
                                             };
                                             match __seq_res {
-                                                Matched(__pos, d) => Matched(__pos, { Declarator { kind: Node::new(DeclaratorKind::Abstract, Span::span(k, k)), derived: concat(p, d), extensions: Vec::new() } }),
+                                                Matched(__pos, d) => Matched(__pos, { Declarator { kind: Node::new(DeclaratorKind::Abstract, env.span(k, k)), derived: concat(p, d), extensions: Vec::new() } }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -11025,7 +11331,7 @@ fn __parse_abstract_declarator0<'input>(__input: &'input str, __state: &mut Pars
                             Matched(__pos, p) => {
                                 let __seq_res = Matched(__pos, __pos);
                                 match __seq_res {
-                                    Matched(__pos, k) => Matched(__pos, { Declarator { kind: Node::new(DeclaratorKind::Abstract, Span::span(k, k)), derived: p, extensions: Vec::new() } }),
+                                    Matched(__pos, k) => Matched(__pos, { Declarator { kind: Node::new(DeclaratorKind::Abstract, env.span(k, k)), derived: p, extensions: Vec::new() } }),
                                     Failed => Failed,
                                 }
                             }
@@ -11084,7 +11390,7 @@ fn __parse_derived_abstract_declarator<'input>(__input: &'input str, __state: &m
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -11100,7 +11406,7 @@ fn __parse_derived_abstract_declarator0<'input>(__input: &'input str, __state: &
     #![allow(non_snake_case, unused)]
     {
         let __choice_res = {
-            let __seq_res = slice_eq(__input, __state, __pos, "[");
+            let __seq_res = __parse_lbrack(__input, __state, __pos, env);
             match __seq_res {
                 Matched(__pos, _) => {
                     let __seq_res = __parse__(__input, __state, __pos, env);
@@ -11115,7 +11421,7 @@ fn __parse_derived_abstract_declarator0<'input>(__input: &'input str, __state: &
                                             Matched(__pos, e) => {
                                                 let __seq_res = Matched(__pos, __pos);
                                                 match __seq_res {
-                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                     Failed => Failed,
                                                 }
                                             }
@@ -11154,7 +11460,7 @@ fn __parse_derived_abstract_declarator0<'input>(__input: &'input str, __state: &
                                                 Matched(__pos, e) => {
                                                     let __seq_res = Matched(__pos, __pos);
                                                     match __seq_res {
-                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                         Failed => Failed,
                                                     }
                                                 }
@@ -11233,7 +11539,7 @@ fn __parse_abstract_array_declarator<'input>(__input: &'input str, __state: &mut
                     let __seq_res = __parse__(__input, __state, __pos, env);
                     match __seq_res {
                         Matched(__pos, _) => {
-                            let __seq_res = slice_eq(__input, __state, __pos, "]");
+                            let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                             match __seq_res {
                                 Matched(__pos, _) => Matched(__pos, { ArrayDeclarator { qualifiers: q, size: ArraySize::Unknown } }),
                                 Failed => Failed,
@@ -11293,7 +11599,7 @@ fn __parse_abstract_array_declarator<'input>(__input: &'input str, __state: &mut
                                             let __seq_res = __parse__(__input, __state, __pos, env);
                                             match __seq_res {
                                                 Matched(__pos, _) => {
-                                                    let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                                    let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                                     match __seq_res {
                                                         Matched(__pos, _) => Matched(__pos, { ArrayDeclarator { qualifiers: q, size: ArraySize::VariableExpression(e) } }),
                                                         Failed => Failed,
@@ -11323,15 +11629,7 @@ fn __parse_abstract_array_declarator<'input>(__input: &'input str, __state: &mut
                                         Matched(__pos, e) => {
                                             let __seq_res = {
                                                 __state.suppress_fail += 1;
-                                                let __assert_res = if __input.len() > __pos {
-                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                    match __ch {
-                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                    }
-                                                } else {
-                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                };
+                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                 __state.suppress_fail -= 1;
                                                 match __assert_res {
                                                     Failed => Matched(__pos, ()),
@@ -11398,7 +11696,7 @@ fn __parse_abstract_array_declarator<'input>(__input: &'input str, __state: &mut
                                                                     let __seq_res = __parse__(__input, __state, __pos, env);
                                                                     match __seq_res {
                                                                         Matched(__pos, _) => {
-                                                                            let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                                                            let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                                                             match __seq_res {
                                                                                 Matched(__pos, _) => Matched(__pos, { ArrayDeclarator { qualifiers: q, size: ArraySize::StaticExpression(e) } }),
                                                                                 Failed => Failed,
@@ -11476,15 +11774,7 @@ fn __parse_abstract_array_declarator<'input>(__input: &'input str, __state: &mut
                                                                 Matched(__pos, e) => {
                                                                     let __seq_res = {
                                                                         __state.suppress_fail += 1;
-                                                                        let __assert_res = if __input.len() > __pos {
-                                                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                                                            match __ch {
-                                                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                            }
-                                                                        } else {
-                                                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                        };
+                                                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                                         __state.suppress_fail -= 1;
                                                                         match __assert_res {
                                                                             Failed => Matched(__pos, ()),
@@ -11513,7 +11803,7 @@ fn __parse_abstract_array_declarator<'input>(__input: &'input str, __state: &mut
                                                                             let __seq_res = __parse__(__input, __state, __pos, env);
                                                                             match __seq_res {
                                                                                 Matched(__pos, _) => {
-                                                                                    let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                                                                    let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                                                                     match __seq_res {
                                                                                         Matched(__pos, _) => Matched(__pos, { ArrayDeclarator { qualifiers: q, size: ArraySize::StaticExpression(e) } }),
                                                                                         Failed => Failed,
@@ -11546,7 +11836,7 @@ fn __parse_abstract_array_declarator<'input>(__input: &'input str, __state: &mut
                                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                                 match __seq_res {
                                                     Matched(__pos, _) => {
-                                                        let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                                        let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                                         match __seq_res {
                                                             Matched(__pos, _) => Matched(__pos, { ArrayDeclarator { qualifiers: Vec::new(), size: ArraySize::VariableUnknown } }),
                                                             Failed => Failed,
@@ -11667,85 +11957,226 @@ fn __parse_typedef_name<'input>(__input: &'input str, __state: &mut ParseState<'
 fn __parse_typedef_name0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Identifier>> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = __parse_identifier(__input, __state, __pos, env);
-        match __seq_res {
-            Matched(__pos, i) => {
-                match {
-                    if env.is_typename(&i.node.name) {
-                        Ok(i)
-                    } else {
-                        Err("<unused>")
-                    }
-                } {
-                    Ok(res) => Matched(__pos, res),
-                    Err(expected) => {
-                        __state.mark_failure(__pos, expected);
-                        Failed
+        let __choice_res = {
+            let __seq_res = __parse_identifier(__input, __state, __pos, env);
+            match __seq_res {
+                Matched(__pos, i) => {
+                    match {
+                        if env.is_typename(&i.node.name) {
+                            Ok(i)
+                        } else {
+                            Err("<unused>")
+                        }
+                    } {
+                        Ok(res) => Matched(__pos, res),
+                        Err(expected) => {
+                            __state.mark_failure(__pos, expected);
+                            Failed
+                        }
                     }
                 }
+                Failed => Failed,
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = __parse_inferred_typedef_name(__input, __state, __pos, env);
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => __parse_guessed_typedef_name(__input, __state, __pos, env),
+                }
             }
-            Failed => Failed,
         }
     }
 }
 
-fn __parse_initializer<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Initializer> {
+fn __parse_inferred_typedef_name<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Identifier>> {
     #![allow(non_snake_case, unused)]
     {
-        let __choice_res = {
-            let __seq_res = __parse_assignment_expression(__input, __state, __pos, env);
-            match __seq_res {
-                Matched(__pos, e) => Matched(__pos, { Initializer::Expression(e) }),
+        let __seq_res = {
+            __state.suppress_fail += 1;
+            let __assert_res = __parse_inferring_guard(__input, __state, __pos, env);
+            __state.suppress_fail -= 1;
+            match __assert_res {
+                Matched(_, __value) => Matched(__pos, __value),
                 Failed => Failed,
             }
         };
-        match __choice_res {
-            Matched(__pos, __value) => Matched(__pos, __value),
-            Failed => {
-                let __choice_res = {
-                    let __seq_res = slice_eq(__input, __state, __pos, "{");
-                    match __seq_res {
-                        Matched(__pos, _) => {
-                            let __seq_res = __parse__(__input, __state, __pos, env);
-                            match __seq_res {
-                                Matched(__pos, _) => {
-                                    let __seq_res = {
-                                        let __seq_res = {
-                                            let mut __repeat_pos = __pos;
-                                            let mut __repeat_value = vec![];
-                                            loop {
-                                                let __pos = __repeat_pos;
-                                                let __pos = if __repeat_value.len() > 0 {
-                                                    let __sep_res = {
-                                                        let __seq_res = __parse__(__input, __state, __pos, env);
-                                                        match __seq_res {
-                                                            Matched(__pos, _) => {
-                                                                let __seq_res = slice_eq(__input, __state, __pos, ",");
-                                                                match __seq_res {
-                                                                    Matched(__pos, _) => __parse__(__input, __state, __pos, env),
-                                                                    Failed => Failed,
-                                                                }
-                                                            }
-                                                            Failed => Failed,
-                                                        }
-                                                    };
-                                                    match __sep_res {
-                                                        Matched(__newpos, _) => __newpos,
-                                                        Failed => break,
-                                                    }
-                                                } else {
-                                                    __pos
-                                                };
-                                                let __step_res = {
-                                                    let __seq_res = Matched(__pos, __pos);
-                                                    match __seq_res {
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse_identifier(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, i) => {
+                        let __seq_res = {
+                            __state.suppress_fail += 1;
+                            let __assert_res = {
+                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __choice_res = match __parse_identifier(__input, __state, __pos, env) {
+                                            Matched(pos, _) => Matched(pos, ()),
+                                            Failed => Failed,
+                                        };
+                                        match __choice_res {
+                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                            Failed => {
+                                                let __choice_res = slice_eq(__input, __state, __pos, "*");
+                                                match __choice_res {
+                                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                                    Failed => {
+                                                        let __choice_res = slice_eq(__input, __state, __pos, "(");
+                                                        match __choice_res {
+                                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                                            Failed => {
+                                                                let __seq_res = slice_eq(__input, __state, __pos, ")");
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __choice_res = match __parse_identifier(__input, __state, __pos, env) {
+                                                                                    Matched(pos, _) => Matched(pos, ()),
+                                                                                    Failed => Failed,
+                                                                                };
+                                                                                match __choice_res {
+                                                                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                                                                    Failed => match __parse_constant(__input, __state, __pos, env) {
+                                                                                        Matched(pos, _) => Matched(pos, ()),
+                                                                                        Failed => Failed,
+                                                                                    },
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            };
+                            __state.suppress_fail -= 1;
+                            match __assert_res {
+                                Matched(_, __value) => Matched(__pos, __value),
+                                Failed => Failed,
+                            }
+                        };
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                match {
+                                    if env.can_infer(&i.node.name) {
+                                        Ok(i)
+                                    } else {
+                                        Err("<unused>")
+                                    }
+                                } {
+                                    Ok(res) => Matched(__pos, res),
+                                    Err(expected) => {
+                                        __state.mark_failure(__pos, expected);
+                                        Failed
+                                    }
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_guessed_typedef_name<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Identifier>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = __parse_identifier(__input, __state, __pos, env);
+        match __seq_res {
+            Matched(__pos, i) => {
+                match {
+                    if env.is_cast_guess(i.span.start) {
+                        Ok(i)
+                    } else {
+                        Err("<unused>")
+                    }
+                } {
+                    Ok(res) => Matched(__pos, res),
+                    Err(expected) => {
+                        __state.mark_failure(__pos, expected);
+                        Failed
+                    }
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_initializer<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Initializer> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __seq_res = __parse_assignment_expression(__input, __state, __pos, env);
+            match __seq_res {
+                Matched(__pos, e) => Matched(__pos, { Initializer::Expression(e) }),
+                Failed => Failed,
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __seq_res = __parse_lbrace(__input, __state, __pos, env);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse__(__input, __state, __pos, env);
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = {
+                                        let __seq_res = {
+                                            let mut __repeat_pos = __pos;
+                                            let mut __repeat_value = vec![];
+                                            loop {
+                                                let __pos = __repeat_pos;
+                                                let __pos = if __repeat_value.len() > 0 {
+                                                    let __sep_res = {
+                                                        let __seq_res = __parse__(__input, __state, __pos, env);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = slice_eq(__input, __state, __pos, ",");
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => __parse__(__input, __state, __pos, env),
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    };
+                                                    match __sep_res {
+                                                        Matched(__newpos, _) => __newpos,
+                                                        Failed => break,
+                                                    }
+                                                } else {
+                                                    __pos
+                                                };
+                                                let __step_res = {
+                                                    let __seq_res = Matched(__pos, __pos);
+                                                    match __seq_res {
                                                         Matched(__pos, l) => {
                                                             let __seq_res = __parse_initializer_list_item(__input, __state, __pos, env);
                                                             match __seq_res {
                                                                 Matched(__pos, e) => {
                                                                     let __seq_res = Matched(__pos, __pos);
                                                                     match __seq_res {
-                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                         Failed => Failed,
                                                                     }
                                                                 }
@@ -11790,7 +12221,7 @@ fn __parse_initializer<'input>(__input: &'input str, __state: &mut ParseState<'i
                                                             let __seq_res = __parse__(__input, __state, __pos, env);
                                                             match __seq_res {
                                                                 Matched(__pos, _) => {
-                                                                    let __seq_res = slice_eq(__input, __state, __pos, "}");
+                                                                    let __seq_res = __parse_rbrace(__input, __state, __pos, env);
                                                                     match __seq_res {
                                                                         Matched(__pos, _) => Matched(__pos, { Initializer::List(i) }),
                                                                         Failed => Failed,
@@ -11830,12 +12261,12 @@ fn __parse_initializer<'input>(__input: &'input str, __state: &mut ParseState<'i
                             match __seq_res {
                                 Matched(__pos, _) => {
                                     let __seq_res = {
-                                        let __seq_res = slice_eq(__input, __state, __pos, "{");
+                                        let __seq_res = __parse_lbrace(__input, __state, __pos, env);
                                         match __seq_res {
                                             Matched(__pos, _) => {
                                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                                 match __seq_res {
-                                                    Matched(__pos, _) => slice_eq(__input, __state, __pos, "}"),
+                                                    Matched(__pos, _) => __parse_rbrace(__input, __state, __pos, env),
                                                     Failed => Failed,
                                                 }
                                             }
@@ -11882,7 +12313,7 @@ fn __parse_initializer_list_item<'input>(__input: &'input str, __state: &mut Par
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -11933,7 +12364,7 @@ fn __parse_designation<'input>(__input: &'input str, __state: &mut ParseState<'i
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -12006,7 +12437,7 @@ fn __parse_designation<'input>(__input: &'input str, __state: &mut ParseState<'i
                                                 Matched(__pos, e) => {
                                                     let __seq_res = Matched(__pos, __pos);
                                                     match __seq_res {
-                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                         Failed => Failed,
                                                     }
                                                 }
@@ -12053,7 +12484,7 @@ fn __parse_designation<'input>(__input: &'input str, __state: &mut ParseState<'i
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -12143,7 +12574,7 @@ fn __parse_designator<'input>(__input: &'input str, __state: &mut ParseState<'in
 fn __parse_array_designator<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Designator> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = slice_eq(__input, __state, __pos, "[");
+        let __seq_res = __parse_lbrack(__input, __state, __pos, env);
         match __seq_res {
             Matched(__pos, _) => {
                 let __seq_res = __parse__(__input, __state, __pos, env);
@@ -12158,7 +12589,7 @@ fn __parse_array_designator<'input>(__input: &'input str, __state: &mut ParseSta
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -12199,7 +12630,7 @@ fn __parse_array_designator<'input>(__input: &'input str, __state: &mut ParseSta
                                         };
                                         match __seq_res {
                                             Matched(__pos, b) => {
-                                                let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                                let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                                 match __seq_res {
                                                     Matched(__pos, _) => Matched(__pos, {
                                                         match b {
@@ -12248,7 +12679,7 @@ fn __parse_range_designator_ext<'input>(__input: &'input str, __state: &mut Pars
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -12282,7 +12713,7 @@ fn __parse_static_assert<'input>(__input: &'input str, __state: &mut ParseState<
                     Matched(__pos, e) => {
                         let __seq_res = Matched(__pos, __pos);
                         match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                             Failed => Failed,
                         }
                     }
@@ -12317,15 +12748,7 @@ fn __parse_static_assert0<'input>(__input: &'input str, __state: &mut ParseState
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -12367,15 +12790,7 @@ fn __parse_static_assert0<'input>(__input: &'input str, __state: &mut ParseState
                                     Matched(__pos, e) => {
                                         let __seq_res = {
                                             __state.suppress_fail += 1;
-                                            let __assert_res = if __input.len() > __pos {
-                                                let (__ch, __next) = char_range_at(__input, __pos);
-                                                match __ch {
-                                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                }
-                                            } else {
-                                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                            };
+                                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                             __state.suppress_fail -= 1;
                                             match __assert_res {
                                                 Failed => Matched(__pos, ()),
@@ -12490,7 +12905,7 @@ fn __parse_statement<'input>(__input: &'input str, __state: &mut ParseState<'inp
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -12520,7 +12935,7 @@ fn __parse_statement0<'input>(__input: &'input str, __state: &mut ParseState<'in
                             Matched(__pos, e) => {
                                 let __seq_res = Matched(__pos, __pos);
                                 match __seq_res {
-                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                     Failed => Failed,
                                 }
                             }
@@ -12688,7 +13103,7 @@ fn __parse_labeled_statement<'input>(__input: &'input str, __state: &mut ParseSt
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -12751,15 +13166,7 @@ fn __parse_label<'input>(__input: &'input str, __state: &mut ParseState<'input>,
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -12805,15 +13212,7 @@ fn __parse_label<'input>(__input: &'input str, __state: &mut ParseState<'input>,
                                     Matched(__pos, e) => {
                                         let __seq_res = {
                                             __state.suppress_fail += 1;
-                                            let __assert_res = if __input.len() > __pos {
-                                                let (__ch, __next) = char_range_at(__input, __pos);
-                                                match __ch {
-                                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                }
-                                            } else {
-                                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                            };
+                                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                             __state.suppress_fail -= 1;
                                             match __assert_res {
                                                 Failed => Matched(__pos, ()),
@@ -12845,78 +13244,26 @@ fn __parse_label<'input>(__input: &'input str, __state: &mut ParseState<'input>,
 fn __parse_compound_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Statement> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = slice_eq(__input, __state, __pos, "{");
+        let __seq_res = __parse_block_key(__input, __state, __pos, env);
         match __seq_res {
-            Matched(__pos, _) => {
-                let __seq_res = __parse__(__input, __state, __pos, env);
+            Matched(__pos, k) => {
+                let __seq_res = match __parse_compound_statement0(__input, __state, __pos, env) {
+                    Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                    Failed => Matched(__pos, None),
+                };
                 match __seq_res {
-                    Matched(__pos, _) => {
-                        let __seq_res = {
-                            let __seq_res = {
-                                let mut __repeat_pos = __pos;
-                                let mut __repeat_value = vec![];
-                                loop {
-                                    let __pos = __repeat_pos;
-                                    let __pos = if __repeat_value.len() > 0 {
-                                        let __sep_res = __parse__(__input, __state, __pos, env);
-                                        match __sep_res {
-                                            Matched(__newpos, _) => __newpos,
-                                            Failed => break,
-                                        }
-                                    } else {
-                                        __pos
-                                    };
-                                    let __step_res = {
-                                        let __seq_res = Matched(__pos, __pos);
-                                        match __seq_res {
-                                            Matched(__pos, l) => {
-                                                let __seq_res = __parse_block_item(__input, __state, __pos, env);
-                                                match __seq_res {
-                                                    Matched(__pos, e) => {
-                                                        let __seq_res = Matched(__pos, __pos);
-                                                        match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                                            Failed => Failed,
-                                                        }
-                                                    }
-                                                    Failed => Failed,
-                                                }
-                                            }
-                                            Failed => Failed,
-                                        }
-                                    };
-                                    match __step_res {
-                                        Matched(__newpos, __value) => {
-                                            __repeat_pos = __newpos;
-                                            __repeat_value.push(__value);
-                                        }
-                                        Failed => {
-                                            break;
-                                        }
-                                    }
-                                }
-                                Matched(__repeat_pos, __repeat_value)
-                            };
-                            match __seq_res {
-                                Matched(__pos, e) => Matched(__pos, { e }),
-                                Failed => Failed,
-                            }
-                        };
-                        match __seq_res {
-                            Matched(__pos, b) => {
-                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                match __seq_res {
-                                    Matched(__pos, _) => {
-                                        let __seq_res = slice_eq(__input, __state, __pos, "}");
-                                        match __seq_res {
-                                            Matched(__pos, _) => Matched(__pos, { Statement::Compound(b) }),
-                                            Failed => Failed,
-                                        }
-                                    }
-                                    Failed => Failed,
-                                }
+                    Matched(__pos, b) => {
+                        match {
+                            b.ok_or_else(|| {
+                                env.fail_block(k);
+                                "<unused>"
+                            })
+                        } {
+                            Ok(res) => Matched(__pos, res),
+                            Err(expected) => {
+                                __state.mark_failure(__pos, expected);
+                                Failed
                             }
-                            Failed => Failed,
                         }
                     }
                     Failed => Failed,
@@ -12927,40 +13274,86 @@ fn __parse_compound_statement<'input>(__input: &'input str, __state: &mut ParseS
     }
 }
 
-fn __parse_block_item<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<BlockItem> {
+fn __parse_compound_statement0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Statement> {
     #![allow(non_snake_case, unused)]
     {
-        let __choice_res = {
-            let __seq_res = __parse_declaration(__input, __state, __pos, env);
-            match __seq_res {
-                Matched(__pos, d) => Matched(__pos, { BlockItem::Declaration(d) }),
-                Failed => Failed,
-            }
-        };
-        match __choice_res {
-            Matched(__pos, __value) => Matched(__pos, __value),
-            Failed => {
-                let __choice_res = {
-                    let __seq_res = __parse_static_assert(__input, __state, __pos, env);
-                    match __seq_res {
-                        Matched(__pos, s) => Matched(__pos, { BlockItem::StaticAssert(s) }),
-                        Failed => Failed,
-                    }
-                };
-                match __choice_res {
-                    Matched(__pos, __value) => Matched(__pos, __value),
-                    Failed => {
-                        let __seq_res = {
-                            let __seq_res = Matched(__pos, __pos);
-                            match __seq_res {
-                                Matched(__pos, l) => {
-                                    let __seq_res = __parse_statement0(__input, __state, __pos, env);
+        let __seq_res = __parse_lbrace(__input, __state, __pos, env);
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse__(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = {
+                            let __seq_res = Matched(__pos, {
+                                env.push_inference(false);
+                            });
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = match {
+                                        let __seq_res = {
+                                            let mut __repeat_pos = __pos;
+                                            let mut __repeat_value = vec![];
+                                            loop {
+                                                let __pos = __repeat_pos;
+                                                let __pos = if __repeat_value.len() > 0 {
+                                                    let __sep_res = __parse__(__input, __state, __pos, env);
+                                                    match __sep_res {
+                                                        Matched(__newpos, _) => __newpos,
+                                                        Failed => break,
+                                                    }
+                                                } else {
+                                                    __pos
+                                                };
+                                                let __step_res = {
+                                                    let __seq_res = Matched(__pos, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, l) => {
+                                                            let __seq_res = __parse_block_item(__input, __state, __pos, env);
+                                                            match __seq_res {
+                                                                Matched(__pos, e) => {
+                                                                    let __seq_res = Matched(__pos, __pos);
+                                                                    match __seq_res {
+                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                };
+                                                match __step_res {
+                                                    Matched(__newpos, __value) => {
+                                                        __repeat_pos = __newpos;
+                                                        __repeat_value.push(__value);
+                                                    }
+                                                    Failed => {
+                                                        break;
+                                                    }
+                                                }
+                                            }
+                                            Matched(__repeat_pos, __repeat_value)
+                                        };
+                                        match __seq_res {
+                                            Matched(__pos, e) => Matched(__pos, { e }),
+                                            Failed => Failed,
+                                        }
+                                    } {
+                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                        Failed => Matched(__pos, None),
+                                    };
                                     match __seq_res {
                                         Matched(__pos, e) => {
-                                            let __seq_res = Matched(__pos, __pos);
-                                            match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                                Failed => Failed,
+                                            match {
+                                                env.pop_inference();
+                                                e.ok_or("")
+                                            } {
+                                                Ok(res) => Matched(__pos, res),
+                                                Err(expected) => {
+                                                    __state.mark_failure(__pos, expected);
+                                                    Failed
+                                                }
                                             }
                                         }
                                         Failed => Failed,
@@ -12970,57 +13363,72 @@ fn __parse_block_item<'input>(__input: &'input str, __state: &mut ParseState<'in
                             }
                         };
                         match __seq_res {
-                            Matched(__pos, s) => Matched(__pos, { BlockItem::Statement(s) }),
+                            Matched(__pos, b) => {
+                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse_rbrace(__input, __state, __pos, env);
+                                        match __seq_res {
+                                            Matched(__pos, _) => Matched(__pos, { Statement::Compound(b) }),
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            }
                             Failed => Failed,
                         }
                     }
+                    Failed => Failed,
                 }
             }
+            Failed => Failed,
         }
     }
 }
 
-fn __parse_expression_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Statement> {
+fn __parse_block_key<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<(usize, usize, bool)> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = match __parse_expression(__input, __state, __pos, env) {
-            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-            Failed => Matched(__pos, None),
-        };
+        let __seq_res = Matched(__pos, __pos);
         match __seq_res {
-            Matched(__pos, e) => {
-                let __seq_res = __parse__(__input, __state, __pos, env);
-                match __seq_res {
-                    Matched(__pos, _) => {
-                        let __seq_res = slice_eq(__input, __state, __pos, ";");
-                        match __seq_res {
-                            Matched(__pos, _) => Matched(__pos, { Statement::Expression(e) }),
-                            Failed => Failed,
-                        }
-                    }
-                    Failed => Failed,
+            Matched(__pos, p) => match { env.block_key(p).ok_or("<unused>") } {
+                Ok(res) => Matched(__pos, res),
+                Err(expected) => {
+                    __state.mark_failure(__pos, expected);
+                    Failed
                 }
-            }
+            },
             Failed => Failed,
         }
     }
 }
 
-fn __parse_selection_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Statement> {
+fn __parse_block_item<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<BlockItem> {
     #![allow(non_snake_case, unused)]
     {
         let __choice_res = {
             let __seq_res = {
-                let __seq_res = Matched(__pos, __pos);
+                let __seq_res = Matched(__pos, {
+                    env.begin_attempt();
+                });
                 match __seq_res {
-                    Matched(__pos, l) => {
-                        let __seq_res = __parse_if_statement(__input, __state, __pos, env);
+                    Matched(__pos, _) => {
+                        let __seq_res = match __parse_declaration(__input, __state, __pos, env) {
+                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                            Failed => Matched(__pos, None),
+                        };
                         match __seq_res {
                             Matched(__pos, e) => {
-                                let __seq_res = Matched(__pos, __pos);
-                                match __seq_res {
-                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                    Failed => Failed,
+                                match {
+                                    env.end_attempt(e.is_some());
+                                    e.ok_or("")
+                                } {
+                                    Ok(res) => Matched(__pos, res),
+                                    Err(expected) => {
+                                        __state.mark_failure(__pos, expected);
+                                        Failed
+                                    }
                                 }
                             }
                             Failed => Failed,
@@ -13030,137 +13438,284 @@ fn __parse_selection_statement<'input>(__input: &'input str, __state: &mut Parse
                 }
             };
             match __seq_res {
-                Matched(__pos, s) => Matched(__pos, { Statement::If(s) }),
+                Matched(__pos, d) => Matched(__pos, { BlockItem::Declaration(d) }),
                 Failed => Failed,
             }
         };
         match __choice_res {
             Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let __seq_res = {
-                    let __seq_res = Matched(__pos, __pos);
+                let __choice_res = {
+                    let __seq_res = __parse_static_assert(__input, __state, __pos, env);
                     match __seq_res {
-                        Matched(__pos, l) => {
-                            let __seq_res = __parse_switch_statement(__input, __state, __pos, env);
-                            match __seq_res {
-                                Matched(__pos, e) => {
-                                    let __seq_res = Matched(__pos, __pos);
-                                    match __seq_res {
-                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                        Failed => Failed,
-                                    }
-                                }
-                                Failed => Failed,
-                            }
-                        }
+                        Matched(__pos, s) => Matched(__pos, { BlockItem::StaticAssert(s) }),
                         Failed => Failed,
                     }
                 };
-                match __seq_res {
-                    Matched(__pos, s) => Matched(__pos, { Statement::Switch(s) }),
-                    Failed => Failed,
-                }
-            }
-        }
-    }
-}
-
-fn __parse_if_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<IfStatement> {
-    #![allow(non_snake_case, unused)]
-    {
-        let __seq_res = {
-            __state.suppress_fail += 1;
-            let res = {
-                let __seq_res = slice_eq(__input, __state, __pos, "if");
-                match __seq_res {
-                    Matched(__pos, e) => {
-                        let __seq_res = {
-                            __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
-                            __state.suppress_fail -= 1;
-                            match __assert_res {
-                                Failed => Matched(__pos, ()),
-                                Matched(..) => Failed,
-                            }
-                        };
-                        match __seq_res {
-                            Matched(__pos, _) => Matched(__pos, { e }),
-                            Failed => Failed,
-                        }
-                    }
-                    Failed => Failed,
-                }
-            };
-            __state.suppress_fail -= 1;
-            res
-        };
-        match __seq_res {
-            Matched(__pos, _) => {
-                let __seq_res = __parse__(__input, __state, __pos, env);
-                match __seq_res {
-                    Matched(__pos, _) => {
-                        let __seq_res = slice_eq(__input, __state, __pos, "(");
-                        match __seq_res {
-                            Matched(__pos, _) => {
-                                let __seq_res = __parse__(__input, __state, __pos, env);
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = {
+                            let __seq_res = {
+                                let __seq_res = Matched(__pos, {
+                                    env.begin_attempt();
+                                });
                                 match __seq_res {
                                     Matched(__pos, _) => {
-                                        let __seq_res = __parse_expression(__input, __state, __pos, env);
-                                        match __seq_res {
-                                            Matched(__pos, e) => {
-                                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                                match __seq_res {
-                                                    Matched(__pos, _) => {
-                                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
-                                                        match __seq_res {
-                                                            Matched(__pos, _) => {
-                                                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                match __seq_res {
-                                                                    Matched(__pos, _) => {
-                                                                        let __seq_res = __parse_statement(__input, __state, __pos, env);
-                                                                        match __seq_res {
-                                                                            Matched(__pos, a) => {
-                                                                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                                match __seq_res {
-                                                                                    Matched(__pos, _) => {
-                                                                                        let __seq_res = match __parse_else_statement(__input, __state, __pos, env) {
-                                                                                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                                                                                            Failed => Matched(__pos, None),
-                                                                                        };
-                                                                                        match __seq_res {
-                                                                                            Matched(__pos, b) => Matched(__pos, { IfStatement { condition: e, then_statement: a, else_statement: b } }),
-                                                                                            Failed => Failed,
-                                                                                        }
-                                                                                    }
-                                                                                    Failed => Failed,
-                                                                                }
-                                                                            }
-                                                                            Failed => Failed,
-                                                                        }
-                                                                    }
-                                                                    Failed => Failed,
-                                                                }
+                                        let __seq_res = match {
+                                            let __seq_res = Matched(__pos, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, l) => {
+                                                    let __seq_res = __parse_statement0(__input, __state, __pos, env);
+                                                    match __seq_res {
+                                                        Matched(__pos, e) => {
+                                                            let __seq_res = Matched(__pos, __pos);
+                                                            match __seq_res {
+                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                                Failed => Failed,
                                                             }
-                                                            Failed => Failed,
                                                         }
+                                                        Failed => Failed,
                                                     }
-                                                    Failed => Failed,
                                                 }
+                                                Failed => Failed,
                                             }
-                                            Failed => Failed,
-                                        }
-                                    }
-                                    Failed => Failed,
-                                }
-                            }
+                                        } {
+                                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                            Failed => Matched(__pos, None),
+                                        };
+                                        match __seq_res {
+                                            Matched(__pos, e) => {
+                                                match {
+                                                    env.end_attempt(e.is_some());
+                                                    e.ok_or("")
+                                                } {
+                                                    Ok(res) => Matched(__pos, res),
+                                                    Err(expected) => {
+                                                        __state.mark_failure(__pos, expected);
+                                                        Failed
+                                                    }
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            };
+                            match __seq_res {
+                                Matched(__pos, s) => Matched(__pos, { statement_block_item(s, env) }),
+                                Failed => Failed,
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __seq_res = {
+                                        let __seq_res = Matched(__pos, {
+                                            env.begin_attempt();
+                                        });
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res = match {
+                                                    let __seq_res = {
+                                                        __state.suppress_fail += 1;
+                                                        let __assert_res = __parse_infer_guard(__input, __state, __pos, env);
+                                                        __state.suppress_fail -= 1;
+                                                        match __assert_res {
+                                                            Matched(_, __value) => Matched(__pos, __value),
+                                                            Failed => Failed,
+                                                        }
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = Matched(__pos, {
+                                                                env.push_inference(true);
+                                                            });
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res = match __parse_declaration(__input, __state, __pos, env) {
+                                                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                                        Failed => Matched(__pos, None),
+                                                                    };
+                                                                    match __seq_res {
+                                                                        Matched(__pos, e) => {
+                                                                            match {
+                                                                                env.pop_inference();
+                                                                                e.ok_or("")
+                                                                            } {
+                                                                                Ok(res) => Matched(__pos, res),
+                                                                                Err(expected) => {
+                                                                                    __state.mark_failure(__pos, expected);
+                                                                                    Failed
+                                                                                }
+                                                                            }
+                                                                        }
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                } {
+                                                    Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                    Failed => Matched(__pos, None),
+                                                };
+                                                match __seq_res {
+                                                    Matched(__pos, e) => {
+                                                        match {
+                                                            env.end_attempt(e.is_some());
+                                                            e.ok_or("")
+                                                        } {
+                                                            Ok(res) => Matched(__pos, res),
+                                                            Err(expected) => {
+                                                                __state.mark_failure(__pos, expected);
+                                                                Failed
+                                                            }
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    };
+                                    match __seq_res {
+                                        Matched(__pos, d) => Matched(__pos, {
+                                            env.assume_typedefs(declaration_typedef_names(&d));
+                                            BlockItem::Declaration(d)
+                                        }),
+                                        Failed => Failed,
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __seq_res = {
+                                            let __seq_res = Matched(__pos, {
+                                                env.begin_attempt();
+                                            });
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = match {
+                                                        let __seq_res = {
+                                                            __state.suppress_fail += 1;
+                                                            let __assert_res = __parse_infer_guard(__input, __state, __pos, env);
+                                                            __state.suppress_fail -= 1;
+                                                            match __assert_res {
+                                                                Matched(_, __value) => Matched(__pos, __value),
+                                                                Failed => Failed,
+                                                            }
+                                                        };
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = Matched(__pos, {
+                                                                    env.push_inference(true);
+                                                                });
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = match {
+                                                                            let __seq_res = Matched(__pos, __pos);
+                                                                            match __seq_res {
+                                                                                Matched(__pos, l) => {
+                                                                                    let __seq_res = __parse_statement0(__input, __state, __pos, env);
+                                                                                    match __seq_res {
+                                                                                        Matched(__pos, e) => {
+                                                                                            let __seq_res = Matched(__pos, __pos);
+                                                                                            match __seq_res {
+                                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                                                                Failed => Failed,
+                                                                                            }
+                                                                                        }
+                                                                                        Failed => Failed,
+                                                                                    }
+                                                                                }
+                                                                                Failed => Failed,
+                                                                            }
+                                                                        } {
+                                                                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                                            Failed => Matched(__pos, None),
+                                                                        };
+                                                                        match __seq_res {
+                                                                            Matched(__pos, e) => {
+                                                                                match {
+                                                                                    env.pop_inference();
+                                                                                    e.ok_or("")
+                                                                                } {
+                                                                                    Ok(res) => Matched(__pos, res),
+                                                                                    Err(expected) => {
+                                                                                        __state.mark_failure(__pos, expected);
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    } {
+                                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                        Failed => Matched(__pos, None),
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, e) => {
+                                                            match {
+                                                                env.end_attempt(e.is_some());
+                                                                e.ok_or("")
+                                                            } {
+                                                                Ok(res) => Matched(__pos, res),
+                                                                Err(expected) => {
+                                                                    __state.mark_failure(__pos, expected);
+                                                                    Failed
+                                                                }
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        };
+                                        match __seq_res {
+                                            Matched(__pos, s) => Matched(__pos, {
+                                                env.assume_typedefs(statement_typedef_names(&s));
+                                                BlockItem::Statement(s)
+                                            }),
+                                            Failed => Failed,
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_expression_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Statement> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = match __parse_expression(__input, __state, __pos, env) {
+            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+            Failed => Matched(__pos, None),
+        };
+        match __seq_res {
+            Matched(__pos, e) => {
+                let __seq_res = __parse__(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = slice_eq(__input, __state, __pos, ";");
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, { Statement::Expression(e) }),
                             Failed => Failed,
                         }
                     }
@@ -13172,26 +13727,77 @@ fn __parse_if_statement<'input>(__input: &'input str, __state: &mut ParseState<'
     }
 }
 
-fn __parse_else_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Box<Node<Statement>>> {
+fn __parse_selection_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Statement> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __seq_res = {
+                let __seq_res = Matched(__pos, __pos);
+                match __seq_res {
+                    Matched(__pos, l) => {
+                        let __seq_res = __parse_if_statement(__input, __state, __pos, env);
+                        match __seq_res {
+                            Matched(__pos, e) => {
+                                let __seq_res = Matched(__pos, __pos);
+                                match __seq_res {
+                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            };
+            match __seq_res {
+                Matched(__pos, s) => Matched(__pos, { Statement::If(s) }),
+                Failed => Failed,
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __seq_res = {
+                    let __seq_res = Matched(__pos, __pos);
+                    match __seq_res {
+                        Matched(__pos, l) => {
+                            let __seq_res = __parse_switch_statement(__input, __state, __pos, env);
+                            match __seq_res {
+                                Matched(__pos, e) => {
+                                    let __seq_res = Matched(__pos, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                };
+                match __seq_res {
+                    Matched(__pos, s) => Matched(__pos, { Statement::Switch(s) }),
+                    Failed => Failed,
+                }
+            }
+        }
+    }
+}
+
+fn __parse_if_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<IfStatement> {
     #![allow(non_snake_case, unused)]
     {
         let __seq_res = {
             __state.suppress_fail += 1;
             let res = {
-                let __seq_res = slice_eq(__input, __state, __pos, "else");
+                let __seq_res = slice_eq(__input, __state, __pos, "if");
                 match __seq_res {
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -13214,7 +13820,106 @@ fn __parse_else_statement<'input>(__input: &'input str, __state: &mut ParseState
                 let __seq_res = __parse__(__input, __state, __pos, env);
                 match __seq_res {
                     Matched(__pos, _) => {
-                        let __seq_res = __parse_statement(__input, __state, __pos, env);
+                        let __seq_res = slice_eq(__input, __state, __pos, "(");
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse_expression(__input, __state, __pos, env);
+                                        match __seq_res {
+                                            Matched(__pos, e) => {
+                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = __parse_statement(__input, __state, __pos, env);
+                                                                        match __seq_res {
+                                                                            Matched(__pos, a) => {
+                                                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                match __seq_res {
+                                                                                    Matched(__pos, _) => {
+                                                                                        let __seq_res = match __parse_else_statement(__input, __state, __pos, env) {
+                                                                                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                                                            Failed => Matched(__pos, None),
+                                                                                        };
+                                                                                        match __seq_res {
+                                                                                            Matched(__pos, b) => Matched(__pos, { IfStatement { condition: e, then_statement: a, else_statement: b } }),
+                                                                                            Failed => Failed,
+                                                                                        }
+                                                                                    }
+                                                                                    Failed => Failed,
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_else_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Box<Node<Statement>>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = {
+            __state.suppress_fail += 1;
+            let res = {
+                let __seq_res = slice_eq(__input, __state, __pos, "else");
+                match __seq_res {
+                    Matched(__pos, e) => {
+                        let __seq_res = {
+                            __state.suppress_fail += 1;
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                            __state.suppress_fail -= 1;
+                            match __assert_res {
+                                Failed => Matched(__pos, ()),
+                                Matched(..) => Failed,
+                            }
+                        };
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, { e }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            };
+            __state.suppress_fail -= 1;
+            res
+        };
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse__(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = __parse_statement(__input, __state, __pos, env);
                         match __seq_res {
                             Matched(__pos, s) => Matched(__pos, { s }),
                             Failed => Failed,
@@ -13239,15 +13944,7 @@ fn __parse_switch_statement<'input>(__input: &'input str, __state: &mut ParseSta
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -13333,7 +14030,7 @@ fn __parse_iteration_statement<'input>(__input: &'input str, __state: &mut Parse
                             Matched(__pos, e) => {
                                 let __seq_res = Matched(__pos, __pos);
                                 match __seq_res {
-                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                     Failed => Failed,
                                 }
                             }
@@ -13361,7 +14058,7 @@ fn __parse_iteration_statement<'input>(__input: &'input str, __state: &mut Parse
                                     Matched(__pos, e) => {
                                         let __seq_res = Matched(__pos, __pos);
                                         match __seq_res {
-                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                             Failed => Failed,
                                         }
                                     }
@@ -13388,7 +14085,7 @@ fn __parse_iteration_statement<'input>(__input: &'input str, __state: &mut Parse
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -13420,15 +14117,7 @@ fn __parse_while_statement<'input>(__input: &'input str, __state: &mut ParseStat
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -13512,15 +14201,7 @@ fn __parse_do_while_statement<'input>(__input: &'input str, __state: &mut ParseS
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -13557,15 +14238,7 @@ fn __parse_do_while_statement<'input>(__input: &'input str, __state: &mut ParseS
                                                     Matched(__pos, e) => {
                                                         let __seq_res = {
                                                             __state.suppress_fail += 1;
-                                                            let __assert_res = if __input.len() > __pos {
-                                                                let (__ch, __next) = char_range_at(__input, __pos);
-                                                                match __ch {
-                                                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                }
-                                                            } else {
-                                                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                            };
+                                                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                             __state.suppress_fail -= 1;
                                                             match __assert_res {
                                                                 Failed => Matched(__pos, ()),
@@ -13661,15 +14334,7 @@ fn __parse_for_statement<'input>(__input: &'input str, __state: &mut ParseState<
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -13707,7 +14372,7 @@ fn __parse_for_statement<'input>(__input: &'input str, __state: &mut ParseState<
                                                         Matched(__pos, e) => {
                                                             let __seq_res = Matched(__pos, __pos);
                                                             match __seq_res {
-                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                 Failed => Failed,
                                                             }
                                                         }
@@ -13873,15 +14538,7 @@ fn __parse_jump_statement<'input>(__input: &'input str, __state: &mut ParseState
                         Matched(__pos, e) => {
                             let __seq_res = {
                                 __state.suppress_fail += 1;
-                                let __assert_res = if __input.len() > __pos {
-                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                    match __ch {
-                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                    }
-                                } else {
-                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                };
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                 __state.suppress_fail -= 1;
                                 match __assert_res {
                                     Failed => Matched(__pos, ()),
@@ -13940,15 +14597,7 @@ fn __parse_jump_statement<'input>(__input: &'input str, __state: &mut ParseState
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -13995,15 +14644,7 @@ fn __parse_jump_statement<'input>(__input: &'input str, __state: &mut ParseState
                                         Matched(__pos, e) => {
                                             let __seq_res = {
                                                 __state.suppress_fail += 1;
-                                                let __assert_res = if __input.len() > __pos {
-                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                    match __ch {
-                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                    }
-                                                } else {
-                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                };
+                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                 __state.suppress_fail -= 1;
                                                 match __assert_res {
                                                     Failed => Matched(__pos, ()),
@@ -14049,15 +14690,7 @@ fn __parse_jump_statement<'input>(__input: &'input str, __state: &mut ParseState
                                             Matched(__pos, e) => {
                                                 let __seq_res = {
                                                     __state.suppress_fail += 1;
-                                                    let __assert_res = if __input.len() > __pos {
-                                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                                        match __ch {
-                                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                        }
-                                                    } else {
-                                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                    };
+                                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                     __state.suppress_fail -= 1;
                                                     match __assert_res {
                                                         Failed => Matched(__pos, ()),
@@ -14115,6 +14748,98 @@ fn __parse_jump_statement<'input>(__input: &'input str, __state: &mut ParseState
     }
 }
 
+fn __parse_expression_fragment<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Box<Node<Expression>>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = __parse_fragment_space(__input, __state, __pos, env);
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse_expression(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, e) => {
+                        let __seq_res = __parse_fragment_space(__input, __state, __pos, env);
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, { e }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_statement_fragment<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Box<Node<Statement>>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = __parse_fragment_space(__input, __state, __pos, env);
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse_statement(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, s) => {
+                        let __seq_res = __parse_fragment_space(__input, __state, __pos, env);
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, { s }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_declaration_fragment<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Declaration>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = __parse_fragment_space(__input, __state, __pos, env);
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse_declaration(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, d) => {
+                        let __seq_res = __parse_fragment_space(__input, __state, __pos, env);
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, { d }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_type_name_fragment<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<TypeName>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = __parse_fragment_space(__input, __state, __pos, env);
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse_type_name(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, t) => {
+                        let __seq_res = __parse_fragment_space(__input, __state, __pos, env);
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, { t }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
 fn __parse_translation_unit<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TranslationUnit> {
     #![allow(non_snake_case, unused)]
     {
@@ -14151,7 +14876,7 @@ fn __parse_translation_unit<'input>(__input: &'input str, __state: &mut ParseSta
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -14201,7 +14926,35 @@ fn __parse_external_declaration<'input>(__input: &'input str, __state: &mut Pars
     #![allow(non_snake_case, unused)]
     {
         let __choice_res = {
-            let __seq_res = __parse_declaration(__input, __state, __pos, env);
+            let __seq_res = {
+                let __seq_res = Matched(__pos, {
+                    env.begin_attempt();
+                });
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = match __parse_declaration(__input, __state, __pos, env) {
+                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                            Failed => Matched(__pos, None),
+                        };
+                        match __seq_res {
+                            Matched(__pos, e) => {
+                                match {
+                                    env.end_attempt(e.is_some());
+                                    e.ok_or("")
+                                } {
+                                    Ok(res) => Matched(__pos, res),
+                                    Err(expected) => {
+                                        __state.mark_failure(__pos, expected);
+                                        Failed
+                                    }
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            };
             match __seq_res {
                 Matched(__pos, d) => Matched(__pos, { ExternalDeclaration::Declaration(d) }),
                 Failed => Failed,
@@ -14220,56 +14973,300 @@ fn __parse_external_declaration<'input>(__input: &'input str, __state: &mut Pars
                 match __choice_res {
                     Matched(__pos, __value) => Matched(__pos, __value),
                     Failed => {
-                        let __seq_res = {
-                            let __seq_res = Matched(__pos, {
-                                env.enter_scope();
-                            });
-                            match __seq_res {
-                                Matched(__pos, _) => {
-                                    let __seq_res = match {
-                                        let __seq_res = Matched(__pos, __pos);
-                                        match __seq_res {
-                                            Matched(__pos, l) => {
-                                                let __seq_res = __parse_function_definition(__input, __state, __pos, env);
-                                                match __seq_res {
-                                                    Matched(__pos, e) => {
-                                                        let __seq_res = Matched(__pos, __pos);
-                                                        match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                                            Failed => Failed,
-                                                        }
-                                                    }
-                                                    Failed => Failed,
-                                                }
+                        let __choice_res = {
+                            let __seq_res = {
+                                let __seq_res = Matched(__pos, {
+                                    env.begin_attempt();
+                                });
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = match {
+                                            let __seq_res = Matched(__pos, {
+                                                env.enter_scope();
+                                            });
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = match {
+                                                        let __seq_res = Matched(__pos, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, l) => {
+                                                                let __seq_res = __parse_function_definition(__input, __state, __pos, env);
+                                                                match __seq_res {
+                                                                    Matched(__pos, e) => {
+                                                                        let __seq_res = Matched(__pos, __pos);
+                                                                        match __seq_res {
+                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    } {
+                                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                        Failed => Matched(__pos, None),
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, e) => {
+                                                            match {
+                                                                env.leave_scope();
+                                                                e.ok_or("")
+                                                            } {
+                                                                Ok(res) => Matched(__pos, res),
+                                                                Err(expected) => {
+                                                                    __state.mark_failure(__pos, expected);
+                                                                    Failed
+                                                                }
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        } {
+                                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                            Failed => Matched(__pos, None),
+                                        };
+                                        match __seq_res {
+                                            Matched(__pos, e) => {
+                                                match {
+                                                    env.end_attempt(e.is_some());
+                                                    e.ok_or("")
+                                                } {
+                                                    Ok(res) => Matched(__pos, res),
+                                                    Err(expected) => {
+                                                        __state.mark_failure(__pos, expected);
+                                                        Failed
+                                                    }
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            };
+                            match __seq_res {
+                                Matched(__pos, d) => Matched(__pos, { ExternalDeclaration::FunctionDefinition(d) }),
+                                Failed => Failed,
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __seq_res = {
+                                        let __seq_res = Matched(__pos, {
+                                            env.begin_attempt();
+                                        });
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res = match {
+                                                    let __seq_res = {
+                                                        __state.suppress_fail += 1;
+                                                        let __assert_res = __parse_infer_guard(__input, __state, __pos, env);
+                                                        __state.suppress_fail -= 1;
+                                                        match __assert_res {
+                                                            Matched(_, __value) => Matched(__pos, __value),
+                                                            Failed => Failed,
+                                                        }
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = Matched(__pos, {
+                                                                env.push_inference(true);
+                                                            });
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res = match __parse_declaration(__input, __state, __pos, env) {
+                                                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                                        Failed => Matched(__pos, None),
+                                                                    };
+                                                                    match __seq_res {
+                                                                        Matched(__pos, e) => {
+                                                                            match {
+                                                                                env.pop_inference();
+                                                                                e.ok_or("")
+                                                                            } {
+                                                                                Ok(res) => Matched(__pos, res),
+                                                                                Err(expected) => {
+                                                                                    __state.mark_failure(__pos, expected);
+                                                                                    Failed
+                                                                                }
+                                                                            }
+                                                                        }
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                } {
+                                                    Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                    Failed => Matched(__pos, None),
+                                                };
+                                                match __seq_res {
+                                                    Matched(__pos, e) => {
+                                                        match {
+                                                            env.end_attempt(e.is_some());
+                                                            e.ok_or("")
+                                                        } {
+                                                            Ok(res) => Matched(__pos, res),
+                                                            Err(expected) => {
+                                                                __state.mark_failure(__pos, expected);
+                                                                Failed
+                                                            }
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
                                             }
                                             Failed => Failed,
                                         }
-                                    } {
-                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                                        Failed => Matched(__pos, None),
                                     };
                                     match __seq_res {
-                                        Matched(__pos, e) => {
-                                            match {
-                                                env.leave_scope();
-                                                e.ok_or("")
-                                            } {
-                                                Ok(res) => Matched(__pos, res),
-                                                Err(expected) => {
-                                                    __state.mark_failure(__pos, expected);
-                                                    Failed
+                                        Matched(__pos, d) => Matched(__pos, {
+                                            env.assume_typedefs(declaration_typedef_names(&d));
+                                            ExternalDeclaration::Declaration(d)
+                                        }),
+                                        Failed => Failed,
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __seq_res = {
+                                            let __seq_res = Matched(__pos, {
+                                                env.begin_attempt();
+                                            });
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = match {
+                                                        let __seq_res = {
+                                                            __state.suppress_fail += 1;
+                                                            let __assert_res = __parse_infer_guard(__input, __state, __pos, env);
+                                                            __state.suppress_fail -= 1;
+                                                            match __assert_res {
+                                                                Matched(_, __value) => Matched(__pos, __value),
+                                                                Failed => Failed,
+                                                            }
+                                                        };
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = Matched(__pos, {
+                                                                    env.push_inference(true);
+                                                                });
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = match {
+                                                                            let __seq_res = Matched(__pos, {
+                                                                                env.enter_scope();
+                                                                            });
+                                                                            match __seq_res {
+                                                                                Matched(__pos, _) => {
+                                                                                    let __seq_res = match {
+                                                                                        let __seq_res = Matched(__pos, __pos);
+                                                                                        match __seq_res {
+                                                                                            Matched(__pos, l) => {
+                                                                                                let __seq_res = __parse_function_definition(__input, __state, __pos, env);
+                                                                                                match __seq_res {
+                                                                                                    Matched(__pos, e) => {
+                                                                                                        let __seq_res = Matched(__pos, __pos);
+                                                                                                        match __seq_res {
+                                                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                                                                            Failed => Failed,
+                                                                                                        }
+                                                                                                    }
+                                                                                                    Failed => Failed,
+                                                                                                }
+                                                                                            }
+                                                                                            Failed => Failed,
+                                                                                        }
+                                                                                    } {
+                                                                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                                                        Failed => Matched(__pos, None),
+                                                                                    };
+                                                                                    match __seq_res {
+                                                                                        Matched(__pos, e) => {
+                                                                                            match {
+                                                                                                env.leave_scope();
+                                                                                                e.ok_or("")
+                                                                                            } {
+                                                                                                Ok(res) => Matched(__pos, res),
+                                                                                                Err(expected) => {
+                                                                                                    __state.mark_failure(__pos, expected);
+                                                                                                    Failed
+                                                                                                }
+                                                                                            }
+                                                                                        }
+                                                                                        Failed => Failed,
+                                                                                    }
+                                                                                }
+                                                                                Failed => Failed,
+                                                                            }
+                                                                        } {
+                                                                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                                            Failed => Matched(__pos, None),
+                                                                        };
+                                                                        match __seq_res {
+                                                                            Matched(__pos, e) => {
+                                                                                match {
+                                                                                    env.pop_inference();
+                                                                                    e.ok_or("")
+                                                                                } {
+                                                                                    Ok(res) => Matched(__pos, res),
+                                                                                    Err(expected) => {
+                                                                                        __state.mark_failure(__pos, expected);
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    } {
+                                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                        Failed => Matched(__pos, None),
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, e) => {
+                                                            match {
+                                                                env.end_attempt(e.is_some());
+                                                                e.ok_or("")
+                                                            } {
+                                                                Ok(res) => Matched(__pos, res),
+                                                                Err(expected) => {
+                                                                    __state.mark_failure(__pos, expected);
+                                                                    Failed
+                                                                }
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
                                                 }
+                                                Failed => Failed,
                                             }
+                                        };
+                                        match __seq_res {
+                                            Matched(__pos, d) => Matched(__pos, {
+                                                env.assume_typedefs(function_typedef_names(&d));
+                                                ExternalDeclaration::FunctionDefinition(d)
+                                            }),
+                                            Failed => Failed,
                                         }
-                                        Failed => Failed,
                                     }
                                 }
-                                Failed => Failed,
                             }
-                        };
-                        match __seq_res {
-                            Matched(__pos, d) => Matched(__pos, { ExternalDeclaration::FunctionDefinition(d) }),
-                            Failed => Failed,
                         }
                     }
                 }
@@ -14301,15 +15298,7 @@ fn __parse_function_definition<'input>(__input: &'input str, __state: &mut Parse
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -14402,7 +15391,7 @@ fn __parse_function_definition<'input>(__input: &'input str, __state: &mut Parse
                                                                                         Matched(__pos, e) => {
                                                                                             let __seq_res = Matched(__pos, __pos);
                                                                                             match __seq_res {
-                                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                                 Failed => Failed,
                                                                                             }
                                                                                         }
@@ -14460,25 +15449,59 @@ fn __parse_gnu_guard<'input>(__input: &'input str, __state: &mut ParseState<'inp
     }
 }
 
-fn __parse_attribute_specifier_list<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Vec<Node<Extension>>> {
+fn __parse_infer_guard<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
     #![allow(non_snake_case, unused)]
-    {
-        let __seq_res = {
-            let __seq_res = {
-                let mut __repeat_pos = __pos;
-                let mut __repeat_value = vec![];
-                loop {
-                    let __pos = __repeat_pos;
-                    let __pos = if __repeat_value.len() > 0 {
-                        let __sep_res = __parse__(__input, __state, __pos, env);
-                        match __sep_res {
-                            Matched(__newpos, _) => __newpos,
-                            Failed => break,
-                        }
-                    } else {
-                        __pos
-                    };
-                    let __step_res = __parse_attribute_specifier(__input, __state, __pos, env);
+    match {
+        if env.infer_typedefs {
+            Ok(())
+        } else {
+            Err("typedef inference disabled")
+        }
+    } {
+        Ok(res) => Matched(__pos, res),
+        Err(expected) => {
+            __state.mark_failure(__pos, expected);
+            Failed
+        }
+    }
+}
+
+fn __parse_inferring_guard<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    match {
+        if env.is_inferring() {
+            Ok(())
+        } else {
+            Err("<unused>")
+        }
+    } {
+        Ok(res) => Matched(__pos, res),
+        Err(expected) => {
+            __state.mark_failure(__pos, expected);
+            Failed
+        }
+    }
+}
+
+fn __parse_attribute_specifier_list<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Vec<Node<Extension>>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = {
+            let __seq_res = {
+                let mut __repeat_pos = __pos;
+                let mut __repeat_value = vec![];
+                loop {
+                    let __pos = __repeat_pos;
+                    let __pos = if __repeat_value.len() > 0 {
+                        let __sep_res = __parse__(__input, __state, __pos, env);
+                        match __sep_res {
+                            Matched(__newpos, _) => __newpos,
+                            Failed => break,
+                        }
+                    } else {
+                        __pos
+                    };
+                    let __step_res = __parse_attribute_specifier(__input, __state, __pos, env);
                     match __step_res {
                         Matched(__newpos, __value) => {
                             __repeat_pos = __newpos;
@@ -14514,15 +15537,7 @@ fn __parse_attribute_specifier<'input>(__input: &'input str, __state: &mut Parse
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -14587,7 +15602,7 @@ fn __parse_attribute_specifier<'input>(__input: &'input str, __state: &mut Parse
                                                                     Matched(__pos, e) => {
                                                                         let __seq_res = Matched(__pos, __pos);
                                                                         match __seq_res {
-                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                             Failed => Failed,
                                                                         }
                                                                     }
@@ -14670,7 +15685,7 @@ fn __parse_attribute<'input>(__input: &'input str, __state: &mut ParseState<'inp
                                         Matched(__pos, e) => {
                                             let __seq_res = Matched(__pos, __pos);
                                             match __seq_res {
-                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                 Failed => Failed,
                                             }
                                         }
@@ -14705,7 +15720,7 @@ fn __parse_attribute<'input>(__input: &'input str, __state: &mut ParseState<'inp
                                 Matched(__pos, e) => {
                                     let __seq_res = Matched(__pos, __pos);
                                     match __seq_res {
-                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                         Failed => Failed,
                                     }
                                 }
@@ -14843,7 +15858,7 @@ fn __parse_attribute_parameters<'input>(__input: &'input str, __state: &mut Pars
                                                     Matched(__pos, e) => {
                                                         let __seq_res = Matched(__pos, __pos);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -14906,15 +15921,7 @@ fn __parse_attr_availability<'input>(__input: &'input str, __state: &mut ParseSt
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -14991,7 +15998,7 @@ fn __parse_attr_availability<'input>(__input: &'input str, __state: &mut ParseSt
                                                                                                     Matched(__pos, e) => {
                                                                                                         let __seq_res = Matched(__pos, __pos);
                                                                                                         match __seq_res {
-                                                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                                             Failed => Failed,
                                                                                                         }
                                                                                                     }
@@ -15077,15 +16084,7 @@ fn __parse_attr_availability_clause<'input>(__input: &'input str, __state: &mut
                         Matched(__pos, e) => {
                             let __seq_res = {
                                 __state.suppress_fail += 1;
-                                let __assert_res = if __input.len() > __pos {
-                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                    match __ch {
-                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                    }
-                                } else {
-                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                };
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                 __state.suppress_fail -= 1;
                                 match __assert_res {
                                     Failed => Matched(__pos, ()),
@@ -15123,7 +16122,7 @@ fn __parse_attr_availability_clause<'input>(__input: &'input str, __state: &mut
                                                             Matched(__pos, e) => {
                                                                 let __seq_res = Matched(__pos, __pos);
                                                                 match __seq_res {
-                                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                     Failed => Failed,
                                                                 }
                                                             }
@@ -15162,15 +16161,7 @@ fn __parse_attr_availability_clause<'input>(__input: &'input str, __state: &mut
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -15208,7 +16199,7 @@ fn __parse_attr_availability_clause<'input>(__input: &'input str, __state: &mut
                                                                     Matched(__pos, e) => {
                                                                         let __seq_res = Matched(__pos, __pos);
                                                                         match __seq_res {
-                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                             Failed => Failed,
                                                                         }
                                                                     }
@@ -15247,15 +16238,7 @@ fn __parse_attr_availability_clause<'input>(__input: &'input str, __state: &mut
                                         Matched(__pos, e) => {
                                             let __seq_res = {
                                                 __state.suppress_fail += 1;
-                                                let __assert_res = if __input.len() > __pos {
-                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                    match __ch {
-                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                    }
-                                                } else {
-                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                };
+                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                                 __state.suppress_fail -= 1;
                                                 match __assert_res {
                                                     Failed => Matched(__pos, ()),
@@ -15293,7 +16276,7 @@ fn __parse_attr_availability_clause<'input>(__input: &'input str, __state: &mut
                                                                             Matched(__pos, e) => {
                                                                                 let __seq_res = Matched(__pos, __pos);
                                                                                 match __seq_res {
-                                                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                     Failed => Failed,
                                                                                 }
                                                                             }
@@ -15319,105 +16302,888 @@ fn __parse_attr_availability_clause<'input>(__input: &'input str, __state: &mut
                                 }
                                 Failed => Failed,
                             }
-                        };
-                        match __choice_res {
-                            Matched(__pos, __value) => Matched(__pos, __value),
-                            Failed => {
-                                let __choice_res = {
-                                    let __seq_res = {
-                                        __state.suppress_fail += 1;
-                                        let res = {
-                                            let __seq_res = slice_eq(__input, __state, __pos, "unavailable");
-                                            match __seq_res {
-                                                Matched(__pos, e) => {
-                                                    let __seq_res = {
-                                                        __state.suppress_fail += 1;
-                                                        let __assert_res = if __input.len() > __pos {
-                                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                                            match __ch {
-                                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                            }
-                                                        } else {
-                                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                        };
-                                                        __state.suppress_fail -= 1;
-                                                        match __assert_res {
-                                                            Failed => Matched(__pos, ()),
-                                                            Matched(..) => Failed,
-                                                        }
-                                                    };
-                                                    match __seq_res {
-                                                        Matched(__pos, _) => Matched(__pos, { e }),
-                                                        Failed => Failed,
-                                                    }
-                                                }
-                                                Failed => Failed,
-                                            }
-                                        };
-                                        __state.suppress_fail -= 1;
-                                        res
-                                    };
-                                    match __seq_res {
-                                        Matched(__pos, _) => Matched(__pos, { AvailabilityClause::Unavailable }),
-                                        Failed => Failed,
-                                    }
-                                };
-                                match __choice_res {
-                                    Matched(__pos, __value) => Matched(__pos, __value),
-                                    Failed => {
-                                        let __choice_res = {
-                                            let __seq_res = {
-                                                __state.suppress_fail += 1;
-                                                let res = {
-                                                    let __seq_res = slice_eq(__input, __state, __pos, "message");
-                                                    match __seq_res {
-                                                        Matched(__pos, e) => {
-                                                            let __seq_res = {
-                                                                __state.suppress_fail += 1;
-                                                                let __assert_res = if __input.len() > __pos {
-                                                                    let (__ch, __next) = char_range_at(__input, __pos);
-                                                                    match __ch {
-                                                                        '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                        _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                    }
-                                                                } else {
-                                                                    __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                };
-                                                                __state.suppress_fail -= 1;
-                                                                match __assert_res {
-                                                                    Failed => Matched(__pos, ()),
-                                                                    Matched(..) => Failed,
-                                                                }
-                                                            };
-                                                            match __seq_res {
-                                                                Matched(__pos, _) => Matched(__pos, { e }),
-                                                                Failed => Failed,
-                                                            }
-                                                        }
-                                                        Failed => Failed,
-                                                    }
-                                                };
-                                                __state.suppress_fail -= 1;
-                                                res
-                                            };
-                                            match __seq_res {
-                                                Matched(__pos, _) => {
-                                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __seq_res = {
+                                        __state.suppress_fail += 1;
+                                        let res = {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "unavailable");
+                                            match __seq_res {
+                                                Matched(__pos, e) => {
+                                                    let __seq_res = {
+                                                        __state.suppress_fail += 1;
+                                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                                                        __state.suppress_fail -= 1;
+                                                        match __assert_res {
+                                                            Failed => Matched(__pos, ()),
+                                                            Matched(..) => Failed,
+                                                        }
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => Matched(__pos, { e }),
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        };
+                                        __state.suppress_fail -= 1;
+                                        res
+                                    };
+                                    match __seq_res {
+                                        Matched(__pos, _) => Matched(__pos, { AvailabilityClause::Unavailable }),
+                                        Failed => Failed,
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __choice_res = {
+                                            let __seq_res = {
+                                                __state.suppress_fail += 1;
+                                                let res = {
+                                                    let __seq_res = slice_eq(__input, __state, __pos, "message");
+                                                    match __seq_res {
+                                                        Matched(__pos, e) => {
+                                                            let __seq_res = {
+                                                                __state.suppress_fail += 1;
+                                                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                                                                __state.suppress_fail -= 1;
+                                                                match __assert_res {
+                                                                    Failed => Matched(__pos, ()),
+                                                                    Matched(..) => Failed,
+                                                                }
+                                                            };
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => Matched(__pos, { e }),
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                };
+                                                __state.suppress_fail -= 1;
+                                                res
+                                            };
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = slice_eq(__input, __state, __pos, "=");
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                    match __seq_res {
+                                                                        Matched(__pos, _) => {
+                                                                            let __seq_res = __parse_string_literal(__input, __state, __pos, env);
+                                                                            match __seq_res {
+                                                                                Matched(__pos, s) => Matched(__pos, { AvailabilityClause::Message(s) }),
+                                                                                Failed => Failed,
+                                                                            }
+                                                                        }
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        };
+                                        match __choice_res {
+                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                            Failed => {
+                                                let __seq_res = {
+                                                    __state.suppress_fail += 1;
+                                                    let res = {
+                                                        let __seq_res = slice_eq(__input, __state, __pos, "replacement");
+                                                        match __seq_res {
+                                                            Matched(__pos, e) => {
+                                                                let __seq_res = {
+                                                                    __state.suppress_fail += 1;
+                                                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                                                                    __state.suppress_fail -= 1;
+                                                                    match __assert_res {
+                                                                        Failed => Matched(__pos, ()),
+                                                                        Matched(..) => Failed,
+                                                                    }
+                                                                };
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => Matched(__pos, { e }),
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    };
+                                                    __state.suppress_fail -= 1;
+                                                    res
+                                                };
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res = __parse__(__input, __state, __pos, env);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = slice_eq(__input, __state, __pos, "=");
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res = __parse_string_literal(__input, __state, __pos, env);
+                                                                                match __seq_res {
+                                                                                    Matched(__pos, s) => Matched(__pos, { AvailabilityClause::Replacement(s) }),
+                                                                                    Failed => Failed,
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_attr_availability_version<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<AvailabilityVersion> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = {
+            let str_start = __pos;
+            match {
+                let mut __repeat_pos = __pos;
+                let mut __repeat_value = vec![];
+                loop {
+                    let __pos = __repeat_pos;
+                    let __step_res = __parse_dec(__input, __state, __pos, env);
+                    match __step_res {
+                        Matched(__newpos, __value) => {
+                            __repeat_pos = __newpos;
+                            __repeat_value.push(__value);
+                        }
+                        Failed => {
+                            break;
+                        }
+                    }
+                }
+                if __repeat_value.len() >= 1 {
+                    Matched(__repeat_pos, ())
+                } else {
+                    Failed
+                }
+            } {
+                Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
+                Failed => Failed,
+            }
+        };
+        match __seq_res {
+            Matched(__pos, a) => {
+                let __seq_res = match {
+                    let __seq_res = slice_eq(__input, __state, __pos, ".");
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let str_start = __pos;
+                            match {
+                                let mut __repeat_pos = __pos;
+                                let mut __repeat_value = vec![];
+                                loop {
+                                    let __pos = __repeat_pos;
+                                    let __step_res = __parse_dec(__input, __state, __pos, env);
+                                    match __step_res {
+                                        Matched(__newpos, __value) => {
+                                            __repeat_pos = __newpos;
+                                            __repeat_value.push(__value);
+                                        }
+                                        Failed => {
+                                            break;
+                                        }
+                                    }
+                                }
+                                if __repeat_value.len() >= 1 {
+                                    Matched(__repeat_pos, ())
+                                } else {
+                                    Failed
+                                }
+                            } {
+                                Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                } {
+                    Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                    Failed => Matched(__pos, None),
+                };
+                match __seq_res {
+                    Matched(__pos, b) => {
+                        let __seq_res = match {
+                            let __seq_res = slice_eq(__input, __state, __pos, ".");
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let str_start = __pos;
+                                    match {
+                                        let mut __repeat_pos = __pos;
+                                        let mut __repeat_value = vec![];
+                                        loop {
+                                            let __pos = __repeat_pos;
+                                            let __step_res = __parse_dec(__input, __state, __pos, env);
+                                            match __step_res {
+                                                Matched(__newpos, __value) => {
+                                                    __repeat_pos = __newpos;
+                                                    __repeat_value.push(__value);
+                                                }
+                                                Failed => {
+                                                    break;
+                                                }
+                                            }
+                                        }
+                                        if __repeat_value.len() >= 1 {
+                                            Matched(__repeat_pos, ())
+                                        } else {
+                                            Failed
+                                        }
+                                    } {
+                                        Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        } {
+                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                            Failed => Matched(__pos, None),
+                        };
+                        match __seq_res {
+                            Matched(__pos, c) => Matched(__pos, { AvailabilityVersion { major: a.into(), minor: b.map(str::to_owned), subminor: c.map(str::to_owned) } }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_asm_label<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Extension>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = Matched(__pos, __pos);
+        match __seq_res {
+            Matched(__pos, l) => {
+                let __seq_res = __parse_asm_label0(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, e) => {
+                        let __seq_res = Matched(__pos, __pos);
+                        match __seq_res {
+                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_asm_label0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Extension> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = __parse_asm_label_keyword(__input, __state, __pos, env);
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse__(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = slice_eq(__input, __state, __pos, "(");
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse_string_literal(__input, __state, __pos, env);
+                                        match __seq_res {
+                                            Matched(__pos, s) => {
+                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => Matched(__pos, { Extension::AsmLabel(s) }),
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_asm_label_keyword<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            __state.suppress_fail += 1;
+            let res = {
+                let __choice_res = {
+                    __state.suppress_fail += 1;
+                    let res = {
+                        let __seq_res = slice_eq(__input, __state, __pos, "asm");
+                        match __seq_res {
+                            Matched(__pos, e) => {
+                                let __seq_res = {
+                                    __state.suppress_fail += 1;
+                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                                    __state.suppress_fail -= 1;
+                                    match __assert_res {
+                                        Failed => Matched(__pos, ()),
+                                        Matched(..) => Failed,
+                                    }
+                                };
+                                match __seq_res {
+                                    Matched(__pos, _) => Matched(__pos, { e }),
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    };
+                    __state.suppress_fail -= 1;
+                    res
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        __state.suppress_fail += 1;
+                        let res = {
+                            let __seq_res = {
+                                let __seq_res = slice_eq(__input, __state, __pos, "__asm");
+                                match __seq_res {
+                                    Matched(__pos, _) => match slice_eq(__input, __state, __pos, "__") {
+                                        Matched(__newpos, _) => Matched(__newpos, ()),
+                                        Failed => Matched(__pos, ()),
+                                    },
+                                    Failed => Failed,
+                                }
+                            };
+                            match __seq_res {
+                                Matched(__pos, e) => {
+                                    let __seq_res = {
+                                        __state.suppress_fail += 1;
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                                        __state.suppress_fail -= 1;
+                                        match __assert_res {
+                                            Failed => Matched(__pos, ()),
+                                            Matched(..) => Failed,
+                                        }
+                                    };
+                                    match __seq_res {
+                                        Matched(__pos, _) => Matched(__pos, { e }),
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        };
+                        __state.suppress_fail -= 1;
+                        res
+                    }
+                }
+            };
+            __state.suppress_fail -= 1;
+            res
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                __state.mark_failure(__pos, "asm");
+                Failed
+            }
+        }
+    }
+}
+
+fn __parse_asm_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Statement> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = {
+            let __seq_res = Matched(__pos, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = __parse_asm_statement0(__input, __state, __pos, env);
+                    match __seq_res {
+                        Matched(__pos, e) => {
+                            let __seq_res = Matched(__pos, __pos);
+                            match __seq_res {
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        };
+        match __seq_res {
+            Matched(__pos, s) => Matched(__pos, { Statement::Asm(s) }),
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_asm_statement0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<AsmStatement> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = {
+            __state.suppress_fail += 1;
+            let res = {
+                let __seq_res = {
+                    let __choice_res = slice_eq(__input, __state, __pos, "asm");
+                    match __choice_res {
+                        Matched(__pos, __value) => Matched(__pos, __value),
+                        Failed => {
+                            let __seq_res = slice_eq(__input, __state, __pos, "__asm");
+                            match __seq_res {
+                                Matched(__pos, _) => match slice_eq(__input, __state, __pos, "__") {
+                                    Matched(__newpos, _) => Matched(__newpos, ()),
+                                    Failed => Matched(__pos, ()),
+                                },
+                                Failed => Failed,
+                            }
+                        }
+                    }
+                };
+                match __seq_res {
+                    Matched(__pos, e) => {
+                        let __seq_res = {
+                            __state.suppress_fail += 1;
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                            __state.suppress_fail -= 1;
+                            match __assert_res {
+                                Failed => Matched(__pos, ()),
+                                Matched(..) => Failed,
+                            }
+                        };
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, { e }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            };
+            __state.suppress_fail -= 1;
+            res
+        };
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse__(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = match __parse_type_qualifier(__input, __state, __pos, env) {
+                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                            Failed => Matched(__pos, None),
+                        };
+                        match __seq_res {
+                            Matched(__pos, q) => {
+                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = slice_eq(__input, __state, __pos, "(");
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res = __parse_string_literal(__input, __state, __pos, env);
+                                                        match __seq_res {
+                                                            Matched(__pos, a) => {
+                                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = match {
+                                                                            let __seq_res = slice_eq(__input, __state, __pos, ":");
+                                                                            match __seq_res {
+                                                                                Matched(__pos, _) => {
+                                                                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                    match __seq_res {
+                                                                                        Matched(__pos, _) => {
+                                                                                            let __seq_res = __parse_asm_operand_list(__input, __state, __pos, env);
+                                                                                            match __seq_res {
+                                                                                                Matched(__pos, e) => {
+                                                                                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                                    match __seq_res {
+                                                                                                        Matched(__pos, _) => {
+                                                                                                            let __seq_res = match {
+                                                                                                                let __seq_res = slice_eq(__input, __state, __pos, ":");
+                                                                                                                match __seq_res {
+                                                                                                                    Matched(__pos, _) => {
+                                                                                                                        let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                                                        match __seq_res {
+                                                                                                                            Matched(__pos, _) => {
+                                                                                                                                let __seq_res = __parse_asm_operand_list(__input, __state, __pos, env);
+                                                                                                                                match __seq_res {
+                                                                                                                                    Matched(__pos, e) => {
+                                                                                                                                        let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                                                                        match __seq_res {
+                                                                                                                                            Matched(__pos, _) => {
+                                                                                                                                                let __seq_res = match {
+                                                                                                                                                    let __seq_res = slice_eq(__input, __state, __pos, ":");
+                                                                                                                                                    match __seq_res {
+                                                                                                                                                        Matched(__pos, _) => {
+                                                                                                                                                            let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                                                                                            match __seq_res {
+                                                                                                                                                                Matched(__pos, _) => {
+                                                                                                                                                                    let __seq_res = {
+                                                                                                                                                                        let __seq_res = {
+                                                                                                                                                                            let mut __repeat_pos = __pos;
+                                                                                                                                                                            let mut __repeat_value = vec![];
+                                                                                                                                                                            loop {
+                                                                                                                                                                                let __pos = __repeat_pos;
+                                                                                                                                                                                let __pos = if __repeat_value.len() > 0 {
+                                                                                                                                                                                    let __sep_res = {
+                                                                                                                                                                                        let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                                                                                                                        match __seq_res {
+                                                                                                                                                                                            Matched(__pos, _) => {
+                                                                                                                                                                                                let __seq_res = slice_eq(__input, __state, __pos, ",");
+                                                                                                                                                                                                match __seq_res {
+                                                                                                                                                                                                    Matched(__pos, _) => __parse__(__input, __state, __pos, env),
+                                                                                                                                                                                                    Failed => Failed,
+                                                                                                                                                                                                }
+                                                                                                                                                                                            }
+                                                                                                                                                                                            Failed => Failed,
+                                                                                                                                                                                        }
+                                                                                                                                                                                    };
+                                                                                                                                                                                    match __sep_res {
+                                                                                                                                                                                        Matched(__newpos, _) => __newpos,
+                                                                                                                                                                                        Failed => break,
+                                                                                                                                                                                    }
+                                                                                                                                                                                } else {
+                                                                                                                                                                                    __pos
+                                                                                                                                                                                };
+                                                                                                                                                                                let __step_res = __parse_string_literal(__input, __state, __pos, env);
+                                                                                                                                                                                match __step_res {
+                                                                                                                                                                                    Matched(__newpos, __value) => {
+                                                                                                                                                                                        __repeat_pos = __newpos;
+                                                                                                                                                                                        __repeat_value.push(__value);
+                                                                                                                                                                                    }
+                                                                                                                                                                                    Failed => {
+                                                                                                                                                                                        break;
+                                                                                                                                                                                    }
+                                                                                                                                                                                }
+                                                                                                                                                                            }
+                                                                                                                                                                            Matched(__repeat_pos, __repeat_value)
+                                                                                                                                                                        };
+                                                                                                                                                                        match __seq_res {
+                                                                                                                                                                            Matched(__pos, e) => Matched(__pos, { e }),
+                                                                                                                                                                            Failed => Failed,
+                                                                                                                                                                        }
+                                                                                                                                                                    };
+                                                                                                                                                                    match __seq_res {
+                                                                                                                                                                        Matched(__pos, e) => {
+                                                                                                                                                                            let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                                                                                                            match __seq_res {
+                                                                                                                                                                                Matched(__pos, _) => {
+                                                                                                                                                                                    let __seq_res = match Matched(__pos, ()) {
+                                                                                                                                                                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                                                                                                                                                        Failed => Matched(__pos, None),
+                                                                                                                                                                                    };
+                                                                                                                                                                                    match __seq_res {
+                                                                                                                                                                                        Matched(__pos, t) => Matched(__pos, { (e, t.unwrap_or_default()) }),
+                                                                                                                                                                                        Failed => Failed,
+                                                                                                                                                                                    }
+                                                                                                                                                                                }
+                                                                                                                                                                                Failed => Failed,
+                                                                                                                                                                            }
+                                                                                                                                                                        }
+                                                                                                                                                                        Failed => Failed,
+                                                                                                                                                                    }
+                                                                                                                                                                }
+                                                                                                                                                                Failed => Failed,
+                                                                                                                                                            }
+                                                                                                                                                        }
+                                                                                                                                                        Failed => Failed,
+                                                                                                                                                    }
+                                                                                                                                                } {
+                                                                                                                                                    Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                                                                                                                    Failed => Matched(__pos, None),
+                                                                                                                                                };
+                                                                                                                                                match __seq_res {
+                                                                                                                                                    Matched(__pos, t) => Matched(__pos, { (e, t.unwrap_or_default()) }),
+                                                                                                                                                    Failed => Failed,
+                                                                                                                                                }
+                                                                                                                                            }
+                                                                                                                                            Failed => Failed,
+                                                                                                                                        }
+                                                                                                                                    }
+                                                                                                                                    Failed => Failed,
+                                                                                                                                }
+                                                                                                                            }
+                                                                                                                            Failed => Failed,
+                                                                                                                        }
+                                                                                                                    }
+                                                                                                                    Failed => Failed,
+                                                                                                                }
+                                                                                                            } {
+                                                                                                                Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                                                                                Failed => Matched(__pos, None),
+                                                                                                            };
+                                                                                                            match __seq_res {
+                                                                                                                Matched(__pos, t) => Matched(__pos, { (e, t.unwrap_or_default()) }),
+                                                                                                                Failed => Failed,
+                                                                                                            }
+                                                                                                        }
+                                                                                                        Failed => Failed,
+                                                                                                    }
+                                                                                                }
+                                                                                                Failed => Failed,
+                                                                                            }
+                                                                                        }
+                                                                                        Failed => Failed,
+                                                                                    }
+                                                                                }
+                                                                                Failed => Failed,
+                                                                            }
+                                                                        } {
+                                                                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                                            Failed => Matched(__pos, None),
+                                                                        };
+                                                                        match __seq_res {
+                                                                            Matched(__pos, o) => {
+                                                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                match __seq_res {
+                                                                                    Matched(__pos, _) => {
+                                                                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
+                                                                                        match __seq_res {
+                                                                                            Matched(__pos, _) => {
+                                                                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                                match __seq_res {
+                                                                                                    Matched(__pos, _) => {
+                                                                                                        let __seq_res = slice_eq(__input, __state, __pos, ";");
+                                                                                                        match __seq_res {
+                                                                                                            Matched(__pos, _) => Matched(__pos, {
+                                                                                                                if let Some((o, (i, (c, ())))) = o {
+                                                                                                                    AsmStatement::GnuExtended(GnuExtendedAsmStatement { qualifier: q, template: a, outputs: o, inputs: i, clobbers: c })
+                                                                                                                } else {
+                                                                                                                    AsmStatement::GnuBasic(a)
+                                                                                                                }
+                                                                                                            }),
+                                                                                                            Failed => Failed,
+                                                                                                        }
+                                                                                                    }
+                                                                                                    Failed => Failed,
+                                                                                                }
+                                                                                            }
+                                                                                            Failed => Failed,
+                                                                                        }
+                                                                                    }
+                                                                                    Failed => Failed,
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_asm_operand_list<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Vec<Node<GnuAsmOperand>>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = {
+            let mut __repeat_pos = __pos;
+            let mut __repeat_value = vec![];
+            loop {
+                let __pos = __repeat_pos;
+                let __pos = if __repeat_value.len() > 0 {
+                    let __sep_res = {
+                        let __seq_res = __parse__(__input, __state, __pos, env);
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = slice_eq(__input, __state, __pos, ",");
+                                match __seq_res {
+                                    Matched(__pos, _) => __parse__(__input, __state, __pos, env),
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    };
+                    match __sep_res {
+                        Matched(__newpos, _) => __newpos,
+                        Failed => break,
+                    }
+                } else {
+                    __pos
+                };
+                let __step_res = {
+                    let __seq_res = Matched(__pos, __pos);
+                    match __seq_res {
+                        Matched(__pos, l) => {
+                            let __seq_res = __parse_asm_operand(__input, __state, __pos, env);
+                            match __seq_res {
+                                Matched(__pos, e) => {
+                                    let __seq_res = Matched(__pos, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                };
+                match __step_res {
+                    Matched(__newpos, __value) => {
+                        __repeat_pos = __newpos;
+                        __repeat_value.push(__value);
+                    }
+                    Failed => {
+                        break;
+                    }
+                }
+            }
+            Matched(__repeat_pos, __repeat_value)
+        };
+        match __seq_res {
+            Matched(__pos, e) => Matched(__pos, { e }),
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_asm_operand<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<GnuAsmOperand> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = match {
+            let __seq_res = __parse_lbrack(__input, __state, __pos, env);
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse__(__input, __state, __pos, env);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse_identifier(__input, __state, __pos, env);
+                            match __seq_res {
+                                Matched(__pos, i) => {
+                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = __parse_rbrack(__input, __state, __pos, env);
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => Matched(__pos, { i }),
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        } {
+            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+            Failed => Matched(__pos, None),
+        };
+        match __seq_res {
+            Matched(__pos, i) => {
+                let __seq_res = __parse_string_literal(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, s) => {
+                        let __seq_res = __parse__(__input, __state, __pos, env);
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = slice_eq(__input, __state, __pos, "(");
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse__(__input, __state, __pos, env);
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res = {
+                                                    let __seq_res = Matched(__pos, __pos);
                                                     match __seq_res {
-                                                        Matched(__pos, _) => {
-                                                            let __seq_res = slice_eq(__input, __state, __pos, "=");
+                                                        Matched(__pos, l) => {
+                                                            let __seq_res = __parse_expression0(__input, __state, __pos, env);
                                                             match __seq_res {
-                                                                Matched(__pos, _) => {
-                                                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                Matched(__pos, e) => {
+                                                                    let __seq_res = Matched(__pos, __pos);
                                                                     match __seq_res {
-                                                                        Matched(__pos, _) => {
-                                                                            let __seq_res = __parse_string_literal(__input, __state, __pos, env);
-                                                                            match __seq_res {
-                                                                                Matched(__pos, s) => Matched(__pos, { AvailabilityClause::Message(s) }),
-                                                                                Failed => Failed,
-                                                                            }
-                                                                        }
+                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                         Failed => Failed,
                                                                     }
                                                                 }
@@ -15426,67 +17192,15 @@ fn __parse_attr_availability_clause<'input>(__input: &'input str, __state: &mut
                                                         }
                                                         Failed => Failed,
                                                     }
-                                                }
-                                                Failed => Failed,
-                                            }
-                                        };
-                                        match __choice_res {
-                                            Matched(__pos, __value) => Matched(__pos, __value),
-                                            Failed => {
-                                                let __seq_res = {
-                                                    __state.suppress_fail += 1;
-                                                    let res = {
-                                                        let __seq_res = slice_eq(__input, __state, __pos, "replacement");
-                                                        match __seq_res {
-                                                            Matched(__pos, e) => {
-                                                                let __seq_res = {
-                                                                    __state.suppress_fail += 1;
-                                                                    let __assert_res = if __input.len() > __pos {
-                                                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                                                        match __ch {
-                                                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                                                        }
-                                                                    } else {
-                                                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                                                    };
-                                                                    __state.suppress_fail -= 1;
-                                                                    match __assert_res {
-                                                                        Failed => Matched(__pos, ()),
-                                                                        Matched(..) => Failed,
-                                                                    }
-                                                                };
-                                                                match __seq_res {
-                                                                    Matched(__pos, _) => Matched(__pos, { e }),
-                                                                    Failed => Failed,
-                                                                }
-                                                            }
-                                                            Failed => Failed,
-                                                        }
-                                                    };
-                                                    __state.suppress_fail -= 1;
-                                                    res
                                                 };
                                                 match __seq_res {
-                                                    Matched(__pos, _) => {
+                                                    Matched(__pos, e) => {
                                                         let __seq_res = __parse__(__input, __state, __pos, env);
                                                         match __seq_res {
                                                             Matched(__pos, _) => {
-                                                                let __seq_res = slice_eq(__input, __state, __pos, "=");
+                                                                let __seq_res = slice_eq(__input, __state, __pos, ")");
                                                                 match __seq_res {
-                                                                    Matched(__pos, _) => {
-                                                                        let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                        match __seq_res {
-                                                                            Matched(__pos, _) => {
-                                                                                let __seq_res = __parse_string_literal(__input, __state, __pos, env);
-                                                                                match __seq_res {
-                                                                                    Matched(__pos, s) => Matched(__pos, { AvailabilityClause::Replacement(s) }),
-                                                                                    Failed => Failed,
-                                                                                }
-                                                                            }
-                                                                            Failed => Failed,
-                                                                        }
-                                                                    }
+                                                                    Matched(__pos, _) => Matched(__pos, { GnuAsmOperand { symbolic_name: i, constraints: s, variable_name: e } }),
                                                                     Failed => Failed,
                                                                 }
                                                             }
@@ -15496,129 +17210,116 @@ fn __parse_attr_availability_clause<'input>(__input: &'input str, __state: &mut
                                                     Failed => Failed,
                                                 }
                                             }
+                                            Failed => Failed,
                                         }
                                     }
+                                    Failed => Failed,
                                 }
                             }
+                            Failed => Failed,
                         }
                     }
+                    Failed => Failed,
                 }
             }
+            Failed => Failed,
         }
     }
 }
 
-fn __parse_attr_availability_version<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<AvailabilityVersion> {
+fn __parse_gnu_primary_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = {
-            let str_start = __pos;
-            match {
-                let mut __repeat_pos = __pos;
-                let mut __repeat_value = vec![];
-                loop {
-                    let __pos = __repeat_pos;
-                    let __step_res = __parse_dec(__input, __state, __pos, env);
-                    match __step_res {
-                        Matched(__newpos, __value) => {
-                            __repeat_pos = __newpos;
-                            __repeat_value.push(__value);
-                        }
-                        Failed => {
-                            break;
+        let __choice_res = __parse_statement_expression(__input, __state, __pos, env);
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = __parse_offsetof_expression(__input, __state, __pos, env);
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = __parse_va_arg_expression(__input, __state, __pos, env);
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => __parse_keyword_expression(__input, __state, __pos, env),
                         }
                     }
                 }
-                if __repeat_value.len() >= 1 {
-                    Matched(__repeat_pos, ())
-                } else {
-                    Failed
-                }
-            } {
-                Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
-                Failed => Failed,
             }
-        };
-        match __seq_res {
-            Matched(__pos, a) => {
-                let __seq_res = match {
-                    let __seq_res = slice_eq(__input, __state, __pos, ".");
-                    match __seq_res {
-                        Matched(__pos, _) => {
-                            let str_start = __pos;
-                            match {
-                                let mut __repeat_pos = __pos;
-                                let mut __repeat_value = vec![];
-                                loop {
-                                    let __pos = __repeat_pos;
-                                    let __step_res = __parse_dec(__input, __state, __pos, env);
-                                    match __step_res {
-                                        Matched(__newpos, __value) => {
-                                            __repeat_pos = __newpos;
-                                            __repeat_value.push(__value);
-                                        }
-                                        Failed => {
-                                            break;
-                                        }
-                                    }
-                                }
-                                if __repeat_value.len() >= 1 {
-                                    Matched(__repeat_pos, ())
-                                } else {
-                                    Failed
-                                }
-                            } {
-                                Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
-                                Failed => Failed,
-                            }
-                        }
-                        Failed => Failed,
-                    }
-                } {
-                    Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                    Failed => Matched(__pos, None),
-                };
+        }
+    }
+}
+
+fn __parse_statement_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = slice_eq(__input, __state, __pos, "(");
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse__(__input, __state, __pos, env);
                 match __seq_res {
-                    Matched(__pos, b) => {
-                        let __seq_res = match {
-                            let __seq_res = slice_eq(__input, __state, __pos, ".");
+                    Matched(__pos, _) => {
+                        let __seq_res = {
+                            let __seq_res = Matched(__pos, {
+                                env.enter_scope();
+                            });
                             match __seq_res {
                                 Matched(__pos, _) => {
-                                    let str_start = __pos;
-                                    match {
-                                        let mut __repeat_pos = __pos;
-                                        let mut __repeat_value = vec![];
-                                        loop {
-                                            let __pos = __repeat_pos;
-                                            let __step_res = __parse_dec(__input, __state, __pos, env);
-                                            match __step_res {
-                                                Matched(__newpos, __value) => {
-                                                    __repeat_pos = __newpos;
-                                                    __repeat_value.push(__value);
-                                                }
-                                                Failed => {
-                                                    break;
+                                    let __seq_res = match {
+                                        let __seq_res = Matched(__pos, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, l) => {
+                                                let __seq_res = __parse_compound_statement(__input, __state, __pos, env);
+                                                match __seq_res {
+                                                    Matched(__pos, e) => {
+                                                        let __seq_res = Matched(__pos, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                    Failed => Failed,
                                                 }
                                             }
-                                        }
-                                        if __repeat_value.len() >= 1 {
-                                            Matched(__repeat_pos, ())
-                                        } else {
-                                            Failed
+                                            Failed => Failed,
                                         }
                                     } {
-                                        Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
+                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                        Failed => Matched(__pos, None),
+                                    };
+                                    match __seq_res {
+                                        Matched(__pos, e) => {
+                                            match {
+                                                env.leave_scope();
+                                                e.ok_or("")
+                                            } {
+                                                Ok(res) => Matched(__pos, res),
+                                                Err(expected) => {
+                                                    __state.mark_failure(__pos, expected);
+                                                    Failed
+                                                }
+                                            }
+                                        }
                                         Failed => Failed,
                                     }
                                 }
                                 Failed => Failed,
                             }
-                        } {
-                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                            Failed => Matched(__pos, None),
                         };
                         match __seq_res {
-                            Matched(__pos, c) => Matched(__pos, { AvailabilityVersion { major: a.into(), minor: b.map(str::to_owned), subminor: c.map(str::to_owned) } }),
+                            Matched(__pos, s) => {
+                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
+                                        match __seq_res {
+                                            Matched(__pos, _) => Matched(__pos, { Expression::Statement(Box::new(s)) }),
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            }
                             Failed => Failed,
                         }
                     }
@@ -15630,33 +17331,64 @@ fn __parse_attr_availability_version<'input>(__input: &'input str, __state: &mut
     }
 }
 
-fn __parse_asm_label<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Extension>> {
+fn __parse_va_arg_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = Matched(__pos, __pos);
-        match __seq_res {
-            Matched(__pos, l) => {
-                let __seq_res = __parse_asm_label0(__input, __state, __pos, env);
-                match __seq_res {
-                    Matched(__pos, e) => {
-                        let __seq_res = Matched(__pos, __pos);
-                        match __seq_res {
-                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                            Failed => Failed,
+        let __seq_res = {
+            let __seq_res = Matched(__pos, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = __parse_va_arg_expression_inner(__input, __state, __pos, env);
+                    match __seq_res {
+                        Matched(__pos, e) => {
+                            let __seq_res = Matched(__pos, __pos);
+                            match __seq_res {
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                Failed => Failed,
+                            }
                         }
+                        Failed => Failed,
                     }
-                    Failed => Failed,
                 }
+                Failed => Failed,
             }
+        };
+        match __seq_res {
+            Matched(__pos, n) => Matched(__pos, { Expression::VaArg(Box::new(n)) }),
             Failed => Failed,
         }
     }
 }
 
-fn __parse_asm_label0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Extension> {
+fn __parse_va_arg_expression_inner<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<VaArgExpression> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = __parse_asm_label_keyword(__input, __state, __pos, env);
+        let __seq_res = {
+            __state.suppress_fail += 1;
+            let res = {
+                let __seq_res = slice_eq(__input, __state, __pos, "__builtin_va_arg");
+                match __seq_res {
+                    Matched(__pos, e) => {
+                        let __seq_res = {
+                            __state.suppress_fail += 1;
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                            __state.suppress_fail -= 1;
+                            match __assert_res {
+                                Failed => Matched(__pos, ()),
+                                Matched(..) => Failed,
+                            }
+                        };
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, { e }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            };
+            __state.suppress_fail -= 1;
+            res
+        };
         match __seq_res {
             Matched(__pos, _) => {
                 let __seq_res = __parse__(__input, __state, __pos, env);
@@ -15668,15 +17400,39 @@ fn __parse_asm_label0<'input>(__input: &'input str, __state: &mut ParseState<'in
                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                 match __seq_res {
                                     Matched(__pos, _) => {
-                                        let __seq_res = __parse_string_literal(__input, __state, __pos, env);
+                                        let __seq_res = __parse_assignment_expression(__input, __state, __pos, env);
                                         match __seq_res {
-                                            Matched(__pos, s) => {
+                                            Matched(__pos, e) => {
                                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                                 match __seq_res {
                                                     Matched(__pos, _) => {
-                                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
+                                                        let __seq_res = slice_eq(__input, __state, __pos, ",");
                                                         match __seq_res {
-                                                            Matched(__pos, _) => Matched(__pos, { Extension::AsmLabel(s) }),
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = __parse_type_name(__input, __state, __pos, env);
+                                                                        match __seq_res {
+                                                                            Matched(__pos, t) => {
+                                                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                match __seq_res {
+                                                                                    Matched(__pos, _) => {
+                                                                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
+                                                                                        match __seq_res {
+                                                                                            Matched(__pos, _) => Matched(__pos, { VaArgExpression { va_list: e, type_name: t } }),
+                                                                                            Failed => Failed,
+                                                                                        }
+                                                                                    }
+                                                                                    Failed => Failed,
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -15700,29 +17456,85 @@ fn __parse_asm_label0<'input>(__input: &'input str, __state: &mut ParseState<'in
     }
 }
 
-fn __parse_asm_label_keyword<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+fn __parse_keyword_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = {
+            let __seq_res = Matched(__pos, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let str_start = __pos;
+                        match __parse_keyword_expression0(__input, __state, __pos, env) {
+                            Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
+                            Failed => Failed,
+                        }
+                    };
+                    match __seq_res {
+                        Matched(__pos, e) => {
+                            let __seq_res = Matched(__pos, __pos);
+                            match __seq_res {
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        };
+        match __seq_res {
+            Matched(__pos, k) => Matched(__pos, {
+                let ident = Identifier { name: env.intern(k.node) };
+                Expression::Identifier(Box::new(Node::new(ident, k.span)))
+            }),
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_keyword_expression0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
     #![allow(non_snake_case, unused)]
     {
         let __choice_res = {
             __state.suppress_fail += 1;
             let res = {
+                let __seq_res = slice_eq(__input, __state, __pos, "__func__");
+                match __seq_res {
+                    Matched(__pos, e) => {
+                        let __seq_res = {
+                            __state.suppress_fail += 1;
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                            __state.suppress_fail -= 1;
+                            match __assert_res {
+                                Failed => Matched(__pos, ()),
+                                Matched(..) => Failed,
+                            }
+                        };
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, { e }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            };
+            __state.suppress_fail -= 1;
+            res
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
                 let __choice_res = {
                     __state.suppress_fail += 1;
                     let res = {
-                        let __seq_res = slice_eq(__input, __state, __pos, "asm");
+                        let __seq_res = slice_eq(__input, __state, __pos, "__FUNCTION__");
                         match __seq_res {
                             Matched(__pos, e) => {
                                 let __seq_res = {
                                     __state.suppress_fail += 1;
-                                    let __assert_res = if __input.len() > __pos {
-                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                        match __ch {
-                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                        }
-                                    } else {
-                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                    };
+                                    let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                     __state.suppress_fail -= 1;
                                     match __assert_res {
                                         Failed => Matched(__pos, ()),
@@ -15742,32 +17554,15 @@ fn __parse_asm_label_keyword<'input>(__input: &'input str, __state: &mut ParseSt
                 };
                 match __choice_res {
                     Matched(__pos, __value) => Matched(__pos, __value),
-                    Failed => {
-                        __state.suppress_fail += 1;
-                        let res = {
-                            let __seq_res = {
-                                let __seq_res = slice_eq(__input, __state, __pos, "__asm");
-                                match __seq_res {
-                                    Matched(__pos, _) => match slice_eq(__input, __state, __pos, "__") {
-                                        Matched(__newpos, _) => Matched(__newpos, ()),
-                                        Failed => Matched(__pos, ()),
-                                    },
-                                    Failed => Failed,
-                                }
-                            };
+                    Failed => {
+                        __state.suppress_fail += 1;
+                        let res = {
+                            let __seq_res = slice_eq(__input, __state, __pos, "__PRETTY_FUNCTION__");
                             match __seq_res {
                                 Matched(__pos, e) => {
                                     let __seq_res = {
                                         __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                                         __state.suppress_fail -= 1;
                                         match __assert_res {
                                             Failed => Matched(__pos, ()),
@@ -15786,33 +17581,24 @@ fn __parse_asm_label_keyword<'input>(__input: &'input str, __state: &mut ParseSt
                         res
                     }
                 }
-            };
-            __state.suppress_fail -= 1;
-            res
-        };
-        match __choice_res {
-            Matched(__pos, __value) => Matched(__pos, __value),
-            Failed => {
-                __state.mark_failure(__pos, "asm");
-                Failed
             }
         }
     }
 }
 
-fn __parse_asm_statement<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Statement> {
+fn __parse_offsetof_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
     #![allow(non_snake_case, unused)]
     {
         let __seq_res = {
             let __seq_res = Matched(__pos, __pos);
             match __seq_res {
                 Matched(__pos, l) => {
-                    let __seq_res = __parse_asm_statement0(__input, __state, __pos, env);
+                    let __seq_res = __parse_offsetof_expression_inner(__input, __state, __pos, env);
                     match __seq_res {
                         Matched(__pos, e) => {
                             let __seq_res = Matched(__pos, __pos);
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                 Failed => Failed,
                             }
                         }
@@ -15823,47 +17609,24 @@ fn __parse_asm_statement<'input>(__input: &'input str, __state: &mut ParseState<
             }
         };
         match __seq_res {
-            Matched(__pos, s) => Matched(__pos, { Statement::Asm(s) }),
+            Matched(__pos, n) => Matched(__pos, { Expression::OffsetOf(Box::new(n)) }),
             Failed => Failed,
         }
     }
 }
 
-fn __parse_asm_statement0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<AsmStatement> {
+fn __parse_offsetof_expression_inner<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<OffsetOfExpression> {
     #![allow(non_snake_case, unused)]
     {
         let __seq_res = {
             __state.suppress_fail += 1;
             let res = {
-                let __seq_res = {
-                    let __choice_res = slice_eq(__input, __state, __pos, "asm");
-                    match __choice_res {
-                        Matched(__pos, __value) => Matched(__pos, __value),
-                        Failed => {
-                            let __seq_res = slice_eq(__input, __state, __pos, "__asm");
-                            match __seq_res {
-                                Matched(__pos, _) => match slice_eq(__input, __state, __pos, "__") {
-                                    Matched(__newpos, _) => Matched(__newpos, ()),
-                                    Failed => Matched(__pos, ()),
-                                },
-                                Failed => Failed,
-                            }
-                        }
-                    }
-                };
+                let __seq_res = slice_eq(__input, __state, __pos, "__builtin_offsetof");
                 match __seq_res {
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -15886,162 +17649,34 @@ fn __parse_asm_statement0<'input>(__input: &'input str, __state: &mut ParseState
                 let __seq_res = __parse__(__input, __state, __pos, env);
                 match __seq_res {
                     Matched(__pos, _) => {
-                        let __seq_res = match __parse_type_qualifier(__input, __state, __pos, env) {
-                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                            Failed => Matched(__pos, None),
-                        };
+                        let __seq_res = slice_eq(__input, __state, __pos, "(");
                         match __seq_res {
-                            Matched(__pos, q) => {
+                            Matched(__pos, _) => {
                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                 match __seq_res {
                                     Matched(__pos, _) => {
-                                        let __seq_res = slice_eq(__input, __state, __pos, "(");
+                                        let __seq_res = __parse_type_name(__input, __state, __pos, env);
                                         match __seq_res {
-                                            Matched(__pos, _) => {
+                                            Matched(__pos, t) => {
                                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                                 match __seq_res {
                                                     Matched(__pos, _) => {
-                                                        let __seq_res = __parse_string_literal(__input, __state, __pos, env);
+                                                        let __seq_res = slice_eq(__input, __state, __pos, ",");
                                                         match __seq_res {
-                                                            Matched(__pos, a) => {
+                                                            Matched(__pos, _) => {
                                                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                                                 match __seq_res {
                                                                     Matched(__pos, _) => {
-                                                                        let __seq_res = match {
-                                                                            let __seq_res = slice_eq(__input, __state, __pos, ":");
+                                                                        let __seq_res = {
+                                                                            let __seq_res = Matched(__pos, __pos);
                                                                             match __seq_res {
-                                                                                Matched(__pos, _) => {
-                                                                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                                Matched(__pos, l) => {
+                                                                                    let __seq_res = __parse_offsetof_designator(__input, __state, __pos, env);
                                                                                     match __seq_res {
-                                                                                        Matched(__pos, _) => {
-                                                                                            let __seq_res = __parse_asm_operand_list(__input, __state, __pos, env);
+                                                                                        Matched(__pos, e) => {
+                                                                                            let __seq_res = Matched(__pos, __pos);
                                                                                             match __seq_res {
-                                                                                                Matched(__pos, e) => {
-                                                                                                    let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                                                    match __seq_res {
-                                                                                                        Matched(__pos, _) => {
-                                                                                                            let __seq_res = match {
-                                                                                                                let __seq_res = slice_eq(__input, __state, __pos, ":");
-                                                                                                                match __seq_res {
-                                                                                                                    Matched(__pos, _) => {
-                                                                                                                        let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                                                                        match __seq_res {
-                                                                                                                            Matched(__pos, _) => {
-                                                                                                                                let __seq_res = __parse_asm_operand_list(__input, __state, __pos, env);
-                                                                                                                                match __seq_res {
-                                                                                                                                    Matched(__pos, e) => {
-                                                                                                                                        let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                                                                                        match __seq_res {
-                                                                                                                                            Matched(__pos, _) => {
-                                                                                                                                                let __seq_res = match {
-                                                                                                                                                    let __seq_res = slice_eq(__input, __state, __pos, ":");
-                                                                                                                                                    match __seq_res {
-                                                                                                                                                        Matched(__pos, _) => {
-                                                                                                                                                            let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                                                                                                            match __seq_res {
-                                                                                                                                                                Matched(__pos, _) => {
-                                                                                                                                                                    let __seq_res = {
-                                                                                                                                                                        let __seq_res = {
-                                                                                                                                                                            let mut __repeat_pos = __pos;
-                                                                                                                                                                            let mut __repeat_value = vec![];
-                                                                                                                                                                            loop {
-                                                                                                                                                                                let __pos = __repeat_pos;
-                                                                                                                                                                                let __pos = if __repeat_value.len() > 0 {
-                                                                                                                                                                                    let __sep_res = {
-                                                                                                                                                                                        let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                                                                                                                                        match __seq_res {
-                                                                                                                                                                                            Matched(__pos, _) => {
-                                                                                                                                                                                                let __seq_res = slice_eq(__input, __state, __pos, ",");
-                                                                                                                                                                                                match __seq_res {
-                                                                                                                                                                                                    Matched(__pos, _) => __parse__(__input, __state, __pos, env),
-                                                                                                                                                                                                    Failed => Failed,
-                                                                                                                                                                                                }
-                                                                                                                                                                                            }
-                                                                                                                                                                                            Failed => Failed,
-                                                                                                                                                                                        }
-                                                                                                                                                                                    };
-                                                                                                                                                                                    match __sep_res {
-                                                                                                                                                                                        Matched(__newpos, _) => __newpos,
-                                                                                                                                                                                        Failed => break,
-                                                                                                                                                                                    }
-                                                                                                                                                                                } else {
-                                                                                                                                                                                    __pos
-                                                                                                                                                                                };
-                                                                                                                                                                                let __step_res = __parse_string_literal(__input, __state, __pos, env);
-                                                                                                                                                                                match __step_res {
-                                                                                                                                                                                    Matched(__newpos, __value) => {
-                                                                                                                                                                                        __repeat_pos = __newpos;
-                                                                                                                                                                                        __repeat_value.push(__value);
-                                                                                                                                                                                    }
-                                                                                                                                                                                    Failed => {
-                                                                                                                                                                                        break;
-                                                                                                                                                                                    }
-                                                                                                                                                                                }
-                                                                                                                                                                            }
-                                                                                                                                                                            Matched(__repeat_pos, __repeat_value)
-                                                                                                                                                                        };
-                                                                                                                                                                        match __seq_res {
-                                                                                                                                                                            Matched(__pos, e) => Matched(__pos, { e }),
-                                                                                                                                                                            Failed => Failed,
-                                                                                                                                                                        }
-                                                                                                                                                                    };
-                                                                                                                                                                    match __seq_res {
-                                                                                                                                                                        Matched(__pos, e) => {
-                                                                                                                                                                            let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                                                                                                                            match __seq_res {
-                                                                                                                                                                                Matched(__pos, _) => {
-                                                                                                                                                                                    let __seq_res = match Matched(__pos, ()) {
-                                                                                                                                                                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                                                                                                                                                                                        Failed => Matched(__pos, None),
-                                                                                                                                                                                    };
-                                                                                                                                                                                    match __seq_res {
-                                                                                                                                                                                        Matched(__pos, t) => Matched(__pos, { (e, t.unwrap_or_default()) }),
-                                                                                                                                                                                        Failed => Failed,
-                                                                                                                                                                                    }
-                                                                                                                                                                                }
-                                                                                                                                                                                Failed => Failed,
-                                                                                                                                                                            }
-                                                                                                                                                                        }
-                                                                                                                                                                        Failed => Failed,
-                                                                                                                                                                    }
-                                                                                                                                                                }
-                                                                                                                                                                Failed => Failed,
-                                                                                                                                                            }
-                                                                                                                                                        }
-                                                                                                                                                        Failed => Failed,
-                                                                                                                                                    }
-                                                                                                                                                } {
-                                                                                                                                                    Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                                                                                                                                                    Failed => Matched(__pos, None),
-                                                                                                                                                };
-                                                                                                                                                match __seq_res {
-                                                                                                                                                    Matched(__pos, t) => Matched(__pos, { (e, t.unwrap_or_default()) }),
-                                                                                                                                                    Failed => Failed,
-                                                                                                                                                }
-                                                                                                                                            }
-                                                                                                                                            Failed => Failed,
-                                                                                                                                        }
-                                                                                                                                    }
-                                                                                                                                    Failed => Failed,
-                                                                                                                                }
-                                                                                                                            }
-                                                                                                                            Failed => Failed,
-                                                                                                                        }
-                                                                                                                    }
-                                                                                                                    Failed => Failed,
-                                                                                                                }
-                                                                                                            } {
-                                                                                                                Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                                                                                                                Failed => Matched(__pos, None),
-                                                                                                            };
-                                                                                                            match __seq_res {
-                                                                                                                Matched(__pos, t) => Matched(__pos, { (e, t.unwrap_or_default()) }),
-                                                                                                                Failed => Failed,
-                                                                                                            }
-                                                                                                        }
-                                                                                                        Failed => Failed,
-                                                                                                    }
-                                                                                                }
+                                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                                                 Failed => Failed,
                                                                                             }
                                                                                         }
@@ -16050,36 +17685,15 @@ fn __parse_asm_statement0<'input>(__input: &'input str, __state: &mut ParseState
                                                                                 }
                                                                                 Failed => Failed,
                                                                             }
-                                                                        } {
-                                                                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                                                                            Failed => Matched(__pos, None),
                                                                         };
                                                                         match __seq_res {
-                                                                            Matched(__pos, o) => {
+                                                                            Matched(__pos, d) => {
                                                                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                                                                 match __seq_res {
                                                                                     Matched(__pos, _) => {
                                                                                         let __seq_res = slice_eq(__input, __state, __pos, ")");
                                                                                         match __seq_res {
-                                                                                            Matched(__pos, _) => {
-                                                                                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                                                match __seq_res {
-                                                                                                    Matched(__pos, _) => {
-                                                                                                        let __seq_res = slice_eq(__input, __state, __pos, ";");
-                                                                                                        match __seq_res {
-                                                                                                            Matched(__pos, _) => Matched(__pos, {
-                                                                                                                if let Some((o, (i, (c, ())))) = o {
-                                                                                                                    AsmStatement::GnuExtended(GnuExtendedAsmStatement { qualifier: q, template: a, outputs: o, inputs: i, clobbers: c })
-                                                                                                                } else {
-                                                                                                                    AsmStatement::GnuBasic(a)
-                                                                                                                }
-                                                                                                            }),
-                                                                                                            Failed => Failed,
-                                                                                                        }
-                                                                                                    }
-                                                                                                    Failed => Failed,
-                                                                                                }
-                                                                                            }
+                                                                                            Matched(__pos, _) => Matched(__pos, { OffsetOfExpression { type_name: t, designator: d } }),
                                                                                             Failed => Failed,
                                                                                         }
                                                                                     }
@@ -16101,9 +17715,82 @@ fn __parse_asm_statement0<'input>(__input: &'input str, __state: &mut ParseState
                                             Failed => Failed,
                                         }
                                     }
-                                    Failed => Failed,
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+            Failed => Failed,
+        }
+    }
+}
+
+fn __parse_offsetof_designator<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<OffsetDesignator> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = __parse_identifier(__input, __state, __pos, env);
+        match __seq_res {
+            Matched(__pos, i) => {
+                let __seq_res = __parse__(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = {
+                            let __seq_res = {
+                                let mut __repeat_pos = __pos;
+                                let mut __repeat_value = vec![];
+                                loop {
+                                    let __pos = __repeat_pos;
+                                    let __pos = if __repeat_value.len() > 0 {
+                                        let __sep_res = __parse__(__input, __state, __pos, env);
+                                        match __sep_res {
+                                            Matched(__newpos, _) => __newpos,
+                                            Failed => break,
+                                        }
+                                    } else {
+                                        __pos
+                                    };
+                                    let __step_res = {
+                                        let __seq_res = Matched(__pos, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, l) => {
+                                                let __seq_res = __parse_offsetof_member(__input, __state, __pos, env);
+                                                match __seq_res {
+                                                    Matched(__pos, e) => {
+                                                        let __seq_res = Matched(__pos, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    };
+                                    match __step_res {
+                                        Matched(__newpos, __value) => {
+                                            __repeat_pos = __newpos;
+                                            __repeat_value.push(__value);
+                                        }
+                                        Failed => {
+                                            break;
+                                        }
+                                    }
                                 }
+                                Matched(__repeat_pos, __repeat_value)
+                            };
+                            match __seq_res {
+                                Matched(__pos, e) => Matched(__pos, { e }),
+                                Failed => Failed,
                             }
+                        };
+                        match __seq_res {
+                            Matched(__pos, d) => Matched(__pos, { OffsetDesignator { base: i, members: d } }),
                             Failed => Failed,
                         }
                     }
@@ -16115,101 +17802,41 @@ fn __parse_asm_statement0<'input>(__input: &'input str, __state: &mut ParseState
     }
 }
 
-fn __parse_asm_operand_list<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Vec<Node<GnuAsmOperand>>> {
+fn __parse_offsetof_member<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<OffsetMember> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = {
-            let mut __repeat_pos = __pos;
-            let mut __repeat_value = vec![];
-            loop {
-                let __pos = __repeat_pos;
-                let __pos = if __repeat_value.len() > 0 {
-                    let __sep_res = {
-                        let __seq_res = __parse__(__input, __state, __pos, env);
-                        match __seq_res {
-                            Matched(__pos, _) => {
-                                let __seq_res = slice_eq(__input, __state, __pos, ",");
-                                match __seq_res {
-                                    Matched(__pos, _) => __parse__(__input, __state, __pos, env),
-                                    Failed => Failed,
-                                }
-                            }
-                            Failed => Failed,
-                        }
-                    };
-                    match __sep_res {
-                        Matched(__newpos, _) => __newpos,
-                        Failed => break,
-                    }
-                } else {
-                    __pos
-                };
-                let __step_res = {
-                    let __seq_res = Matched(__pos, __pos);
+        let __choice_res = {
+            let __seq_res = slice_eq(__input, __state, __pos, ".");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse__(__input, __state, __pos, env);
                     match __seq_res {
-                        Matched(__pos, l) => {
-                            let __seq_res = __parse_asm_operand(__input, __state, __pos, env);
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse_identifier(__input, __state, __pos, env);
                             match __seq_res {
-                                Matched(__pos, e) => {
-                                    let __seq_res = Matched(__pos, __pos);
-                                    match __seq_res {
-                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                        Failed => Failed,
-                                    }
-                                }
+                                Matched(__pos, i) => Matched(__pos, { OffsetMember::Member(i) }),
                                 Failed => Failed,
                             }
                         }
                         Failed => Failed,
                     }
-                };
-                match __step_res {
-                    Matched(__newpos, __value) => {
-                        __repeat_pos = __newpos;
-                        __repeat_value.push(__value);
-                    }
-                    Failed => {
-                        break;
-                    }
                 }
+                Failed => Failed,
             }
-            Matched(__repeat_pos, __repeat_value)
         };
-        match __seq_res {
-            Matched(__pos, e) => Matched(__pos, { e }),
-            Failed => Failed,
-        }
-    }
-}
-
-fn __parse_asm_operand<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<GnuAsmOperand> {
-    #![allow(non_snake_case, unused)]
-    {
-        let __seq_res = match {
-            let __seq_res = slice_eq(__input, __state, __pos, "[");
-            match __seq_res {
-                Matched(__pos, _) => {
-                    let __seq_res = __parse__(__input, __state, __pos, env);
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __seq_res = slice_eq(__input, __state, __pos, "->");
                     match __seq_res {
                         Matched(__pos, _) => {
-                            let __seq_res = __parse_identifier(__input, __state, __pos, env);
+                            let __seq_res = __parse__(__input, __state, __pos, env);
                             match __seq_res {
-                                Matched(__pos, i) => {
-                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                Matched(__pos, _) => {
+                                    let __seq_res = __parse_identifier(__input, __state, __pos, env);
                                     match __seq_res {
-                                        Matched(__pos, _) => {
-                                            let __seq_res = slice_eq(__input, __state, __pos, "]");
-                                            match __seq_res {
-                                                Matched(__pos, _) => {
-                                                    let __seq_res = __parse__(__input, __state, __pos, env);
-                                                    match __seq_res {
-                                                        Matched(__pos, _) => Matched(__pos, { i }),
-                                                        Failed => Failed,
-                                                    }
-                                                }
-                                                Failed => Failed,
-                                            }
-                                        }
+                                        Matched(__pos, i) => Matched(__pos, { OffsetMember::IndirectMember(i) }),
                                         Failed => Failed,
                                     }
                                 }
@@ -16218,128 +17845,43 @@ fn __parse_asm_operand<'input>(__input: &'input str, __state: &mut ParseState<'i
                         }
                         Failed => Failed,
                     }
-                }
-                Failed => Failed,
-            }
-        } {
-            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-            Failed => Matched(__pos, None),
-        };
-        match __seq_res {
-            Matched(__pos, i) => {
-                let __seq_res = __parse_string_literal(__input, __state, __pos, env);
-                match __seq_res {
-                    Matched(__pos, s) => {
-                        let __seq_res = __parse__(__input, __state, __pos, env);
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __seq_res = __parse_lbrack(__input, __state, __pos, env);
                         match __seq_res {
                             Matched(__pos, _) => {
-                                let __seq_res = slice_eq(__input, __state, __pos, "(");
+                                let __seq_res = __parse__(__input, __state, __pos, env);
                                 match __seq_res {
                                     Matched(__pos, _) => {
-                                        let __seq_res = __parse__(__input, __state, __pos, env);
-                                        match __seq_res {
-                                            Matched(__pos, _) => {
-                                                let __seq_res = {
-                                                    let __seq_res = Matched(__pos, __pos);
+                                        let __seq_res = {
+                                            let __seq_res = Matched(__pos, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, l) => {
+                                                    let __seq_res = __parse_expression0(__input, __state, __pos, env);
                                                     match __seq_res {
-                                                        Matched(__pos, l) => {
-                                                            let __seq_res = __parse_expression0(__input, __state, __pos, env);
+                                                        Matched(__pos, e) => {
+                                                            let __seq_res = Matched(__pos, __pos);
                                                             match __seq_res {
-                                                                Matched(__pos, e) => {
-                                                                    let __seq_res = Matched(__pos, __pos);
-                                                                    match __seq_res {
-                                                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                                                        Failed => Failed,
-                                                                    }
-                                                                }
+                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                                                 Failed => Failed,
                                                             }
                                                         }
                                                         Failed => Failed,
                                                     }
-                                                };
-                                                match __seq_res {
-                                                    Matched(__pos, e) => {
-                                                        let __seq_res = __parse__(__input, __state, __pos, env);
-                                                        match __seq_res {
-                                                            Matched(__pos, _) => {
-                                                                let __seq_res = slice_eq(__input, __state, __pos, ")");
-                                                                match __seq_res {
-                                                                    Matched(__pos, _) => Matched(__pos, { GnuAsmOperand { symbolic_name: i, constraints: s, variable_name: e } }),
-                                                                    Failed => Failed,
-                                                                }
-                                                            }
-                                                            Failed => Failed,
-                                                        }
-                                                    }
-                                                    Failed => Failed,
                                                 }
+                                                Failed => Failed,
                                             }
-                                            Failed => Failed,
-                                        }
-                                    }
-                                    Failed => Failed,
-                                }
-                            }
-                            Failed => Failed,
-                        }
-                    }
-                    Failed => Failed,
-                }
-            }
-            Failed => Failed,
-        }
-    }
-}
-
-fn __parse_gnu_primary_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
-    #![allow(non_snake_case, unused)]
-    {
-        let __choice_res = __parse_statement_expression(__input, __state, __pos, env);
-        match __choice_res {
-            Matched(__pos, __value) => Matched(__pos, __value),
-            Failed => {
-                let __choice_res = __parse_offsetof_expression(__input, __state, __pos, env);
-                match __choice_res {
-                    Matched(__pos, __value) => Matched(__pos, __value),
-                    Failed => {
-                        let __choice_res = __parse_va_arg_expression(__input, __state, __pos, env);
-                        match __choice_res {
-                            Matched(__pos, __value) => Matched(__pos, __value),
-                            Failed => __parse_keyword_expression(__input, __state, __pos, env),
-                        }
-                    }
-                }
-            }
-        }
-    }
-}
-
-fn __parse_statement_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
-    #![allow(non_snake_case, unused)]
-    {
-        let __seq_res = slice_eq(__input, __state, __pos, "(");
-        match __seq_res {
-            Matched(__pos, _) => {
-                let __seq_res = __parse__(__input, __state, __pos, env);
-                match __seq_res {
-                    Matched(__pos, _) => {
-                        let __seq_res = {
-                            let __seq_res = Matched(__pos, {
-                                env.enter_scope();
-                            });
-                            match __seq_res {
-                                Matched(__pos, _) => {
-                                    let __seq_res = match {
-                                        let __seq_res = Matched(__pos, __pos);
+                                        };
                                         match __seq_res {
-                                            Matched(__pos, l) => {
-                                                let __seq_res = __parse_compound_statement(__input, __state, __pos, env);
+                                            Matched(__pos, e) => {
+                                                let __seq_res = __parse__(__input, __state, __pos, env);
                                                 match __seq_res {
-                                                    Matched(__pos, e) => {
-                                                        let __seq_res = Matched(__pos, __pos);
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res = __parse_rbrack(__input, __state, __pos, env);
                                                         match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                                            Matched(__pos, _) => Matched(__pos, { OffsetMember::Index(e) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -16348,39 +17890,6 @@ fn __parse_statement_expression<'input>(__input: &'input str, __state: &mut Pars
                                             }
                                             Failed => Failed,
                                         }
-                                    } {
-                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                                        Failed => Matched(__pos, None),
-                                    };
-                                    match __seq_res {
-                                        Matched(__pos, e) => {
-                                            match {
-                                                env.leave_scope();
-                                                e.ok_or("")
-                                            } {
-                                                Ok(res) => Matched(__pos, res),
-                                                Err(expected) => {
-                                                    __state.mark_failure(__pos, expected);
-                                                    Failed
-                                                }
-                                            }
-                                        }
-                                        Failed => Failed,
-                                    }
-                                }
-                                Failed => Failed,
-                            }
-                        };
-                        match __seq_res {
-                            Matched(__pos, s) => {
-                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                match __seq_res {
-                                    Matched(__pos, _) => {
-                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
-                                        match __seq_res {
-                                            Matched(__pos, _) => Matched(__pos, { Expression::Statement(Box::new(s)) }),
-                                            Failed => Failed,
-                                        }
                                     }
                                     Failed => Failed,
                                 }
@@ -16388,63 +17897,39 @@ fn __parse_statement_expression<'input>(__input: &'input str, __state: &mut Pars
                             Failed => Failed,
                         }
                     }
-                    Failed => Failed,
                 }
             }
-            Failed => Failed,
         }
     }
 }
 
-fn __parse_va_arg_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
+fn __parse_typeof_specifier<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TypeSpecifier> {
     #![allow(non_snake_case, unused)]
     {
         let __seq_res = {
-            let __seq_res = Matched(__pos, __pos);
-            match __seq_res {
-                Matched(__pos, l) => {
-                    let __seq_res = __parse_va_arg_expression_inner(__input, __state, __pos, env);
-                    match __seq_res {
-                        Matched(__pos, e) => {
-                            let __seq_res = Matched(__pos, __pos);
+            __state.suppress_fail += 1;
+            let res = {
+                let __seq_res = {
+                    let __choice_res = slice_eq(__input, __state, __pos, "typeof");
+                    match __choice_res {
+                        Matched(__pos, __value) => Matched(__pos, __value),
+                        Failed => {
+                            let __seq_res = slice_eq(__input, __state, __pos, "__typeof");
                             match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                Matched(__pos, _) => match slice_eq(__input, __state, __pos, "__") {
+                                    Matched(__newpos, _) => Matched(__newpos, ()),
+                                    Failed => Matched(__pos, ()),
+                                },
                                 Failed => Failed,
                             }
                         }
-                        Failed => Failed,
                     }
-                }
-                Failed => Failed,
-            }
-        };
-        match __seq_res {
-            Matched(__pos, n) => Matched(__pos, { Expression::VaArg(Box::new(n)) }),
-            Failed => Failed,
-        }
-    }
-}
-
-fn __parse_va_arg_expression_inner<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<VaArgExpression> {
-    #![allow(non_snake_case, unused)]
-    {
-        let __seq_res = {
-            __state.suppress_fail += 1;
-            let res = {
-                let __seq_res = slice_eq(__input, __state, __pos, "__builtin_va_arg");
+                };
                 match __seq_res {
                     Matched(__pos, e) => {
                         let __seq_res = {
                             __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
+                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
                             __state.suppress_fail -= 1;
                             match __assert_res {
                                 Failed => Matched(__pos, ()),
@@ -16473,39 +17958,33 @@ fn __parse_va_arg_expression_inner<'input>(__input: &'input str, __state: &mut P
                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                 match __seq_res {
                                     Matched(__pos, _) => {
-                                        let __seq_res = __parse_assignment_expression(__input, __state, __pos, env);
+                                        let __seq_res = {
+                                            let __seq_res = Matched(__pos, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, l) => {
+                                                    let __seq_res = __parse_typeof_specifier0(__input, __state, __pos, env);
+                                                    match __seq_res {
+                                                        Matched(__pos, e) => {
+                                                            let __seq_res = Matched(__pos, __pos);
+                                                            match __seq_res {
+                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        };
                                         match __seq_res {
                                             Matched(__pos, e) => {
                                                 let __seq_res = __parse__(__input, __state, __pos, env);
                                                 match __seq_res {
                                                     Matched(__pos, _) => {
-                                                        let __seq_res = slice_eq(__input, __state, __pos, ",");
+                                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
                                                         match __seq_res {
-                                                            Matched(__pos, _) => {
-                                                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                match __seq_res {
-                                                                    Matched(__pos, _) => {
-                                                                        let __seq_res = __parse_type_name(__input, __state, __pos, env);
-                                                                        match __seq_res {
-                                                                            Matched(__pos, t) => {
-                                                                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                                match __seq_res {
-                                                                                    Matched(__pos, _) => {
-                                                                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
-                                                                                        match __seq_res {
-                                                                                            Matched(__pos, _) => Matched(__pos, { VaArgExpression { va_list: e, type_name: t } }),
-                                                                                            Failed => Failed,
-                                                                                        }
-                                                                                    }
-                                                                                    Failed => Failed,
-                                                                                }
-                                                                            }
-                                                                            Failed => Failed,
-                                                                        }
-                                                                    }
-                                                                    Failed => Failed,
-                                                                }
-                                                            }
+                                                            Matched(__pos, _) => Matched(__pos, { TypeSpecifier::TypeOf(e) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -16529,300 +18008,134 @@ fn __parse_va_arg_expression_inner<'input>(__input: &'input str, __state: &mut P
     }
 }
 
-fn __parse_keyword_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
+fn __parse_typeof_specifier0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TypeOf> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = {
-            let __seq_res = Matched(__pos, __pos);
-            match __seq_res {
-                Matched(__pos, l) => {
-                    let __seq_res = {
-                        let str_start = __pos;
-                        match __parse_keyword_expression0(__input, __state, __pos, env) {
-                            Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
-                            Failed => Failed,
-                        }
-                    };
-                    match __seq_res {
-                        Matched(__pos, e) => {
-                            let __seq_res = Matched(__pos, __pos);
-                            match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                Failed => Failed,
+        let __choice_res = {
+            let __seq_res = {
+                let __seq_res = Matched(__pos, __pos);
+                match __seq_res {
+                    Matched(__pos, l) => {
+                        let __seq_res = __parse_expression0(__input, __state, __pos, env);
+                        match __seq_res {
+                            Matched(__pos, e) => {
+                                let __seq_res = Matched(__pos, __pos);
+                                match __seq_res {
+                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                    Failed => Failed,
+                                }
                             }
+                            Failed => Failed,
                         }
-                        Failed => Failed,
                     }
+                    Failed => Failed,
                 }
+            };
+            match __seq_res {
+                Matched(__pos, e) => Matched(__pos, { TypeOf::Expression(e) }),
                 Failed => Failed,
             }
         };
-        match __seq_res {
-            Matched(__pos, k) => Matched(__pos, {
-                let ident = Identifier { name: k.node.to_string() };
-                Expression::Identifier(Box::new(Node::new(ident, k.span)))
-            }),
-            Failed => Failed,
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __seq_res = __parse_type_name(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, t) => Matched(__pos, { TypeOf::Type(t) }),
+                    Failed => Failed,
+                }
+            }
         }
     }
 }
 
-fn __parse_keyword_expression0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+fn __parse_ts18661_float_type_specifier<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TS18661FloatType> {
     #![allow(non_snake_case, unused)]
     {
-        let __choice_res = {
-            __state.suppress_fail += 1;
-            let res = {
-                let __seq_res = slice_eq(__input, __state, __pos, "__func__");
-                match __seq_res {
-                    Matched(__pos, e) => {
-                        let __seq_res = {
-                            __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
-                            __state.suppress_fail -= 1;
-                            match __assert_res {
-                                Failed => Matched(__pos, ()),
-                                Matched(..) => Failed,
-                            }
-                        };
-                        match __seq_res {
-                            Matched(__pos, _) => Matched(__pos, { e }),
-                            Failed => Failed,
-                        }
-                    }
-                    Failed => Failed,
-                }
-            };
-            __state.suppress_fail -= 1;
-            res
-        };
+        let __choice_res = __parse_ts18661_binary_float(__input, __state, __pos, env);
         match __choice_res {
             Matched(__pos, __value) => Matched(__pos, __value),
-            Failed => {
-                let __choice_res = {
-                    __state.suppress_fail += 1;
-                    let res = {
-                        let __seq_res = slice_eq(__input, __state, __pos, "__FUNCTION__");
-                        match __seq_res {
-                            Matched(__pos, e) => {
-                                let __seq_res = {
-                                    __state.suppress_fail += 1;
-                                    let __assert_res = if __input.len() > __pos {
-                                        let (__ch, __next) = char_range_at(__input, __pos);
-                                        match __ch {
-                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                        }
-                                    } else {
-                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                    };
-                                    __state.suppress_fail -= 1;
-                                    match __assert_res {
-                                        Failed => Matched(__pos, ()),
-                                        Matched(..) => Failed,
-                                    }
-                                };
-                                match __seq_res {
-                                    Matched(__pos, _) => Matched(__pos, { e }),
-                                    Failed => Failed,
-                                }
-                            }
+            Failed => __parse_ts18661_decimal_float(__input, __state, __pos, env),
+        }
+    }
+}
+
+fn __parse_ts18661_binary_float<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TS18661FloatType> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __seq_res = slice_eq(__input, __state, __pos, "_Float");
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = __parse_ts18661_binary_width(__input, __state, __pos, env);
+                match __seq_res {
+                    Matched(__pos, width) => {
+                        let __seq_res = match slice_eq(__input, __state, __pos, "x") {
+                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                            Failed => Matched(__pos, None),
+                        };
+                        match __seq_res {
+                            Matched(__pos, extended) => Matched(__pos, { ts18661_float(true, width, extended.is_some()) }),
                             Failed => Failed,
                         }
-                    };
-                    __state.suppress_fail -= 1;
-                    res
-                };
-                match __choice_res {
-                    Matched(__pos, __value) => Matched(__pos, __value),
-                    Failed => {
-                        __state.suppress_fail += 1;
-                        let res = {
-                            let __seq_res = slice_eq(__input, __state, __pos, "__PRETTY_FUNCTION__");
-                            match __seq_res {
-                                Matched(__pos, e) => {
-                                    let __seq_res = {
-                                        __state.suppress_fail += 1;
-                                        let __assert_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                                        };
-                                        __state.suppress_fail -= 1;
-                                        match __assert_res {
-                                            Failed => Matched(__pos, ()),
-                                            Matched(..) => Failed,
-                                        }
-                                    };
-                                    match __seq_res {
-                                        Matched(__pos, _) => Matched(__pos, { e }),
-                                        Failed => Failed,
-                                    }
-                                }
-                                Failed => Failed,
-                            }
-                        };
-                        __state.suppress_fail -= 1;
-                        res
                     }
+                    Failed => Failed,
                 }
             }
+            Failed => Failed,
         }
     }
 }
 
-fn __parse_offsetof_expression<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Expression> {
+fn __parse_ts18661_binary_width<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<usize> {
     #![allow(non_snake_case, unused)]
     {
         let __seq_res = {
-            let __seq_res = Matched(__pos, __pos);
-            match __seq_res {
-                Matched(__pos, l) => {
-                    let __seq_res = __parse_offsetof_expression_inner(__input, __state, __pos, env);
-                    match __seq_res {
-                        Matched(__pos, e) => {
-                            let __seq_res = Matched(__pos, __pos);
-                            match __seq_res {
-                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                Failed => Failed,
+            let str_start = __pos;
+            match {
+                let __choice_res = slice_eq(__input, __state, __pos, "16");
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = slice_eq(__input, __state, __pos, "32");
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = slice_eq(__input, __state, __pos, "64");
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => slice_eq(__input, __state, __pos, "128"),
+                                }
                             }
                         }
-                        Failed => Failed,
                     }
                 }
+            } {
+                Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
                 Failed => Failed,
             }
         };
         match __seq_res {
-            Matched(__pos, n) => Matched(__pos, { Expression::OffsetOf(Box::new(n)) }),
+            Matched(__pos, n) => Matched(__pos, { n.parse().unwrap() }),
             Failed => Failed,
         }
     }
 }
 
-fn __parse_offsetof_expression_inner<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<OffsetOfExpression> {
+fn __parse_ts18661_decimal_float<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TS18661FloatType> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = {
-            __state.suppress_fail += 1;
-            let res = {
-                let __seq_res = slice_eq(__input, __state, __pos, "__builtin_offsetof");
-                match __seq_res {
-                    Matched(__pos, e) => {
-                        let __seq_res = {
-                            __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
-                                }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
-                            };
-                            __state.suppress_fail -= 1;
-                            match __assert_res {
-                                Failed => Matched(__pos, ()),
-                                Matched(..) => Failed,
-                            }
-                        };
-                        match __seq_res {
-                            Matched(__pos, _) => Matched(__pos, { e }),
-                            Failed => Failed,
-                        }
-                    }
-                    Failed => Failed,
-                }
-            };
-            __state.suppress_fail -= 1;
-            res
-        };
+        let __seq_res = slice_eq(__input, __state, __pos, "_Decimal");
         match __seq_res {
             Matched(__pos, _) => {
-                let __seq_res = __parse__(__input, __state, __pos, env);
+                let __seq_res = __parse_ts18661_decimal_width(__input, __state, __pos, env);
                 match __seq_res {
-                    Matched(__pos, _) => {
-                        let __seq_res = slice_eq(__input, __state, __pos, "(");
+                    Matched(__pos, width) => {
+                        let __seq_res = match slice_eq(__input, __state, __pos, "x") {
+                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                            Failed => Matched(__pos, None),
+                        };
                         match __seq_res {
-                            Matched(__pos, _) => {
-                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                match __seq_res {
-                                    Matched(__pos, _) => {
-                                        let __seq_res = __parse_type_name(__input, __state, __pos, env);
-                                        match __seq_res {
-                                            Matched(__pos, t) => {
-                                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                                match __seq_res {
-                                                    Matched(__pos, _) => {
-                                                        let __seq_res = slice_eq(__input, __state, __pos, ",");
-                                                        match __seq_res {
-                                                            Matched(__pos, _) => {
-                                                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                match __seq_res {
-                                                                    Matched(__pos, _) => {
-                                                                        let __seq_res = {
-                                                                            let __seq_res = Matched(__pos, __pos);
-                                                                            match __seq_res {
-                                                                                Matched(__pos, l) => {
-                                                                                    let __seq_res = __parse_offsetof_designator(__input, __state, __pos, env);
-                                                                                    match __seq_res {
-                                                                                        Matched(__pos, e) => {
-                                                                                            let __seq_res = Matched(__pos, __pos);
-                                                                                            match __seq_res {
-                                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                                                                                Failed => Failed,
-                                                                                            }
-                                                                                        }
-                                                                                        Failed => Failed,
-                                                                                    }
-                                                                                }
-                                                                                Failed => Failed,
-                                                                            }
-                                                                        };
-                                                                        match __seq_res {
-                                                                            Matched(__pos, d) => {
-                                                                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                                                                match __seq_res {
-                                                                                    Matched(__pos, _) => {
-                                                                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
-                                                                                        match __seq_res {
-                                                                                            Matched(__pos, _) => Matched(__pos, { OffsetOfExpression { type_name: t, designator: d } }),
-                                                                                            Failed => Failed,
-                                                                                        }
-                                                                                    }
-                                                                                    Failed => Failed,
-                                                                                }
-                                                                            }
-                                                                            Failed => Failed,
-                                                                        }
-                                                                    }
-                                                                    Failed => Failed,
-                                                                }
-                                                            }
-                                                            Failed => Failed,
-                                                        }
-                                                    }
-                                                    Failed => Failed,
-                                                }
-                                            }
-                                            Failed => Failed,
-                                        }
-                                    }
-                                    Failed => Failed,
-                                }
-                            }
+                            Matched(__pos, extended) => Matched(__pos, { ts18661_float(false, width, extended.is_some()) }),
                             Failed => Failed,
                         }
                     }
@@ -16834,98 +18147,48 @@ fn __parse_offsetof_expression_inner<'input>(__input: &'input str, __state: &mut
     }
 }
 
-fn __parse_offsetof_designator<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<OffsetDesignator> {
+fn __parse_ts18661_decimal_width<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<usize> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = __parse_identifier(__input, __state, __pos, env);
-        match __seq_res {
-            Matched(__pos, i) => {
-                let __seq_res = __parse__(__input, __state, __pos, env);
-                match __seq_res {
-                    Matched(__pos, _) => {
-                        let __seq_res = {
-                            let __seq_res = {
-                                let mut __repeat_pos = __pos;
-                                let mut __repeat_value = vec![];
-                                loop {
-                                    let __pos = __repeat_pos;
-                                    let __pos = if __repeat_value.len() > 0 {
-                                        let __sep_res = __parse__(__input, __state, __pos, env);
-                                        match __sep_res {
-                                            Matched(__newpos, _) => __newpos,
-                                            Failed => break,
-                                        }
-                                    } else {
-                                        __pos
-                                    };
-                                    let __step_res = {
-                                        let __seq_res = Matched(__pos, __pos);
-                                        match __seq_res {
-                                            Matched(__pos, l) => {
-                                                let __seq_res = __parse_offsetof_member(__input, __state, __pos, env);
-                                                match __seq_res {
-                                                    Matched(__pos, e) => {
-                                                        let __seq_res = Matched(__pos, __pos);
-                                                        match __seq_res {
-                                                            Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                                            Failed => Failed,
-                                                        }
-                                                    }
-                                                    Failed => Failed,
-                                                }
-                                            }
-                                            Failed => Failed,
-                                        }
-                                    };
-                                    match __step_res {
-                                        Matched(__newpos, __value) => {
-                                            __repeat_pos = __newpos;
-                                            __repeat_value.push(__value);
-                                        }
-                                        Failed => {
-                                            break;
-                                        }
-                                    }
-                                }
-                                Matched(__repeat_pos, __repeat_value)
-                            };
-                            match __seq_res {
-                                Matched(__pos, e) => Matched(__pos, { e }),
-                                Failed => Failed,
-                            }
-                        };
-                        match __seq_res {
-                            Matched(__pos, d) => Matched(__pos, { OffsetDesignator { base: i, members: d } }),
-                            Failed => Failed,
+        let __seq_res = {
+            let str_start = __pos;
+            match {
+                let __choice_res = slice_eq(__input, __state, __pos, "32");
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = slice_eq(__input, __state, __pos, "64");
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => slice_eq(__input, __state, __pos, "128"),
                         }
                     }
-                    Failed => Failed,
                 }
+            } {
+                Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
+                Failed => Failed,
             }
+        };
+        match __seq_res {
+            Matched(__pos, n) => Matched(__pos, { n.parse().unwrap() }),
             Failed => Failed,
         }
     }
 }
 
-fn __parse_offsetof_member<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<OffsetMember> {
+fn __parse_ts18661_float_suffix<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TS18661FloatType> {
     #![allow(non_snake_case, unused)]
     {
         let __choice_res = {
-            let __seq_res = slice_eq(__input, __state, __pos, ".");
-            match __seq_res {
-                Matched(__pos, _) => {
-                    let __seq_res = __parse__(__input, __state, __pos, env);
-                    match __seq_res {
-                        Matched(__pos, _) => {
-                            let __seq_res = __parse_identifier(__input, __state, __pos, env);
-                            match __seq_res {
-                                Matched(__pos, i) => Matched(__pos, { OffsetMember::Member(i) }),
-                                Failed => Failed,
-                            }
-                        }
-                        Failed => Failed,
-                    }
+            let __seq_res = {
+                let __choice_res = slice_eq(__input, __state, __pos, "df");
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => slice_eq(__input, __state, __pos, "DF"),
                 }
+            };
+            match __seq_res {
+                Matched(__pos, _) => Matched(__pos, { ts18661_float(false, 32, false) }),
                 Failed => Failed,
             }
         };
@@ -16933,60 +18196,90 @@ fn __parse_offsetof_member<'input>(__input: &'input str, __state: &mut ParseStat
             Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
                 let __choice_res = {
-                    let __seq_res = slice_eq(__input, __state, __pos, "->");
-                    match __seq_res {
-                        Matched(__pos, _) => {
-                            let __seq_res = __parse__(__input, __state, __pos, env);
-                            match __seq_res {
-                                Matched(__pos, _) => {
-                                    let __seq_res = __parse_identifier(__input, __state, __pos, env);
-                                    match __seq_res {
-                                        Matched(__pos, i) => Matched(__pos, { OffsetMember::IndirectMember(i) }),
-                                        Failed => Failed,
-                                    }
-                                }
-                                Failed => Failed,
-                            }
+                    let __seq_res = {
+                        let __choice_res = slice_eq(__input, __state, __pos, "dd");
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => slice_eq(__input, __state, __pos, "DD"),
                         }
+                    };
+                    match __seq_res {
+                        Matched(__pos, _) => Matched(__pos, { ts18661_float(false, 64, false) }),
                         Failed => Failed,
                     }
                 };
                 match __choice_res {
                     Matched(__pos, __value) => Matched(__pos, __value),
                     Failed => {
-                        let __seq_res = slice_eq(__input, __state, __pos, "[");
-                        match __seq_res {
-                            Matched(__pos, _) => {
-                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                match __seq_res {
-                                    Matched(__pos, _) => {
-                                        let __seq_res = {
-                                            let __seq_res = Matched(__pos, __pos);
+                        let __choice_res = {
+                            let __seq_res = {
+                                let __choice_res = slice_eq(__input, __state, __pos, "dl");
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => slice_eq(__input, __state, __pos, "DL"),
+                                }
+                            };
+                            match __seq_res {
+                                Matched(__pos, _) => Matched(__pos, { ts18661_float(false, 128, false) }),
+                                Failed => Failed,
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __seq_res = if __input.len() > __pos {
+                                        let (__ch, __next) = char_range_at(__input, __pos);
+                                        match __ch {
+                                            'f' | 'F' => Matched(__next, ()),
+                                            _ => __state.mark_failure(__pos, "[fF]"),
+                                        }
+                                    } else {
+                                        __state.mark_failure(__pos, "[fF]")
+                                    };
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = __parse_ts18661_binary_width(__input, __state, __pos, env);
                                             match __seq_res {
-                                                Matched(__pos, l) => {
-                                                    let __seq_res = __parse_expression0(__input, __state, __pos, env);
+                                                Matched(__pos, width) => {
+                                                    let __seq_res = match slice_eq(__input, __state, __pos, "x") {
+                                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                        Failed => Matched(__pos, None),
+                                                    };
                                                     match __seq_res {
-                                                        Matched(__pos, e) => {
-                                                            let __seq_res = Matched(__pos, __pos);
-                                                            match __seq_res {
-                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                                                Failed => Failed,
-                                                            }
-                                                        }
+                                                        Matched(__pos, extended) => Matched(__pos, { ts18661_float(true, width, extended.is_some()) }),
                                                         Failed => Failed,
                                                     }
                                                 }
                                                 Failed => Failed,
                                             }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __seq_res = if __input.len() > __pos {
+                                            let (__ch, __next) = char_range_at(__input, __pos);
+                                            match __ch {
+                                                'd' | 'D' => Matched(__next, ()),
+                                                _ => __state.mark_failure(__pos, "[dD]"),
+                                            }
+                                        } else {
+                                            __state.mark_failure(__pos, "[dD]")
                                         };
                                         match __seq_res {
-                                            Matched(__pos, e) => {
-                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                            Matched(__pos, _) => {
+                                                let __seq_res = __parse_ts18661_decimal_width(__input, __state, __pos, env);
                                                 match __seq_res {
-                                                    Matched(__pos, _) => {
-                                                        let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                                    Matched(__pos, width) => {
+                                                        let __seq_res = match slice_eq(__input, __state, __pos, "x") {
+                                                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                                            Failed => Matched(__pos, None),
+                                                        };
                                                         match __seq_res {
-                                                            Matched(__pos, _) => Matched(__pos, { OffsetMember::Index(e) }),
+                                                            Matched(__pos, extended) => Matched(__pos, { ts18661_float(false, width, extended.is_some()) }),
                                                             Failed => Failed,
                                                         }
                                                     }
@@ -16996,10 +18289,8 @@ fn __parse_offsetof_member<'input>(__input: &'input str, __state: &mut ParseStat
                                             Failed => Failed,
                                         }
                                     }
-                                    Failed => Failed,
                                 }
                             }
-                            Failed => Failed,
                         }
                     }
                 }
@@ -17008,120 +18299,179 @@ fn __parse_offsetof_member<'input>(__input: &'input str, __state: &mut ParseStat
     }
 }
 
-fn __parse_typeof_specifier<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TypeSpecifier> {
+fn __parse_clang_guard<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    match {
+        if env.extensions_clang {
+            Ok(())
+        } else {
+            Err("clang extensions disabled")
+        }
+    } {
+        Ok(res) => Matched(__pos, res),
+        Err(expected) => {
+            __state.mark_failure(__pos, expected);
+            Failed
+        }
+    }
+}
+
+fn __parse_embedded_guard<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    match {
+        if env.extensions_embedded {
+            Ok(())
+        } else {
+            Err("embedded extensions disabled")
+        }
+    } {
+        Ok(res) => Matched(__pos, res),
+        Err(expected) => {
+            __state.mark_failure(__pos, expected);
+            Failed
+        }
+    }
+}
+
+fn __parse_tr18037_type_specifier<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TypeSpecifier> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = {
-            __state.suppress_fail += 1;
-            let res = {
-                let __seq_res = {
-                    let __choice_res = slice_eq(__input, __state, __pos, "typeof");
-                    match __choice_res {
-                        Matched(__pos, __value) => Matched(__pos, __value),
-                        Failed => {
-                            let __seq_res = slice_eq(__input, __state, __pos, "__typeof");
-                            match __seq_res {
-                                Matched(__pos, _) => match slice_eq(__input, __state, __pos, "__") {
-                                    Matched(__newpos, _) => Matched(__newpos, ()),
+        let __choice_res = {
+            let __seq_res = {
+                __state.suppress_fail += 1;
+                let res = {
+                    let __seq_res = slice_eq(__input, __state, __pos, "_Fract");
+                    match __seq_res {
+                        Matched(__pos, e) => {
+                            let __seq_res = {
+                                __state.suppress_fail += 1;
+                                let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                                __state.suppress_fail -= 1;
+                                match __assert_res {
                                     Failed => Matched(__pos, ()),
-                                },
-                                Failed => Failed,
-                            }
-                        }
-                    }
-                };
-                match __seq_res {
-                    Matched(__pos, e) => {
-                        let __seq_res = {
-                            __state.suppress_fail += 1;
-                            let __assert_res = if __input.len() > __pos {
-                                let (__ch, __next) = char_range_at(__input, __pos);
-                                match __ch {
-                                    '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
-                                    _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
+                                    Matched(..) => Failed,
                                 }
-                            } else {
-                                __state.mark_failure(__pos, "[_a-zA-Z0-9]")
                             };
-                            __state.suppress_fail -= 1;
-                            match __assert_res {
-                                Failed => Matched(__pos, ()),
-                                Matched(..) => Failed,
+                            match __seq_res {
+                                Matched(__pos, _) => Matched(__pos, { e }),
+                                Failed => Failed,
                             }
-                        };
-                        match __seq_res {
-                            Matched(__pos, _) => Matched(__pos, { e }),
-                            Failed => Failed,
                         }
+                        Failed => Failed,
                     }
-                    Failed => Failed,
-                }
+                };
+                __state.suppress_fail -= 1;
+                res
             };
-            __state.suppress_fail -= 1;
-            res
+            match __seq_res {
+                Matched(__pos, _) => Matched(__pos, { TypeSpecifier::Fract }),
+                Failed => Failed,
+            }
         };
-        match __seq_res {
-            Matched(__pos, _) => {
-                let __seq_res = __parse__(__input, __state, __pos, env);
-                match __seq_res {
-                    Matched(__pos, _) => {
-                        let __seq_res = slice_eq(__input, __state, __pos, "(");
-                        match __seq_res {
-                            Matched(__pos, _) => {
-                                let __seq_res = __parse__(__input, __state, __pos, env);
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __seq_res = {
+                        __state.suppress_fail += 1;
+                        let res = {
+                            let __seq_res = slice_eq(__input, __state, __pos, "_Accum");
+                            match __seq_res {
+                                Matched(__pos, e) => {
+                                    let __seq_res = {
+                                        __state.suppress_fail += 1;
+                                        let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                                        __state.suppress_fail -= 1;
+                                        match __assert_res {
+                                            Failed => Matched(__pos, ()),
+                                            Matched(..) => Failed,
+                                        }
+                                    };
+                                    match __seq_res {
+                                        Matched(__pos, _) => Matched(__pos, { e }),
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        };
+                        __state.suppress_fail -= 1;
+                        res
+                    };
+                    match __seq_res {
+                        Matched(__pos, _) => Matched(__pos, { TypeSpecifier::Accum }),
+                        Failed => Failed,
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __seq_res = {
+                            __state.suppress_fail += 1;
+                            let res = {
+                                let __seq_res = slice_eq(__input, __state, __pos, "_Sat");
                                 match __seq_res {
-                                    Matched(__pos, _) => {
+                                    Matched(__pos, e) => {
                                         let __seq_res = {
-                                            let __seq_res = Matched(__pos, __pos);
-                                            match __seq_res {
-                                                Matched(__pos, l) => {
-                                                    let __seq_res = __parse_typeof_specifier0(__input, __state, __pos, env);
-                                                    match __seq_res {
-                                                        Matched(__pos, e) => {
-                                                            let __seq_res = Matched(__pos, __pos);
-                                                            match __seq_res {
-                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
-                                                                Failed => Failed,
-                                                            }
-                                                        }
-                                                        Failed => Failed,
-                                                    }
-                                                }
-                                                Failed => Failed,
+                                            __state.suppress_fail += 1;
+                                            let __assert_res = __parse_identifier_char(__input, __state, __pos, env);
+                                            __state.suppress_fail -= 1;
+                                            match __assert_res {
+                                                Failed => Matched(__pos, ()),
+                                                Matched(..) => Failed,
                                             }
                                         };
                                         match __seq_res {
-                                            Matched(__pos, e) => {
-                                                let __seq_res = __parse__(__input, __state, __pos, env);
-                                                match __seq_res {
-                                                    Matched(__pos, _) => {
-                                                        let __seq_res = slice_eq(__input, __state, __pos, ")");
-                                                        match __seq_res {
-                                                            Matched(__pos, _) => Matched(__pos, { TypeSpecifier::TypeOf(e) }),
-                                                            Failed => Failed,
-                                                        }
-                                                    }
-                                                    Failed => Failed,
-                                                }
-                                            }
+                                            Matched(__pos, _) => Matched(__pos, { e }),
                                             Failed => Failed,
                                         }
                                     }
                                     Failed => Failed,
                                 }
-                            }
+                            };
+                            __state.suppress_fail -= 1;
+                            res
+                        };
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, { TypeSpecifier::Sat }),
                             Failed => Failed,
                         }
                     }
-                    Failed => Failed,
                 }
             }
-            Failed => Failed,
         }
     }
 }
 
-fn __parse_typeof_specifier0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TypeOf> {
+fn __parse_vendor_qualifier<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Node<Extension>> {
+    #![allow(non_snake_case, unused)]
+    {
+        __state.suppress_fail += 1;
+        let res = {
+            let __seq_res = Matched(__pos, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = __parse_vendor_qualifier0(__input, __state, __pos, env);
+                    match __seq_res {
+                        Matched(__pos, e) => {
+                            let __seq_res = Matched(__pos, __pos);
+                            match __seq_res {
+                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        };
+        __state.suppress_fail -= 1;
+        res
+    }
+}
+
+fn __parse_vendor_qualifier0<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<Extension> {
     #![allow(non_snake_case, unused)]
     {
         let __choice_res = {
@@ -17129,12 +18479,12 @@ fn __parse_typeof_specifier0<'input>(__input: &'input str, __state: &mut ParseSt
                 let __seq_res = Matched(__pos, __pos);
                 match __seq_res {
                     Matched(__pos, l) => {
-                        let __seq_res = __parse_expression0(__input, __state, __pos, env);
+                        let __seq_res = __parse_vendor_keyword_with_arguments(__input, __state, __pos, env);
                         match __seq_res {
                             Matched(__pos, e) => {
                                 let __seq_res = Matched(__pos, __pos);
                                 match __seq_res {
-                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, Span::span(l, r)) }),
+                                    Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
                                     Failed => Failed,
                                 }
                             }
@@ -17145,114 +18495,156 @@ fn __parse_typeof_specifier0<'input>(__input: &'input str, __state: &mut ParseSt
                 }
             };
             match __seq_res {
-                Matched(__pos, e) => Matched(__pos, { TypeOf::Expression(e) }),
+                Matched(__pos, n) => {
+                    let __seq_res = __parse__(__input, __state, __pos, env);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = slice_eq(__input, __state, __pos, "(");
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = {
+                                                let __seq_res = {
+                                                    let mut __repeat_pos = __pos;
+                                                    let mut __repeat_value = vec![];
+                                                    loop {
+                                                        let __pos = __repeat_pos;
+                                                        let __pos = if __repeat_value.len() > 0 {
+                                                            let __sep_res = {
+                                                                let __seq_res = __parse__(__input, __state, __pos, env);
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = slice_eq(__input, __state, __pos, ",");
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => __parse__(__input, __state, __pos, env),
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            };
+                                                            match __sep_res {
+                                                                Matched(__newpos, _) => __newpos,
+                                                                Failed => break,
+                                                            }
+                                                        } else {
+                                                            __pos
+                                                        };
+                                                        let __step_res = {
+                                                            let __seq_res = Matched(__pos, __pos);
+                                                            match __seq_res {
+                                                                Matched(__pos, l) => {
+                                                                    let __seq_res = __parse_assignment_expression0(__input, __state, __pos, env);
+                                                                    match __seq_res {
+                                                                        Matched(__pos, e) => {
+                                                                            let __seq_res = Matched(__pos, __pos);
+                                                                            match __seq_res {
+                                                                                Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                                                                Failed => Failed,
+                                                                            }
+                                                                        }
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        };
+                                                        match __step_res {
+                                                            Matched(__newpos, __value) => {
+                                                                __repeat_pos = __newpos;
+                                                                __repeat_value.push(__value);
+                                                            }
+                                                            Failed => {
+                                                                break;
+                                                            }
+                                                        }
+                                                    }
+                                                    Matched(__repeat_pos, __repeat_value)
+                                                };
+                                                match __seq_res {
+                                                    Matched(__pos, e) => Matched(__pos, { e }),
+                                                    Failed => Failed,
+                                                }
+                                            };
+                                            match __seq_res {
+                                                Matched(__pos, a) => {
+                                                    let __seq_res = __parse__(__input, __state, __pos, env);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = slice_eq(__input, __state, __pos, ")");
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => Matched(__pos, { Extension::VendorQualifier(VendorQualifier { name: n, arguments: a }) }),
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
                 Failed => Failed,
             }
         };
         match __choice_res {
             Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let __seq_res = __parse_type_name(__input, __state, __pos, env);
-                match __seq_res {
-                    Matched(__pos, t) => Matched(__pos, { TypeOf::Type(t) }),
-                    Failed => Failed,
-                }
-            }
-        }
-    }
-}
-
-fn __parse_ts18661_float_type_specifier<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TS18661FloatType> {
-    #![allow(non_snake_case, unused)]
-    {
-        let __choice_res = __parse_ts18661_binary_float(__input, __state, __pos, env);
-        match __choice_res {
-            Matched(__pos, __value) => Matched(__pos, __value),
-            Failed => __parse_ts18661_decimal_float(__input, __state, __pos, env),
-        }
-    }
-}
-
-fn __parse_ts18661_binary_float<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TS18661FloatType> {
-    #![allow(non_snake_case, unused)]
-    {
-        let __seq_res = slice_eq(__input, __state, __pos, "_Float");
-        match __seq_res {
-            Matched(__pos, _) => {
-                let __seq_res = __parse_ts18661_binary_width(__input, __state, __pos, env);
-                match __seq_res {
-                    Matched(__pos, width) => {
-                        let __seq_res = match slice_eq(__input, __state, __pos, "x") {
-                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                            Failed => Matched(__pos, None),
-                        };
-                        match __seq_res {
-                            Matched(__pos, extended) => Matched(__pos, { ts18661_float(true, width, extended.is_some()) }),
-                            Failed => Failed,
-                        }
-                    }
-                    Failed => Failed,
-                }
-            }
-            Failed => Failed,
-        }
-    }
-}
-
-fn __parse_ts18661_binary_width<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<usize> {
-    #![allow(non_snake_case, unused)]
-    {
-        let __seq_res = {
-            let str_start = __pos;
-            match {
-                let __choice_res = slice_eq(__input, __state, __pos, "16");
-                match __choice_res {
-                    Matched(__pos, __value) => Matched(__pos, __value),
-                    Failed => {
-                        let __choice_res = slice_eq(__input, __state, __pos, "32");
-                        match __choice_res {
-                            Matched(__pos, __value) => Matched(__pos, __value),
-                            Failed => {
-                                let __choice_res = slice_eq(__input, __state, __pos, "64");
-                                match __choice_res {
-                                    Matched(__pos, __value) => Matched(__pos, __value),
-                                    Failed => slice_eq(__input, __state, __pos, "128"),
+                let __seq_res = {
+                    let __seq_res = Matched(__pos, __pos);
+                    match __seq_res {
+                        Matched(__pos, l) => {
+                            let __seq_res = __parse_vendor_keyword(__input, __state, __pos, env);
+                            match __seq_res {
+                                Matched(__pos, e) => {
+                                    let __seq_res = Matched(__pos, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, r) => Matched(__pos, { Node::new(e, env.span(l, r)) }),
+                                        Failed => Failed,
+                                    }
                                 }
+                                Failed => Failed,
                             }
                         }
+                        Failed => Failed,
                     }
+                };
+                match __seq_res {
+                    Matched(__pos, n) => Matched(__pos, { Extension::VendorQualifier(VendorQualifier { name: n, arguments: Vec::new() }) }),
+                    Failed => Failed,
                 }
-            } {
-                Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
-                Failed => Failed,
             }
-        };
-        match __seq_res {
-            Matched(__pos, n) => Matched(__pos, { n.parse().unwrap() }),
-            Failed => Failed,
         }
     }
 }
 
-fn __parse_ts18661_decimal_float<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TS18661FloatType> {
+fn __parse_vendor_keyword<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<String> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = slice_eq(__input, __state, __pos, "_Decimal");
+        let __seq_res = __parse_vendor_keyword_name(__input, __state, __pos, env);
         match __seq_res {
-            Matched(__pos, _) => {
-                let __seq_res = __parse_ts18661_decimal_width(__input, __state, __pos, env);
-                match __seq_res {
-                    Matched(__pos, width) => {
-                        let __seq_res = match slice_eq(__input, __state, __pos, "x") {
-                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                            Failed => Matched(__pos, None),
-                        };
-                        match __seq_res {
-                            Matched(__pos, extended) => Matched(__pos, { ts18661_float(false, width, extended.is_some()) }),
-                            Failed => Failed,
-                        }
+            Matched(__pos, n) => {
+                match {
+                    match env.vendor_keywords.get(n) {
+                        Some(&false) => Ok(String::from(n)),
+                        _ => Err("vendor keyword"),
+                    }
+                } {
+                    Ok(res) => Matched(__pos, res),
+                    Err(expected) => {
+                        __state.mark_failure(__pos, expected);
+                        Failed
                     }
-                    Failed => Failed,
                 }
             }
             Failed => Failed,
@@ -17260,165 +18652,105 @@ fn __parse_ts18661_decimal_float<'input>(__input: &'input str, __state: &mut Par
     }
 }
 
-fn __parse_ts18661_decimal_width<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<usize> {
+fn __parse_vendor_keyword_with_arguments<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<String> {
     #![allow(non_snake_case, unused)]
     {
-        let __seq_res = {
-            let str_start = __pos;
-            match {
-                let __choice_res = slice_eq(__input, __state, __pos, "32");
-                match __choice_res {
-                    Matched(__pos, __value) => Matched(__pos, __value),
-                    Failed => {
-                        let __choice_res = slice_eq(__input, __state, __pos, "64");
-                        match __choice_res {
-                            Matched(__pos, __value) => Matched(__pos, __value),
-                            Failed => slice_eq(__input, __state, __pos, "128"),
-                        }
+        let __seq_res = __parse_vendor_keyword_name(__input, __state, __pos, env);
+        match __seq_res {
+            Matched(__pos, n) => {
+                match {
+                    match env.vendor_keywords.get(n) {
+                        Some(&true) => Ok(String::from(n)),
+                        _ => Err("vendor keyword"),
+                    }
+                } {
+                    Ok(res) => Matched(__pos, res),
+                    Err(expected) => {
+                        __state.mark_failure(__pos, expected);
+                        Failed
                     }
                 }
-            } {
-                Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
-                Failed => Failed,
             }
-        };
-        match __seq_res {
-            Matched(__pos, n) => Matched(__pos, { n.parse().unwrap() }),
             Failed => Failed,
         }
     }
 }
 
-fn __parse_ts18661_float_suffix<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<TS18661FloatType> {
+fn __parse_vendor_keyword_name<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<&'input str> {
     #![allow(non_snake_case, unused)]
     {
-        let __choice_res = {
-            let __seq_res = {
-                let __choice_res = slice_eq(__input, __state, __pos, "df");
-                match __choice_res {
-                    Matched(__pos, __value) => Matched(__pos, __value),
-                    Failed => slice_eq(__input, __state, __pos, "DF"),
-                }
-            };
-            match __seq_res {
-                Matched(__pos, _) => Matched(__pos, { ts18661_float(false, 32, false) }),
+        let __seq_res = {
+            __state.suppress_fail += 1;
+            let __assert_res = __parse_vendor_guard(__input, __state, __pos, env);
+            __state.suppress_fail -= 1;
+            match __assert_res {
+                Matched(_, __value) => Matched(__pos, __value),
                 Failed => Failed,
             }
         };
-        match __choice_res {
-            Matched(__pos, __value) => Matched(__pos, __value),
-            Failed => {
-                let __choice_res = {
-                    let __seq_res = {
-                        let __choice_res = slice_eq(__input, __state, __pos, "dd");
-                        match __choice_res {
-                            Matched(__pos, __value) => Matched(__pos, __value),
-                            Failed => slice_eq(__input, __state, __pos, "DD"),
-                        }
-                    };
-                    match __seq_res {
-                        Matched(__pos, _) => Matched(__pos, { ts18661_float(false, 64, false) }),
-                        Failed => Failed,
-                    }
-                };
-                match __choice_res {
-                    Matched(__pos, __value) => Matched(__pos, __value),
-                    Failed => {
-                        let __choice_res = {
-                            let __seq_res = {
-                                let __choice_res = slice_eq(__input, __state, __pos, "dl");
-                                match __choice_res {
-                                    Matched(__pos, __value) => Matched(__pos, __value),
-                                    Failed => slice_eq(__input, __state, __pos, "DL"),
-                                }
-                            };
-                            match __seq_res {
-                                Matched(__pos, _) => Matched(__pos, { ts18661_float(false, 128, false) }),
-                                Failed => Failed,
+        match __seq_res {
+            Matched(__pos, _) => {
+                let __seq_res = {
+                    let str_start = __pos;
+                    match {
+                        let __seq_res = if __input.len() > __pos {
+                            let (__ch, __next) = char_range_at(__input, __pos);
+                            match __ch {
+                                '_' | 'a'...'z' | 'A'...'Z' => Matched(__next, ()),
+                                _ => __state.mark_failure(__pos, "[_a-zA-Z]"),
                             }
+                        } else {
+                            __state.mark_failure(__pos, "[_a-zA-Z]")
                         };
-                        match __choice_res {
-                            Matched(__pos, __value) => Matched(__pos, __value),
-                            Failed => {
-                                let __choice_res = {
-                                    let __seq_res = if __input.len() > __pos {
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let mut __repeat_pos = __pos;
+                                loop {
+                                    let __pos = __repeat_pos;
+                                    let __step_res = if __input.len() > __pos {
                                         let (__ch, __next) = char_range_at(__input, __pos);
                                         match __ch {
-                                            'f' | 'F' => Matched(__next, ()),
-                                            _ => __state.mark_failure(__pos, "[fF]"),
+                                            '_' | 'a'...'z' | 'A'...'Z' | '0'...'9' => Matched(__next, ()),
+                                            _ => __state.mark_failure(__pos, "[_a-zA-Z0-9]"),
                                         }
                                     } else {
-                                        __state.mark_failure(__pos, "[fF]")
+                                        __state.mark_failure(__pos, "[_a-zA-Z0-9]")
                                     };
-                                    match __seq_res {
-                                        Matched(__pos, _) => {
-                                            let __seq_res = __parse_ts18661_binary_width(__input, __state, __pos, env);
-                                            match __seq_res {
-                                                Matched(__pos, width) => {
-                                                    let __seq_res = match slice_eq(__input, __state, __pos, "x") {
-                                                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                                                        Failed => Matched(__pos, None),
-                                                    };
-                                                    match __seq_res {
-                                                        Matched(__pos, extended) => Matched(__pos, { ts18661_float(true, width, extended.is_some()) }),
-                                                        Failed => Failed,
-                                                    }
-                                                }
-                                                Failed => Failed,
-                                            }
+                                    match __step_res {
+                                        Matched(__newpos, __value) => {
+                                            __repeat_pos = __newpos;
                                         }
-                                        Failed => Failed,
-                                    }
-                                };
-                                match __choice_res {
-                                    Matched(__pos, __value) => Matched(__pos, __value),
-                                    Failed => {
-                                        let __seq_res = if __input.len() > __pos {
-                                            let (__ch, __next) = char_range_at(__input, __pos);
-                                            match __ch {
-                                                'd' | 'D' => Matched(__next, ()),
-                                                _ => __state.mark_failure(__pos, "[dD]"),
-                                            }
-                                        } else {
-                                            __state.mark_failure(__pos, "[dD]")
-                                        };
-                                        match __seq_res {
-                                            Matched(__pos, _) => {
-                                                let __seq_res = __parse_ts18661_decimal_width(__input, __state, __pos, env);
-                                                match __seq_res {
-                                                    Matched(__pos, width) => {
-                                                        let __seq_res = match slice_eq(__input, __state, __pos, "x") {
-                                                            Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
-                                                            Failed => Matched(__pos, None),
-                                                        };
-                                                        match __seq_res {
-                                                            Matched(__pos, extended) => Matched(__pos, { ts18661_float(false, width, extended.is_some()) }),
-                                                            Failed => Failed,
-                                                        }
-                                                    }
-                                                    Failed => Failed,
-                                                }
-                                            }
-                                            Failed => Failed,
+                                        Failed => {
+                                            break;
                                         }
                                     }
                                 }
+                                Matched(__repeat_pos, ())
                             }
+                            Failed => Failed,
                         }
+                    } {
+                        Matched(__newpos, _) => Matched(__newpos, &__input[str_start..__newpos]),
+                        Failed => Failed,
                     }
+                };
+                match __seq_res {
+                    Matched(__pos, n) => Matched(__pos, { n }),
+                    Failed => Failed,
                 }
             }
+            Failed => Failed,
         }
     }
 }
 
-fn __parse_clang_guard<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
+fn __parse_vendor_guard<'input>(__input: &'input str, __state: &mut ParseState<'input>, __pos: usize, env: &mut Env) -> RuleResult<()> {
     #![allow(non_snake_case, unused)]
     match {
-        if env.extensions_clang {
+        if !env.vendor_keywords.is_empty() {
             Ok(())
         } else {
-            Err("clang extensions disabled")
+            Err("no vendor keywords")
         }
     } {
         Ok(res) => Matched(__pos, res),
@@ -17489,6 +18821,21 @@ pub fn declaration<'input>(__input: &'input str, env: &mut Env) -> ParseResult<N
     Err(ParseError { line: __line, column: __col, offset: __state.max_err_pos, expected: __state.expected })
 }
 
+pub fn type_name<'input>(__input: &'input str, env: &mut Env) -> ParseResult<Node<TypeName>> {
+    #![allow(non_snake_case, unused)]
+    let mut __state = ParseState::new();
+    match __parse_type_name(__input, &mut __state, 0, env) {
+        Matched(__pos, __value) => {
+            if __pos == __input.len() {
+                return Ok(__value);
+            }
+        }
+        _ => {}
+    }
+    let (__line, __col) = pos_to_line(__input, __state.max_err_pos);
+    Err(ParseError { line: __line, column: __col, offset: __state.max_err_pos, expected: __state.expected })
+}
+
 pub fn statement<'input>(__input: &'input str, env: &mut Env) -> ParseResult<Box<Node<Statement>>> {
     #![allow(non_snake_case, unused)]
     let mut __state = ParseState::new();
@@ -17504,6 +18851,66 @@ pub fn statement<'input>(__input: &'input str, env: &mut Env) -> ParseResult<Box
     Err(ParseError { line: __line, column: __col, offset: __state.max_err_pos, expected: __state.expected })
 }
 
+pub fn expression_fragment<'input>(__input: &'input str, env: &mut Env) -> ParseResult<Box<Node<Expression>>> {
+    #![allow(non_snake_case, unused)]
+    let mut __state = ParseState::new();
+    match __parse_expression_fragment(__input, &mut __state, 0, env) {
+        Matched(__pos, __value) => {
+            if __pos == __input.len() {
+                return Ok(__value);
+            }
+        }
+        _ => {}
+    }
+    let (__line, __col) = pos_to_line(__input, __state.max_err_pos);
+    Err(ParseError { line: __line, column: __col, offset: __state.max_err_pos, expected: __state.expected })
+}
+
+pub fn statement_fragment<'input>(__input: &'input str, env: &mut Env) -> ParseResult<Box<Node<Statement>>> {
+    #![allow(non_snake_case, unused)]
+    let mut __state = ParseState::new();
+    match __parse_statement_fragment(__input, &mut __state, 0, env) {
+        Matched(__pos, __value) => {
+            if __pos == __input.len() {
+                return Ok(__value);
+            }
+        }
+        _ => {}
+    }
+    let (__line, __col) = pos_to_line(__input, __state.max_err_pos);
+    Err(ParseError { line: __line, column: __col, offset: __state.max_err_pos, expected: __state.expected })
+}
+
+pub fn declaration_fragment<'input>(__input: &'input str, env: &mut Env) -> ParseResult<Node<Declaration>> {
+    #![allow(non_snake_case, unused)]
+    let mut __state = ParseState::new();
+    match __parse_declaration_fragment(__input, &mut __state, 0, env) {
+        Matched(__pos, __value) => {
+            if __pos == __input.len() {
+                return Ok(__value);
+            }
+        }
+        _ => {}
+    }
+    let (__line, __col) = pos_to_line(__input, __state.max_err_pos);
+    Err(ParseError { line: __line, column: __col, offset: __state.max_err_pos, expected: __state.expected })
+}
+
+pub fn type_name_fragment<'input>(__input: &'input str, env: &mut Env) -> ParseResult<Node<TypeName>> {
+    #![allow(non_snake_case, unused)]
+    let mut __state = ParseState::new();
+    match __parse_type_name_fragment(__input, &mut __state, 0, env) {
+        Matched(__pos, __value) => {
+            if __pos == __input.len() {
+                return Ok(__value);
+            }
+        }
+        _ => {}
+    }
+    let (__line, __col) = pos_to_line(__input, __state.max_err_pos);
+    Err(ParseError { line: __line, column: __col, offset: __state.max_err_pos, expected: __state.expected })
+}
+
 pub fn translation_unit<'input>(__input: &'input str, env: &mut Env) -> ParseResult<TranslationUnit> {
     #![allow(non_snake_case, unused)]
     let mut __state = ParseState::new();
@@ -0,0 +1,1815 @@
+//! Printing abstract syntax tree back as C source
+//!
+//! ```rust
+//! # use lang_c::driver::{parse_preprocessed, Config};
+//! # use lang_c::print;
+//! let source = "int (*handlers[4])(int);".to_string();
+//! let parse = parse_preprocessed(&Config::default(), source).unwrap();
+//! assert_eq!(print::to_string(&parse.unit), "int (*handlers[4])(int);\n");
+//! ```
+//!
+//! Output is meant to be parsed back rather than read: parsing printed code (with the same
+//! flavor and typedefs in scope) yields a tree equal to the original, save for spans. Only
+//! parentheses needed by operator precedence are printed, GNU spellings (`__attribute__`,
+//! `__asm__`, `__typeof__`) are used for extensions, and the layout is set by `Style`.
+//!
+//! Source details not kept in the tree are lost: digraphs, alternative keyword spellings,
+//! redundant parentheses and `__extension__` markers. Comments and preprocessor directives can
+//! be passed to the printer as `Trivia`, see the `format` module.
+//!
+//! Derived declarators are expected in the order the parser produces them: pointers first,
+//! then arrays and functions.
+
+use std::cmp;
+use std::ptr;
+
+use ast::*;
+use span::Node;
+
+/// Accumulates C source text for printed nodes
+pub struct Printer {
+    out: String,
+    indent: usize,
+    style: Style,
+    /// Print comma separated lists on one line regardless of the line width
+    flat: bool,
+    trivia: Vec<Trivia>,
+    next_trivia: usize,
+}
+
+/// Layout options
+#[derive(Clone, Debug)]
+pub struct Style {
+    /// Number of spaces per indentation level
+    pub indent_width: usize,
+    /// Placement of opening braces
+    pub braces: BraceStyle,
+    /// Lines longer than this are broken at commas of argument, parameter and initializer lists
+    pub line_width: Option<usize>,
+    /// Placement of `*` between declaration specifiers and the declarator
+    pub pointer_alignment: PointerAlignment,
+}
+
+impl Default for Style {
+    fn default() -> Style {
+        Style {
+            indent_width: 4,
+            braces: BraceStyle::Attach,
+            line_width: None,
+            pointer_alignment: PointerAlignment::Right,
+        }
+    }
+}
+
+/// Placement of opening braces
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BraceStyle {
+    /// Always on the same line as the statement or declaration
+    Attach,
+    /// On a line of its own for function definitions, attached otherwise
+    Linux,
+    /// Always on a line of its own
+    Allman,
+}
+
+/// Placement of `*` in declarations
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PointerAlignment {
+    /// `int *x`
+    Right,
+    /// `int* x`
+    Left,
+    /// `int * x`
+    Middle,
+}
+
+/// Source text that is not part of the tree
+///
+/// Trivia given to `Printer::set_trivia` is printed between block items, struct fields and
+/// external declarations, before the first one that starts after `offset`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trivia {
+    /// Byte offset in the parsed source
+    pub offset: usize,
+    pub kind: TriviaKind,
+    /// Text to print, without the final newline
+    pub text: String,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TriviaKind {
+    /// Comment on a line of its own, printed at the current indentation
+    Comment,
+    /// Comment following code on the same line, appended to the last printed line
+    TrailingComment,
+    /// Preprocessor directive or other text printed at the start of a line as is
+    Verbatim,
+    /// Empty line separating code, repeated ones are collapsed
+    BlankLine,
+}
+
+/// Nodes that can be printed as C source
+pub trait Print {
+    fn print(&self, printer: &mut Printer);
+}
+
+/// Print a node into a new string
+pub fn to_string<T: Print + ?Sized>(node: &T) -> String {
+    let mut printer = Printer::new();
+    node.print(&mut printer);
+    printer.finish()
+}
+
+/// Print an expression that is to replace `operand` of `parent`
+///
+/// The expression is parenthesized if it binds looser than the position of `operand` in
+/// `parent` allows, so that replacing `b` in `a * b` with `c + d` gives `a * (c + d)`.
+pub fn operand_to_string(
+    parent: &Expression,
+    operand: &Node<Expression>,
+    expr: &Expression,
+) -> String {
+    let mut printer = Printer::new();
+    printer.expression(expr, operand_precedence(parent, operand));
+    printer.finish()
+}
+
+/// Loosest precedence an expression may have in place of `operand` of `parent`
+fn operand_precedence(parent: &Expression, operand: &Node<Expression>) -> u8 {
+    let is = |e: &Node<Expression>| ptr::eq(e, operand);
+    match *parent {
+        Expression::Member(ref m) if is(&m.node.expression) => POSTFIX,
+        Expression::Call(ref c) if is(&c.node.callee) => POSTFIX,
+        Expression::UnaryOperator(ref u) => match u.node.operator.node {
+            UnaryOperator::PostIncrement | UnaryOperator::PostDecrement => POSTFIX,
+            UnaryOperator::SizeOf | UnaryOperator::PreIncrement | UnaryOperator::PreDecrement => {
+                UNARY
+            }
+            _ => CAST,
+        },
+        Expression::Cast(_) => CAST,
+        Expression::BinaryOperator(ref b) => {
+            let lhs = is(&b.node.lhs);
+            match binary_precedence(&b.node.operator.node) {
+                POSTFIX if lhs => POSTFIX,
+                POSTFIX => COMMA,
+                ASSIGNMENT if lhs => UNARY,
+                ASSIGNMENT => ASSIGNMENT,
+                p if lhs => p,
+                p => p + 1,
+            }
+        }
+        Expression::Conditional(ref c) if is(&c.node.condition) => LOGICAL_OR,
+        Expression::Conditional(ref c) if is(&c.node.then_expression) => COMMA,
+        Expression::Conditional(_) => CONDITIONAL,
+        // Arguments, list elements and the rest, where only a comma would be misread
+        _ => ASSIGNMENT,
+    }
+}
+
+impl<T: Print> Print for Node<T> {
+    fn print(&self, printer: &mut Printer) {
+        self.node.print(printer)
+    }
+}
+
+impl<T: Print + ?Sized> Print for Box<T> {
+    fn print(&self, printer: &mut Printer) {
+        (**self).print(printer)
+    }
+}
+
+// Operator precedence levels, from loosest to tightest binding.
+const COMMA: u8 = 0;
+const ASSIGNMENT: u8 = 1;
+const CONDITIONAL: u8 = 2;
+const LOGICAL_OR: u8 = 3;
+const CAST: u8 = 13;
+const UNARY: u8 = 14;
+const POSTFIX: u8 = 15;
+
+fn precedence(expr: &Expression) -> u8 {
+    match *expr {
+        Expression::UnaryOperator(ref u) => match u.node.operator.node {
+            UnaryOperator::PostIncrement | UnaryOperator::PostDecrement => POSTFIX,
+            _ => UNARY,
+        },
+        Expression::SizeOf(_) | Expression::AlignOf(_) => UNARY,
+        Expression::Cast(_) => CAST,
+        Expression::BinaryOperator(ref b) => binary_precedence(&b.node.operator.node),
+        Expression::Conditional(_) => CONDITIONAL,
+        Expression::Comma(_) => COMMA,
+        Expression::Ambiguous(ref a) => {
+            let cast = precedence(&a.node.cast.node);
+            cmp::min(cast, precedence(&a.node.expression.node))
+        }
+        _ => POSTFIX,
+    }
+}
+
+fn binary_precedence(op: &BinaryOperator) -> u8 {
+    match *op {
+        BinaryOperator::Index => POSTFIX,
+        BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Modulo => 12,
+        BinaryOperator::Plus | BinaryOperator::Minus => 11,
+        BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => 10,
+        BinaryOperator::Less
+        | BinaryOperator::Greater
+        | BinaryOperator::LessOrEqual
+        | BinaryOperator::GreaterOrEqual => 9,
+        BinaryOperator::Equals | BinaryOperator::NotEquals => 8,
+        BinaryOperator::BitwiseAnd => 7,
+        BinaryOperator::BitwiseXor => 6,
+        BinaryOperator::BitwiseOr => 5,
+        BinaryOperator::LogicalAnd => 4,
+        BinaryOperator::LogicalOr => LOGICAL_OR,
+        _ => ASSIGNMENT,
+    }
+}
+
+fn binary_operator_str(op: &BinaryOperator) -> &'static str {
+    match *op {
+        BinaryOperator::Index => "[]",
+        BinaryOperator::Multiply => "*",
+        BinaryOperator::Divide => "/",
+        BinaryOperator::Modulo => "%",
+        BinaryOperator::Plus => "+",
+        BinaryOperator::Minus => "-",
+        BinaryOperator::ShiftLeft => "<<",
+        BinaryOperator::ShiftRight => ">>",
+        BinaryOperator::Less => "<",
+        BinaryOperator::Greater => ">",
+        BinaryOperator::LessOrEqual => "<=",
+        BinaryOperator::GreaterOrEqual => ">=",
+        BinaryOperator::Equals => "==",
+        BinaryOperator::NotEquals => "!=",
+        BinaryOperator::BitwiseAnd => "&",
+        BinaryOperator::BitwiseXor => "^",
+        BinaryOperator::BitwiseOr => "|",
+        BinaryOperator::LogicalAnd => "&&",
+        BinaryOperator::LogicalOr => "||",
+        BinaryOperator::Assign => "=",
+        BinaryOperator::AssignMultiply => "*=",
+        BinaryOperator::AssignDivide => "/=",
+        BinaryOperator::AssignModulo => "%=",
+        BinaryOperator::AssignPlus => "+=",
+        BinaryOperator::AssignMinus => "-=",
+        BinaryOperator::AssignShiftLeft => "<<=",
+        BinaryOperator::AssignShiftRight => ">>=",
+        BinaryOperator::AssignBitwiseAnd => "&=",
+        BinaryOperator::AssignBitwiseXor => "^=",
+        BinaryOperator::AssignBitwiseOr => "|=",
+    }
+}
+
+fn unary_operator_str(op: &UnaryOperator) -> &'static str {
+    match *op {
+        UnaryOperator::PostIncrement | UnaryOperator::PreIncrement => "++",
+        UnaryOperator::PostDecrement | UnaryOperator::PreDecrement => "--",
+        UnaryOperator::Address => "&",
+        UnaryOperator::Indirection => "*",
+        UnaryOperator::Plus => "+",
+        UnaryOperator::Minus => "-",
+        UnaryOperator::Complement => "~",
+        UnaryOperator::Negate => "!",
+        UnaryOperator::SizeOf => "sizeof",
+    }
+}
+
+/// Test if an `else` printed after this statement would bind to a nested `if`
+fn ends_with_open_if(stmt: &Statement) -> bool {
+    match *stmt {
+        Statement::If(ref s) => match s.node.else_statement {
+            Some(ref e) => ends_with_open_if(&e.node),
+            None => true,
+        },
+        Statement::Labeled(ref s) => ends_with_open_if(&s.node.statement.node),
+        Statement::Switch(ref s) => ends_with_open_if(&s.node.statement.node),
+        Statement::While(ref s) => ends_with_open_if(&s.node.statement.node),
+        Statement::For(ref s) => ends_with_open_if(&s.node.statement.node),
+        _ => false,
+    }
+}
+
+fn is_attribute(ext: &Node<Extension>) -> bool {
+    matches!(
+        ext.node,
+        Extension::Attribute(_) | Extension::AvailabilityAttribute(_)
+    )
+}
+
+fn is_vendor_qualifier(ext: &Node<Extension>) -> bool {
+    matches!(ext.node, Extension::VendorQualifier(_))
+}
+
+type Extensions<'a> = &'a [Node<Extension>];
+
+/// Split declarator extensions by where they are printed
+///
+/// Attributes before any other extension go in front of the declarator and vendor qualifiers
+/// after it. When `trailing` is set, the declarator is followed by an asm label and attributes,
+/// which are returned as the third part; declarators of declarations and struct fields have
+/// them. Without other extensions all attributes are printed trailing, since in front of the
+/// declarator they would be parsed as declaration specifiers.
+fn split_extensions(
+    exts: &[Node<Extension>],
+    trailing: bool,
+) -> (Extensions<'_>, Extensions<'_>, Extensions<'_>) {
+    let lead = exts.iter().take_while(|e| is_attribute(e)).count();
+    if !trailing {
+        return (&exts[..lead], &exts[lead..], &[]);
+    }
+    if lead == exts.len() {
+        return (&[], &[], exts);
+    }
+    let inner = lead
+        + exts[lead..]
+            .iter()
+            .take_while(|e| is_vendor_qualifier(e))
+            .count();
+    (&exts[..lead], &exts[lead..inner], &exts[inner..])
+}
+
+impl Printer {
+    /// Create an empty printer
+    pub fn new() -> Printer {
+        Printer::with_style(Style::default())
+    }
+
+    /// Create an empty printer with the given layout options
+    pub fn with_style(style: Style) -> Printer {
+        Printer {
+            out: String::new(),
+            indent: 0,
+            style,
+            flat: false,
+            trivia: Vec::new(),
+            next_trivia: 0,
+        }
+    }
+
+    /// Set trivia to interleave with printed nodes, sorted by offset
+    pub fn set_trivia(&mut self, trivia: Vec<Trivia>) {
+        self.trivia = trivia;
+        self.next_trivia = 0;
+    }
+
+    /// Print a node after the text printed so far
+    pub fn print<T: Print + ?Sized>(&mut self, node: &T) {
+        node.print(self)
+    }
+
+    /// Get the printed text
+    pub fn finish(self) -> String {
+        self.out
+    }
+
+    fn word(&mut self, word: &str) {
+        self.out.push_str(word);
+    }
+
+    fn trim_line_end(&mut self) {
+        let len = self.out.trim_end_matches(' ').len();
+        self.out.truncate(len);
+    }
+
+    fn newline(&mut self) {
+        self.trim_line_end();
+        if self.out.is_empty() {
+            return;
+        }
+        self.out.push('\n');
+        for _ in 0..self.indent * self.style.indent_width {
+            self.out.push(' ');
+        }
+    }
+
+    /// Make the next `newline` leave an empty line, unless at the start of a block
+    fn blank_line(&mut self) {
+        self.trim_line_end();
+        if !self.out.is_empty() && !self.out.ends_with('\n') && !self.out.ends_with('{') {
+            self.out.push('\n');
+        }
+    }
+
+    fn column(&self) -> usize {
+        let line = match self.out.rfind('\n') {
+            Some(pos) => &self.out[pos + 1..],
+            None => &self.out[..],
+        };
+        line.chars().count()
+    }
+
+    /// Print trivia that precedes `offset`
+    fn flush_trivia(&mut self, offset: usize) {
+        while let Some(t) = self.trivia.get(self.next_trivia).cloned() {
+            if t.offset >= offset {
+                break;
+            }
+            self.next_trivia += 1;
+            match t.kind {
+                TriviaKind::TrailingComment if !self.out.ends_with('\n') => {
+                    self.trim_line_end();
+                    self.word(" ");
+                    self.word(&t.text);
+                }
+                TriviaKind::Comment | TriviaKind::TrailingComment => {
+                    self.newline();
+                    self.word(&t.text);
+                }
+                TriviaKind::Verbatim => {
+                    self.trim_line_end();
+                    if !self.out.is_empty() {
+                        self.out.push('\n');
+                    }
+                    self.word(&t.text);
+                }
+                TriviaKind::BlankLine => self.blank_line(),
+            }
+        }
+    }
+
+    /// Print trivia that precedes `offset` at the end of a block
+    fn flush_trivia_in_block(&mut self, offset: usize) {
+        self.flush_trivia(offset);
+        self.trim_line_end();
+        while self.out.ends_with('\n') {
+            self.out.pop();
+        }
+    }
+
+    /// Separate opening brace of a block from the preceding code
+    fn brace_separator(&mut self, function: bool) {
+        let own_line = match self.style.braces {
+            BraceStyle::Attach => false,
+            BraceStyle::Linux => function,
+            BraceStyle::Allman => true,
+        };
+        if own_line {
+            self.newline();
+        } else {
+            self.word(" ");
+        }
+    }
+
+    fn has_trivia_before(&self, offset: usize) -> bool {
+        self.trivia[self.next_trivia..]
+            .iter()
+            .take_while(|t| t.offset < offset)
+            .any(|t| t.kind != TriviaKind::BlankLine)
+    }
+
+    /// Separate a keyword from the closing brace of a preceding block
+    fn after_brace(&mut self) {
+        if self.style.braces == BraceStyle::Allman {
+            self.newline();
+        } else {
+            self.word(" ");
+        }
+    }
+
+    fn list<T, F>(&mut self, items: &[T], sep: &str, mut f: F)
+    where
+        F: FnMut(&mut Printer, &T),
+    {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.word(sep);
+            }
+            f(self, item);
+        }
+    }
+
+    /// Print a comma separated list between `open` and `close`
+    ///
+    /// If the list does not fit in the line width, each item is printed on a line of its own and
+    /// `close` on the line after them. With an empty `open` the first item stays on the current
+    /// line. A `close` starting with a comma continues the list, its text up to the closing bracket
+    /// is printed as the last item.
+    fn wrapped_list<T, F>(&mut self, open: &str, items: &[T], close: &str, mut f: F)
+    where
+        F: FnMut(&mut Printer, &T),
+    {
+        let width = match self.style.line_width {
+            Some(width) if !self.flat && !items.is_empty() => width,
+            _ => {
+                self.word(open);
+                self.list(items, ", ", f);
+                self.word(close);
+                return;
+            }
+        };
+
+        let mark = (self.out.len(), self.next_trivia);
+        self.flat = true;
+        self.word(open);
+        self.list(items, ", ", &mut f);
+        self.word(close);
+        self.flat = false;
+        if !self.out[mark.0..].contains('\n') && self.column() <= width {
+            return;
+        }
+        self.out.truncate(mark.0);
+        self.next_trivia = mark.1;
+
+        self.word(open.trim_end());
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.word(",");
+            }
+            if i > 0 || !open.is_empty() {
+                self.indent += 1;
+                self.newline();
+                self.indent -= 1;
+            }
+            f(self, item);
+        }
+        let close = match close.strip_prefix(", ") {
+            Some(rest) => {
+                let (last, close) = rest.split_at(rest.len() - 1);
+                self.word(",");
+                self.indent += 1;
+                self.newline();
+                self.indent -= 1;
+                self.word(last);
+                close
+            }
+            None => close.trim_start(),
+        };
+        if !close.is_empty() {
+            self.newline();
+            self.word(close);
+        }
+    }
+
+    // 6.4 Lexical elements
+
+    fn identifier(&mut self, identifier: &Identifier) {
+        self.word(&identifier.name);
+    }
+
+    fn constant(&mut self, constant: &Constant) {
+        match *constant {
+            Constant::Integer(ref i) => self.integer(i),
+            Constant::Float(ref f) => self.float(f),
+            Constant::Character(ref c) => self.word(c),
+        }
+    }
+
+    fn integer(&mut self, integer: &Integer) {
+        self.word(match integer.base {
+            IntegerBase::Decimal => "",
+            IntegerBase::Octal => "0",
+            IntegerBase::Hexadecimal => "0x",
+            IntegerBase::Binary => "0b",
+        });
+        self.word(&integer.number);
+        if integer.suffix.unsigned {
+            self.word("u");
+        }
+        self.word(match integer.suffix.size {
+            IntegerSize::Int => "",
+            IntegerSize::Long => "l",
+            IntegerSize::LongLong => "ll",
+        });
+        if integer.suffix.imaginary {
+            self.word("i");
+        }
+    }
+
+    fn float(&mut self, float: &Float) {
+        if float.base == FloatBase::Hexadecimal {
+            self.word("0x");
+        }
+        self.word(&float.number);
+        match float.suffix.format {
+            FloatFormat::Float => self.word("f"),
+            FloatFormat::Double => {}
+            FloatFormat::LongDouble => self.word("l"),
+            FloatFormat::TS18661Format(ref t) => {
+                let (prefix, extended) = match t.format {
+                    TS18661FloatFormat::BinaryInterchange => ("f", ""),
+                    TS18661FloatFormat::BinaryExtended => ("f", "x"),
+                    TS18661FloatFormat::DecimalInterchange => ("d", ""),
+                    TS18661FloatFormat::DecimalExtended => ("d", "x"),
+                };
+                self.word(&format!("{}{}{}", prefix, t.width, extended));
+            }
+        }
+        if float.suffix.imaginary {
+            self.word("i");
+        }
+    }
+
+    fn string_literal(&mut self, literal: &StringLiteral) {
+        self.list(literal, " ", |p, s| p.word(s));
+    }
+
+    // 6.5 Expressions
+
+    fn expression(&mut self, expr: &Expression, min: u8) {
+        if precedence(expr) < min {
+            self.word("(");
+            self.expression(expr, COMMA);
+            self.word(")");
+            return;
+        }
+
+        match *expr {
+            Expression::Identifier(ref i) => self.identifier(&i.node),
+            Expression::Constant(ref c) => self.constant(&c.node),
+            Expression::StringLiteral(ref s) => self.string_literal(&s.node),
+            Expression::GenericSelection(ref g) => self.generic_selection(&g.node),
+            Expression::Member(ref m) => self.member_expression(&m.node),
+            Expression::Call(ref c) => self.call_expression(&c.node),
+            Expression::CompoundLiteral(ref c) => self.compound_literal(&c.node),
+            Expression::SizeOf(ref t) => {
+                self.word("sizeof(");
+                self.type_name(&t.node);
+                self.word(")");
+            }
+            Expression::AlignOf(ref t) => {
+                self.word("_Alignof(");
+                self.type_name(&t.node);
+                self.word(")");
+            }
+            Expression::UnaryOperator(ref u) => self.unary_operator_expression(&u.node),
+            Expression::Cast(ref c) => self.cast_expression(&c.node),
+            Expression::BinaryOperator(ref b) => self.binary_operator_expression(&b.node),
+            Expression::Conditional(ref c) => self.conditional_expression(&c.node),
+            Expression::Comma(ref es) => {
+                self.list(es, ", ", |p, e| p.expression(&e.node, ASSIGNMENT))
+            }
+            Expression::OffsetOf(ref o) => self.offsetof_expression(&o.node),
+            Expression::VaArg(ref v) => self.va_arg_expression(&v.node),
+            Expression::Statement(ref s) => {
+                self.word("(");
+                self.statement(&s.node);
+                self.word(")");
+            }
+            Expression::Ambiguous(ref a) => self.ambiguous_expression(&a.node),
+        }
+    }
+
+    fn full_expression(&mut self, expr: &Expression) {
+        self.expression(expr, COMMA);
+    }
+
+    fn member_operator(&mut self, op: &MemberOperator) {
+        self.word(match *op {
+            MemberOperator::Direct => ".",
+            MemberOperator::Indirect => "->",
+        });
+    }
+
+    fn generic_selection(&mut self, g: &GenericSelection) {
+        self.word("_Generic(");
+        self.expression(&g.expression.node, ASSIGNMENT);
+        for a in &g.associations {
+            self.word(", ");
+            self.generic_association(&a.node);
+        }
+        self.word(")");
+    }
+
+    fn generic_association(&mut self, a: &GenericAssociation) {
+        match *a {
+            GenericAssociation::Type(ref t) => self.generic_association_type(&t.node),
+            GenericAssociation::Default(ref e) => {
+                self.word("default: ");
+                self.expression(&e.node, ASSIGNMENT);
+            }
+        }
+    }
+
+    fn generic_association_type(&mut self, a: &GenericAssociationType) {
+        self.type_name(&a.type_name.node);
+        self.word(": ");
+        self.expression(&a.expression.node, ASSIGNMENT);
+    }
+
+    fn member_expression(&mut self, m: &MemberExpression) {
+        self.expression(&m.expression.node, POSTFIX);
+        self.member_operator(&m.operator.node);
+        self.identifier(&m.identifier.node);
+    }
+
+    fn call_expression(&mut self, c: &CallExpression) {
+        self.expression(&c.callee.node, POSTFIX);
+        self.wrapped_list("(", &c.arguments, ")", |p, a| {
+            p.expression(&a.node, ASSIGNMENT)
+        });
+    }
+
+    fn compound_literal(&mut self, c: &CompoundLiteral) {
+        self.word("(");
+        self.type_name(&c.type_name.node);
+        self.word(")");
+        self.initializer_list(&c.initializer_list);
+    }
+
+    fn unary_operator(&mut self, op: &UnaryOperator) {
+        self.word(unary_operator_str(op));
+    }
+
+    fn unary_operator_expression(&mut self, u: &UnaryOperatorExpression) {
+        let op = unary_operator_str(&u.operator.node);
+        let min = match u.operator.node {
+            UnaryOperator::PostIncrement | UnaryOperator::PostDecrement => {
+                self.expression(&u.operand.node, POSTFIX);
+                self.word(op);
+                return;
+            }
+            UnaryOperator::SizeOf => {
+                self.word("sizeof ");
+                self.expression(&u.operand.node, UNARY);
+                return;
+            }
+            UnaryOperator::PreIncrement | UnaryOperator::PreDecrement => UNARY,
+            _ => CAST,
+        };
+
+        self.word(op);
+        let start = self.out.len();
+        self.expression(&u.operand.node, min);
+        // Keep `- -x` and `& &x` from being read as `--x` and `&&x`.
+        let last = op.chars().last();
+        if last.is_some_and(|c| "+-&".contains(c)) && self.out[start..].chars().next() == last {
+            self.out.insert(start, ' ');
+        }
+    }
+
+    fn cast_expression(&mut self, c: &CastExpression) {
+        self.word("(");
+        self.type_name(&c.type_name.node);
+        self.word(")");
+        self.expression(&c.expression.node, CAST);
+    }
+
+    fn binary_operator(&mut self, op: &BinaryOperator) {
+        self.word(binary_operator_str(op));
+    }
+
+    fn binary_operator_expression(&mut self, b: &BinaryOperatorExpression) {
+        let op = &b.operator.node;
+        let (lhs, rhs) = match binary_precedence(op) {
+            POSTFIX => {
+                self.expression(&b.lhs.node, POSTFIX);
+                self.word("[");
+                self.expression(&b.rhs.node, COMMA);
+                self.word("]");
+                return;
+            }
+            ASSIGNMENT => (UNARY, ASSIGNMENT),
+            p => (p, p + 1),
+        };
+        self.expression(&b.lhs.node, lhs);
+        self.word(" ");
+        self.binary_operator(op);
+        self.word(" ");
+        self.expression(&b.rhs.node, rhs);
+    }
+
+    fn conditional_expression(&mut self, c: &ConditionalExpression) {
+        self.expression(&c.condition.node, LOGICAL_OR);
+        self.word(" ? ");
+        self.expression(&c.then_expression.node, COMMA);
+        self.word(" : ");
+        self.expression(&c.else_expression.node, CONDITIONAL);
+    }
+
+    fn va_arg_expression(&mut self, v: &VaArgExpression) {
+        self.word("__builtin_va_arg(");
+        self.expression(&v.va_list.node, ASSIGNMENT);
+        self.word(", ");
+        self.type_name(&v.type_name.node);
+        self.word(")");
+    }
+
+    fn offsetof_expression(&mut self, o: &OffsetOfExpression) {
+        self.word("__builtin_offsetof(");
+        self.type_name(&o.type_name.node);
+        self.word(", ");
+        self.offset_designator(&o.designator.node);
+        self.word(")");
+    }
+
+    fn offset_designator(&mut self, d: &OffsetDesignator) {
+        self.identifier(&d.base.node);
+        for m in &d.members {
+            self.offset_member(&m.node);
+        }
+    }
+
+    fn offset_member(&mut self, m: &OffsetMember) {
+        match *m {
+            OffsetMember::Member(ref i) => {
+                self.word(".");
+                self.identifier(&i.node);
+            }
+            OffsetMember::IndirectMember(ref i) => {
+                self.word("->");
+                self.identifier(&i.node);
+            }
+            OffsetMember::Index(ref e) => {
+                self.word("[");
+                self.expression(&e.node, COMMA);
+                self.word("]");
+            }
+        }
+    }
+
+    fn ambiguous_expression(&mut self, a: &AmbiguousExpression) {
+        // Both readings are spelled the same
+        self.expression(&a.cast.node, COMMA)
+    }
+
+    // 6.7 Declarations
+
+    fn declaration(&mut self, d: &Declaration) {
+        self.declaration_specifiers(&d.specifiers);
+        if !d.declarators.is_empty() {
+            self.word(" ");
+            let first = &d.declarators[0];
+            self.wrapped_list("", &d.declarators, "", |p, d| {
+                p.init_declarator(&d.node, ptr::eq(d, first))
+            });
+        }
+        self.word(";");
+    }
+
+    fn declaration_specifiers(&mut self, specifiers: &[Node<DeclarationSpecifier>]) {
+        self.list(specifiers, " ", |p, s| p.declaration_specifier(&s.node));
+    }
+
+    fn declaration_specifier(&mut self, s: &DeclarationSpecifier) {
+        match *s {
+            DeclarationSpecifier::StorageClass(ref s) => self.storage_class_specifier(&s.node),
+            DeclarationSpecifier::TypeSpecifier(ref s) => self.type_specifier(&s.node),
+            DeclarationSpecifier::TypeQualifier(ref s) => self.type_qualifier(&s.node),
+            DeclarationSpecifier::Function(ref s) => self.function_specifier(&s.node),
+            DeclarationSpecifier::Alignment(ref s) => self.alignment_specifier(&s.node),
+            DeclarationSpecifier::Extension(ref e) => self.extension_specifier(e),
+        }
+    }
+
+    /// Print an init declarator, aligning pointers to `specifiers` if it follows them
+    fn init_declarator(&mut self, d: &InitDeclarator, specifiers: bool) {
+        let trailing = self.declarator(&d.declarator.node, true, specifiers);
+        self.trailing_extensions(trailing);
+        if let Some(ref i) = d.initializer {
+            self.word(" = ");
+            self.initializer(&i.node);
+        }
+    }
+
+    fn storage_class_specifier(&mut self, s: &StorageClassSpecifier) {
+        self.word(match *s {
+            StorageClassSpecifier::Typedef => "typedef",
+            StorageClassSpecifier::Extern => "extern",
+            StorageClassSpecifier::Static => "static",
+            StorageClassSpecifier::ThreadLocal => "_Thread_local",
+            StorageClassSpecifier::Auto => "auto",
+            StorageClassSpecifier::Register => "register",
+        });
+    }
+
+    fn type_specifier(&mut self, s: &TypeSpecifier) {
+        match *s {
+            TypeSpecifier::Void => self.word("void"),
+            TypeSpecifier::Char => self.word("char"),
+            TypeSpecifier::Short => self.word("short"),
+            TypeSpecifier::Int => self.word("int"),
+            TypeSpecifier::Long => self.word("long"),
+            TypeSpecifier::Float => self.word("float"),
+            TypeSpecifier::Double => self.word("double"),
+            TypeSpecifier::Signed => self.word("signed"),
+            TypeSpecifier::Unsigned => self.word("unsigned"),
+            TypeSpecifier::Bool => self.word("_Bool"),
+            TypeSpecifier::Complex => self.word("_Complex"),
+            TypeSpecifier::Atomic(ref t) => {
+                self.word("_Atomic(");
+                self.type_name(&t.node);
+                self.word(")");
+            }
+            TypeSpecifier::Struct(ref s) => self.struct_type(&s.node, s.span.end),
+            TypeSpecifier::Enum(ref e) => self.enum_type(&e.node),
+            TypeSpecifier::TypedefName(ref i) => self.identifier(&i.node),
+            TypeSpecifier::TypeOf(ref t) => self.type_of(&t.node),
+            TypeSpecifier::TS18661Float(ref t) => self.ts18661_float_type(t),
+            TypeSpecifier::Fract => self.word("_Fract"),
+            TypeSpecifier::Accum => self.word("_Accum"),
+            TypeSpecifier::Sat => self.word("_Sat"),
+        }
+    }
+
+    fn ts18661_float_type(&mut self, t: &TS18661FloatType) {
+        let (name, extended) = match t.format {
+            TS18661FloatFormat::BinaryInterchange => ("_Float", ""),
+            TS18661FloatFormat::BinaryExtended => ("_Float", "x"),
+            TS18661FloatFormat::DecimalInterchange => ("_Decimal", ""),
+            TS18661FloatFormat::DecimalExtended => ("_Decimal", "x"),
+        };
+        self.word(&format!("{}{}{}", name, t.width, extended));
+    }
+
+    /// Print a struct or union, with trivia in its body that precedes `end`
+    fn struct_type(&mut self, s: &StructType, end: usize) {
+        self.struct_kind(&s.kind.node);
+        if let Some(ref i) = s.identifier {
+            self.word(" ");
+            self.identifier(&i.node);
+        }
+        match s.declarations {
+            Some(ref d) if d.is_empty() && !self.has_trivia_before(end) => self.word(" {}"),
+            Some(ref d) => {
+                self.brace_separator(false);
+                self.word("{");
+                self.indent += 1;
+                for d in d {
+                    self.flush_trivia(d.span.start);
+                    self.newline();
+                    self.struct_declaration(&d.node);
+                }
+                self.flush_trivia_in_block(end);
+                self.indent -= 1;
+                self.newline();
+                self.word("}");
+            }
+            None => {}
+        }
+    }
+
+    fn struct_kind(&mut self, k: &StructKind) {
+        self.word(match *k {
+            StructKind::Struct => "struct",
+            StructKind::Union => "union",
+        });
+    }
+
+    fn struct_declaration(&mut self, d: &StructDeclaration) {
+        match *d {
+            StructDeclaration::Field(ref f) => self.struct_field(&f.node),
+            StructDeclaration::StaticAssert(ref s) => self.static_assert(&s.node),
+        }
+    }
+
+    fn struct_field(&mut self, f: &StructField) {
+        self.specifier_qualifiers(&f.specifiers);
+        if !f.declarators.is_empty() {
+            self.word(" ");
+            let first = &f.declarators[0];
+            self.list(&f.declarators, ", ", |p, d| {
+                p.struct_declarator(&d.node, ptr::eq(d, first))
+            });
+        }
+        self.word(";");
+    }
+
+    fn specifier_qualifiers(&mut self, specifiers: &[Node<SpecifierQualifier>]) {
+        self.list(specifiers, " ", |p, s| p.specifier_qualifier(&s.node));
+    }
+
+    fn specifier_qualifier(&mut self, s: &SpecifierQualifier) {
+        match *s {
+            SpecifierQualifier::TypeSpecifier(ref s) => self.type_specifier(&s.node),
+            SpecifierQualifier::TypeQualifier(ref s) => self.type_qualifier(&s.node),
+            SpecifierQualifier::Extension(ref e) => self.extension_specifier(e),
+        }
+    }
+
+    fn struct_declarator(&mut self, d: &StructDeclarator, specifiers: bool) {
+        let trailing = match d.declarator {
+            Some(ref d) => self.declarator(&d.node, true, specifiers),
+            None => &[],
+        };
+        if let Some(ref e) = d.bit_width {
+            if d.declarator.is_some() {
+                self.word(" ");
+            }
+            self.word(": ");
+            self.expression(&e.node, CONDITIONAL);
+        }
+        self.trailing_extensions(trailing);
+    }
+
+    fn enum_type(&mut self, e: &EnumType) {
+        self.word("enum");
+        if let Some(ref i) = e.identifier {
+            self.word(" ");
+            self.identifier(&i.node);
+        }
+        if !e.enumerators.is_empty() {
+            self.wrapped_list(" { ", &e.enumerators, " }", |p, e| p.enumerator(&e.node));
+        }
+    }
+
+    fn enumerator(&mut self, e: &Enumerator) {
+        self.identifier(&e.identifier.node);
+        if let Some(ref e) = e.expression {
+            self.word(" = ");
+            self.expression(&e.node, CONDITIONAL);
+        }
+    }
+
+    fn type_qualifier(&mut self, q: &TypeQualifier) {
+        self.word(match *q {
+            TypeQualifier::Const => "const",
+            TypeQualifier::Restrict => "restrict",
+            TypeQualifier::Volatile => "volatile",
+            TypeQualifier::Nonnull => "_Nonnull",
+            TypeQualifier::NullUnspecified => "_Null_unspecified",
+            TypeQualifier::Nullable => "_Nullable",
+            TypeQualifier::Atomic => "_Atomic",
+        });
+    }
+
+    fn function_specifier(&mut self, s: &FunctionSpecifier) {
+        self.word(match *s {
+            FunctionSpecifier::Inline => "inline",
+            FunctionSpecifier::Noreturn => "_Noreturn",
+        });
+    }
+
+    fn alignment_specifier(&mut self, s: &AlignmentSpecifier) {
+        self.word("_Alignas(");
+        match *s {
+            AlignmentSpecifier::Type(ref t) => self.type_name(&t.node),
+            AlignmentSpecifier::Constant(ref e) => self.expression(&e.node, CONDITIONAL),
+        }
+        self.word(")");
+    }
+
+    /// Print a declarator and return extensions that go after it
+    ///
+    /// See `split_extensions` for the meaning of `trailing`. Pointers are placed according to
+    /// the pointer alignment only if the declarator follows declaration `specifiers`.
+    fn declarator<'a>(
+        &mut self,
+        d: &'a Declarator,
+        trailing: bool,
+        specifiers: bool,
+    ) -> &'a [Node<Extension>] {
+        let (leading, inner, rest) = split_extensions(&d.extensions, trailing);
+        if !leading.is_empty() {
+            self.extensions(leading);
+            self.word(" ");
+        }
+
+        let pointers = d
+            .derived
+            .iter()
+            .take_while(|d| matches!(d.node, DerivedDeclarator::Pointer(_)))
+            .count();
+        let (pointers, postfix) = d.derived.split_at(pointers);
+        let is_empty =
+            d.kind.node == DeclaratorKind::Abstract && postfix.is_empty() && inner.is_empty();
+
+        let alignment = match self.style.pointer_alignment {
+            _ if !specifiers || !leading.is_empty() => PointerAlignment::Right,
+            a => a,
+        };
+        if alignment == PointerAlignment::Left && !pointers.is_empty() {
+            self.trim_line_end();
+        }
+        for (i, p) in pointers.iter().enumerate() {
+            self.derived_declarator(&p.node);
+            let is_last = i + 1 == pointers.len();
+            let qualified = match p.node {
+                DerivedDeclarator::Pointer(ref q) => !q.is_empty(),
+                _ => false,
+            };
+            let aligned = is_last && alignment != PointerAlignment::Right;
+            if (qualified || aligned) && !(is_last && is_empty) {
+                self.word(" ");
+            }
+        }
+
+        self.declarator_kind(&d.kind.node);
+        for d in postfix {
+            self.derived_declarator(&d.node);
+        }
+
+        if !inner.is_empty() {
+            self.word(" ");
+            self.extensions(inner);
+        }
+
+        rest
+    }
+
+    fn full_declarator(&mut self, d: &Declarator, specifiers: bool) {
+        let trailing = self.declarator(d, false, specifiers);
+        self.trailing_extensions(trailing);
+    }
+
+    fn declarator_kind(&mut self, k: &DeclaratorKind) {
+        match *k {
+            DeclaratorKind::Abstract => {}
+            DeclaratorKind::Identifier(ref i) => self.identifier(&i.node),
+            DeclaratorKind::Declarator(ref d) => {
+                self.word("(");
+                self.full_declarator(&d.node, false);
+                self.word(")");
+            }
+        }
+    }
+
+    fn derived_declarator(&mut self, d: &DerivedDeclarator) {
+        match *d {
+            DerivedDeclarator::Pointer(ref q) => {
+                self.word("*");
+                self.list(q, " ", |p, q| p.pointer_qualifier(&q.node));
+            }
+            DerivedDeclarator::Array(ref a) => {
+                self.word("[");
+                self.array_declarator(&a.node);
+                self.word("]");
+            }
+            DerivedDeclarator::Function(ref f) => {
+                let close = match f.node.ellipsis {
+                    Ellipsis::Some => ", ...)",
+                    Ellipsis::None => ")",
+                };
+                self.wrapped_list("(", &f.node.parameters, close, |p, d| {
+                    p.parameter_declaration(&d.node)
+                });
+            }
+            DerivedDeclarator::KRFunction(ref ids) => {
+                self.word("(");
+                self.list(ids, ", ", |p, i| p.identifier(&i.node));
+                self.word(")");
+            }
+        }
+    }
+
+    fn array_declarator(&mut self, a: &ArrayDeclarator) {
+        if let ArraySize::StaticExpression(_) = a.size {
+            self.word("static");
+            if !a.qualifiers.is_empty() {
+                self.word(" ");
+            }
+        }
+        self.list(&a.qualifiers, " ", |p, q| p.type_qualifier(&q.node));
+        let size = match a.size {
+            ArraySize::Unknown => return,
+            ArraySize::VariableUnknown => None,
+            ArraySize::VariableExpression(ref e) => Some(e),
+            ArraySize::StaticExpression(ref e) => Some(e),
+        };
+        if !self.out.ends_with('[') {
+            self.word(" ");
+        }
+        match size {
+            Some(e) => self.expression(&e.node, ASSIGNMENT),
+            None => self.word("*"),
+        }
+    }
+
+    fn function_declarator(&mut self, f: &FunctionDeclarator) {
+        self.list(&f.parameters, ", ", |p, d| p.parameter_declaration(&d.node));
+        if f.ellipsis == Ellipsis::Some {
+            self.word(", ...");
+        }
+    }
+
+    fn pointer_qualifier(&mut self, q: &PointerQualifier) {
+        match *q {
+            PointerQualifier::TypeQualifier(ref q) => self.type_qualifier(&q.node),
+            PointerQualifier::Extension(ref e) => self.extension_specifier(e),
+        }
+    }
+
+    fn parameter_declaration(&mut self, d: &ParameterDeclaration) {
+        self.declaration_specifiers(&d.specifiers);
+        if let Some(ref d) = d.declarator {
+            self.word(" ");
+            self.full_declarator(&d.node, true);
+        }
+        self.trailing_extensions(&d.extensions);
+    }
+
+    // 6.7.7 Type names
+
+    fn type_name(&mut self, t: &TypeName) {
+        self.specifier_qualifiers(&t.specifiers);
+        if let Some(ref d) = t.declarator {
+            self.word(" ");
+            self.full_declarator(&d.node, true);
+        }
+    }
+
+    // 6.7.9 Initialization
+
+    fn initializer(&mut self, i: &Initializer) {
+        match *i {
+            Initializer::Expression(ref e) => self.expression(&e.node, ASSIGNMENT),
+            Initializer::List(ref items) => self.initializer_list(items),
+        }
+    }
+
+    fn initializer_list(&mut self, items: &[Node<InitializerListItem>]) {
+        self.wrapped_list("{", items, "}", |p, i| p.initializer_list_item(&i.node));
+    }
+
+    fn initializer_list_item(&mut self, i: &InitializerListItem) {
+        if !i.designation.is_empty() {
+            for d in &i.designation {
+                self.designator(&d.node);
+            }
+            self.word(" = ");
+        }
+        self.initializer(&i.initializer.node);
+    }
+
+    fn designator(&mut self, d: &Designator) {
+        match *d {
+            Designator::Index(ref e) => {
+                self.word("[");
+                self.expression(&e.node, CONDITIONAL);
+                self.word("]");
+            }
+            Designator::Member(ref i) => {
+                self.word(".");
+                self.identifier(&i.node);
+            }
+            Designator::Range(ref r) => {
+                self.word("[");
+                self.range_designator(&r.node);
+                self.word("]");
+            }
+        }
+    }
+
+    fn range_designator(&mut self, r: &RangeDesignator) {
+        self.expression(&r.from.node, CONDITIONAL);
+        self.word(" ... ");
+        self.expression(&r.to.node, CONDITIONAL);
+    }
+
+    // 6.7.10 Static assertions
+
+    fn static_assert(&mut self, s: &StaticAssert) {
+        self.word("_Static_assert(");
+        self.expression(&s.expression.node, CONDITIONAL);
+        self.word(", ");
+        self.string_literal(&s.message.node);
+        self.word(");");
+    }
+
+    // 6.8 Statements and blocks
+
+    fn statement(&mut self, s: &Statement) {
+        match *s {
+            Statement::Labeled(ref s) => self.labeled_statement(&s.node),
+            Statement::Compound(ref items) => self.compound_statement(items, 0),
+            Statement::Expression(ref e) => {
+                if let Some(ref e) = *e {
+                    self.full_expression(&e.node);
+                }
+                self.word(";");
+            }
+            Statement::If(ref s) => self.if_statement(&s.node),
+            Statement::Switch(ref s) => self.switch_statement(&s.node),
+            Statement::While(ref s) => self.while_statement(&s.node),
+            Statement::DoWhile(ref s) => self.do_while_statement(&s.node),
+            Statement::For(ref s) => self.for_statement(&s.node),
+            Statement::Goto(ref i) => {
+                self.word("goto ");
+                self.identifier(&i.node);
+                self.word(";");
+            }
+            Statement::Continue => self.word("continue;"),
+            Statement::Break => self.word("break;"),
+            Statement::Return(ref e) => {
+                self.word("return");
+                if let Some(ref e) = *e {
+                    self.word(" ");
+                    self.full_expression(&e.node);
+                }
+                self.word(";");
+            }
+            Statement::Asm(ref s) => self.asm_statement(&s.node),
+        }
+    }
+
+    /// Print a statement, placing trivia inside its block if it is a compound statement
+    fn statement_node(&mut self, s: &Node<Statement>) {
+        match s.node {
+            Statement::Compound(ref items) => self.compound_statement(items, s.span.end),
+            ref s => self.statement(s),
+        }
+    }
+
+    /// Print a block, with trivia that precedes `end`
+    fn compound_statement(&mut self, items: &[Node<BlockItem>], end: usize) {
+        if items.is_empty() && !self.has_trivia_before(end) {
+            self.word("{}");
+            return;
+        }
+        self.word("{");
+        self.indent += 1;
+        for item in items {
+            self.flush_trivia(item.span.start);
+            self.newline();
+            self.block_item(&item.node);
+        }
+        self.flush_trivia_in_block(end);
+        self.indent -= 1;
+        self.newline();
+        self.word("}");
+    }
+
+    /// Print statement controlled by `if`, `for` and similar, returning `true` if it was braced
+    fn body(&mut self, s: &Node<Statement>) -> bool {
+        if let Statement::Compound(_) = s.node {
+            self.brace_separator(false);
+            self.statement_node(s);
+            true
+        } else {
+            self.indent += 1;
+            self.flush_trivia(s.span.start);
+            self.newline();
+            self.statement_node(s);
+            self.indent -= 1;
+            false
+        }
+    }
+
+    fn labeled_statement(&mut self, s: &LabeledStatement) {
+        self.label(&s.label.node);
+        self.word(":");
+        self.newline();
+        self.statement_node(&s.statement);
+    }
+
+    fn label(&mut self, l: &Label) {
+        match *l {
+            Label::Identifier(ref i) => self.identifier(&i.node),
+            Label::Case(ref e) => {
+                self.word("case ");
+                self.expression(&e.node, CONDITIONAL);
+            }
+            Label::Default => self.word("default"),
+        }
+    }
+
+    fn if_statement(&mut self, s: &IfStatement) {
+        self.word("if (");
+        self.full_expression(&s.condition.node);
+        self.word(")");
+
+        let else_statement = match s.else_statement {
+            Some(ref e) => e,
+            None => {
+                self.body(&s.then_statement);
+                return;
+            }
+        };
+
+        let braced = if ends_with_open_if(&s.then_statement.node) {
+            self.brace_separator(false);
+            self.word("{");
+            self.indent += 1;
+            self.newline();
+            self.statement_node(&s.then_statement);
+            self.indent -= 1;
+            self.newline();
+            self.word("}");
+            true
+        } else {
+            self.body(&s.then_statement)
+        };
+
+        if braced {
+            self.after_brace();
+        } else {
+            self.newline();
+        }
+        self.word("else");
+
+        if let Statement::If(_) = else_statement.node {
+            self.word(" ");
+            self.statement_node(else_statement);
+        } else {
+            self.body(else_statement);
+        }
+    }
+
+    fn switch_statement(&mut self, s: &SwitchStatement) {
+        self.word("switch (");
+        self.full_expression(&s.expression.node);
+        self.word(")");
+        self.body(&s.statement);
+    }
+
+    fn while_statement(&mut self, s: &WhileStatement) {
+        self.word("while (");
+        self.full_expression(&s.expression.node);
+        self.word(")");
+        self.body(&s.statement);
+    }
+
+    fn do_while_statement(&mut self, s: &DoWhileStatement) {
+        self.word("do");
+        if self.body(&s.statement) {
+            self.after_brace();
+        } else {
+            self.newline();
+        }
+        self.word("while (");
+        self.full_expression(&s.expression.node);
+        self.word(");");
+    }
+
+    fn for_statement(&mut self, s: &ForStatement) {
+        self.word("for (");
+        self.for_initializer(&s.initializer.node);
+        if let Some(ref e) = s.condition {
+            self.word(" ");
+            self.full_expression(&e.node);
+        }
+        self.word(";");
+        if let Some(ref e) = s.step {
+            self.word(" ");
+            self.full_expression(&e.node);
+        }
+        self.word(")");
+        self.body(&s.statement);
+    }
+
+    fn for_initializer(&mut self, i: &ForInitializer) {
+        match *i {
+            ForInitializer::Empty => self.word(";"),
+            ForInitializer::Expression(ref e) => {
+                self.full_expression(&e.node);
+                self.word(";");
+            }
+            ForInitializer::Declaration(ref d) => self.declaration(&d.node),
+            ForInitializer::StaticAssert(ref s) => self.static_assert(&s.node),
+        }
+    }
+
+    fn block_item(&mut self, i: &BlockItem) {
+        match *i {
+            BlockItem::Declaration(ref d) => self.declaration(&d.node),
+            BlockItem::StaticAssert(ref s) => self.static_assert(&s.node),
+            BlockItem::Statement(ref s) => self.statement_node(s),
+            BlockItem::Ambiguous(ref a) => self.ambiguous_block_item(&a.node),
+        }
+    }
+
+    /// Both interpretations have the same text, kept as in the source
+    fn ambiguous_block_item(&mut self, a: &AmbiguousBlockItem) {
+        self.statement_node(&a.statement)
+    }
+
+    // 6.9 External definitions
+
+    fn translation_unit(&mut self, unit: &TranslationUnit) {
+        let mut previous_function = false;
+        for (i, d) in unit.0.iter().enumerate() {
+            let function = matches!(d.node, ExternalDeclaration::FunctionDefinition(_));
+            self.flush_trivia(d.span.start);
+            if i > 0 && (function || previous_function) {
+                self.blank_line();
+            }
+            self.newline();
+            self.external_declaration(&d.node);
+            previous_function = function;
+        }
+        self.flush_trivia(usize::MAX);
+        self.trim_line_end();
+        while self.out.ends_with('\n') {
+            self.out.pop();
+        }
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+    }
+
+    fn external_declaration(&mut self, d: &ExternalDeclaration) {
+        match *d {
+            ExternalDeclaration::Declaration(ref d) => self.declaration(&d.node),
+            ExternalDeclaration::StaticAssert(ref s) => self.static_assert(&s.node),
+            ExternalDeclaration::FunctionDefinition(ref f) => self.function_definition(&f.node),
+        }
+    }
+
+    fn function_definition(&mut self, f: &FunctionDefinition) {
+        self.declaration_specifiers(&f.specifiers);
+        self.word(" ");
+        self.full_declarator(&f.declarator.node, true);
+        if f.declarations.is_empty() {
+            self.brace_separator(true);
+        } else {
+            self.indent += 1;
+            for d in &f.declarations {
+                self.newline();
+                self.declaration(&d.node);
+            }
+            self.indent -= 1;
+            self.newline();
+        }
+        self.statement_node(&f.statement);
+    }
+
+    // Extensions
+
+    /// Print extensions, grouping consecutive attributes into one `__attribute__` specifier
+    fn extensions(&mut self, exts: &[Node<Extension>]) {
+        let mut rest = exts;
+        while let Some(first) = rest.first() {
+            if rest.len() != exts.len() {
+                self.word(" ");
+            }
+            let attributes = rest.iter().take_while(|e| is_attribute(e)).count();
+            if attributes > 0 {
+                self.attribute_specifier(&rest[..attributes]);
+                rest = &rest[attributes..];
+            } else {
+                self.extension(&first.node);
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    fn trailing_extensions(&mut self, exts: &[Node<Extension>]) {
+        if !exts.is_empty() {
+            self.word(" ");
+            self.extensions(exts);
+        }
+    }
+
+    /// Print extensions that came from a single specifier
+    fn extension_specifier(&mut self, exts: &[Node<Extension>]) {
+        if exts.is_empty() {
+            self.attribute_specifier(exts);
+        } else {
+            self.extensions(exts);
+        }
+    }
+
+    fn attribute_specifier(&mut self, attributes: &[Node<Extension>]) {
+        self.word("__attribute__((");
+        self.list(attributes, ", ", |p, a| p.extension(&a.node));
+        self.word("))");
+    }
+
+    fn extension(&mut self, e: &Extension) {
+        match *e {
+            Extension::Attribute(ref a) => self.attribute(a),
+            Extension::AsmLabel(ref s) => {
+                self.word("__asm__(");
+                self.string_literal(&s.node);
+                self.word(")");
+            }
+            Extension::AvailabilityAttribute(ref a) => self.availability_attribute(&a.node),
+            Extension::VendorQualifier(ref v) => self.vendor_qualifier(v),
+        }
+    }
+
+    fn attribute(&mut self, a: &Attribute) {
+        self.word(&a.name.node);
+        if !a.arguments.is_empty() {
+            self.word("(");
+            self.list(&a.arguments, ", ", |p, e| p.expression(&e.node, ASSIGNMENT));
+            self.word(")");
+        }
+    }
+
+    fn vendor_qualifier(&mut self, v: &VendorQualifier) {
+        self.word(&v.name.node);
+        if !v.arguments.is_empty() {
+            self.word("(");
+            self.list(&v.arguments, ", ", |p, e| p.expression(&e.node, ASSIGNMENT));
+            self.word(")");
+        }
+    }
+
+    fn availability_attribute(&mut self, a: &AvailabilityAttribute) {
+        self.word("availability(");
+        self.identifier(&a.platform.node);
+        for c in &a.clauses {
+            self.word(", ");
+            self.availability_clause(&c.node);
+        }
+        self.word(")");
+    }
+
+    fn availability_clause(&mut self, c: &AvailabilityClause) {
+        match *c {
+            AvailabilityClause::Introduced(ref v) => {
+                self.word("introduced=");
+                self.availability_version(&v.node);
+            }
+            AvailabilityClause::Deprecated(ref v) => {
+                self.word("deprecated=");
+                self.availability_version(&v.node);
+            }
+            AvailabilityClause::Obsoleted(ref v) => {
+                self.word("obsoleted=");
+                self.availability_version(&v.node);
+            }
+            AvailabilityClause::Unavailable => self.word("unavailable"),
+            AvailabilityClause::Message(ref s) => {
+                self.word("message=");
+                self.string_literal(&s.node);
+            }
+            AvailabilityClause::Replacement(ref s) => {
+                self.word("replacement=");
+                self.string_literal(&s.node);
+            }
+        }
+    }
+
+    fn availability_version(&mut self, v: &AvailabilityVersion) {
+        self.word(&v.major);
+        if let Some(ref minor) = v.minor {
+            self.word(".");
+            self.word(minor);
+        }
+        if let Some(ref subminor) = v.subminor {
+            self.word(".");
+            self.word(subminor);
+        }
+    }
+
+    fn asm_statement(&mut self, s: &AsmStatement) {
+        match *s {
+            AsmStatement::GnuBasic(ref t) => {
+                self.word("__asm__(");
+                self.string_literal(&t.node);
+                self.word(");");
+            }
+            AsmStatement::GnuExtended(ref s) => self.gnu_extended_asm_statement(s),
+        }
+    }
+
+    fn gnu_extended_asm_statement(&mut self, s: &GnuExtendedAsmStatement) {
+        self.word("__asm__");
+        if let Some(ref q) = s.qualifier {
+            self.word(" ");
+            self.type_qualifier(&q.node);
+        }
+        self.word("(");
+        self.string_literal(&s.template.node);
+        self.word(" :");
+        self.asm_operands(&s.outputs);
+        if !s.inputs.is_empty() || !s.clobbers.is_empty() {
+            self.word(" :");
+            self.asm_operands(&s.inputs);
+        }
+        if !s.clobbers.is_empty() {
+            self.word(" : ");
+            self.list(&s.clobbers, ", ", |p, c| p.string_literal(&c.node));
+        }
+        self.word(");");
+    }
+
+    fn asm_operands(&mut self, operands: &[Node<GnuAsmOperand>]) {
+        if !operands.is_empty() {
+            self.word(" ");
+            self.list(operands, ", ", |p, o| p.gnu_asm_operand(&o.node));
+        }
+    }
+
+    fn gnu_asm_operand(&mut self, o: &GnuAsmOperand) {
+        if let Some(ref i) = o.symbolic_name {
+            self.word("[");
+            self.identifier(&i.node);
+            self.word("] ");
+        }
+        self.string_literal(&o.constraints.node);
+        self.word(" (");
+        self.full_expression(&o.variable_name.node);
+        self.word(")");
+    }
+
+    fn type_of(&mut self, t: &TypeOf) {
+        self.word("__typeof__(");
+        match *t {
+            TypeOf::Expression(ref e) => self.full_expression(&e.node),
+            TypeOf::Type(ref t) => self.type_name(&t.node),
+        }
+        self.word(")");
+    }
+}
+
+impl Default for Printer {
+    fn default() -> Printer {
+        Printer::new()
+    }
+}
+
+macro_rules! impl_print {
+    ($($ty:ident => $method:ident,)*) => {
+        $(
+            impl Print for $ty {
+                fn print(&self, printer: &mut Printer) {
+                    printer.$method(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_print! {
+    Identifier => identifier,
+    Constant => constant,
+    Integer => integer,
+    Float => float,
+    Expression => full_expression,
+    MemberOperator => member_operator,
+    GenericSelection => generic_selection,
+    GenericAssociation => generic_association,
+    GenericAssociationType => generic_association_type,
+    MemberExpression => member_expression,
+    CallExpression => call_expression,
+    CompoundLiteral => compound_literal,
+    UnaryOperator => unary_operator,
+    UnaryOperatorExpression => unary_operator_expression,
+    CastExpression => cast_expression,
+    BinaryOperator => binary_operator,
+    BinaryOperatorExpression => binary_operator_expression,
+    ConditionalExpression => conditional_expression,
+    VaArgExpression => va_arg_expression,
+    OffsetOfExpression => offsetof_expression,
+    OffsetDesignator => offset_designator,
+    OffsetMember => offset_member,
+    AmbiguousExpression => ambiguous_expression,
+    Declaration => declaration,
+    DeclarationSpecifier => declaration_specifier,
+    StorageClassSpecifier => storage_class_specifier,
+    TypeSpecifier => type_specifier,
+    TS18661FloatType => ts18661_float_type,
+    StructKind => struct_kind,
+    StructDeclaration => struct_declaration,
+    StructField => struct_field,
+    SpecifierQualifier => specifier_qualifier,
+    EnumType => enum_type,
+    Enumerator => enumerator,
+    TypeQualifier => type_qualifier,
+    FunctionSpecifier => function_specifier,
+    AlignmentSpecifier => alignment_specifier,
+    DeclaratorKind => declarator_kind,
+    DerivedDeclarator => derived_declarator,
+    ArrayDeclarator => array_declarator,
+    FunctionDeclarator => function_declarator,
+    PointerQualifier => pointer_qualifier,
+    ParameterDeclaration => parameter_declaration,
+    TypeName => type_name,
+    Initializer => initializer,
+    InitializerListItem => initializer_list_item,
+    Designator => designator,
+    RangeDesignator => range_designator,
+    StaticAssert => static_assert,
+    Statement => statement,
+    LabeledStatement => labeled_statement,
+    IfStatement => if_statement,
+    SwitchStatement => switch_statement,
+    WhileStatement => while_statement,
+    DoWhileStatement => do_while_statement,
+    ForStatement => for_statement,
+    Label => label,
+    ForInitializer => for_initializer,
+    BlockItem => block_item,
+    AmbiguousBlockItem => ambiguous_block_item,
+    TranslationUnit => translation_unit,
+    ExternalDeclaration => external_declaration,
+    FunctionDefinition => function_definition,
+    Extension => extension,
+    Attribute => attribute,
+    VendorQualifier => vendor_qualifier,
+    AvailabilityAttribute => availability_attribute,
+    AvailabilityClause => availability_clause,
+    AvailabilityVersion => availability_version,
+    AsmStatement => asm_statement,
+    GnuExtendedAsmStatement => gnu_extended_asm_statement,
+    GnuAsmOperand => gnu_asm_operand,
+    TypeOf => type_of,
+}
+
+impl Print for InitDeclarator {
+    fn print(&self, printer: &mut Printer) {
+        printer.init_declarator(self, false)
+    }
+}
+
+impl Print for StructType {
+    fn print(&self, printer: &mut Printer) {
+        printer.struct_type(self, 0)
+    }
+}
+
+impl Print for StructDeclarator {
+    fn print(&self, printer: &mut Printer) {
+        printer.struct_declarator(self, false)
+    }
+}
+
+impl Print for Declarator {
+    fn print(&self, printer: &mut Printer) {
+        printer.full_declarator(self, false)
+    }
+}
@@ -0,0 +1,376 @@
+//! Structural search with C code patterns
+//!
+//! ```rust
+//! # use lang_c::driver::{parse_preprocessed, Config};
+//! # use lang_c::query::Pattern;
+//! let source = "void f(char *a, char *b) { memcpy(a, b, sizeof(a)); memcpy(a, b, 16); }";
+//! let parse = parse_preprocessed(&Config::default(), source.to_string()).unwrap();
+//!
+//! let pattern = Pattern::new(&Config::default(), "memcpy($dst, $_, sizeof($dst))").unwrap();
+//! let matches = pattern.find(&parse.unit);
+//! assert_eq!(matches.len(), 1);
+//! let dst = matches[0].get("dst").unwrap();
+//! assert_eq!(&parse.source[dst.span.start..dst.span.end], "a");
+//! ```
+//!
+//! A pattern is a C expression or statement in which `$name` stands for any sub-expression. The
+//! expression bound to a metavariable is reported with the match. A metavariable used more than
+//! once matches only if all its occurrences are the same code, compared by structural equality
+//! of the nodes.
+//! `$_` matches anything and binds nothing.
+//!
+//! Metavariables also match other parts of the tree where C syntax allows an identifier:
+//!
+//! - `sizeof($t)` and `_Alignof($t)` match a type name as well as an expression,
+//! - `$s.$m` and `$s->$m` bind the member name,
+//! - `goto $l;` binds the label,
+//! - an expression statement `$s;` matches any statement.
+//!
+//! Other parts of a pattern, such as types of casts and declarations in blocks, match if they are
+//! structurally equal. A `$` in a string or character literal is part of the literal, so
+//! `printf("$x")` matches only that exact call. Patterns match anywhere in a translation unit, including within other
+//! matches.
+
+use ast::*;
+use astutil::{metavariable, replace_metavariables};
+use driver::{Config, SyntaxError};
+use env::Env;
+use parser;
+use span::{Node, Span, Structural};
+use visit::{self, Visit};
+use visit_path::NodeRef;
+
+/// Compiled search pattern
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    kind: PatternKind,
+}
+
+#[derive(Debug, Clone)]
+enum PatternKind {
+    Expression(Box<Node<Expression>>),
+    Statement(Box<Node<Statement>>),
+}
+
+/// Part of the tree matched by a pattern
+#[derive(Debug, Clone)]
+pub struct Match<'ast> {
+    /// Expression or statement matched by the whole pattern
+    pub node: NodeRef<'ast>,
+    pub span: Span,
+    /// Metavariables in order of their first occurrence in the pattern
+    pub bindings: Vec<(String, Binding<'ast>)>,
+}
+
+/// Node bound to a metavariable
+#[derive(Debug, Clone, Copy)]
+pub struct Binding<'ast> {
+    /// Expression, statement, type name or identifier
+    pub node: NodeRef<'ast>,
+    pub span: Span,
+}
+
+impl<'ast> Match<'ast> {
+    /// Node bound to a metavariable, named without the `$`
+    pub fn get(&self, name: &str) -> Option<&Binding<'ast>> {
+        self.bindings.iter().find(|b| b.0 == name).map(|b| &b.1)
+    }
+}
+
+impl Pattern {
+    /// Parse a pattern, as an expression if possible and as a statement otherwise
+    ///
+    /// Typedef names the pattern uses must be known to the configuration flavor, so patterns
+    /// with casts to types declared by a program do not parse.
+    pub fn new(config: &Config, text: &str) -> Result<Pattern, SyntaxError> {
+        let code = replace_metavariables(text);
+
+        let mut env = Env::with_config(config);
+        if let Ok(expr) = parser::expression(&code, &mut env) {
+            return Ok(Pattern {
+                kind: PatternKind::Expression(expr),
+            });
+        }
+
+        let mut env = Env::with_config(config);
+        match parser::statement(&code, &mut env) {
+            Ok(stmt) => Ok(Pattern {
+                kind: PatternKind::Statement(stmt),
+            }),
+            Err(err) => Err(SyntaxError {
+                source: code,
+                line: err.line,
+                column: err.column,
+                offset: err.offset,
+                expected: err.expected,
+            }),
+        }
+    }
+
+    /// Find all matches in a translation unit, in source order
+    pub fn find<'ast>(&self, unit: &'ast TranslationUnit) -> Vec<Match<'ast>> {
+        let mut finder = Finder {
+            pattern: self,
+            matches: Vec::new(),
+        };
+        finder.visit_translation_unit(unit);
+        finder.matches
+    }
+
+    /// Match an expression, without searching its sub-expressions
+    pub fn match_expression<'ast>(
+        &self,
+        expr: &'ast Expression,
+        span: &'ast Span,
+    ) -> Option<Match<'ast>> {
+        let pattern = match self.kind {
+            PatternKind::Expression(ref e) => e,
+            PatternKind::Statement(_) => return None,
+        };
+        let mut matcher = Matcher::default();
+        if matcher.expression(&pattern.node, expr, span) {
+            Some(matcher.finish(NodeRef::Expression(expr), *span))
+        } else {
+            None
+        }
+    }
+
+    /// Match a statement, without searching its sub-statements
+    pub fn match_statement<'ast>(
+        &self,
+        stmt: &'ast Statement,
+        span: &'ast Span,
+    ) -> Option<Match<'ast>> {
+        let pattern = match self.kind {
+            PatternKind::Statement(ref s) => s,
+            PatternKind::Expression(_) => return None,
+        };
+        let mut matcher = Matcher::default();
+        if matcher.statement(&pattern.node, stmt, span) {
+            Some(matcher.finish(NodeRef::Statement(stmt), *span))
+        } else {
+            None
+        }
+    }
+}
+
+/// Metavariable an expression consists of
+fn expression_metavariable(expr: &Expression) -> Option<&str> {
+    match *expr {
+        Expression::Identifier(ref i) => metavariable(&i.node),
+        _ => None,
+    }
+}
+
+/// Structural equality of bound nodes
+fn same(a: NodeRef, b: NodeRef) -> bool {
+    match (a, b) {
+        (NodeRef::Expression(a), NodeRef::Expression(b)) => Structural(a) == Structural(b),
+        (NodeRef::Statement(a), NodeRef::Statement(b)) => Structural(a) == Structural(b),
+        (NodeRef::TypeName(a), NodeRef::TypeName(b)) => Structural(a) == Structural(b),
+        (NodeRef::Identifier(a), NodeRef::Identifier(b)) => Structural(a) == Structural(b),
+        _ => false,
+    }
+}
+
+#[derive(Default)]
+struct Matcher<'ast> {
+    bindings: Vec<(String, Binding<'ast>)>,
+}
+
+impl<'ast> Matcher<'ast> {
+    fn finish(self, node: NodeRef<'ast>, span: Span) -> Match<'ast> {
+        Match {
+            node,
+            span,
+            bindings: self.bindings,
+        }
+    }
+
+    fn bind(&mut self, name: &str, node: NodeRef<'ast>, span: Span) -> bool {
+        if name == "_" {
+            return true;
+        }
+        if let Some(bound) = self.bindings.iter().find(|b| b.0 == name) {
+            return same(bound.1.node, node);
+        }
+        self.bindings
+            .push((name.to_owned(), Binding { node, span }));
+        true
+    }
+
+    fn identifier(&mut self, p: &Identifier, t: &'ast Node<Identifier>) -> bool {
+        match metavariable(p) {
+            Some(name) => self.bind(name, NodeRef::Identifier(&t.node), t.span),
+            None => p.name == t.node.name,
+        }
+    }
+
+    fn expression(&mut self, p: &Expression, t: &'ast Expression, span: &'ast Span) -> bool {
+        if let Some(name) = expression_metavariable(p) {
+            return self.bind(name, NodeRef::Expression(t), *span);
+        }
+
+        match (p, t) {
+            (Expression::Identifier(p), Expression::Identifier(t)) => p.node.name == t.node.name,
+            (Expression::Constant(p), Expression::Constant(t)) => p.node == t.node,
+            (Expression::StringLiteral(p), Expression::StringLiteral(t)) => p.node == t.node,
+            (Expression::Member(p), Expression::Member(t)) => {
+                p.node.operator.node == t.node.operator.node
+                    && self.expr(&p.node.expression, &t.node.expression)
+                    && self.identifier(&p.node.identifier.node, &t.node.identifier)
+            }
+            (Expression::Call(p), Expression::Call(t)) => {
+                p.node.arguments.len() == t.node.arguments.len()
+                    && self.expr(&p.node.callee, &t.node.callee)
+                    && self.exprs(&p.node.arguments, &t.node.arguments)
+            }
+            (Expression::UnaryOperator(p), Expression::UnaryOperator(t)) => {
+                p.node.operator.node == t.node.operator.node
+                    && self.expr(&p.node.operand, &t.node.operand)
+            }
+            (Expression::UnaryOperator(p), Expression::SizeOf(t)) => {
+                p.node.operator.node == UnaryOperator::SizeOf && self.type_name(&p.node.operand, t)
+            }
+            (Expression::AlignOf(p), Expression::AlignOf(t))
+            | (Expression::SizeOf(p), Expression::SizeOf(t)) => {
+                Structural(&p.node) == Structural(&t.node)
+            }
+            (Expression::Cast(p), Expression::Cast(t)) => {
+                Structural(&p.node.type_name) == Structural(&t.node.type_name)
+                    && self.expr(&p.node.expression, &t.node.expression)
+            }
+            (Expression::BinaryOperator(p), Expression::BinaryOperator(t)) => {
+                p.node.operator.node == t.node.operator.node
+                    && self.expr(&p.node.lhs, &t.node.lhs)
+                    && self.expr(&p.node.rhs, &t.node.rhs)
+            }
+            (Expression::Conditional(p), Expression::Conditional(t)) => {
+                self.expr(&p.node.condition, &t.node.condition)
+                    && self.expr(&p.node.then_expression, &t.node.then_expression)
+                    && self.expr(&p.node.else_expression, &t.node.else_expression)
+            }
+            (Expression::Comma(p), Expression::Comma(t)) => p.len() == t.len() && self.exprs(p, t),
+            (Expression::Statement(p), Expression::Statement(t)) => self.stmt(p, t),
+            (p, Expression::Ambiguous(t)) => {
+                let expression = &t.node.expression;
+                self.expression(p, &expression.node, &expression.span)
+            }
+            _ => Structural(p) == Structural(t),
+        }
+    }
+
+    /// Operand of `sizeof` or `_Alignof` in a pattern against a type name
+    fn type_name(&mut self, p: &Node<Expression>, t: &'ast Node<TypeName>) -> bool {
+        match expression_metavariable(&p.node) {
+            Some(name) => self.bind(name, NodeRef::TypeName(&t.node), t.span),
+            None => false,
+        }
+    }
+
+    fn expr(&mut self, p: &Node<Expression>, t: &'ast Node<Expression>) -> bool {
+        self.expression(&p.node, &t.node, &t.span)
+    }
+
+    fn exprs(&mut self, p: &[Node<Expression>], t: &'ast [Node<Expression>]) -> bool {
+        p.iter().zip(t).all(|(p, t)| self.expr(p, t))
+    }
+
+    fn opt_expr(
+        &mut self,
+        p: &Option<Box<Node<Expression>>>,
+        t: &'ast Option<Box<Node<Expression>>>,
+    ) -> bool {
+        match (p, t) {
+            (Some(p), Some(t)) => self.expr(p, t),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn statement(&mut self, p: &Statement, t: &'ast Statement, span: &'ast Span) -> bool {
+        if let Statement::Expression(Some(ref e)) = *p {
+            if let Some(name) = expression_metavariable(&e.node) {
+                return self.bind(name, NodeRef::Statement(t), *span);
+            }
+        }
+
+        match (p, t) {
+            (Statement::Compound(p), Statement::Compound(t)) => {
+                p.len() == t.len() && p.iter().zip(t).all(|(p, t)| self.block_item(p, t))
+            }
+            (Statement::Expression(p), Statement::Expression(t)) => self.opt_expr(p, t),
+            (Statement::If(p), Statement::If(t)) => {
+                let else_matches = match (&p.node.else_statement, &t.node.else_statement) {
+                    (Some(p), Some(t)) => self.stmt(p, t),
+                    (None, None) => true,
+                    _ => false,
+                };
+                self.expr(&p.node.condition, &t.node.condition)
+                    && self.stmt(&p.node.then_statement, &t.node.then_statement)
+                    && else_matches
+            }
+            (Statement::Switch(p), Statement::Switch(t)) => {
+                self.expr(&p.node.expression, &t.node.expression)
+                    && self.stmt(&p.node.statement, &t.node.statement)
+            }
+            (Statement::While(p), Statement::While(t)) => {
+                self.expr(&p.node.expression, &t.node.expression)
+                    && self.stmt(&p.node.statement, &t.node.statement)
+            }
+            (Statement::DoWhile(p), Statement::DoWhile(t)) => {
+                self.stmt(&p.node.statement, &t.node.statement)
+                    && self.expr(&p.node.expression, &t.node.expression)
+            }
+            (Statement::For(p), Statement::For(t)) => {
+                let init_matches = match (&p.node.initializer.node, &t.node.initializer.node) {
+                    (ForInitializer::Expression(p), ForInitializer::Expression(t)) => {
+                        self.expr(p, t)
+                    }
+                    (p, t) => Structural(p) == Structural(t),
+                };
+                init_matches
+                    && self.opt_expr(&p.node.condition, &t.node.condition)
+                    && self.opt_expr(&p.node.step, &t.node.step)
+                    && self.stmt(&p.node.statement, &t.node.statement)
+            }
+            (Statement::Goto(p), Statement::Goto(t)) => self.identifier(&p.node, t),
+            (Statement::Return(p), Statement::Return(t)) => self.opt_expr(p, t),
+            _ => Structural(p) == Structural(t),
+        }
+    }
+
+    fn stmt(&mut self, p: &Node<Statement>, t: &'ast Node<Statement>) -> bool {
+        self.statement(&p.node, &t.node, &t.span)
+    }
+
+    fn block_item(&mut self, p: &Node<BlockItem>, t: &'ast Node<BlockItem>) -> bool {
+        match (&p.node, &t.node) {
+            (BlockItem::Statement(p), BlockItem::Statement(t)) => self.stmt(p, t),
+            (BlockItem::Statement(p), BlockItem::Ambiguous(t)) => self.stmt(p, &t.node.statement),
+            (p, t) => Structural(p) == Structural(t),
+        }
+    }
+}
+
+/// Collects matches of a pattern
+struct Finder<'p, 'ast> {
+    pattern: &'p Pattern,
+    matches: Vec<Match<'ast>>,
+}
+
+impl<'p, 'ast> Visit<'ast> for Finder<'p, 'ast> {
+    fn visit_expression(&mut self, expr: &'ast Expression, span: &'ast Span) {
+        if let Some(m) = self.pattern.match_expression(expr, span) {
+            self.matches.push(m);
+        }
+        visit::visit_expression(self, expr, span);
+    }
+
+    fn visit_statement(&mut self, stmt: &'ast Statement, span: &'ast Span) {
+        if let Some(m) = self.pattern.match_statement(stmt, span) {
+            self.matches.push(m);
+        }
+        visit::visit_statement(self, stmt, span);
+    }
+}
@@ -0,0 +1,397 @@
+//! Build AST nodes from C snippets
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate lang_c;
+//! # use lang_c::print::to_string;
+//!
+//! # fn main() {
+//! let value = c_expr!("n - 1");
+//! let recurse = c_expr!("$n * $f($value)", n = c_expr!("n"), f = c_expr!("fact"), value = value);
+//! let body = c_stmt!("{ if (n <= 1) return 1; return $e; }", e = recurse);
+//! assert_eq!(to_string(&body), "{\n    if (n <= 1)\n        return 1;\n    return n * fact(n - 1);\n}");
+//! # }
+//! ```
+//!
+//! Snippets are parsed like any other code, except that `$name` placeholders are replaced with
+//! nodes given for them, called splices, after parsing. Spliced nodes keep their spans, nodes
+//! parsed from the snippet have undefined spans (see `Span::none`). A `$` inside a string or
+//! character literal is kept as written.
+//!
+//! Placeholders can stand for:
+//!
+//! - an expression, written wherever an expression may be,
+//! - an identifier, wherever a name may be: in declarators, member access or labels, as well as
+//!   in expressions,
+//! - a type specifier, such as a struct type or a typedef name, wherever a type may be,
+//! - a statement or a declaration, written as an expression statement `$s;`.
+//!
+//! `c_expr!`, `c_stmt!` and `c_decl!` take a snippet and splices as `name = value` pairs, where
+//! a value is a node or a boxed node of one of these types. They parse with the default
+//! configuration and panic if the snippet does not parse, which is usually what a code
+//! generator or a test needs for snippets written in the program. Functions of this module
+//! return errors instead and take the configuration to use, to choose the flavor and typedef
+//! names that snippets may refer to.
+
+use std::error;
+use std::fmt;
+
+use ast::*;
+use astutil::{metavariable, metavariable_identifier, original_offset, replace_metavariables};
+use driver::{Config, SyntaxError};
+use env::Env;
+use parser::{self, ParseResult};
+use span::{Node, Span};
+use visit_mut::{self, VisitMut};
+
+/// Node to put in place of a placeholder
+#[derive(Debug, Clone)]
+pub enum Splice {
+    Expression(Node<Expression>),
+    Identifier(Node<Identifier>),
+    TypeSpecifier(Node<TypeSpecifier>),
+    Statement(Node<Statement>),
+    Declaration(Node<Declaration>),
+}
+
+macro_rules! splice_from {
+    ($($ty:ident,)*) => {$(
+        impl From<Node<$ty>> for Splice {
+            fn from(node: Node<$ty>) -> Splice {
+                Splice::$ty(node)
+            }
+        }
+
+        impl From<Box<Node<$ty>>> for Splice {
+            fn from(node: Box<Node<$ty>>) -> Splice {
+                Splice::$ty(*node)
+            }
+        }
+
+        impl From<$ty> for Splice {
+            fn from(node: $ty) -> Splice {
+                Splice::$ty(Node::new(node, Span::none()))
+            }
+        }
+    )*};
+}
+
+splice_from! {
+    Expression,
+    Identifier,
+    TypeSpecifier,
+    Statement,
+    Declaration,
+}
+
+/// Error building a node from a snippet
+#[derive(Debug)]
+pub enum Error {
+    SyntaxError(SyntaxError),
+    /// Placeholder without a splice
+    UnknownPlaceholder(String),
+    /// Splice of a kind that cannot be used in place of its placeholder
+    MisplacedSplice(String),
+}
+
+impl From<SyntaxError> for Error {
+    fn from(e: SyntaxError) -> Error {
+        Error::SyntaxError(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::SyntaxError(ref e) => write!(fmt, "syntax error: {}", e),
+            Error::UnknownPlaceholder(ref name) => write!(fmt, "no splice for ${}", name),
+            Error::MisplacedSplice(ref name) => {
+                write!(fmt, "splice for ${} does not fit in its place", name)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::SyntaxError(_) => "syntax error",
+            Error::UnknownPlaceholder(_) => "unknown placeholder",
+            Error::MisplacedSplice(_) => "misplaced splice",
+        }
+    }
+}
+
+/// Build an expression
+pub fn expression(
+    config: &Config,
+    text: &str,
+    splices: &[(&str, Splice)],
+) -> Result<Box<Node<Expression>>, Error> {
+    quote(
+        config,
+        text,
+        splices,
+        parser::expression_fragment,
+        |v, e| v.visit_expression_mut(&mut e.node, &mut e.span),
+    )
+}
+
+/// Build a statement
+pub fn statement(
+    config: &Config,
+    text: &str,
+    splices: &[(&str, Splice)],
+) -> Result<Box<Node<Statement>>, Error> {
+    quote(config, text, splices, parser::statement_fragment, |v, s| {
+        v.visit_statement_mut(&mut s.node, &mut s.span)
+    })
+}
+
+/// Build a declaration
+pub fn declaration(
+    config: &Config,
+    text: &str,
+    splices: &[(&str, Splice)],
+) -> Result<Node<Declaration>, Error> {
+    quote(
+        config,
+        text,
+        splices,
+        parser::declaration_fragment,
+        |v, d| v.visit_declaration_mut(&mut d.node, &mut d.span),
+    )
+}
+
+fn quote<T, P, V>(
+    config: &Config,
+    text: &str,
+    splices: &[(&str, Splice)],
+    parse: P,
+    visit: V,
+) -> Result<T, Error>
+where
+    P: FnOnce(&str, &mut Env) -> ParseResult<T>,
+    V: Fn(&mut dyn VisitMut, &mut T),
+{
+    let code = replace_metavariables(text);
+    let mut env = Env::with_config(config);
+    for &(name, ref splice) in splices {
+        if let Splice::TypeSpecifier(_) = *splice {
+            env.add_typename(&metavariable_identifier(name));
+        }
+    }
+
+    let mut node = match parse(&code, &mut env) {
+        Ok(node) => node,
+        Err(err) => {
+            // Report the error in the snippet as written
+            let offset = original_offset(text, err.offset);
+            let before = &text[..offset];
+            return Err(Error::SyntaxError(SyntaxError {
+                source: text.to_owned(),
+                line: before.matches('\n').count() + 1,
+                column: before.chars().rev().take_while(|&c| c != '\n').count() + 1,
+                offset,
+                expected: err.expected,
+            }));
+        }
+    };
+
+    visit(&mut ResetSpans, &mut node);
+    let mut splicer = Splicer {
+        splices,
+        error: None,
+    };
+    visit(&mut splicer, &mut node);
+    match splicer.error {
+        Some(err) => Err(err),
+        None => Ok(node),
+    }
+}
+
+/// Placeholder an expression consists of
+fn placeholder(expr: &Expression) -> Option<String> {
+    match *expr {
+        Expression::Identifier(ref i) => metavariable(&i.node).map(str::to_owned),
+        _ => None,
+    }
+}
+
+/// Placeholder of an expression statement `$s;`
+fn statement_placeholder(stmt: &Statement) -> Option<String> {
+    match *stmt {
+        Statement::Expression(Some(ref e)) => placeholder(&e.node),
+        _ => None,
+    }
+}
+
+/// Replaces placeholders with splices
+struct Splicer<'a> {
+    splices: &'a [(&'a str, Splice)],
+    error: Option<Error>,
+}
+
+impl<'a> Splicer<'a> {
+    fn splice(&mut self, name: &str) -> Option<&'a Splice> {
+        let found = self.splices.iter().find(|s| s.0 == name);
+        if found.is_none() {
+            self.fail(Error::UnknownPlaceholder(name.to_owned()));
+        }
+        found.map(|s| &s.1)
+    }
+
+    fn fail(&mut self, error: Error) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+
+    fn misplaced(&mut self, name: &str) {
+        self.fail(Error::MisplacedSplice(name.to_owned()));
+    }
+}
+
+impl<'a> VisitMut for Splicer<'a> {
+    fn visit_expression_mut(&mut self, expression: &mut Expression, span: &mut Span) {
+        if let Some(name) = placeholder(expression) {
+            match self.splice(&name) {
+                Some(Splice::Expression(e)) => {
+                    *expression = e.node.clone();
+                    *span = e.span;
+                    return;
+                }
+                Some(Splice::Identifier(_)) => {}
+                Some(_) => return self.misplaced(&name),
+                None => return,
+            }
+        }
+        visit_mut::visit_expression_mut(self, expression, span)
+    }
+
+    fn visit_identifier_mut(&mut self, identifier: &mut Identifier, span: &mut Span) {
+        let name = match metavariable(identifier) {
+            Some(name) => name.to_owned(),
+            None => return,
+        };
+        match self.splice(&name) {
+            Some(Splice::Identifier(i)) => {
+                *identifier = i.node.clone();
+                *span = i.span;
+            }
+            Some(_) => self.misplaced(&name),
+            None => {}
+        }
+    }
+
+    fn visit_type_specifier_mut(&mut self, type_specifier: &mut TypeSpecifier, span: &mut Span) {
+        let name = match *type_specifier {
+            TypeSpecifier::TypedefName(ref i) => metavariable(&i.node).map(str::to_owned),
+            _ => None,
+        };
+        match name.map(|name| (self.splice(&name), name)) {
+            Some((Some(Splice::TypeSpecifier(t)), _)) => {
+                *type_specifier = t.node.clone();
+                *span = t.span;
+            }
+            Some((Some(_), name)) => self.misplaced(&name),
+            Some((None, _)) => {}
+            None => visit_mut::visit_type_specifier_mut(self, type_specifier, span),
+        }
+    }
+
+    fn visit_statement_mut(&mut self, statement: &mut Statement, span: &mut Span) {
+        if let Some(name) = statement_placeholder(statement) {
+            match self.splice(&name) {
+                Some(Splice::Statement(s)) => {
+                    *statement = s.node.clone();
+                    *span = s.span;
+                    return;
+                }
+                Some(Splice::Declaration(_)) => return self.misplaced(&name),
+                _ => {}
+            }
+        }
+        visit_mut::visit_statement_mut(self, statement, span)
+    }
+
+    fn visit_block_item_mut(&mut self, block_item: &mut BlockItem, span: &mut Span) {
+        let name = match *block_item {
+            BlockItem::Statement(ref s) => statement_placeholder(&s.node),
+            _ => None,
+        };
+        if let Some(name) = name {
+            if let Some(Splice::Declaration(d)) = self.splice(&name) {
+                *block_item = BlockItem::Declaration(d.clone());
+                *span = d.span;
+                return;
+            }
+        }
+        visit_mut::visit_block_item_mut(self, block_item, span)
+    }
+}
+
+/// Makes spans of nodes parsed from a snippet undefined
+struct ResetSpans;
+
+macro_rules! reset_spans {
+    ($($ty:ident: $_method:ident, $method:ident;)*) => {
+        impl VisitMut for ResetSpans {
+            $(fn $method(&mut self, node: &mut $ty, span: &mut Span) {
+                *span = Span::none();
+                visit_mut::$method(self, node, span);
+            })*
+
+            fn visit_availability_clause_mut(&mut self, _: &mut AvailabilityClause, span: &mut Span) {
+                *span = Span::none();
+            }
+        }
+    };
+}
+
+for_each_node_type!(reset_spans);
+
+/// Build an expression from a C snippet
+///
+/// See the `quote` module for details. Panics if the snippet is not valid.
+#[macro_export]
+macro_rules! c_expr {
+    ($text:expr $(, $name:ident = $value:expr)* $(,)*) => {
+        $crate::quote::expression(
+            &$crate::driver::Config::default(),
+            $text,
+            &[$((stringify!($name), $crate::quote::Splice::from($value))),*],
+        )
+        .unwrap_or_else(|e| panic!("c_expr!: {}", e))
+    };
+}
+
+/// Build a statement from a C snippet
+///
+/// See the `quote` module for details. Panics if the snippet is not valid.
+#[macro_export]
+macro_rules! c_stmt {
+    ($text:expr $(, $name:ident = $value:expr)* $(,)*) => {
+        $crate::quote::statement(
+            &$crate::driver::Config::default(),
+            $text,
+            &[$((stringify!($name), $crate::quote::Splice::from($value))),*],
+        )
+        .unwrap_or_else(|e| panic!("c_stmt!: {}", e))
+    };
+}
+
+/// Build a declaration from a C snippet
+///
+/// See the `quote` module for details. Panics if the snippet is not valid.
+#[macro_export]
+macro_rules! c_decl {
+    ($text:expr $(, $name:ident = $value:expr)* $(,)*) => {
+        $crate::quote::declaration(
+            &$crate::driver::Config::default(),
+            $text,
+            &[$((stringify!($name), $crate::quote::Splice::from($value))),*],
+        )
+        .unwrap_or_else(|e| panic!("c_decl!: {}", e))
+    };
+}
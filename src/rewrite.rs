@@ -0,0 +1,202 @@
+//! Rewrite source text by spans
+//!
+//! ```rust
+//! # use lang_c::ast::Statement;
+//! # use lang_c::driver::{parse_preprocessed, Config};
+//! # use lang_c::rewrite::Rewriter;
+//! # use lang_c::visit::{self, Visit};
+//! # use lang_c::span::Span;
+//! // Replace every `goto` with a call
+//! struct Gotos(Vec<Span>);
+//!
+//! impl<'ast> Visit<'ast> for Gotos {
+//!     fn visit_statement(&mut self, statement: &'ast Statement, span: &'ast Span) {
+//!         if let Statement::Goto(_) = *statement {
+//!             self.0.push(*span);
+//!         }
+//!         visit::visit_statement(self, statement, span);
+//!     }
+//! }
+//!
+//! let source = "void f(void) { goto out; out: return; }".to_string();
+//! let parse = parse_preprocessed(&Config::default(), source).unwrap();
+//! let mut gotos = Gotos(Vec::new());
+//! gotos.visit_translation_unit(&parse.unit);
+//!
+//! let mut rewriter = Rewriter::new(&parse.source);
+//! for span in gotos.0 {
+//!     rewriter.replace(span, "leave();");
+//! }
+//! assert_eq!(rewriter.finish().unwrap(), "void f(void) { leave(); out: return; }");
+//! ```
+//!
+//! Edits are collected first and applied together by `Rewriter::finish`, so all spans refer to
+//! the original text. Edits that change overlapping parts of the text are rejected. Insertions
+//! at the same position are applied in the order they were added, and before a replacement
+//! starting there.
+//!
+//! Spans of some nodes include whitespace that follows them. It is excluded from edited spans,
+//! so that inserting after or replacing a node leaves the whitespace in place.
+
+use std::error;
+use std::fmt;
+
+use ast::Expression;
+use print::{self, Print};
+use span::{Node, Span};
+
+/// Collects edits of a source text
+#[derive(Clone, Debug)]
+pub struct Rewriter<'a> {
+    source: &'a str,
+    edits: Vec<Edit>,
+}
+
+/// Replacement of a span of the source text
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edit {
+    /// Replaced text, empty for insertions
+    pub span: Span,
+    /// New text
+    pub text: String,
+}
+
+/// Two edits change overlapping parts of the source
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conflict {
+    pub first: Edit,
+    pub second: Edit,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "edit of {}..{} overlaps edit of {}..{}",
+            self.first.span.start,
+            self.first.span.end,
+            self.second.span.start,
+            self.second.span.end
+        )
+    }
+}
+
+impl error::Error for Conflict {
+    fn description(&self) -> &str {
+        "overlapping edits"
+    }
+}
+
+impl<'a> Rewriter<'a> {
+    /// Create a rewriter for the text spans refer to, such as `Parse::source`
+    pub fn new(source: &'a str) -> Rewriter<'a> {
+        Rewriter {
+            source,
+            edits: Vec::new(),
+        }
+    }
+
+    /// Edits added so far
+    pub fn edits(&self) -> &[Edit] {
+        &self.edits
+    }
+
+    /// Replace text of a span
+    ///
+    /// Panics if the span is undefined or out of the source bounds.
+    pub fn replace<S: Into<String>>(&mut self, span: Span, text: S) {
+        let span = self.trim(span);
+        self.edits.push(Edit {
+            span,
+            text: text.into(),
+        });
+    }
+
+    /// Remove text of a span
+    pub fn delete(&mut self, span: Span) {
+        self.replace(span, "");
+    }
+
+    /// Insert text before a span
+    pub fn insert_before<S: Into<String>>(&mut self, span: Span, text: S) {
+        let start = self.trim(span).start;
+        self.replace(Span::span(start, start), text);
+    }
+
+    /// Insert text after a span
+    pub fn insert_after<S: Into<String>>(&mut self, span: Span, text: S) {
+        let end = self.trim(span).end;
+        self.replace(Span::span(end, end), text);
+    }
+
+    /// Replace text of a span with a printed node
+    ///
+    /// The node is printed as by `print::to_string`, without any indentation. Use
+    /// `replace_operand` for expressions that are operands of other expressions.
+    pub fn replace_with<T: Print + ?Sized>(&mut self, span: Span, node: &T) {
+        self.replace(span, print::to_string(node));
+    }
+
+    /// Replace a node with another one
+    ///
+    /// As with `replace_with`, no parentheses are added around expressions.
+    pub fn replace_node<T: Print>(&mut self, node: &Node<T>, replacement: &T) {
+        self.replace_with(node.span, replacement);
+    }
+
+    /// Replace an operand of an expression with another expression
+    ///
+    /// The replacement is parenthesized if the operator of `parent` binds tighter, and
+    /// separated from a preceding operator it would merge with, as in `a - -b`.
+    pub fn replace_operand(
+        &mut self,
+        parent: &Expression,
+        operand: &Node<Expression>,
+        replacement: &Expression,
+    ) {
+        let mut text = print::operand_to_string(parent, operand, replacement);
+        let span = self.trim(operand.span);
+        let before = self.source[..span.start].chars().next_back();
+        let merges = match (before, text.chars().next()) {
+            (Some(a), Some(b)) => a == b && "+-&".contains(a) || a == '/' && b == '*',
+            _ => false,
+        };
+        if merges {
+            text.insert(0, ' ');
+        }
+        self.replace(span, text);
+    }
+
+    /// Apply all edits and return the rewritten text
+    pub fn finish(self) -> Result<String, Conflict> {
+        let mut edits = self.edits;
+        // Stable sort keeps insertions at the same position in order, and before replacements
+        edits.sort_by_key(|e| (e.span.start, e.span.end));
+
+        let mut out = String::with_capacity(self.source.len());
+        let mut pos = 0;
+        for (i, edit) in edits.iter().enumerate() {
+            if edit.span.start < pos {
+                return Err(Conflict {
+                    first: edits[i - 1].clone(),
+                    second: edit.clone(),
+                });
+            }
+            out.push_str(&self.source[pos..edit.span.start]);
+            out.push_str(&edit.text);
+            pos = edit.span.end;
+        }
+        out.push_str(&self.source[pos..]);
+        Ok(out)
+    }
+
+    /// Exclude trailing whitespace from a span
+    fn trim(&self, span: Span) -> Span {
+        assert!(!span.is_none(), "edit of an undefined span");
+        let text = &self.source[span.start..span.end];
+        match text.trim_end() {
+            "" => span,
+            t => Span::span(span.start, span.start + t.len()),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Data attached to nodes outside of the tree
+//!
+//! ```rust
+//! # use lang_c::driver::{parse_preprocessed, Config};
+//! # use lang_c::side_table::SideTable;
+//! # use lang_c::{ast, span, visit};
+//! // Remember the nesting depth of each compound statement
+//! struct Depth<'t> {
+//!     depth: usize,
+//!     table: &'t mut SideTable<usize>,
+//! }
+//!
+//! impl<'ast, 't> visit::Visit<'ast> for Depth<'t> {
+//!     fn visit_block_item(&mut self, item: &'ast ast::BlockItem, span: &'ast span::Span) {
+//!         if let ast::BlockItem::Statement(ref s) = *item {
+//!             self.table.insert(s, self.depth);
+//!         }
+//!         self.depth += 1;
+//!         visit::visit_block_item(self, item, span);
+//!         self.depth -= 1;
+//!     }
+//! }
+//!
+//! let source = "void f(void) { { return; } }".to_string();
+//! let parse = parse_preprocessed(&Config::default(), source).unwrap();
+//! let mut table = SideTable::new();
+//! visit::Visit::visit_translation_unit(&mut Depth { depth: 0, table: &mut table }, &parse.unit);
+//! assert_eq!(table.len(), 2);
+//! ```
+//!
+//! Nodes are identified by `span::NodeId`, so values attached to a node are not found for an
+//! equal node parsed separately. They are found for a clone of the node, unless it was given a
+//! new identifier with `Node::fresh_id`.
+
+use std::collections::hash_map::{self, HashMap};
+
+use span::{Node, NodeId};
+
+/// Map from nodes to values of type `T`
+#[derive(Clone, Debug)]
+pub struct SideTable<T> {
+    values: HashMap<NodeId, T>,
+}
+
+impl<T> SideTable<T> {
+    /// Create an empty table
+    pub fn new() -> SideTable<T> {
+        SideTable {
+            values: HashMap::new(),
+        }
+    }
+
+    /// Attach a value to a node, returning the value attached before
+    pub fn insert<N>(&mut self, node: &Node<N>, value: T) -> Option<T> {
+        self.values.insert(node.id, value)
+    }
+
+    /// Value attached to a node
+    pub fn get<N>(&self, node: &Node<N>) -> Option<&T> {
+        self.values.get(&node.id)
+    }
+
+    /// Mutable reference to the value attached to a node
+    pub fn get_mut<N>(&mut self, node: &Node<N>) -> Option<&mut T> {
+        self.values.get_mut(&node.id)
+    }
+
+    /// Detach the value from a node
+    pub fn remove<N>(&mut self, node: &Node<N>) -> Option<T> {
+        self.values.remove(&node.id)
+    }
+
+    /// Test if a value is attached to a node
+    pub fn contains<N>(&self, node: &Node<N>) -> bool {
+        self.values.contains_key(&node.id)
+    }
+
+    /// Value attached to a node identifier
+    pub fn get_id(&self, id: NodeId) -> Option<&T> {
+        self.values.get(&id)
+    }
+
+    /// Number of nodes with values
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Identifiers of nodes and their values, in no particular order
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter(self.values.iter())
+    }
+}
+
+impl<T> Default for SideTable<T> {
+    fn default() -> SideTable<T> {
+        SideTable::new()
+    }
+}
+
+/// Iterator over entries of a `SideTable`
+pub struct Iter<'a, T: 'a>(hash_map::Iter<'a, NodeId, T>);
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (NodeId, &'a T);
+
+    fn next(&mut self) -> Option<(NodeId, &'a T)> {
+        self.0.next().map(|(id, value)| (*id, value))
+    }
+}
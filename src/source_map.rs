@@ -0,0 +1,227 @@
+//! Files that parts of preprocessed source come from
+//!
+//! ```rust
+//! # use lang_c::source_map::SourceMap;
+//! let source = "# 1 \"main.c\"\n# 1 \"defs.h\" 1\ntypedef int T;\n# 3 \"main.c\" 2\nT x;\n";
+//! let map = SourceMap::new(source);
+//! let offset = source.find("T x").unwrap();
+//! let location = map.location(offset).unwrap();
+//! assert_eq!(map.file_name(location.file), "main.c");
+//! assert_eq!((location.line, location.column), (3, 1));
+//! assert_eq!(map.main_file(), map.file(offset));
+//! ```
+//!
+//! The preprocessor marks where included files start and end with line markers, like
+//! `# 12 "file.h"` (or `#line 12 "file.h"`): lines that follow the marker come from line 12 and
+//! on of `file.h`. A `SourceMap` collects the markers of one preprocessed text, so that spans of
+//! nodes parsed from it can be traced back to the original files.
+//!
+//! File identifiers are given out per file name, once for the whole process, so a file has the
+//! same identifier in the maps of all translation units that include it.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use intern::{Interner, Name};
+use line_index::LineIndex;
+use literal::{self, CodeUnits, Target};
+
+/// Identifier of a file, the same for all files of one name
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileId(usize);
+
+/// File names in the order their identifiers were given out
+#[derive(Default)]
+struct Paths {
+    names: Interner,
+    ids: HashMap<Name, FileId>,
+    paths: Vec<Name>,
+}
+
+fn paths() -> &'static Mutex<Paths> {
+    static PATHS: OnceLock<Mutex<Paths>> = OnceLock::new();
+    PATHS.get_or_init(Mutex::default)
+}
+
+impl FileId {
+    /// Identifier of the file with the given name
+    pub fn of(name: &str) -> FileId {
+        let mut paths = paths().lock().expect("file names are not poisoned");
+        let name = paths.names.intern(name);
+        let next = FileId(paths.paths.len());
+        let id = *paths.ids.entry(name.clone()).or_insert(next);
+        if id == next {
+            paths.paths.push(name);
+        }
+        id
+    }
+
+    /// Name of the file
+    pub fn name(self) -> Name {
+        let paths = paths().lock().expect("file names are not poisoned");
+        paths.paths[self.0].clone()
+    }
+}
+
+/// Position in an original file
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Location {
+    pub file: FileId,
+    /// Line number in the file, from one
+    pub line: usize,
+    /// Byte column in the line, from one
+    pub column: usize,
+}
+
+/// Lines of preprocessed text following a line marker
+#[derive(Clone, Debug)]
+struct Region {
+    /// Offset of the first line after the marker
+    start: usize,
+    file: FileId,
+    /// Number of that line in the file
+    line: usize,
+}
+
+/// Mapping from offsets in a preprocessed text to original files
+#[derive(Clone, Debug)]
+pub struct SourceMap<'a> {
+    lines: LineIndex<'a>,
+    files: Vec<(FileId, Name)>,
+    regions: Vec<Region>,
+}
+
+impl<'a> SourceMap<'a> {
+    /// Collect line markers of a preprocessed text, such as `Parse::source`
+    pub fn new(source: &'a str) -> SourceMap<'a> {
+        let mut names = HashMap::new();
+        let mut files = Vec::new();
+        let mut regions = Vec::new();
+        let mut offset = 0;
+
+        for line in source.split_inclusive('\n') {
+            offset += line.len();
+            if let Some((number, name)) = line_marker(line) {
+                let file = *names.entry(name.clone()).or_insert_with(|| {
+                    let file = FileId::of(&name);
+                    files.push((file, file.name()));
+                    file
+                });
+                regions.push(Region {
+                    start: offset,
+                    file,
+                    line: number,
+                });
+            }
+        }
+
+        SourceMap {
+            lines: LineIndex::new(source),
+            files,
+            regions,
+        }
+    }
+
+    /// File named by the first line marker, normally the file given to the preprocessor
+    ///
+    /// Markers of pseudo files like `<built-in>` and `<command-line>` are skipped, as some
+    /// preprocessors put them first.
+    pub fn main_file(&self) -> Option<FileId> {
+        let mut regions = self.regions.iter();
+        let main = regions.find(|r| !self.file_name(r.file).starts_with('<'));
+        main.map(|r| r.file)
+    }
+
+    /// Test if an offset of the preprocessed text comes from the main file
+    ///
+    /// Text without line markers counts as part of the main file. In text with markers, what
+    /// comes before the first one is not part of any file.
+    pub fn is_main_file(&self, offset: usize) -> bool {
+        match self.file(offset) {
+            Some(file) => self.main_file() == Some(file),
+            None => self.regions.is_empty(),
+        }
+    }
+
+    /// Name of a file as given in line markers
+    ///
+    /// Panics if the file has no line marker in this text, see `FileId::name` for other files.
+    pub fn file_name(&self, file: FileId) -> &str {
+        match self.files.iter().find(|f| f.0 == file) {
+            Some(f) => &f.1,
+            None => panic!("{:?} is not in the source map", file),
+        }
+    }
+
+    /// Find a file by name
+    pub fn file_id(&self, name: &str) -> Option<FileId> {
+        self.files.iter().find(|f| f.1 == name).map(|f| f.0)
+    }
+
+    /// All files, in order of their first line marker
+    pub fn files(&self) -> Vec<(FileId, &str)> {
+        self.files.iter().map(|f| (f.0, &f.1[..])).collect()
+    }
+
+    /// File an offset of the preprocessed text comes from
+    ///
+    /// Returns `None` for text before the first line marker.
+    pub fn file(&self, offset: usize) -> Option<FileId> {
+        self.region(offset).map(|r| r.file)
+    }
+
+    /// Position in the original file of an offset of the preprocessed text
+    ///
+    /// Columns are the same as in the preprocessed text, which differ from the original ones on
+    /// lines with expanded macros.
+    pub fn location(&self, offset: usize) -> Option<Location> {
+        let region = self.region(offset)?;
+        let pos = self.lines.line_col(offset);
+        let first = self.lines.line_col(region.start).line;
+        Some(Location {
+            file: region.file,
+            line: region.line + pos.line - first,
+            column: pos.column,
+        })
+    }
+
+    fn region(&self, offset: usize) -> Option<&Region> {
+        match self.regions.binary_search_by(|r| r.start.cmp(&offset)) {
+            Ok(i) => Some(&self.regions[i]),
+            Err(0) => None,
+            Err(i) => Some(&self.regions[i - 1]),
+        }
+    }
+}
+
+/// Line number and file name of a line marker
+///
+/// The name is a string literal, so escape sequences in it are decoded, as in
+/// `# 1 "C:\\dir\\a.h"`.
+fn line_marker(line: &str) -> Option<(usize, String)> {
+    let rest = line.strip_prefix('#')?.trim_start();
+    let rest = rest.strip_prefix("line").unwrap_or(rest).trim_start();
+    let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+    let number = rest[..digits].parse().ok()?;
+    let rest = rest[digits..].trim_start();
+    if !rest.starts_with('"') {
+        return None;
+    }
+
+    let mut chars = rest.char_indices().skip(1);
+    let end = loop {
+        match chars.next()? {
+            (_, '\\') => {
+                chars.next();
+            }
+            (i, '"') => break i + 1,
+            _ => (),
+        }
+    };
+
+    let literal = vec![rest[..end].to_owned()];
+    match literal::string_value(&literal, &Target::lp64()).ok()?.units {
+        CodeUnits::Bytes(ref bytes) => Some((number, String::from_utf8_lossy(bytes).into_owned())),
+        _ => None,
+    }
+}
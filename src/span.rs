@@ -1,9 +1,15 @@
 //! Source text location tracking
-use std::usize::MAX;
-use std::{cmp, fmt};
+use std::cell::Cell;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Byte offset of a node start and end positions in the input stream
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Span {
     pub start: usize,
     pub end: usize,
@@ -11,30 +17,22 @@ pub struct Span {
 
 impl Span {
     /// Create a new span for a specific location
+    #[allow(clippy::self_named_constructors)]
     pub fn span(start: usize, end: usize) -> Span {
-        Span {
-            start: start,
-            end: end,
-        }
+        Span { start, end }
     }
 
-    /// Create a new undefined span that is equal to any other span
+    /// Create a new undefined span, for nodes not parsed from source
     pub fn none() -> Span {
         Span {
-            start: MAX,
-            end: MAX,
+            start: usize::MAX,
+            end: usize::MAX,
         }
     }
 
     /// Test if span is undefined
     pub fn is_none(&self) -> bool {
-        self.start == MAX && self.end == MAX
-    }
-}
-
-impl cmp::PartialEq for Span {
-    fn eq(&self, other: &Self) -> bool {
-        (self.start == other.start && self.end == other.end) || self.is_none() || other.is_none()
+        self.start == usize::MAX && self.end == usize::MAX
     }
 }
 
@@ -48,19 +46,209 @@ impl fmt::Debug for Span {
     }
 }
 
+/// Identity of a node
+///
+/// Every node created by `Node::new`, including the ones built by the parser, gets an identifier
+/// not used by any other node created before in the same process. The identifier belongs to the
+/// node, not to its place in a tree: clones, folded and deserialized nodes keep it. Call
+/// `Node::fresh_id` on a copy that should be told apart from the original. Unlike spans,
+/// identifiers are fit for map keys, see `side_table::SideTable`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct NodeId(usize);
+
+/// Next identifier to hand out
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+impl NodeId {
+    /// Take a new identifier
+    pub fn fresh() -> NodeId {
+        NodeId(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Deserialized identifiers are kept, and are not handed out again by `NodeId::fresh`
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for NodeId {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<NodeId, D::Error> {
+        let id = usize::deserialize(deserializer)?;
+        NEXT_ID.fetch_max(id + 1, Ordering::Relaxed);
+        Ok(NodeId(id))
+    }
+}
+
 /// Associate a span with an arbitrary type
-#[derive(Debug, PartialEq, Clone)]
+///
+/// Nodes are equal if both their contents and spans are, the identifier does not take part.
+/// Wrap nodes in `Structural` to compare them ignoring spans.
+///
+/// The node identifier is not shown by `Debug`, wrap a tree in `Compact` to leave out spans as
+/// well. It is serialized as the `id` field; nodes deserialized without one get new identifiers.
+///
+/// Since the `id` field was added, nodes can no longer be built with a `Node { node, span }`
+/// literal; use `Node::new` instead.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Node<T> {
     pub node: T,
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(default = "NodeId::fresh"))]
+    pub id: NodeId,
 }
 
 impl<T> Node<T> {
     /// Create new node
     pub fn new(node: T, span: Span) -> Node<T> {
         Node {
-            node: node,
-            span: span,
+            node,
+            span,
+            id: NodeId::fresh(),
+        }
+    }
+
+    /// Give the node a new identifier, leaving the ones of nodes inside it unchanged
+    pub fn fresh_id(&mut self) {
+        self.id = NodeId::fresh();
+    }
+}
+
+impl<T: PartialEq> PartialEq for Node<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.span == other.span && self.node == other.node
+    }
+}
+
+impl<T: Eq> Eq for Node<T> {}
+
+/// Hashes the contents only, so that structurally equal nodes hash the same
+impl<T: Hash> Hash for Node<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Node<T> {
+    /// Inside of `Compact`, nodes print as their contents without spans.
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if COMPACT.with(|c| c.get()) {
+            return self.node.fmt(fmt);
+        }
+        fmt.debug_struct("Node")
+            .field("node", &self.node)
+            .field("span", &self.span)
+            .finish()
+    }
+}
+
+/// Formats a value with `Debug`, showing the nodes in it as their contents without spans
+///
+/// ```rust
+/// # use lang_c::driver::{parse_expression, Config};
+/// # use lang_c::span::Compact;
+/// let e = parse_expression(&Config::default(), "-x").unwrap();
+/// assert_eq!(
+///     format!("{:?}", Compact(&e)),
+///     "UnaryOperator(UnaryOperatorExpression { operator: Minus, \
+///      operand: Identifier(Identifier { name: \"x\" }) })"
+/// );
+/// ```
+pub struct Compact<'a, T: 'a + ?Sized>(pub &'a T);
+
+impl<'a, T: fmt::Debug + ?Sized> fmt::Debug for Compact<'a, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let _compact = CompactScope::new();
+        self.0.fmt(fmt)
+    }
+}
+
+thread_local! {
+    /// A `Compact` value is being formatted
+    static COMPACT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Makes nodes leave out spans from `Debug` output until dropped
+struct CompactScope(bool);
+
+impl CompactScope {
+    fn new() -> CompactScope {
+        CompactScope(COMPACT.with(|c| c.replace(true)))
+    }
+}
+
+impl Drop for CompactScope {
+    fn drop(&mut self) {
+        let previous = self.0;
+        COMPACT.with(|c| c.set(previous));
+    }
+}
+
+/// Compares and hashes a value by its structure, ignoring spans of all nodes in it
+///
+/// Trees parsed from differently formatted code are structurally equal if they differ only in
+/// spans, so wrapped nodes can be used to deduplicate code or as hash map keys.
+///
+/// ```rust
+/// # use lang_c::driver::{parse_preprocessed, Config};
+/// # use lang_c::span::Structural;
+/// let a = parse_preprocessed(&Config::default(), "int x=1;".into()).unwrap();
+/// let b = parse_preprocessed(&Config::default(), "int x = 1;".into()).unwrap();
+/// assert!(a.unit != b.unit);
+/// assert_eq!(Structural(&a.unit), Structural(&b.unit));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Structural<T>(pub T);
+
+impl<T: EqIgnoringSpans> PartialEq for Structural<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignoring_spans(&other.0)
+    }
+}
+
+impl<T: EqIgnoringSpans + Eq> Eq for Structural<T> {}
+
+impl<T: Hash> Hash for Structural<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+/// Equality that does not compare spans of nodes
+///
+/// Implemented for all AST types, nodes and containers of them.
+pub trait EqIgnoringSpans {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool;
+}
+
+impl<T: EqIgnoringSpans> EqIgnoringSpans for Node<T> {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.node.eq_ignoring_spans(&other.node)
+    }
+}
+
+impl<T: EqIgnoringSpans + ?Sized> EqIgnoringSpans for Box<T> {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        (**self).eq_ignoring_spans(other)
+    }
+}
+
+impl<T: EqIgnoringSpans + ?Sized> EqIgnoringSpans for &T {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        (**self).eq_ignoring_spans(other)
+    }
+}
+
+impl<T: EqIgnoringSpans> EqIgnoringSpans for Vec<T> {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.eq_ignoring_spans(b))
+    }
+}
+
+impl<T: EqIgnoringSpans> EqIgnoringSpans for Option<T> {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.eq_ignoring_spans(b),
+            (None, None) => true,
+            _ => false,
         }
     }
 }
@@ -0,0 +1,726 @@
+//! Comparison of AST nodes ignoring spans, see `span::Structural`
+
+use ast::*;
+use span::EqIgnoringSpans;
+
+/// Types without nodes in them, compared with `==`
+macro_rules! leaves {
+    ($($ty:ty),*) => {
+        $(impl EqIgnoringSpans for $ty {
+            fn eq_ignoring_spans(&self, other: &Self) -> bool {
+                self == other
+            }
+        })*
+    };
+}
+
+leaves!(
+    String,
+    Identifier,
+    Constant,
+    Integer,
+    IntegerBase,
+    IntegerSuffix,
+    IntegerSize,
+    Float,
+    FloatBase,
+    FloatSuffix,
+    FloatFormat,
+    MemberOperator,
+    UnaryOperator,
+    BinaryOperator,
+    StorageClassSpecifier,
+    TS18661FloatType,
+    TS18661FloatFormat,
+    StructKind,
+    TypeQualifier,
+    FunctionSpecifier,
+    Ellipsis,
+    AvailabilityVersion
+);
+
+impl EqIgnoringSpans for Expression {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Expression::Identifier(a), Expression::Identifier(b)) => a.eq_ignoring_spans(b),
+            (Expression::Constant(a), Expression::Constant(b)) => a.eq_ignoring_spans(b),
+            (Expression::StringLiteral(a), Expression::StringLiteral(b)) => a.eq_ignoring_spans(b),
+            (Expression::GenericSelection(a), Expression::GenericSelection(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (Expression::Member(a), Expression::Member(b)) => a.eq_ignoring_spans(b),
+            (Expression::Call(a), Expression::Call(b)) => a.eq_ignoring_spans(b),
+            (Expression::CompoundLiteral(a), Expression::CompoundLiteral(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (Expression::SizeOf(a), Expression::SizeOf(b)) => a.eq_ignoring_spans(b),
+            (Expression::AlignOf(a), Expression::AlignOf(b)) => a.eq_ignoring_spans(b),
+            (Expression::UnaryOperator(a), Expression::UnaryOperator(b)) => a.eq_ignoring_spans(b),
+            (Expression::Cast(a), Expression::Cast(b)) => a.eq_ignoring_spans(b),
+            (Expression::BinaryOperator(a), Expression::BinaryOperator(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (Expression::Conditional(a), Expression::Conditional(b)) => a.eq_ignoring_spans(b),
+            (Expression::Comma(a), Expression::Comma(b)) => a.eq_ignoring_spans(b),
+            (Expression::OffsetOf(a), Expression::OffsetOf(b)) => a.eq_ignoring_spans(b),
+            (Expression::VaArg(a), Expression::VaArg(b)) => a.eq_ignoring_spans(b),
+            (Expression::Statement(a), Expression::Statement(b)) => a.eq_ignoring_spans(b),
+            (Expression::Ambiguous(a), Expression::Ambiguous(b)) => a.eq_ignoring_spans(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for GenericSelection {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.expression.eq_ignoring_spans(&other.expression)
+            && self.associations.eq_ignoring_spans(&other.associations)
+    }
+}
+
+impl EqIgnoringSpans for GenericAssociation {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (GenericAssociation::Type(a), GenericAssociation::Type(b)) => a.eq_ignoring_spans(b),
+            (GenericAssociation::Default(a), GenericAssociation::Default(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for GenericAssociationType {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.type_name.eq_ignoring_spans(&other.type_name)
+            && self.expression.eq_ignoring_spans(&other.expression)
+    }
+}
+
+impl EqIgnoringSpans for MemberExpression {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.operator.eq_ignoring_spans(&other.operator)
+            && self.expression.eq_ignoring_spans(&other.expression)
+            && self.identifier.eq_ignoring_spans(&other.identifier)
+    }
+}
+
+impl EqIgnoringSpans for CallExpression {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.callee.eq_ignoring_spans(&other.callee)
+            && self.arguments.eq_ignoring_spans(&other.arguments)
+    }
+}
+
+impl EqIgnoringSpans for CompoundLiteral {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.type_name.eq_ignoring_spans(&other.type_name)
+            && self
+                .initializer_list
+                .eq_ignoring_spans(&other.initializer_list)
+    }
+}
+
+impl EqIgnoringSpans for UnaryOperatorExpression {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.operator.eq_ignoring_spans(&other.operator)
+            && self.operand.eq_ignoring_spans(&other.operand)
+    }
+}
+
+impl EqIgnoringSpans for CastExpression {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.type_name.eq_ignoring_spans(&other.type_name)
+            && self.expression.eq_ignoring_spans(&other.expression)
+    }
+}
+
+impl EqIgnoringSpans for BinaryOperatorExpression {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.operator.eq_ignoring_spans(&other.operator)
+            && self.lhs.eq_ignoring_spans(&other.lhs)
+            && self.rhs.eq_ignoring_spans(&other.rhs)
+    }
+}
+
+impl EqIgnoringSpans for ConditionalExpression {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.condition.eq_ignoring_spans(&other.condition)
+            && self
+                .then_expression
+                .eq_ignoring_spans(&other.then_expression)
+            && self
+                .else_expression
+                .eq_ignoring_spans(&other.else_expression)
+    }
+}
+
+impl EqIgnoringSpans for VaArgExpression {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.va_list.eq_ignoring_spans(&other.va_list)
+            && self.type_name.eq_ignoring_spans(&other.type_name)
+    }
+}
+
+impl EqIgnoringSpans for OffsetOfExpression {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.type_name.eq_ignoring_spans(&other.type_name)
+            && self.designator.eq_ignoring_spans(&other.designator)
+    }
+}
+
+impl EqIgnoringSpans for OffsetDesignator {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.base.eq_ignoring_spans(&other.base) && self.members.eq_ignoring_spans(&other.members)
+    }
+}
+
+impl EqIgnoringSpans for OffsetMember {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (OffsetMember::Member(a), OffsetMember::Member(b)) => a.eq_ignoring_spans(b),
+            (OffsetMember::IndirectMember(a), OffsetMember::IndirectMember(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (OffsetMember::Index(a), OffsetMember::Index(b)) => a.eq_ignoring_spans(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for AmbiguousExpression {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.cast.eq_ignoring_spans(&other.cast)
+            && self.expression.eq_ignoring_spans(&other.expression)
+    }
+}
+
+impl EqIgnoringSpans for Declaration {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.specifiers.eq_ignoring_spans(&other.specifiers)
+            && self.declarators.eq_ignoring_spans(&other.declarators)
+    }
+}
+
+impl EqIgnoringSpans for DeclarationSpecifier {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DeclarationSpecifier::StorageClass(a), DeclarationSpecifier::StorageClass(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (DeclarationSpecifier::TypeSpecifier(a), DeclarationSpecifier::TypeSpecifier(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (DeclarationSpecifier::TypeQualifier(a), DeclarationSpecifier::TypeQualifier(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (DeclarationSpecifier::Function(a), DeclarationSpecifier::Function(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (DeclarationSpecifier::Alignment(a), DeclarationSpecifier::Alignment(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (DeclarationSpecifier::Extension(a), DeclarationSpecifier::Extension(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for InitDeclarator {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.declarator.eq_ignoring_spans(&other.declarator)
+            && self.initializer.eq_ignoring_spans(&other.initializer)
+    }
+}
+
+impl EqIgnoringSpans for TypeSpecifier {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TypeSpecifier::Void, TypeSpecifier::Void) => true,
+            (TypeSpecifier::Char, TypeSpecifier::Char) => true,
+            (TypeSpecifier::Short, TypeSpecifier::Short) => true,
+            (TypeSpecifier::Int, TypeSpecifier::Int) => true,
+            (TypeSpecifier::Long, TypeSpecifier::Long) => true,
+            (TypeSpecifier::Float, TypeSpecifier::Float) => true,
+            (TypeSpecifier::Double, TypeSpecifier::Double) => true,
+            (TypeSpecifier::Signed, TypeSpecifier::Signed) => true,
+            (TypeSpecifier::Unsigned, TypeSpecifier::Unsigned) => true,
+            (TypeSpecifier::Bool, TypeSpecifier::Bool) => true,
+            (TypeSpecifier::Complex, TypeSpecifier::Complex) => true,
+            (TypeSpecifier::Atomic(a), TypeSpecifier::Atomic(b)) => a.eq_ignoring_spans(b),
+            (TypeSpecifier::Struct(a), TypeSpecifier::Struct(b)) => a.eq_ignoring_spans(b),
+            (TypeSpecifier::Enum(a), TypeSpecifier::Enum(b)) => a.eq_ignoring_spans(b),
+            (TypeSpecifier::TypedefName(a), TypeSpecifier::TypedefName(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (TypeSpecifier::TypeOf(a), TypeSpecifier::TypeOf(b)) => a.eq_ignoring_spans(b),
+            (TypeSpecifier::TS18661Float(a), TypeSpecifier::TS18661Float(b)) => a == b,
+            (TypeSpecifier::Fract, TypeSpecifier::Fract) => true,
+            (TypeSpecifier::Accum, TypeSpecifier::Accum) => true,
+            (TypeSpecifier::Sat, TypeSpecifier::Sat) => true,
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for StructType {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.kind.eq_ignoring_spans(&other.kind)
+            && self.identifier.eq_ignoring_spans(&other.identifier)
+            && self.declarations.eq_ignoring_spans(&other.declarations)
+    }
+}
+
+impl EqIgnoringSpans for StructDeclaration {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (StructDeclaration::Field(a), StructDeclaration::Field(b)) => a.eq_ignoring_spans(b),
+            (StructDeclaration::StaticAssert(a), StructDeclaration::StaticAssert(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for StructField {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.specifiers.eq_ignoring_spans(&other.specifiers)
+            && self.declarators.eq_ignoring_spans(&other.declarators)
+    }
+}
+
+impl EqIgnoringSpans for SpecifierQualifier {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SpecifierQualifier::TypeSpecifier(a), SpecifierQualifier::TypeSpecifier(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (SpecifierQualifier::TypeQualifier(a), SpecifierQualifier::TypeQualifier(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (SpecifierQualifier::Extension(a), SpecifierQualifier::Extension(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for StructDeclarator {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.declarator.eq_ignoring_spans(&other.declarator)
+            && self.bit_width.eq_ignoring_spans(&other.bit_width)
+    }
+}
+
+impl EqIgnoringSpans for EnumType {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.identifier.eq_ignoring_spans(&other.identifier)
+            && self.enumerators.eq_ignoring_spans(&other.enumerators)
+    }
+}
+
+impl EqIgnoringSpans for Enumerator {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.identifier.eq_ignoring_spans(&other.identifier)
+            && self.expression.eq_ignoring_spans(&other.expression)
+    }
+}
+
+impl EqIgnoringSpans for AlignmentSpecifier {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AlignmentSpecifier::Type(a), AlignmentSpecifier::Type(b)) => a.eq_ignoring_spans(b),
+            (AlignmentSpecifier::Constant(a), AlignmentSpecifier::Constant(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for Declarator {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.kind.eq_ignoring_spans(&other.kind)
+            && self.derived.eq_ignoring_spans(&other.derived)
+            && self.extensions.eq_ignoring_spans(&other.extensions)
+    }
+}
+
+impl EqIgnoringSpans for DeclaratorKind {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DeclaratorKind::Abstract, DeclaratorKind::Abstract) => true,
+            (DeclaratorKind::Identifier(a), DeclaratorKind::Identifier(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (DeclaratorKind::Declarator(a), DeclaratorKind::Declarator(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for DerivedDeclarator {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DerivedDeclarator::Pointer(a), DerivedDeclarator::Pointer(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (DerivedDeclarator::Array(a), DerivedDeclarator::Array(b)) => a.eq_ignoring_spans(b),
+            (DerivedDeclarator::Function(a), DerivedDeclarator::Function(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (DerivedDeclarator::KRFunction(a), DerivedDeclarator::KRFunction(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for ArrayDeclarator {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.qualifiers.eq_ignoring_spans(&other.qualifiers)
+            && self.size.eq_ignoring_spans(&other.size)
+    }
+}
+
+impl EqIgnoringSpans for FunctionDeclarator {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.parameters.eq_ignoring_spans(&other.parameters) && self.ellipsis == other.ellipsis
+    }
+}
+
+impl EqIgnoringSpans for PointerQualifier {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PointerQualifier::TypeQualifier(a), PointerQualifier::TypeQualifier(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (PointerQualifier::Extension(a), PointerQualifier::Extension(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for ArraySize {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ArraySize::Unknown, ArraySize::Unknown) => true,
+            (ArraySize::VariableUnknown, ArraySize::VariableUnknown) => true,
+            (ArraySize::VariableExpression(a), ArraySize::VariableExpression(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (ArraySize::StaticExpression(a), ArraySize::StaticExpression(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for ParameterDeclaration {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.specifiers.eq_ignoring_spans(&other.specifiers)
+            && self.declarator.eq_ignoring_spans(&other.declarator)
+            && self.extensions.eq_ignoring_spans(&other.extensions)
+    }
+}
+
+impl EqIgnoringSpans for TypeName {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.specifiers.eq_ignoring_spans(&other.specifiers)
+            && self.declarator.eq_ignoring_spans(&other.declarator)
+    }
+}
+
+impl EqIgnoringSpans for Initializer {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Initializer::Expression(a), Initializer::Expression(b)) => a.eq_ignoring_spans(b),
+            (Initializer::List(a), Initializer::List(b)) => a.eq_ignoring_spans(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for InitializerListItem {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.designation.eq_ignoring_spans(&other.designation)
+            && self.initializer.eq_ignoring_spans(&other.initializer)
+    }
+}
+
+impl EqIgnoringSpans for Designator {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Designator::Index(a), Designator::Index(b)) => a.eq_ignoring_spans(b),
+            (Designator::Member(a), Designator::Member(b)) => a.eq_ignoring_spans(b),
+            (Designator::Range(a), Designator::Range(b)) => a.eq_ignoring_spans(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for RangeDesignator {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.from.eq_ignoring_spans(&other.from) && self.to.eq_ignoring_spans(&other.to)
+    }
+}
+
+impl EqIgnoringSpans for StaticAssert {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.expression.eq_ignoring_spans(&other.expression)
+            && self.message.eq_ignoring_spans(&other.message)
+    }
+}
+
+impl EqIgnoringSpans for Statement {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Statement::Labeled(a), Statement::Labeled(b)) => a.eq_ignoring_spans(b),
+            (Statement::Compound(a), Statement::Compound(b)) => a.eq_ignoring_spans(b),
+            (Statement::Expression(a), Statement::Expression(b)) => a.eq_ignoring_spans(b),
+            (Statement::If(a), Statement::If(b)) => a.eq_ignoring_spans(b),
+            (Statement::Switch(a), Statement::Switch(b)) => a.eq_ignoring_spans(b),
+            (Statement::While(a), Statement::While(b)) => a.eq_ignoring_spans(b),
+            (Statement::DoWhile(a), Statement::DoWhile(b)) => a.eq_ignoring_spans(b),
+            (Statement::For(a), Statement::For(b)) => a.eq_ignoring_spans(b),
+            (Statement::Goto(a), Statement::Goto(b)) => a.eq_ignoring_spans(b),
+            (Statement::Continue, Statement::Continue) => true,
+            (Statement::Break, Statement::Break) => true,
+            (Statement::Return(a), Statement::Return(b)) => a.eq_ignoring_spans(b),
+            (Statement::Asm(a), Statement::Asm(b)) => a.eq_ignoring_spans(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for LabeledStatement {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.label.eq_ignoring_spans(&other.label)
+            && self.statement.eq_ignoring_spans(&other.statement)
+    }
+}
+
+impl EqIgnoringSpans for IfStatement {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.condition.eq_ignoring_spans(&other.condition)
+            && self.then_statement.eq_ignoring_spans(&other.then_statement)
+            && self.else_statement.eq_ignoring_spans(&other.else_statement)
+    }
+}
+
+impl EqIgnoringSpans for SwitchStatement {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.expression.eq_ignoring_spans(&other.expression)
+            && self.statement.eq_ignoring_spans(&other.statement)
+    }
+}
+
+impl EqIgnoringSpans for WhileStatement {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.expression.eq_ignoring_spans(&other.expression)
+            && self.statement.eq_ignoring_spans(&other.statement)
+    }
+}
+
+impl EqIgnoringSpans for DoWhileStatement {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.statement.eq_ignoring_spans(&other.statement)
+            && self.expression.eq_ignoring_spans(&other.expression)
+    }
+}
+
+impl EqIgnoringSpans for ForStatement {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.initializer.eq_ignoring_spans(&other.initializer)
+            && self.condition.eq_ignoring_spans(&other.condition)
+            && self.step.eq_ignoring_spans(&other.step)
+            && self.statement.eq_ignoring_spans(&other.statement)
+    }
+}
+
+impl EqIgnoringSpans for Label {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Label::Identifier(a), Label::Identifier(b)) => a.eq_ignoring_spans(b),
+            (Label::Case(a), Label::Case(b)) => a.eq_ignoring_spans(b),
+            (Label::Default, Label::Default) => true,
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for ForInitializer {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ForInitializer::Empty, ForInitializer::Empty) => true,
+            (ForInitializer::Expression(a), ForInitializer::Expression(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (ForInitializer::Declaration(a), ForInitializer::Declaration(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for BlockItem {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (BlockItem::Declaration(a), BlockItem::Declaration(b)) => a.eq_ignoring_spans(b),
+            (BlockItem::StaticAssert(a), BlockItem::StaticAssert(b)) => a.eq_ignoring_spans(b),
+            (BlockItem::Statement(a), BlockItem::Statement(b)) => a.eq_ignoring_spans(b),
+            (BlockItem::Ambiguous(a), BlockItem::Ambiguous(b)) => a.eq_ignoring_spans(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for AmbiguousBlockItem {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.declaration.eq_ignoring_spans(&other.declaration)
+            && self.statement.eq_ignoring_spans(&other.statement)
+    }
+}
+
+impl EqIgnoringSpans for TranslationUnit {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.0.eq_ignoring_spans(&other.0)
+    }
+}
+
+impl EqIgnoringSpans for ExternalDeclaration {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ExternalDeclaration::Declaration(a), ExternalDeclaration::Declaration(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (ExternalDeclaration::StaticAssert(a), ExternalDeclaration::StaticAssert(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (
+                ExternalDeclaration::FunctionDefinition(a),
+                ExternalDeclaration::FunctionDefinition(b),
+            ) => a.eq_ignoring_spans(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for FunctionDefinition {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.specifiers.eq_ignoring_spans(&other.specifiers)
+            && self.declarator.eq_ignoring_spans(&other.declarator)
+            && self.declarations.eq_ignoring_spans(&other.declarations)
+            && self.statement.eq_ignoring_spans(&other.statement)
+    }
+}
+
+impl EqIgnoringSpans for Extension {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Extension::Attribute(a), Extension::Attribute(b)) => a.eq_ignoring_spans(b),
+            (Extension::AsmLabel(a), Extension::AsmLabel(b)) => a.eq_ignoring_spans(b),
+            (Extension::AvailabilityAttribute(a), Extension::AvailabilityAttribute(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (Extension::VendorQualifier(a), Extension::VendorQualifier(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for Attribute {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.name.eq_ignoring_spans(&other.name)
+            && self.arguments.eq_ignoring_spans(&other.arguments)
+    }
+}
+
+impl EqIgnoringSpans for VendorQualifier {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.name.eq_ignoring_spans(&other.name)
+            && self.arguments.eq_ignoring_spans(&other.arguments)
+    }
+}
+
+impl EqIgnoringSpans for AvailabilityAttribute {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.platform.eq_ignoring_spans(&other.platform)
+            && self.clauses.eq_ignoring_spans(&other.clauses)
+    }
+}
+
+impl EqIgnoringSpans for AvailabilityClause {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AvailabilityClause::Introduced(a), AvailabilityClause::Introduced(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (AvailabilityClause::Deprecated(a), AvailabilityClause::Deprecated(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (AvailabilityClause::Obsoleted(a), AvailabilityClause::Obsoleted(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (AvailabilityClause::Unavailable, AvailabilityClause::Unavailable) => true,
+            (AvailabilityClause::Message(a), AvailabilityClause::Message(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            (AvailabilityClause::Replacement(a), AvailabilityClause::Replacement(b)) => {
+                a.eq_ignoring_spans(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for AsmStatement {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AsmStatement::GnuBasic(a), AsmStatement::GnuBasic(b)) => a.eq_ignoring_spans(b),
+            (AsmStatement::GnuExtended(a), AsmStatement::GnuExtended(b)) => a.eq_ignoring_spans(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoringSpans for GnuExtendedAsmStatement {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.qualifier.eq_ignoring_spans(&other.qualifier)
+            && self.template.eq_ignoring_spans(&other.template)
+            && self.outputs.eq_ignoring_spans(&other.outputs)
+            && self.inputs.eq_ignoring_spans(&other.inputs)
+            && self.clobbers.eq_ignoring_spans(&other.clobbers)
+    }
+}
+
+impl EqIgnoringSpans for GnuAsmOperand {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.symbolic_name.eq_ignoring_spans(&other.symbolic_name)
+            && self.constraints.eq_ignoring_spans(&other.constraints)
+            && self.variable_name.eq_ignoring_spans(&other.variable_name)
+    }
+}
+
+impl EqIgnoringSpans for TypeOf {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TypeOf::Expression(a), TypeOf::Expression(b)) => a.eq_ignoring_spans(b),
+            (TypeOf::Type(a), TypeOf::Type(b)) => a.eq_ignoring_spans(b),
+            _ => false,
+        }
+    }
+}
@@ -1,11 +1,68 @@
 use ast::*;
 use env::Env;
-use span::{Node, Span};
+use span::{EqIgnoringSpans, Node, Span};
+
+/// Parser entry points used by the tests
+///
+/// With the `serde` feature enabled, every successfully parsed value is also checked to survive a
+/// round trip through JSON unchanged.
+#[cfg(not(feature = "serde"))]
+mod parser {
+    pub use parser::{constant, declaration, expression, statement, translation_unit};
+}
 
-fn ident<T: From<Identifier>>(i: &str) -> T {
-    Identifier {
-        name: i.to_string(),
+#[cfg(feature = "serde")]
+mod parser {
+    use ast::*;
+    use env::Env;
+    use parser::ParseResult;
+    use span::Node;
+
+    macro_rules! round_trip_parsers {
+        ($($name:ident -> $ty:ty,)*) => {$(
+            pub fn $name(input: &str, env: &mut Env) -> ParseResult<$ty> {
+                let result = ::parser::$name(input, env);
+                if let Ok(ref value) = result {
+                    super::json_round_trip(value);
+                }
+                result
+            }
+        )*};
+    }
+
+    round_trip_parsers! {
+        constant -> Constant,
+        expression -> Box<Node<Expression>>,
+        declaration -> Node<Declaration>,
+        statement -> Box<Node<Statement>>,
+        translation_unit -> TranslationUnit,
+    }
+}
+
+/// Assert that two values are equal, ignoring spans of the nodes in them
+///
+/// Trees built by the tests have undefined spans, unlike parsed ones.
+macro_rules! assert_structural_eq {
+    ($left:expr, $right:expr) => {
+        assert_eq!(::span::Structural(&$left), ::span::Structural(&$right))
+    };
+    ($left:expr, $right:expr,) => {
+        assert_structural_eq!($left, $right)
+    };
+}
+
+impl<T: EqIgnoringSpans, E: PartialEq> EqIgnoringSpans for Result<T, E> {
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Ok(a), Ok(b)) => a.eq_ignoring_spans(b),
+            (Err(a), Err(b)) => a == b,
+            _ => false,
+        }
     }
+}
+
+fn ident<T: From<Identifier>>(i: &str) -> T {
+    Identifier { name: i.into() }
     .into()
 }
 
@@ -92,6 +149,7 @@ mk_from_inner! {
     TypeQualifier => PointerQualifier::TypeQualifier;
     TypeSpecifier => DeclarationSpecifier::TypeSpecifier;
     TypeSpecifier => SpecifierQualifier::TypeSpecifier;
+    VendorQualifier => Extension::VendorQualifier;
 }
 
 mod expr {
@@ -147,9 +205,9 @@ mod int {
 
     pub fn num<T: From<Constant>>(base: IntegerBase, number: &str, suffix: IntegerSuffix) -> T {
         Constant::Integer(Integer {
-            base: base,
+            base,
             number: number.to_string().into_boxed_str(),
-            suffix: suffix,
+            suffix,
         })
         .into()
     }
@@ -180,9 +238,9 @@ mod float {
 
     pub fn num<T: From<Constant>>(base: FloatBase, number: &str, suffix: FloatSuffix) -> T {
         Constant::Float(Float {
-            base: base,
+            base,
             number: number.to_string().into_boxed_str(),
-            suffix: suffix,
+            suffix,
         })
         .into()
     }
@@ -202,7 +260,7 @@ fn cchar(i: &str) -> Constant {
 }
 
 fn cstr<T: From<StringLiteral>>(i: &[&str]) -> T {
-    i.into_iter()
+    i.iter()
         .map(|s| String::from(*s))
         .collect::<Vec<String>>()
         .into()
@@ -212,7 +270,7 @@ fn cstr<T: From<StringLiteral>>(i: &[&str]) -> T {
 fn test_integer() {
     use self::int::{num, NONE, UL};
     use ast::IntegerBase::*;
-    use parser::constant;
+    use self::parser::constant;
 
     let env = &mut Env::new();
 
@@ -250,7 +308,7 @@ fn test_integer() {
 fn test_floating() {
     use self::float::*;
     use ast::FloatBase::*;
-    use parser::constant;
+    use self::parser::constant;
 
     let env = &mut Env::new();
 
@@ -316,7 +374,7 @@ fn test_floating() {
 fn ts18661_literal() {
     use self::float::*;
     use ast::FloatBase::*;
-    use parser::constant;
+    use self::parser::constant;
 
     let env = &mut Env::new();
 
@@ -348,7 +406,7 @@ fn ts18661_literal() {
 
 #[test]
 fn test_character() {
-    use parser::constant;
+    use self::parser::constant;
 
     let env = &mut Env::new();
 
@@ -362,17 +420,220 @@ fn test_character() {
     assert_eq!(constant(r"'\xde'", env), Ok(cchar(r"'\xde'")));
 }
 
+#[test]
+fn test_integer_values() {
+    use literal::{Error, IntegerType, Target};
+    use self::parser::constant;
+
+    let env = &mut Env::new();
+    let lp64 = Target::lp64();
+    let llp64 = Target::llp64();
+
+    let int = |s: &str, env: &mut Env| match constant(s, env) {
+        Ok(Constant::Integer(i)) => i,
+        r => panic!("{:?}", r),
+    };
+
+    assert_eq!(int("0", env).value(), Ok(0));
+    assert_eq!(int("0x7f", env).value(), Ok(127));
+    assert_eq!(int("0777", env).value(), Ok(511));
+    assert_eq!(int("0b101", env).value(), Ok(5));
+    assert_eq!(int("18446744073709551615u", env).value(), Ok(u64::MAX));
+    assert_eq!(int("18446744073709551616u", env).value(), Err(Error::IntegerOverflow));
+
+    assert_eq!(int("2147483647", env).integer_type(&lp64), Ok(IntegerType::Int));
+    assert_eq!(int("2147483648", env).integer_type(&lp64), Ok(IntegerType::Long));
+    assert_eq!(int("2147483648", env).integer_type(&llp64), Ok(IntegerType::LongLong));
+    assert_eq!(int("0x80000000", env).integer_type(&lp64), Ok(IntegerType::UnsignedInt));
+    assert_eq!(int("1u", env).integer_type(&lp64), Ok(IntegerType::UnsignedInt));
+    assert_eq!(int("1ll", env).integer_type(&lp64), Ok(IntegerType::LongLong));
+    assert_eq!(
+        int("0xffffffffffffffffl", env).integer_type(&lp64),
+        Ok(IntegerType::UnsignedLong)
+    );
+    assert_eq!(
+        int("18446744073709551615", env).integer_type(&lp64),
+        Err(Error::IntegerOverflow)
+    );
+}
+
+#[test]
+fn test_float_values() {
+    use literal::FloatValue;
+    use self::parser::constant;
+
+    let env = &mut Env::new();
+
+    let float = |s: &str, env: &mut Env| match constant(s, env) {
+        Ok(Constant::Float(f)) => f,
+        r => panic!("{:?}", r),
+    };
+
+    assert_eq!(
+        float("0012.500e-3", env).value(),
+        FloatValue {
+            base: FloatBase::Decimal,
+            significand: "125".to_owned(),
+            exponent: -4,
+        }
+    );
+    assert_eq!(float("1.25e-2", env).value(), float("0.0125", env).value());
+    assert_eq!(
+        float("0x1.8p1", env).value(),
+        FloatValue {
+            base: FloatBase::Hexadecimal,
+            significand: "18".to_owned(),
+            exponent: -3,
+        }
+    );
+    assert_eq!(float("0.0e10", env).value().significand, "");
+
+    assert_eq!(float("0.1", env).to_f64(), 0.1);
+    assert_eq!(float("0.1f", env).to_f32(), 0.1f32);
+    assert_eq!(float("1e400", env).to_f64(), f64::INFINITY);
+    assert_eq!(float("0x1.8p1", env).to_f64(), 3.0);
+    assert_eq!(float("0x1.fffffffffffff8p0", env).to_f64(), 2.0);
+    assert_eq!(float("0x1.fffffffffffff7p0", env).to_f64(), 2.0 - f64::EPSILON);
+    assert_eq!(float("0x1p-1074", env).to_f64(), f64::from_bits(1));
+    assert_eq!(float("0x1p-1075", env).to_f64(), 0.0);
+    assert_eq!(float("0x1p1024", env).to_f64(), f64::INFINITY);
+    assert_eq!(float("0x1.fffffep127", env).to_f32(), f32::MAX);
+    assert_eq!(float("0x1p-149", env).to_f32(), 1.4e-45f32);
+}
+
+#[test]
+fn test_character_values() {
+    use literal::{character_value, Encoding, Error, Target};
+    use self::parser::{expression, translation_unit};
+
+    let target = &Target::lp64();
+    let value = |s: &str| character_value(s, target).map(|c| c.value(target));
+
+    assert_eq!(value("'a'"), Ok(97));
+    assert_eq!(value(r"'\n'"), Ok(10));
+    assert_eq!(value(r"'\''"), Ok(39));
+    assert_eq!(value(r"'\0'"), Ok(0));
+    assert_eq!(value(r"'\177'"), Ok(127));
+    assert_eq!(value(r"'\xff'"), Ok(-1));
+    assert_eq!(value(r"'\x100'"), Err(Error::EscapeOutOfRange));
+    assert_eq!(value(r"'\q'"), Err(Error::InvalidEscape));
+    assert_eq!(value(r"'\u0041'"), Err(Error::InvalidUniversalCharacterName));
+
+    // Multi-character constants follow GCC
+    assert_eq!(value("'ab'"), Ok(24930));
+    assert_eq!(value("'abcde'"), Ok(1650680933));
+    assert_eq!(value("L'ab'"), Err(Error::PrefixedMultiCharacter));
+    assert_eq!(value(r"u'a\n'"), Err(Error::PrefixedMultiCharacter));
+
+    assert_eq!(value(r"L'\u00e9'"), Ok(0xe9));
+    assert_eq!(value(r"U'\U0001F600'"), Ok(0x1F600));
+    assert_eq!(value(r"L'\x10000'"), Ok(0x10000));
+    assert_eq!(value(r"u'\x10000'"), Err(Error::EscapeOutOfRange));
+
+    let c = character_value("'é'", target).unwrap();
+    assert_eq!(c.encoding, Encoding::Plain);
+    assert_eq!(c.units, vec![0xc3, 0xa9]);
+    assert_eq!(c.code_point(), Some('é'));
+
+    let c = character_value(r"u'\U0001F600'", target).unwrap();
+    assert_eq!(c.units, vec![0xd83d, 0xde00]);
+    assert_eq!(c.code_point(), Some('\u{1F600}'));
+
+    // Prefixed constants parse from C source and decode from the constant node
+    let env = &mut Env::new();
+    let mut parsed = |s: &str| match expression(s, env).unwrap().node {
+        Expression::Constant(ref c) => match c.node {
+            Constant::Character(ref c) => character_value(c, target).map(|c| c.value(target)),
+            ref other => panic!("expected a character constant, got {:?}", other),
+        },
+        ref other => panic!("expected a constant, got {:?}", other),
+    };
+    assert_eq!(parsed("L'a'"), Ok(97));
+    assert_eq!(parsed(r"u'\u00e9'"), Ok(0xe9));
+    assert_eq!(parsed(r"U'\U0001F600'"), Ok(0x1F600));
+    assert_eq!(parsed("L'ab'"), Err(Error::PrefixedMultiCharacter));
+    assert!(translation_unit("int c = L'a', d = u'b' + U'c';", env).is_ok());
+    assert_eq!(character_value("'ab'", target).unwrap().code_point(), None);
+}
+
 #[test]
 fn test_string() {
     use self::expr::*;
-    use parser::expression;
+    use self::parser::expression;
 
     let env = &mut Env::new();
 
-    assert_eq!(expression(r#""foo""#, env), Ok(string(r#""foo""#)));
-    assert_eq!(expression(r#""foo\n""#, env), Ok(string(r#""foo\n""#)));
-    assert_eq!(expression(r#""\'\"""#, env), Ok(string(r#""\'\"""#)));
-    assert_eq!(expression(r#""\xaf""#, env), Ok(string(r#""\xaf""#)));
+    assert_structural_eq!(expression(r#""foo""#, env), Ok(string(r#""foo""#)));
+    assert_structural_eq!(expression(r#""foo\n""#, env), Ok(string(r#""foo\n""#)));
+    assert_structural_eq!(expression(r#""\'\"""#, env), Ok(string(r#""\'\"""#)));
+    assert_structural_eq!(expression(r#""\xaf""#, env), Ok(string(r#""\xaf""#)));
+}
+
+#[test]
+fn test_string_values() {
+    use literal::{string_value, CodeUnits, Encoding, Error, Target};
+    use self::parser::{expression, translation_unit};
+
+    let target = &Target::lp64();
+    let value = |s: &[&str]| string_value(&cstr(s), target);
+
+    let s = value(&[r#""a\tb""#, r#""\x41""#]).unwrap();
+    assert_eq!(s.encoding, Encoding::Plain);
+    assert_eq!(s.units, CodeUnits::Bytes(b"a\tbA".to_vec()));
+    assert_eq!(s.array_len(), 5);
+
+    // Hexadecimal escapes end at the end of each piece
+    let s = value(&[r#""\x4""#, r#""1""#]).unwrap();
+    assert_eq!(s.units, CodeUnits::Bytes(vec![4, b'1']));
+
+    let s = value(&[r#""é""#, r#"u8"\u00e9""#]).unwrap();
+    assert_eq!(s.encoding, Encoding::Utf8);
+    assert_eq!(s.units, CodeUnits::Bytes(vec![0xc3, 0xa9, 0xc3, 0xa9]));
+    assert_eq!(s.text(), Some("éé".to_owned()));
+
+    let s = value(&[r#""a""#, r#"u"\U0001F600""#]).unwrap();
+    assert_eq!(s.encoding, Encoding::Char16);
+    assert_eq!(s.units, CodeUnits::Utf16(vec![0x61, 0xd83d, 0xde00]));
+    assert_eq!(s.array_len(), 4);
+
+    let s = value(&[r#"L"\x10000""#]).unwrap();
+    assert_eq!(s.units, CodeUnits::Utf32(vec![0x10000]));
+    assert_eq!(
+        string_value(&cstr(&[r#"L"\x10000""#]), &Target::llp64()),
+        Err(Error::EscapeOutOfRange)
+    );
+
+    let s = value(&[r#""""#]).unwrap();
+    assert!(s.units.is_empty());
+    assert_eq!(s.array_len(), 1);
+
+    assert_eq!(
+        value(&[r#"u"a""#, r#"L"b""#]),
+        Err(Error::IncompatibleEncodings)
+    );
+    assert_eq!(
+        value(&[r#"u8"a""#, r#"U"b""#]),
+        Err(Error::IncompatibleEncodings)
+    );
+    assert_eq!(value(&[r#""\q""#]), Err(Error::InvalidEscape));
+
+    // Concatenated literals with mixed prefixes parse from C source as one literal node
+    let env = &mut Env::new();
+    let mut parsed = |s: &str| match expression(s, env).unwrap().node {
+        Expression::StringLiteral(ref s) => string_value(&s.node, target),
+        ref other => panic!("expected a string literal, got {:?}", other),
+    };
+    let s = parsed(r#"L"ab" "c""#).unwrap();
+    assert_eq!(s.encoding, Encoding::Wide);
+    assert_eq!(s.units, CodeUnits::Utf32(vec![0x61, 0x62, 0x63]));
+    let s = parsed(r#""x" u8"\u00e9""#).unwrap();
+    assert_eq!(s.text(), Some("xé".to_owned()));
+    let s = parsed(r#"u"a" "b""#).unwrap();
+    assert_eq!(s.units, CodeUnits::Utf16(vec![0x61, 0x62]));
+    let s = parsed(r#"U"\U0001F600""#).unwrap();
+    assert_eq!(s.units, CodeUnits::Utf32(vec![0x1F600]));
+    assert_eq!(parsed(r#"u"a" L"b""#), Err(Error::IncompatibleEncodings));
+    assert!(translation_unit(r#"int *p = L"ab"; char *q = u8"é" "e";"#, env).is_ok());
 }
 
 #[test]
@@ -381,15 +642,15 @@ fn test_postfix() {
     use ast::BinaryOperator::Index;
     use ast::MemberOperator::{Direct, Indirect};
     use ast::UnaryOperator::PostIncrement;
-    use parser::expression;
+    use self::parser::expression;
 
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("a  ++", env),
         Ok(unop(PostIncrement, ident("a")))
     );
-    assert_eq!(
+    assert_structural_eq!(
         expression("a.b->c[ d[ e ] ] ++", env),
         Ok(unop(
             PostIncrement,
@@ -407,11 +668,11 @@ fn test_multiplicative() {
     use self::expr::*;
     use ast::BinaryOperator::{Divide, Multiply};
     use ast::UnaryOperator::{PostDecrement, PreIncrement};
-    use parser::expression;
+    use self::parser::expression;
 
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("a-- * ++b / c", env),
         Ok(binop(
             Divide,
@@ -429,10 +690,10 @@ fn test_multiplicative() {
 fn test_logical_and() {
     use self::expr::*;
     use ast::BinaryOperator::LogicalAnd;
-    use parser::expression;
+    use self::parser::expression;
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("a && b", env),
         Ok(binop(LogicalAnd, ident("a"), ident("b")))
     );
@@ -442,10 +703,10 @@ fn test_logical_and() {
 fn test_chained_and() {
     use self::expr::*;
     use ast::BinaryOperator::LogicalAnd;
-    use parser::expression;
+    use self::parser::expression;
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("a && b && c", env),
         Ok(binop(
             LogicalAnd,
@@ -459,9 +720,9 @@ fn test_chained_and() {
 fn test_chained_or() {
     use self::expr::*;
     use ast::BinaryOperator::LogicalOr;
-    use parser::expression;
+    use self::parser::expression;
     let env = &mut Env::new();
-    assert_eq!(
+    assert_structural_eq!(
         expression("a || b || c", env),
         Ok(binop(
             LogicalOr,
@@ -475,9 +736,9 @@ fn test_chained_or() {
 fn test_chained_shl() {
     use self::expr::*;
     use ast::BinaryOperator::ShiftLeft;
-    use parser::expression;
+    use self::parser::expression;
     let env = &mut Env::new();
-    assert_eq!(
+    assert_structural_eq!(
         expression("a << b << c", env),
         Ok(binop(
             ShiftLeft,
@@ -491,9 +752,9 @@ fn test_chained_shl() {
 fn test_chained_shr() {
     use self::expr::*;
     use ast::BinaryOperator::ShiftRight;
-    use parser::expression;
+    use self::parser::expression;
     let env = &mut Env::new();
-    assert_eq!(
+    assert_structural_eq!(
         expression("a >> b >> c", env),
         Ok(binop(
             ShiftRight,
@@ -506,14 +767,14 @@ fn test_chained_shr() {
 #[test]
 fn test_comma() {
     use ast::Expression::Comma;
-    use parser::expression;
+    use self::parser::expression;
 
     let env = &mut Env::new();
 
-    assert_eq!(expression("a", env), Ok(ident("a")));
-    assert_eq!(
+    assert_structural_eq!(expression("a", env), Ok(ident("a")));
+    assert_structural_eq!(
         expression("a, a, a,a\n,a", env),
-        Ok(Comma(Box::new(vec![ident("a"); 5].into())).into())
+        Ok(Comma(Box::new(vec![ident("a"); 5])).into())
     );
 }
 
@@ -522,11 +783,11 @@ fn test_cast() {
     use ast::TypeName;
     use ast::TypeSpecifier::Int;
     use env::Env;
-    use parser::expression;
+    use self::parser::expression;
 
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("(int) 1", env),
         Ok(CastExpression {
             type_name: TypeName {
@@ -549,11 +810,11 @@ fn test_declaration1() {
     use ast::StorageClassSpecifier::Typedef;
     use ast::TypeQualifier::Const;
     use ast::TypeSpecifier::Int;
-    use parser::declaration;
+    use self::parser::declaration;
 
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration("int typedef * foo, baz[static 10][const *];", env),
         Ok(Declaration {
             specifiers: vec![Int.into(), Typedef.into()],
@@ -604,11 +865,11 @@ fn test_declaration2() {
     use ast::Enumerator;
     use ast::StorageClassSpecifier::Typedef;
     use ast::TypeQualifier::Const;
-    use parser::declaration;
+    use self::parser::declaration;
 
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration("typedef enum { FOO, BAR = 1 } * const foobar;", env),
         Ok(Declaration {
             specifiers: vec![
@@ -650,11 +911,11 @@ fn test_declaration2() {
 #[test]
 fn test_declaration3() {
     use ast::TypeSpecifier::{Float, Int};
-    use parser::declaration;
+    use self::parser::declaration;
 
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration("struct { int a, b; float c; } S;", env).unwrap(),
         Declaration {
             specifiers: vec![StructType {
@@ -729,9 +990,9 @@ fn test_declaration3() {
 fn test_declaration4() {
     use ast::TypeQualifier::Restrict;
     use ast::TypeSpecifier::Int;
-    use parser::declaration;
+    use self::parser::declaration;
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration("int __restrict__;", &mut Env::with_core()),
         Ok(Declaration {
             specifiers: vec![Int.into()],
@@ -749,7 +1010,7 @@ fn test_declaration4() {
         .into())
     );
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration("int __restrict__;", &mut Env::with_gnu()),
         Ok(Declaration {
             specifiers: vec![Int.into(), Restrict.into()],
@@ -767,14 +1028,14 @@ fn test_declaration5() {
     use ast::DerivedDeclarator::Pointer;
     use ast::TypeQualifier::Const;
     use ast::TypeSpecifier::{Char, Int, TypedefName};
-    use parser::declaration;
+    use self::parser::declaration;
 
     let env = &mut Env::new();
 
     env.add_typename("FILE");
     env.add_typename("size_t");
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration(
             "char *fparseln(FILE *, size_t *, size_t *, const char[3], int);",
             env
@@ -872,12 +1133,12 @@ fn test_attribute() {
     use ast::Extension::AsmLabel;
     use ast::StorageClassSpecifier::Extern;
     use ast::TypeSpecifier::{Char, Int, TypedefName};
-    use parser::declaration;
+    use self::parser::declaration;
 
     let env = &mut Env::new();
     env.add_typename("size_t");
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration(
             concat!(
                 "extern int strerror_r (int __errnum, char *__buf, size_t __buflen)\n",
@@ -972,9 +1233,9 @@ fn test_attribute2() {
     use ast::DerivedDeclarator::Pointer;
     use ast::TypeQualifier::Const;
     use ast::TypeSpecifier::{Char, Void};
-    use parser::declaration;
+    use self::parser::declaration;
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration(
             r#"__attribute__((noreturn)) void d0 (void),
                 __attribute__((format(printf, 1, 2))) d1 (const char *, ...),
@@ -1075,9 +1336,9 @@ fn test_attribute3() {
     use ast::StorageClassSpecifier::Extern;
     use ast::TypeQualifier::{Const, Restrict};
     use ast::TypeSpecifier::Char;
-    use parser::translation_unit;
+    use self::parser::translation_unit;
 
-    assert_eq!(
+    assert_structural_eq!(
         translation_unit(
             concat!(
                 "extern __inline __attribute__ ((__always_inline__)) __attribute__ \n",
@@ -1179,7 +1440,6 @@ fn test_attribute3() {
             )
             .into()
         ]))
-        .into()
     );
 }
 
@@ -1187,9 +1447,9 @@ fn test_attribute3() {
 fn test_alignof() {
     use ast::Expression::AlignOf;
     use ast::TypeSpecifier::Long;
-    use parser::expression;
+    use self::parser::expression;
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("_Alignof(long long)", &mut Env::new()),
         Ok(AlignOf(
             TypeName {
@@ -1201,7 +1461,7 @@ fn test_alignof() {
         .into())
     );
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("__alignof(long long)", &mut Env::new()),
         Ok(AlignOf(
             TypeName {
@@ -1213,7 +1473,7 @@ fn test_alignof() {
         .into())
     );
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("__alignof__(long long)", &mut Env::new()),
         Ok(AlignOf(
             TypeName {
@@ -1230,9 +1490,9 @@ fn test_alignof() {
 fn test_stmt_expr() {
     use ast::Statement::{Compound, Expression};
     use ast::TypeSpecifier::Int;
-    use parser::expression;
+    use self::parser::expression;
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("({ int p = 0; p; })", &mut Env::new()),
         Ok(Compound(vec![
             Declaration {
@@ -1259,12 +1519,12 @@ fn test_stmt_expr() {
 fn test_expr_cast() {
     use ast::TypeName;
     use ast::TypeSpecifier::TypedefName;
-    use parser::expression;
+    use self::parser::expression;
 
     let env = &mut Env::new();
     env.add_typename("U64");
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("(U64)foo", env),
         Ok(CastExpression {
             type_name: TypeName {
@@ -1280,7 +1540,7 @@ fn test_expr_cast() {
 
 #[test]
 fn test_directives() {
-    use parser::translation_unit;
+    use self::parser::translation_unit;
 
     assert_eq!(
         translation_unit(
@@ -1300,9 +1560,9 @@ fn test_directives() {
 
 #[test]
 fn test_gnu_asm() {
-    use parser::statement;
+    use self::parser::statement;
 
-    assert_eq!(
+    assert_structural_eq!(
         statement(
             r#"__asm ("pmovmskb %1, %0" : "=r" (__m) : "x" (__x));"#,
             &mut Env::new()
@@ -1335,9 +1595,9 @@ fn test_union() {
     use ast::Designator::Member;
     use ast::Initializer::{Expression, List};
     use ast::TypeSpecifier::{Double, Int, Long};
-    use parser::declaration;
+    use self::parser::declaration;
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration(
             "union { long double __l; int __i[3]; } __u = { __l: __x };",
             &mut Env::new()
@@ -1415,9 +1675,9 @@ fn test_offsetof() {
     use ast::Expression::OffsetOf;
     use ast::OffsetMember::IndirectMember;
     use ast::TypeSpecifier::Int;
-    use parser::expression;
+    use self::parser::expression;
 
-    assert_eq!(
+    assert_structural_eq!(
         expression(
             "__builtin_offsetof(struct { struct { int b; } a[2]; }, a->b)",
             &mut Env::new()
@@ -1487,9 +1747,9 @@ fn test_offsetof() {
 
 #[test]
 fn test_call() {
-    use parser::expression;
+    use self::parser::expression;
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("foo(bar, baz)", &mut Env::new()),
         Ok(CallExpression {
             callee: ident("foo"),
@@ -1502,9 +1762,9 @@ fn test_call() {
 #[test]
 fn test_typeof() {
     use ast::TypeSpecifier::TypeOf;
-    use parser::declaration;
+    use self::parser::declaration;
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration(
             "__typeof__(foo(bar, baz)) ook = foo(bar, baz);",
             &mut Env::new()
@@ -1542,9 +1802,9 @@ fn test_typeof() {
 #[test]
 fn test_if() {
     use ast::Statement::Compound;
-    use parser::statement;
+    use self::parser::statement;
 
-    assert_eq!(
+    assert_structural_eq!(
         statement("if (x) do {} while(y); else z();", &mut Env::new()),
         Ok(IfStatement {
             condition: ident("x"),
@@ -1582,11 +1842,11 @@ fn test_attribute4() {
     use ast::Statement::Compound;
     use ast::StorageClassSpecifier::Typedef;
     use ast::TypeSpecifier::Int;
-    use parser::translation_unit;
+    use self::parser::translation_unit;
 
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         translation_unit(
             r#"
                 int foo (int) __attribute__ ((__nothrow__));
@@ -1673,9 +1933,9 @@ fn test_attribute4() {
 fn test_attribute5() {
     use ast::Statement::Compound;
     use ast::TypeSpecifier::Int;
-    use parser::translation_unit;
+    use self::parser::translation_unit;
 
-    assert_eq!(
+    assert_structural_eq!(
         translation_unit(
             "int foo(int a __attribute__((unused)), int b __attribute__((unused))) {}",
             &mut Env::new(),
@@ -1739,9 +1999,9 @@ fn test_declaration6() {
     use ast::Expression::AlignOf;
     use ast::StorageClassSpecifier::Typedef;
     use ast::TypeSpecifier::{Double, Long};
-    use parser::declaration;
+    use self::parser::declaration;
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration(
             r"typedef struct {
               long long __max_align_ll __attribute__((__aligned__(__alignof__(long long))));
@@ -1851,11 +2111,11 @@ fn test_ambiguous_declaration1() {
     use ast::StorageClassSpecifier::Typedef;
     use ast::TypeSpecifier::Int;
     use ast::{FunctionDefinition, TranslationUnit};
-    use parser::translation_unit;
+    use self::parser::translation_unit;
 
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         translation_unit(
             r"
             typedef int a;
@@ -1885,7 +2145,7 @@ fn test_ambiguous_declaration1() {
 
 #[test]
 fn test_ambiguous_declaration2() {
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     assert!(translation_unit(
         r"
@@ -1904,7 +2164,7 @@ fn test_ambiguous_declaration2() {
 
 #[test]
 fn test_ambiguous_parameter_field_declaration() {
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     // If parameter list treated "a" as a type specifier instead of identifier, this would succeed.
     assert!(translation_unit(
@@ -1918,7 +2178,7 @@ fn test_ambiguous_parameter_field_declaration() {
 
 #[test]
 fn test_ambiguous_struct_field_declaration() {
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     // If struct field treated "a" as a type specifier instead of identifier, this would succeed.
     assert!(translation_unit(
@@ -1932,7 +2192,7 @@ fn test_ambiguous_struct_field_declaration() {
 
 #[test]
 fn test_struct_name_scope() {
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     // Struct fields maintain a separate
     assert!(translation_unit(
@@ -1946,7 +2206,7 @@ fn test_struct_name_scope() {
 
 #[test]
 fn test_typedef_redefinition() {
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     assert!(translation_unit(
         r"
@@ -1971,7 +2231,7 @@ fn test_typedef_redefinition() {
 fn test_defines_symbol_before_initializer() {
     // This test is currently broken, and should be enabled once symbols are defined at the
     // end of a declarator (not declaration).
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     // Technically, "a" is defined as a symbol before the "= .." part of the initializer is parsed.
     assert!(translation_unit(
@@ -1988,7 +2248,7 @@ fn test_defines_symbol_before_initializer() {
 #[test]
 fn test_enum_modifies_scope() {
     // Enable once enum correctly modifies scope.
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     // enum {a} defines a new variable "a" into the current scope. So the next _Atomic(a) must fail.
     assert!(translation_unit(
@@ -2015,7 +2275,7 @@ fn test_enum_modifies_scope() {
 
 #[test]
 fn test_restores_scope_after_function_decl() {
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     assert!(translation_unit(
         r"
@@ -2031,7 +2291,7 @@ fn test_restores_scope_after_function_decl() {
 
 #[test]
 fn test_restores_scope_after_block() {
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     assert!(translation_unit(
         r"
@@ -2049,7 +2309,7 @@ fn test_restores_scope_after_block() {
 
 #[test]
 fn test_restores_scope_after_loops() {
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     assert!(translation_unit(
         r"
@@ -2069,7 +2329,7 @@ fn test_restores_scope_after_loops() {
 #[test]
 fn test_restores_scope_after_selections() {
     // Enable once enum constants modify scope.
-    use parser::translation_unit;
+    use self::parser::translation_unit;
     let env = &mut Env::new();
     // Test that scope of "if" condition and statement is cleaned up.
     assert!(translation_unit(
@@ -2101,19 +2361,19 @@ fn test_restores_scope_after_selections() {
 
 #[test]
 fn test_keyword_expr() {
-    use parser::expression;
+    use self::parser::expression;
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("__func__", &mut Env::new()),
         Ok(ident("__func__"))
     );
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("__FUNCTION__", &mut Env::new()),
         Ok(ident("__FUNCTION__"))
     );
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("__PRETTY_FUNCTION__", &mut Env::new()),
         Ok(ident("__PRETTY_FUNCTION__"))
     );
@@ -2121,8 +2381,8 @@ fn test_keyword_expr() {
 
 #[test]
 fn test_ts18661_float() {
-    use parser::declaration;
-    assert_eq!(
+    use self::parser::declaration;
+    assert_structural_eq!(
         declaration("_Float64 foo = 1.5;", &mut Env::new()),
         Ok(Declaration {
             specifiers: vec![TS18661FloatType {
@@ -2148,8 +2408,8 @@ fn test_ts18661_float() {
 #[test]
 fn test_gnu_extension() {
     use ast::TypeSpecifier::Long;
-    use parser::translation_unit;
-    assert_eq!(
+    use self::parser::translation_unit;
+    assert_structural_eq!(
         translation_unit("__extension__ union { long l; };", &mut Env::with_gnu()),
         Ok(TranslationUnit(vec![Declaration {
             specifiers: vec![StructType {
@@ -2175,18 +2435,16 @@ fn test_gnu_extension() {
             .into()],
             declarators: vec![],
         }
-        .into()])
-        .into())
+        .into()]))
     );
 
-    assert_eq!(
+    assert_structural_eq!(
         translation_unit(r#"__extension__ _Static_assert(1,"ERR");"#, &mut Env::new()),
         Ok(TranslationUnit(vec![StaticAssert {
             expression: int::dec("1"),
             message: cstr(&[r#""ERR""#]),
         }
-        .into()])
-        .into())
+        .into()]))
     );
 }
 
@@ -2196,11 +2454,11 @@ fn test_declaration7() {
     use ast::DerivedDeclarator::Pointer;
     use ast::TypeQualifier::Nullable;
     use ast::TypeSpecifier::{Int, Void};
-    use parser::declaration;
+    use self::parser::declaration;
 
     let env = &mut Env::with_clang();
 
-    assert_eq!(
+    assert_structural_eq!(
         // This is the first Clang-specific declaration you'll encounter in macOS
         // if you #include <stdio.h>.
         declaration("int (* _Nullable _close)(void *);", env),
@@ -2248,11 +2506,11 @@ fn test_kr_definition1() {
     use ast::Statement::Compound;
     use ast::TranslationUnit;
     use ast::TypeSpecifier::{Char, Int};
-    use parser::translation_unit;
+    use self::parser::translation_unit;
 
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         translation_unit("int main(argc, argv) int argc; char **argv; { }", env),
         Ok(TranslationUnit(vec![FunctionDefinition {
             specifiers: vec![Int.into()],
@@ -2302,13 +2560,13 @@ fn test_kr_definition1() {
 fn test_clang_availability_attr() {
     use ast::AvailabilityClause::*;
     use ast::TypeSpecifier::Int;
-    use parser::declaration;
+    use self::parser::declaration;
 
     let env = &mut Env::with_clang();
 
     let src = r#"int f __attribute__((availability(p1,introduced=1.2.3))) __attribute__((availability(p2,unavailable,replacement="f2")));"#;
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration(src, env),
         Ok(Declaration {
             specifiers: vec![Int.into(),],
@@ -2352,11 +2610,11 @@ fn test_clang_availability_attr() {
 #[test]
 fn test_struct_decl() {
     use ast::Declaration;
-    use parser::declaration;
+    use self::parser::declaration;
 
     let env = &mut Env::new();
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration("struct foo S;", env).unwrap(),
         Declaration {
             specifiers: vec![StructType {
@@ -2384,14 +2642,14 @@ fn test_struct_decl() {
 #[test]
 fn test_struct_empty_decl() {
     use ast::Declaration;
-    use parser::declaration;
+    use self::parser::declaration;
 
     let env = &mut Env::with_core();
     assert!(declaration("struct foo { } S;", env).is_err());
 
     let env = &mut Env::with_gnu();
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration("struct foo { } S;", env).unwrap(),
         Declaration {
             specifiers: vec![StructType {
@@ -2421,11 +2679,11 @@ fn test_compound_literal() {
     use self::int::dec;
     use ast::Designator::Member;
     use ast::{CompoundLiteral, StructType};
-    use parser::expression;
+    use self::parser::expression;
 
     let env = &mut Env::with_gnu();
 
-    assert_eq!(
+    assert_structural_eq!(
         expression("(struct test_struct) { 1, .x = 2, 3 }", env),
         Ok(CompoundLiteral {
             type_name: TypeName {
@@ -2469,11 +2727,11 @@ fn test_typedef_const() {
     use ast::StorageClassSpecifier::Typedef;
     use ast::TypeQualifier::Const;
     use ast::TypeSpecifier::Int;
-    use parser::declaration;
+    use self::parser::declaration;
 
     let env = &mut Env::with_core();
 
-    assert_eq!(
+    assert_structural_eq!(
         declaration("typedef const int foo;", env).unwrap(),
         Declaration {
             specifiers: vec![Typedef.into(), Const.into(), Int.into()],
@@ -2498,13 +2756,13 @@ fn test_compound_return() {
     use self::int::dec;
     use ast::CompoundLiteral;
     use ast::Designator::Member;
-    use parser::statement;
+    use self::parser::statement;
     use ast::TypeSpecifier::TypedefName;
 
     let env = &mut Env::with_gnu();
     env.add_typename("test_t");
 
-    assert_eq!(
+    assert_structural_eq!(
         statement("return (test_t) { 1, .x = 2, 3 };", env).unwrap(),
         Statement::Return(Some(
             CompoundLiteral {
@@ -2543,17 +2801,17 @@ fn test_fake_compound1() {
     use self::expr::unop;
     use self::int::dec;
     use ast::TypeSpecifier::TypedefName;
-    use parser::statement;
+    use self::parser::statement;
 
     let env = &mut Env::with_gnu();
     env.add_typename("test_t");
 
-    assert_eq!(
+    assert_structural_eq!(
         statement("return (test_t) + 1;", env).unwrap(),
         Statement::Return(Some(
             CastExpression {
                 type_name: TypeName {
-                    specifiers: vec![TypedefName(ident("test_t")).into()].into(),
+                    specifiers: vec![TypedefName(ident("test_t")).into()],
                     declarator: None,
                 }
                 .into(),
@@ -2564,3 +2822,1621 @@ fn test_fake_compound1() {
         .into()
     );
 }
+
+#[test]
+fn test_tr18037_types() {
+    use ast::TypeSpecifier::{Accum, Fract, Long, Sat, Unsigned};
+    use self::parser::declaration;
+
+    let env = &mut Env::with_gnu();
+    assert!(declaration("_Sat unsigned long _Accum x;", env).is_err());
+
+    let env = &mut Env::with_gnu();
+    env.enable_embedded();
+
+    assert_structural_eq!(
+        declaration("_Sat unsigned long _Accum x;", env),
+        Ok(make_declaration(
+            "x",
+            &[Sat.into(), Unsigned.into(), Long.into(), Accum.into()]
+        )
+        .into())
+    );
+
+    assert_structural_eq!(
+        declaration("_Fract y;", env),
+        Ok(make_declaration("y", &[Fract.into()]).into())
+    );
+}
+
+#[test]
+fn test_vendor_qualifiers() {
+    use ast::DeclarationSpecifier::Extension;
+    use ast::DerivedDeclarator::Pointer;
+    use ast::TypeQualifier::Volatile;
+    use ast::TypeSpecifier::Char;
+    use self::parser::{declaration, expression};
+
+    let env = &mut Env::with_core();
+    env.add_vendor_keyword("__far", false);
+    env.add_vendor_keyword("__at", true);
+
+    assert_structural_eq!(
+        declaration("__at(0x20) volatile char * __far p;", env),
+        Ok(Declaration {
+            specifiers: vec![
+                Extension(vec![VendorQualifier {
+                    name: "__at".into(),
+                    arguments: vec![int::num(
+                        IntegerBase::Hexadecimal,
+                        "20",
+                        int::NONE.clone()
+                    )],
+                }
+                .into()])
+                .into(),
+                Volatile.into(),
+                Char.into(),
+            ],
+            declarators: vec![InitDeclarator {
+                declarator: Declarator {
+                    kind: ident("p"),
+                    derived: vec![Pointer(vec![PointerQualifier::Extension(vec![
+                        VendorQualifier {
+                            name: "__far".into(),
+                            arguments: vec![],
+                        }
+                        .into(),
+                    ])
+                    .into()])
+                    .into()],
+                    extensions: vec![],
+                }
+                .into(),
+                initializer: None,
+            }
+            .into()],
+        }
+        .into())
+    );
+
+    // Vendor keywords are not identifiers
+    assert!(expression("__far + 1", env).is_err());
+    assert!(expression("__at", env).is_err());
+}
+
+#[test]
+fn test_vendor_qualifier_after_declarator() {
+    use self::parser::translation_unit;
+
+    let env = &mut Env::with_core();
+    env.add_vendor_keyword("__interrupt", true);
+
+    let unit = translation_unit("void isr(void) __interrupt(1) { }", env).unwrap();
+    match unit.0[0].node {
+        ExternalDeclaration::FunctionDefinition(ref f) => {
+            assert_structural_eq!(
+                f.node.declarator.node.extensions,
+                vec![VendorQualifier {
+                    name: "__interrupt".into(),
+                    arguments: vec![int::dec("1")],
+                }
+                .into()]
+            );
+        }
+        _ => panic!("expected a function definition"),
+    }
+}
+
+#[test]
+fn test_embedded_config() {
+    use driver::{parse_preprocessed, Config, Flavor, VendorKeyword};
+
+    let source = "_Sat _Fract x; volatile char __at(0x20) * __far port;";
+    let config = Config::default().flavor(Flavor::StdC11);
+    assert!(parse_preprocessed(&config, source.to_string()).is_err());
+
+    let config = config
+        .embedded(true)
+        .vendor_keyword(VendorKeyword::new("__far"))
+        .vendor_keyword(VendorKeyword::with_arguments("__at"));
+    let parse = parse_preprocessed(&config, source.to_string()).unwrap();
+    assert_eq!(parse.unit.0.len(), 2);
+}
+
+#[test]
+fn test_digraphs() {
+    use self::expr::binop;
+    use self::int::dec;
+    use ast::ArraySize::VariableExpression;
+    use ast::DerivedDeclarator::Array;
+    use ast::TypeSpecifier::Int;
+    use self::parser::{declaration, statement};
+
+    let env = &mut Env::new();
+
+    assert_structural_eq!(
+        declaration("int a<:2:> = <% 1 %>;", env),
+        Ok(Declaration {
+            specifiers: vec![Int.into()],
+            declarators: vec![InitDeclarator {
+                declarator: Declarator {
+                    kind: ident("a"),
+                    derived: vec![Array(
+                        ArrayDeclarator {
+                            qualifiers: vec![],
+                            size: VariableExpression(dec("2")),
+                        }
+                        .into()
+                    )
+                    .into()],
+                    extensions: vec![],
+                }
+                .into(),
+                initializer: Some(
+                    Initializer::List(vec![InitializerListItem {
+                        designation: vec![],
+                        initializer: dec("1"),
+                    }
+                    .into()])
+                    .into()
+                ),
+            }
+            .into()],
+        }
+        .into())
+    );
+
+    assert_structural_eq!(
+        statement("<% a<:0:> = 1; %>", env),
+        Ok(Statement::Compound(vec![BlockItem::Statement(
+            Statement::Expression(Some(binop(
+                BinaryOperator::Assign,
+                binop(BinaryOperator::Index, ident("a"), dec("0")),
+                dec("1")
+            )))
+            .into()
+        )
+        .into()])
+        .into())
+    );
+}
+
+#[test]
+fn test_unicode_identifiers() {
+    use self::parser::{declaration, expression};
+
+    let env = &mut Env::new();
+
+    assert_structural_eq!(expression("café", env), Ok(ident("café")));
+    assert_structural_eq!(expression(r"caf\u00e9", env), Ok(ident(r"caf\u00e9")));
+    assert_structural_eq!(expression(r"\U0001F600x", env), Ok(ident(r"\U0001F600x")));
+    assert_structural_eq!(expression("число", env), Ok(ident("число")));
+    assert_structural_eq!(expression("intégral", env), Ok(ident("intégral")));
+
+    // UCNs designating basic characters or surrogates are not allowed
+    assert!(expression(r"\u0041", env).is_err());
+    assert!(expression(r"\uD800", env).is_err());
+    assert!(expression(r"\u00e", env).is_err());
+
+    // Combining marks are allowed everywhere except the start
+    assert!(expression("\u{301}a", env).is_err());
+    assert!(expression(r"\u0301a", env).is_err());
+    assert!(expression(r"a\u0301", env).is_ok());
+
+    let i: Identifier = ident(r"caf\u00e9");
+    assert_eq!(i.normalized(), "café");
+
+    env.add_typename("café");
+    assert_structural_eq!(
+        declaration(r"caf\u00e9 x;", env),
+        Ok(make_declaration(
+            "x",
+            &[TypeSpecifier::TypedefName(ident(r"caf\u00e9")).into()]
+        )
+        .into())
+    );
+}
+
+#[test]
+fn test_visit_mut() {
+    use self::parser::statement;
+    use visit_mut::{self, VisitMut};
+
+    struct Rewrite;
+
+    impl VisitMut for Rewrite {
+        fn visit_identifier_mut(&mut self, identifier: &mut Identifier, span: &mut Span) {
+            if identifier.name == "a" {
+                identifier.name = "b".into();
+            }
+            visit_mut::visit_identifier_mut(self, identifier, span);
+        }
+
+        fn visit_expression_mut(&mut self, expression: &mut Expression, span: &mut Span) {
+            visit_mut::visit_expression_mut(self, expression, span);
+            if let Expression::BinaryOperator(ref b) = *expression {
+                if b.node.operator.node == BinaryOperator::Multiply {
+                    *expression = int::zero();
+                }
+            }
+        }
+    }
+
+    use self::expr::binop;
+
+    let env = &mut Env::new();
+    let mut s = statement("a = a + c * d;", env).unwrap();
+    Rewrite.visit_statement_mut(&mut s.node, &mut s.span);
+
+    assert_structural_eq!(
+        s.node,
+        Statement::Expression(Some(binop(
+            BinaryOperator::Assign,
+            ident("b"),
+            binop(BinaryOperator::Plus, ident("b"), int::zero()),
+        )))
+    );
+}
+
+#[test]
+fn test_fold() {
+    use fold::{self, Fold};
+    use self::parser::expression;
+
+    // Rewrite `a.b` into `b(a)`
+    struct MemberToCall;
+
+    impl Fold for MemberToCall {
+        fn fold_expression(&mut self, expression: Expression, span: Span) -> Expression {
+            match fold::fold_expression(self, expression, span) {
+                Expression::Member(m) => {
+                    let m = m.node;
+                    let callee = Node::new(Expression::Identifier(Box::new(m.identifier)), span);
+                    Expression::Call(Box::new(Node::new(
+                        CallExpression {
+                            callee: Box::new(callee),
+                            arguments: vec![*m.expression],
+                        },
+                        span,
+                    )))
+                }
+                e => e,
+            }
+        }
+    }
+
+    let env = &mut Env::new();
+    let e = expression("x.y.z + 1", env).unwrap();
+    let e = MemberToCall.fold_expression(e.node, e.span);
+
+    let call = |f: &str, a: Expression| -> Expression {
+        Expression::Call(
+            CallExpression {
+                callee: Box::new(ident(f)),
+                arguments: vec![a.into()],
+            }
+            .into(),
+        )
+    };
+
+    assert_structural_eq!(
+        e,
+        expr::binop(
+            BinaryOperator::Plus,
+            call("z", call("y", ident("x"))),
+            int::dec("1"),
+        )
+    );
+}
+
+#[test]
+fn test_visit_path() {
+    use self::parser::statement;
+    use visit_path::{self, Path, VisitPath};
+
+    struct Roles(Vec<String>);
+
+    impl<'ast> VisitPath<'ast> for Roles {
+        fn visit_expression(
+            &mut self,
+            expression: &'ast Expression,
+            span: &'ast Span,
+            path: &mut Path<'ast>,
+        ) {
+            if let Expression::Identifier(ref i) = *expression {
+                let parent = path.parent().unwrap();
+                self.0.push(format!("{}: {}", i.node.name, parent));
+            }
+            visit_path::visit_expression(self, expression, span, path);
+        }
+    }
+
+    let env = &mut Env::new();
+    let s = statement("if (a = f(b, c)) return d[e];", env).unwrap();
+    let mut roles = Roles(vec![]);
+    let mut path = Path::new();
+    roles.visit_statement(&s.node, &s.span, &mut path);
+
+    assert!(path.ancestors().is_empty());
+    assert_eq!(
+        roles.0,
+        vec![
+            "a: lhs of BinaryOperatorExpression",
+            "f: callee of CallExpression",
+            "b: arguments[0] of CallExpression",
+            "c: arguments[1] of CallExpression",
+            "d: lhs of BinaryOperatorExpression",
+            "e: rhs of BinaryOperatorExpression",
+        ]
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_representation() {
+    use self::parser::expression;
+    use serde_json;
+    use span::NodeId;
+
+    let env = &mut Env::new();
+    let e = expression("-x", env).unwrap();
+    let (unary, operator, operand, x) = match e.node {
+        Expression::UnaryOperator(ref u) => match u.node.operand.node {
+            Expression::Identifier(ref x) => (u.id, u.node.operator.id, u.node.operand.id, x.id),
+            _ => panic!("not an identifier"),
+        },
+        _ => panic!("not a unary operator"),
+    };
+
+    let id = |id: NodeId| format!(r#""id":{}"#, serde_json::to_string(&id).unwrap());
+    assert_eq!(
+        serde_json::to_string(&e).unwrap(),
+        format!(
+            concat!(
+                r#"{{"node":{{"UnaryOperator":{{"node":{{"#,
+                r#""operator":{{"node":"Minus","span":{{"start":0,"end":1}},{}}},"#,
+                r#""operand":{{"node":{{"Identifier":{{"node":{{"name":"x"}},"#,
+                r#""span":{{"start":1,"end":2}},{}}}}},"span":{{"start":1,"end":2}},{}}}}},"#,
+                r#""span":{{"start":0,"end":2}},{}}}}},"span":{{"start":0,"end":2}},{}}}"#
+            ),
+            id(operator),
+            id(x),
+            id(operand),
+            id(unary),
+            id(e.id)
+        )
+    );
+
+    // Identifiers survive a round trip and are not handed out again
+    let back: Node<Expression> = serde_json::from_str(&serde_json::to_string(&e).unwrap()).unwrap();
+    assert_eq!(back.id, e.id);
+    let big: NodeId = serde_json::from_str("1000000000").unwrap();
+    assert!(NodeId::fresh() > big);
+    let missing: Node<Identifier> =
+        serde_json::from_str(r#"{"node":{"name":"x"},"span":{"start":0,"end":1}}"#).unwrap();
+    assert!(missing.id > big);
+}
+
+/// Check that printed unit matches `expected` and parses back into the same tree
+fn assert_round_trip(new_env: fn() -> Env, source: &str, expected: &str) {
+    use self::parser::translation_unit;
+    use print;
+    use span::Structural;
+
+    let unit = translation_unit(source, &mut new_env()).unwrap();
+    let printed = print::to_string(&unit);
+    let reparsed = match translation_unit(&printed, &mut new_env()) {
+        Ok(unit) => unit,
+        Err(err) => panic!("printed code does not parse: {}\n{}", err, printed),
+    };
+    assert_eq!(Structural(&reparsed), Structural(&unit), "{}", printed);
+    if !expected.is_empty() {
+        assert_eq!(printed, expected);
+    }
+}
+
+#[test]
+fn test_print_declarators() {
+    assert_round_trip(
+        Env::with_core,
+        "int *const *x[3], (*f)(int, ...), (*g(void))[2];\
+         char *(*(*h)[5])(long a, int (*)[3]), (i);\
+         void j(int n, int v[static const n], int w[*], int z[const]);",
+        concat!(
+            "int *const *x[3], (*f)(int, ...), (*g(void))[2];\n",
+            "char *(*(*h)[5])(long a, int (*)[3]), (i);\n",
+            "void j(int n, int v[static const n], int w[*], int z[const]);\n",
+        ),
+    );
+
+    assert_round_trip(
+        Env::with_core,
+        "typedef struct s { int a : 3, *b; union { int c; } u; } T;\
+         enum e { A, B = 1 << 2 } x;\
+         T v = { .a = 1, [2] = { 3, 4 } }, *w = (T []){ 0 };",
+        concat!(
+            "typedef struct s {\n",
+            "    int a : 3, *b;\n",
+            "    union {\n",
+            "        int c;\n",
+            "    } u;\n",
+            "} T;\n",
+            "enum e { A, B = 1 << 2 } x;\n",
+            "T v = {.a = 1, [2] = {3, 4}}, *w = (T []){0};\n",
+        ),
+    );
+}
+
+#[test]
+fn test_print_expressions() {
+    assert_round_trip(
+        Env::with_core,
+        "int x = ((a + b) * (3)) - (-a) - (- (- b)), y = a ? b : (c ? d : e), z = (a, b);\
+         int w = ((a ? b : c) ? d : e) + (a - (b - c) - d) + (sizeof(int) + sizeof(a) + sizeof (a + b));",
+        concat!(
+            "int x = (a + b) * 3 - -a - - -b, y = a ? b : c ? d : e, z = (a, b);\n",
+            "int w = ((a ? b : c) ? d : e) + (a - (b - c) - d) + (sizeof(int) + sizeof a + sizeof (a + b));\n",
+        ),
+    );
+
+    assert_round_trip(
+        Env::with_core,
+        "void f(void) { p = &(*p); p = &(&x); x = -(--y) + (+(++z)); x = (a = b) = c; x = a[b, c];\
+         (f)(a, (b, c)); x = a ? (b, c) : d; x = (int)(-y); x = ((int)y)++; x = (a.b)->c[1](); }",
+        concat!(
+            "void f(void) {\n",
+            "    p = &*p;\n",
+            "    p = & &x;\n",
+            "    x = - --y + + ++z;\n",
+            "    x = (a = b) = c;\n",
+            "    x = a[b, c];\n",
+            "    f(a, (b, c));\n",
+            "    x = a ? b, c : d;\n",
+            "    x = (int)-y;\n",
+            "    x = ((int)y)++;\n",
+            "    x = a.b->c[1]();\n",
+            "}\n",
+        ),
+    );
+}
+
+#[test]
+fn test_print_statements() {
+    assert_round_trip(
+        Env::with_core,
+        "int f(a, b) int a; char b; {\
+         if (a) { if (b) return 1; } else if (b) x = 1; else { y = 2; }\
+         if (a) if (b) return; else ;\
+         while (a--) { b++; } do x(); while (1); do { } while (0);\
+         for (int i = 0; i < 3; i++) ; for (;;) break;\
+         switch (a) { case 1: default: goto l; }\
+         l: return (a = b); }",
+        concat!(
+            "int f(a, b)\n",
+            "    int a;\n",
+            "    char b;\n",
+            "{\n",
+            "    if (a) {\n",
+            "        if (b)\n",
+            "            return 1;\n",
+            "    } else if (b)\n",
+            "        x = 1;\n",
+            "    else {\n",
+            "        y = 2;\n",
+            "    }\n",
+            "    if (a)\n",
+            "        if (b)\n",
+            "            return;\n",
+            "        else\n",
+            "            ;\n",
+            "    while (a--) {\n",
+            "        b++;\n",
+            "    }\n",
+            "    do\n",
+            "        x();\n",
+            "    while (1);\n",
+            "    do {} while (0);\n",
+            "    for (int i = 0; i < 3; i++)\n",
+            "        ;\n",
+            "    for (;;)\n",
+            "        break;\n",
+            "    switch (a) {\n",
+            "        case 1:\n",
+            "        default:\n",
+            "        goto l;\n",
+            "    }\n",
+            "    l:\n",
+            "    return a = b;\n",
+            "}\n",
+        ),
+    );
+}
+
+#[test]
+fn test_print_extensions() {
+    assert_round_trip(
+        Env::with_gnu,
+        "__extension__ typedef int T __attribute__((aligned(8), unused));\
+         extern int x __asm__(\"y\") __attribute__((weak));\
+         struct s { int a : 3 __attribute__((x)), : 2; int __attribute__((y)) b; struct {} c; };\
+         void f(int a __attribute__((unused)), ...) __attribute__((noreturn));\
+         __typeof__(int *) p; typeof(p + 1) q;\
+         int (__attribute__((cdecl)) *fp)(void), *__attribute__((aligned)) const r;",
+        concat!(
+            "typedef int T __attribute__((aligned(8), unused));\n",
+            "extern int x __asm__(\"y\") __attribute__((weak));\n",
+            "struct s {\n",
+            "    int a : 3 __attribute__((x)), : 2;\n",
+            "    int b __attribute__((y));\n",
+            "    struct {} c;\n",
+            "};\n",
+            "void f(int a __attribute__((unused)), ...) __attribute__((noreturn));\n",
+            "__typeof__(int *) p;\n",
+            "__typeof__(p + 1) q;\n",
+            "int (__attribute__((cdecl)) *fp)(void), *__attribute__((aligned)) const r;\n",
+        ),
+    );
+
+    assert_round_trip(
+        Env::with_gnu,
+        "int g(void) { int x = ({ int y = 1; y; });\
+         x = __builtin_offsetof(struct s, a[1].b->c) + __builtin_va_arg(ap, int *) + __alignof__(long);\
+         int a[] = { [0 ... 3] = 1, [4].x = 2, y: 3 };\
+         __asm__ volatile (\"nop\" : [o] \"=r\" (x) : \"r\" (x + 1) : \"memory\", \"cc\");\
+         __asm (\"y\" : : \"r\" (x)); asm (\"z\" : : : \"cc\");\
+         return _Generic(x, int: 1, default: 0b10i); }",
+        concat!(
+            "int g(void) {\n",
+            "    int x = ({\n",
+            "        int y = 1;\n",
+            "        y;\n",
+            "    });\n",
+            "    x = __builtin_offsetof(struct s, a[1].b->c) + __builtin_va_arg(ap, int *) + _Alignof(long);\n",
+            "    int a[] = {[0 ... 3] = 1, [4].x = 2, .y = 3};\n",
+            "    __asm__ volatile(\"nop\" : [o] \"=r\" (x) : \"r\" (x + 1) : \"memory\", \"cc\");\n",
+            "    __asm__(\"y\" : : \"r\" (x));\n",
+            "    __asm__(\"z\" : : : \"cc\");\n",
+            "    return _Generic(x, int: 1, default: 0b10i);\n",
+            "}\n",
+        ),
+    );
+
+    assert_round_trip(
+        Env::with_clang,
+        "int * _Nonnull f(void) __attribute__((availability(macos, introduced=10.4, deprecated=10.6.1, unavailable, message=\"m\")));",
+        "int *_Nonnull f(void) __attribute__((availability(macos, introduced=10.4, deprecated=10.6.1, unavailable, message=\"m\")));\n",
+    );
+
+    fn vendor_env() -> Env {
+        let mut env = Env::with_core();
+        env.add_vendor_keyword("__far", false);
+        env.add_vendor_keyword("__interrupt", true);
+        env
+    }
+
+    assert_round_trip(
+        vendor_env,
+        "__far int *__far p; void isr(void) __interrupt(4);",
+        "__far int *__far p;\nvoid isr(void) __interrupt(4);\n",
+    );
+}
+
+#[test]
+fn test_format_style() {
+    use driver::{Config, Flavor};
+    use format::format;
+    use print::{BraceStyle, PointerAlignment, Style};
+
+    let config = Config {
+        flavor: Flavor::StdC11,
+        ..Config::default()
+    };
+    let source = "struct s { char *name; }; int *f(int *a, char **b) { if (a) { return a; } else { while (*b) b++; } return 0; }";
+
+    let style = Style {
+        indent_width: 2,
+        braces: BraceStyle::Allman,
+        pointer_alignment: PointerAlignment::Left,
+        ..Style::default()
+    };
+    assert_eq!(
+        format(&config, &style, source).unwrap(),
+        concat!(
+            "struct s\n",
+            "{\n",
+            "  char* name;\n",
+            "};\n",
+            "\n",
+            "int* f(int* a, char** b)\n",
+            "{\n",
+            "  if (a)\n",
+            "  {\n",
+            "    return a;\n",
+            "  }\n",
+            "  else\n",
+            "  {\n",
+            "    while (*b)\n",
+            "      b++;\n",
+            "  }\n",
+            "  return 0;\n",
+            "}\n",
+        )
+    );
+
+    let style = Style {
+        braces: BraceStyle::Linux,
+        pointer_alignment: PointerAlignment::Middle,
+        line_width: Some(24),
+        ..Style::default()
+    };
+    assert_eq!(
+        format(&config, &style, source).unwrap(),
+        concat!(
+            "struct s {\n",
+            "    char * name;\n",
+            "};\n",
+            "\n",
+            "int * f(\n",
+            "    int * a,\n",
+            "    char ** b\n",
+            ")\n",
+            "{\n",
+            "    if (a) {\n",
+            "        return a;\n",
+            "    } else {\n",
+            "        while (*b)\n",
+            "            b++;\n",
+            "    }\n",
+            "    return 0;\n",
+            "}\n",
+        )
+    );
+
+    let style = Style {
+        line_width: Some(30),
+        ..Style::default()
+    };
+    assert_eq!(
+        format(
+            &config,
+            &style,
+            "int a = 1, bb = 2, ccc = 3, dddd = 4; int x[] = { 1, 2, 3 }; int printf(const char *fmt, ...);",
+        )
+        .unwrap(),
+        concat!(
+            "int a = 1,\n",
+            "    bb = 2,\n",
+            "    ccc = 3,\n",
+            "    dddd = 4;\n",
+            "int x[] = {1, 2, 3};\n",
+            "int printf(\n",
+            "    const char *fmt,\n",
+            "    ...\n",
+            ");\n",
+        )
+    );
+}
+
+#[test]
+fn test_format_trivia() {
+    use driver::Config;
+    use format::format;
+    use print::Style;
+
+    let source = concat!(
+        "/* file comment */\n",
+        "#include <stdio.h>\n",
+        "#define SQUARE(x) \\\n",
+        "    ((x) * (x))\n",
+        "\n",
+        "\n",
+        "DECLARE_MODULE(demo, \"1.0\");\n",
+        "EXPORT_SYMBOL(count,\n",
+        "  \")\")\n",
+        "int   count ;   // how many\n",
+        "struct s {\n",
+        "  int a; // first\n",
+        "  // last\n",
+        "};\n",
+        "int main(void){\n",
+        "  // start\n",
+        "#ifdef DEBUG\n",
+        "  puts(\"debug\");\n",
+        "#endif\n",
+        "\n",
+        "  return SQUARE(2);  }\n",
+    );
+    let expected = concat!(
+        "/* file comment */\n",
+        "#include <stdio.h>\n",
+        "#define SQUARE(x) \\\n",
+        "    ((x) * (x))\n",
+        "\n",
+        "DECLARE_MODULE(demo, \"1.0\");\n",
+        "EXPORT_SYMBOL(count,\n",
+        "  \")\")\n",
+        "int count; // how many\n",
+        "struct s {\n",
+        "    int a; // first\n",
+        "    // last\n",
+        "};\n",
+        "\n",
+        "int main(void) {\n",
+        "    // start\n",
+        "#ifdef DEBUG\n",
+        "    puts(\"debug\");\n",
+        "#endif\n",
+        "\n",
+        "    return SQUARE(2);\n",
+        "}\n",
+    );
+    let config = Config::default();
+    let formatted = format(&config, &Style::default(), source).unwrap();
+    assert_eq!(formatted, expected);
+    assert_eq!(format(&config, &Style::default(), &formatted).unwrap(), expected);
+
+    let err = format(&config, &Style::default(), "int f(void) {").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 14));
+}
+
+#[test]
+fn test_format_items() {
+    use driver::Config;
+    use format::format;
+    use print::Style;
+
+    let source = concat!(
+        "typedef int T;\n",
+        "int f(a, b) int a; T b; { return a + b; }\n",
+        "BROKEN(T) x;\n",
+        "T g(T t) { return (T)t; }\n",
+    );
+    let expected = concat!(
+        "typedef int T;\n",
+        "\n",
+        "int f(a, b)\n",
+        "    int a;\n",
+        "    T b;\n",
+        "{\n",
+        "    return a + b;\n",
+        "}\n",
+        "BROKEN(T) x;\n",
+        "\n",
+        "T g(T t) {\n",
+        "    return (T)t;\n",
+        "}\n",
+    );
+    let config = Config::default();
+    assert_eq!(format(&config, &Style::default(), source).unwrap(), expected);
+
+    // Every item is parsed on its own, a failing one does not cause the rest to be parsed again
+    let mut source = String::new();
+    for i in 0..3000 {
+        source.push_str(&format!("MACRO({}) int x{} = {};\n", i, i, i));
+    }
+    let start = ::std::time::Instant::now();
+    assert_eq!(format(&config, &Style::default(), &source).unwrap(), source);
+    assert!(start.elapsed() < ::std::time::Duration::from_secs(5));
+}
+
+#[test]
+fn test_syntax_tree() {
+    use cst::{parse, Element, TokenKind, TriviaKind};
+    use driver::Config;
+
+    let source = concat!(
+        "# 1 \"a.c\"\n",
+        "/* doc */\n",
+        "int f(int a) {\n",
+        "    return (a + 1) * 2; // twice\n",
+        "}\n",
+        "char *s = \"x\" \"y\", c = '\\n';\n",
+        "// end\n",
+    );
+    let (_, tree) = parse(&Config::with_gcc(), source.to_owned()).unwrap();
+    assert_eq!(tree.to_string(), source);
+
+    let tokens = tree.root.tokens();
+    assert_eq!(tokens[0].leading.len(), 4);
+    assert_eq!(tokens[0].leading[0].kind, TriviaKind::Directive);
+    assert_eq!(tokens[0].leading[2].kind, TriviaKind::Comment);
+    let end: Vec<_> = tree.trailing.iter().map(|t| tree.text(t.span)).collect();
+    assert_eq!(end, ["// end", "\n"]);
+
+    let semicolon = tokens.iter().find(|t| tree.text(t.span) == ";").unwrap();
+    let trailing: Vec<_> = semicolon.trailing.iter().map(|t| tree.text(t.span)).collect();
+    assert_eq!(trailing, [" ", "// twice", "\n"]);
+
+    let kinds: Vec<_> = tokens
+        .iter()
+        .skip_while(|t| tree.text(t.span) != "\"x\"")
+        .take(6)
+        .map(|t| t.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            TokenKind::String,
+            TokenKind::String,
+            TokenKind::Punctuator,
+            TokenKind::Identifier,
+            TokenKind::Punctuator,
+            TokenKind::Character,
+        ]
+    );
+
+    // Parentheses are tokens of the expression node spanning them, spans of expressions may
+    // include trailing whitespace
+    let children = |kind: &str, text: &str| -> Vec<&str> {
+        let node = tree
+            .root
+            .descendants()
+            .into_iter()
+            .find(|n| n.kind == kind && tree.text(n.span).trim_end() == text)
+            .unwrap();
+        node.children
+            .iter()
+            .map(|c| match *c {
+                Element::Node(ref n) => n.kind,
+                Element::Token(ref t) => tree.text(t.span),
+            })
+            .collect()
+    };
+    assert_eq!(
+        children("BinaryOperatorExpression", "(a + 1) * 2"),
+        ["Expression", "BinaryOperator", "Expression"]
+    );
+    assert_eq!(
+        children("Expression", "(a + 1)"),
+        ["(", "BinaryOperatorExpression", ")"]
+    );
+}
+
+#[test]
+fn test_rewrite() {
+    use rewrite::Rewriter;
+    use self::parser::expression;
+
+    let source = "int x = a + b , y = (c);";
+    let span = |text: &str| {
+        let start = source.find(text).unwrap();
+        Span::span(start, start + text.len())
+    };
+
+    let mut rewriter = Rewriter::new(source);
+    let replacement = expression("f(1, 2)", &mut Env::new()).unwrap();
+    // Whitespace after the node is kept
+    rewriter.replace_with(span("a + b "), &*replacement);
+    rewriter.insert_after(span("a + b"), " /* sum */");
+    rewriter.insert_before(span("int"), "static ");
+    rewriter.insert_before(span("int"), "const ");
+    rewriter.delete(span(" , y = (c)"));
+    assert_eq!(
+        rewriter.finish().unwrap(),
+        "static const int x = f(1, 2) /* sum */;"
+    );
+
+    let mut rewriter = Rewriter::new(source);
+    rewriter.replace(span("a + b"), "1");
+    rewriter.insert_before(span("b"), "+");
+    let conflict = rewriter.finish().unwrap_err();
+    assert_eq!(conflict.first.span, span("a + b"));
+    assert_eq!(conflict.second.span, Span::span(span("b").start, span("b").start));
+
+    // Insertions at the boundaries of a replaced span do not overlap it
+    let mut rewriter = Rewriter::new(source);
+    rewriter.replace(span("(c)"), "c");
+    rewriter.insert_after(span("(c)"), ")");
+    rewriter.insert_before(span("(c)"), "(");
+    assert_eq!(rewriter.finish().unwrap(), "int x = a + b , y = (c);");
+
+    // Replaced operands keep their place in the surrounding expression
+    let parent = expression("a*b-c", &mut Env::new()).unwrap();
+    let mut rewriter = Rewriter::new("a*b-c");
+    if let Expression::BinaryOperator(ref sub) = parent.node {
+        let replacement = expression("-c", &mut Env::new()).unwrap();
+        rewriter.replace_operand(&parent.node, &sub.node.rhs, &replacement.node);
+        if let Expression::BinaryOperator(ref mul) = sub.node.lhs.node {
+            let replacement = expression("c+d", &mut Env::new()).unwrap();
+            rewriter.replace_operand(&sub.node.lhs.node, &mul.node.rhs, &replacement.node);
+        }
+    }
+    assert_eq!(rewriter.finish().unwrap(), "a*(c + d)- -c");
+}
+
+#[test]
+fn test_node_ids() {
+    use self::parser::declaration;
+    use side_table::SideTable;
+
+    let decl = declaration("int a = 1, b = 1;", &mut Env::new()).unwrap();
+    let a = &decl.node.declarators[0].node.initializer;
+    let b = &decl.node.declarators[1].node.initializer;
+    let (a, b) = (a.as_ref().unwrap(), b.as_ref().unwrap());
+    assert_ne!(a.id, b.id);
+    assert_ne!(decl.id, decl.node.declarators[0].id);
+
+    // Identifiers do not take part in comparison
+    let other = Node::new(a.node.clone(), a.span);
+    assert_ne!(other.id, a.id);
+    assert_eq!(other, *a);
+
+    let mut table = SideTable::new();
+    assert_eq!(table.insert(a, "a"), None);
+    assert_eq!(table.insert(b, "b"), None);
+    assert_eq!(table.insert(a, "first"), Some("a"));
+    assert_eq!(table.len(), 2);
+
+    // Clones keep identifiers unless given new ones
+    let mut copy = decl.clone();
+    assert_eq!(copy.id, decl.id);
+    copy.fresh_id();
+    assert_ne!(copy.id, decl.id);
+    let copy_a = copy.node.declarators[0].node.initializer.as_ref().unwrap();
+    assert_eq!(copy_a.id, a.id);
+    assert_eq!(table.get(copy_a), Some(&"first"));
+    let mut copy_a = copy_a.clone();
+    copy_a.fresh_id();
+    assert_eq!(table.get(&copy_a), None);
+    assert_eq!(table.get(&other), None);
+    assert_eq!(table.remove(b), Some("b"));
+    assert!(!table.contains(b));
+    assert_eq!(table.iter().collect::<Vec<_>>(), [(a.id, &"first")]);
+}
+
+#[test]
+fn test_interned_identifiers() {
+    use driver::{parse_preprocessed, Config};
+    use intern::Name;
+    use self::parser::expression;
+
+    let expr = expression("count + count", &mut Env::new()).unwrap();
+    let (lhs, rhs) = match expr.node {
+        Expression::BinaryOperator(ref b) => (&b.node.lhs.node, &b.node.rhs.node),
+        _ => panic!("not a binary expression"),
+    };
+    match (lhs, rhs) {
+        (Expression::Identifier(a), Expression::Identifier(b)) => {
+            assert!(Name::ptr_eq(&a.node.name, &b.node.name));
+            assert_eq!(a.node.name, "count");
+
+            // Names from separate tables are equal without sharing text
+            let other = expression("count", &mut Env::new()).unwrap();
+            match other.node {
+                Expression::Identifier(ref c) => {
+                    assert!(!Name::ptr_eq(&a.node.name, &c.node.name));
+                    assert_eq!(a.node.name, c.node.name);
+                }
+                _ => panic!("not an identifier"),
+            }
+        }
+        _ => panic!("not identifiers"),
+    }
+
+    // Parses with one configuration share a table, predeclared typedef names included
+    let config = Config::default();
+    let name = |source: &str| {
+        let parse = parse_preprocessed(&config.clone(), source.to_owned()).unwrap();
+        let mut names = Vec::new();
+        for decl in &parse.unit.0 {
+            if let ExternalDeclaration::Declaration(ref d) = decl.node {
+                names.push(d.node.declarators[0].node.declarator.node.kind.node.clone());
+            }
+        }
+        (parse.typedefs, names)
+    };
+    let (_, a) = name("int count;");
+    let (typedefs, b) = name("typedef int count;");
+    match (&a[0], &b[0]) {
+        (DeclaratorKind::Identifier(a), DeclaratorKind::Identifier(b)) => {
+            assert!(Name::ptr_eq(&a.node.name, &b.node.name));
+            assert!(Name::ptr_eq(&a.node.name, &config.interner.intern("count")));
+            assert!(typedefs.contains("count"));
+        }
+        _ => panic!("not identifiers"),
+    }
+    assert_eq!(config.interner.len(), ::strings::TYPEDEFS_GNU.len() + 1);
+}
+
+#[test]
+fn test_line_index() {
+    use driver::{parse_preprocessed, Config};
+    use line_index::{LineCol, LineIndex};
+
+    let lc = |line, column| LineCol { line, column };
+
+    let index = LineIndex::new("a\r\n😀b\n\nc");
+    assert_eq!(index.line_count(), 4);
+    assert_eq!(index.line(1), Some("a\r"));
+    assert_eq!(index.line(3), Some(""));
+    assert_eq!(index.line(5), None);
+    assert_eq!(index.line_col(0), lc(1, 1));
+    assert_eq!(index.line_col(2), lc(1, 3));
+    assert_eq!(index.line_col(7), lc(2, 5));
+    assert_eq!(index.line_col_utf16(7), lc(2, 3));
+    assert_eq!(index.line_col(10), lc(4, 1));
+    assert_eq!(index.line_col(11), lc(4, 2));
+
+    assert_eq!(index.offset(lc(2, 5)), Some(7));
+    assert_eq!(index.offset(lc(2, 2)), None);
+    assert_eq!(index.offset(lc(2, 7)), None);
+    assert_eq!(index.offset(lc(0, 1)), None);
+    assert_eq!(index.offset_utf16(lc(2, 3)), Some(7));
+    assert_eq!(index.offset_utf16(lc(2, 2)), None);
+    assert_eq!(index.offset_utf16(lc(2, 4)), Some(8));
+    assert_eq!(index.offset_utf16(lc(2, 5)), None);
+
+    // Spans of parsed nodes
+    let config = Config::default();
+    let parse = parse_preprocessed(&config, "int x;\nint y = 2;\n".into()).unwrap();
+    let index = parse.line_index();
+    let span = parse.unit.0[1].span;
+    assert_eq!(index.text(span), "int y = 2;");
+    assert_eq!(index.span_line_cols(span), (lc(2, 1), lc(2, 11)));
+
+    // Positions agree with those of syntax errors
+    let err = parse_preprocessed(&config, "int x;\nint y = ;\n".into()).unwrap_err();
+    let index = LineIndex::new(&err.source);
+    assert_eq!(index.line_col(err.offset), lc(err.line, err.column));
+}
+
+#[test]
+fn test_source_map() {
+    use driver::{parse_preprocessed, Config};
+    use source_map::{FileId, SourceMap};
+
+    let source = concat!(
+        "int before;\n",
+        "# 1 \"main.c\"\n",
+        "# 1 \"<built-in>\"\n",
+        "# 1 \"main.c\"\n",
+        "# 1 \"a.h\" 1\n",
+        "int a;\n",
+        "\n",
+        "int b;\n",
+        "# 2 \"main.c\" 2\n",
+        "int c;\n",
+        "#line 40 \"gen.y\"\n",
+        "int d;\n",
+    );
+    let parse = parse_preprocessed(&Config::default(), source.into()).unwrap();
+    let map = parse.source_map();
+
+    let names: Vec<_> = map.files().into_iter().map(|(_, name)| name).collect();
+    assert_eq!(names, ["main.c", "<built-in>", "a.h", "gen.y"]);
+    assert_eq!(map.main_file(), map.file_id("main.c"));
+
+    let locations: Vec<_> = parse
+        .unit
+        .0
+        .iter()
+        .map(|d| {
+            map.location(d.span.start)
+                .map(|l| (map.file_name(l.file), l.line, l.column))
+        })
+        .collect();
+    assert_eq!(
+        locations,
+        [
+            None,
+            Some(("a.h", 1, 1)),
+            Some(("a.h", 3, 1)),
+            Some(("main.c", 2, 1)),
+            Some(("gen.y", 40, 1)),
+        ]
+    );
+
+    let main: Vec<_> = parse.unit.0.iter().map(|d| map.is_main_file(d.span.start)).collect();
+    assert_eq!(main, [false, false, false, true, false]);
+
+    assert_eq!(SourceMap::new("int x;").main_file(), None);
+    assert!(SourceMap::new("int x;").is_main_file(0));
+
+    // Pseudo files before the main file
+    let source = concat!(
+        "# 1 \"<built-in>\"\n",
+        "int a;\n",
+        "# 1 \"<command-line>\"\n",
+        "int b;\n",
+        "# 1 \"main.c\"\n",
+        "# 1 \"<built-in>\" 1\n",
+        "int c;\n",
+        "# 1 \"main.c\" 2\n",
+        "int d;\n",
+    );
+    let other = SourceMap::new(source);
+    assert_eq!(other.main_file(), other.file_id("main.c"));
+    let main: Vec<_> = ["int a", "int b", "int c", "int d"]
+        .iter()
+        .map(|d| other.is_main_file(source.find(d).unwrap()))
+        .collect();
+    assert_eq!(main, [false, false, false, true]);
+
+    // Files have the same identifiers in all maps
+    assert_eq!(other.file_id("main.c"), map.file_id("main.c"));
+    assert_eq!(other.file_id("<built-in>"), map.file_id("<built-in>"));
+    let a = map.file_id("a.h").unwrap();
+    assert_eq!(other.file_id("a.h"), None);
+    assert_eq!(a.name(), "a.h");
+    assert_eq!(FileId::of("a.h"), a);
+
+    // File names are string literals
+    let map = SourceMap::new("# 1 \"C:\\\\dir\\\\a.h\"\n# 1 \"say \\\"hi\\\".h\" 1\n");
+    let names: Vec<_> = map.files().into_iter().map(|(_, name)| name).collect();
+    assert_eq!(names, ["C:\\dir\\a.h", "say \"hi\".h"]);
+}
+
+#[test]
+fn test_query() {
+    use driver::Config;
+    use query::Pattern;
+    use self::parser::translation_unit;
+    use visit_path::NodeRef;
+
+    let source = r#"
+void f(char *d, char *s, int n) {
+    memcpy(d, s, sizeof(d));
+    memcpy(d, s, sizeof(char *));
+    memcpy(d, s, n);
+    n = n + n;
+    n = n + 1;
+    if (n) return; else n = memcpy(d, d, sizeof(s));
+    printf("$x", n);
+    printf("%d", n);
+}
+"#;
+    let unit = translation_unit(source, &mut Env::new()).unwrap();
+    let config = Config::default();
+    let find = |pattern: &str| Pattern::new(&config, pattern).unwrap().find(&unit);
+    let text = |span: Span| source[span.start..span.end].trim_end();
+
+    let matches = find("memcpy($dst, $_, sizeof($size))");
+    assert_eq!(matches.len(), 3);
+    assert_eq!(text(matches[0].span), "memcpy(d, s, sizeof(d))");
+    assert_eq!(matches[0].bindings.len(), 2);
+    // Spans of parenthesized expressions include the parentheses
+    assert_eq!(text(matches[0].get("size").unwrap().span), "(d)");
+    let size = matches[1].get("size").unwrap();
+    assert!(matches!(size.node, NodeRef::TypeName(_)));
+    assert_eq!(text(size.span), "char *");
+    assert!(matches[0].get("_").is_none());
+
+    // Repeated metavariables bind the same code
+    assert_eq!(find("memcpy($x, $x, $_)").len(), 1);
+    assert_eq!(find("$a + $a").len(), 1);
+    assert_eq!(find("$a + 1").len(), 1);
+
+    // Statement patterns
+    let matches = find("if ($c) return; else $s;");
+    assert_eq!(matches.len(), 1);
+    assert!(matches!(matches[0].node, NodeRef::Statement(_)));
+    assert_eq!(text(matches[0].get("s").unwrap().span), "n = memcpy(d, d, sizeof(s));");
+    assert_eq!(find("$n = $n + $_;").len(), 2);
+
+    // Dollar signs in literals are kept
+    let matches = find("printf(\"$x\", $n)");
+    assert_eq!(matches.len(), 1);
+    assert_eq!(text(matches[0].span), "printf(\"$x\", n)");
+    assert_eq!(matches[0].bindings.len(), 1);
+    assert_eq!(find("printf($f, $n)").len(), 2);
+    assert_eq!(find("'$'").len(), 0);
+
+    assert!(Pattern::new(&config, "memcpy($a,").is_err());
+}
+
+#[test]
+fn test_structural_eq() {
+    use self::parser::{expression, statement};
+    use span::Structural;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let a = expression("a+(b*c)", &mut Env::new()).unwrap();
+    let b = expression("a + b * c", &mut Env::new()).unwrap();
+    let c = expression("(a + b) * c", &mut Env::new()).unwrap();
+    assert_ne!(a.span, b.span);
+    assert_ne!(a, b);
+    assert_eq!(Structural(&a), Structural(&b));
+    assert_eq!(hash(&Structural(&a)), hash(&Structural(&b)));
+    assert_ne!(Structural(&a), Structural(&c));
+
+    // Spans are compared like any other field, undefined ones included
+    assert_ne!(Span::none(), a.span);
+    assert_eq!(Span::none(), Span::none());
+    let mut d = a.clone();
+    d.span = Span::none();
+    assert_ne!(d, a);
+    assert_eq!(Structural(&d), Structural(&a));
+
+    fn is_eq<T: Eq>() {}
+    is_eq::<Node<Expression>>();
+    is_eq::<Statement>();
+    is_eq::<TranslationUnit>();
+
+    let statements = ["x = 1;", "x=1;", "{ x = 1; }", "x = 2;", "{x=1;}"];
+    let unique = statements
+        .iter()
+        .map(|s| Structural(statement(s, &mut Env::new()).unwrap()))
+        .collect::<HashSet<_>>();
+    assert_eq!(unique.len(), 3);
+}
+
+#[test]
+fn test_quote() {
+    use driver::Config;
+    use print::to_string;
+    use quote::{self, Error, Splice};
+    use self::parser::expression;
+
+    let source = expression("a[i]", &mut Env::new()).unwrap();
+    let sum = c_expr!("$x + $x * 2", x = source.clone());
+    assert_eq!(to_string(&sum), "a[i] + a[i] * 2");
+    assert!(sum.span.is_none());
+    match sum.node {
+        Expression::BinaryOperator(ref b) => assert_eq!(b.node.lhs.span, source.span),
+        _ => panic!("not a binary expression: {:?}", sum),
+    }
+
+    let name: Identifier = ident("count");
+    let decl = c_decl!(
+        "static $T $name = $init;",
+        T = TypeSpecifier::Long,
+        name = name.clone(),
+        init = c_expr!("sizeof($T *)", T = TypeSpecifier::Char),
+    );
+    assert_eq!(to_string(&decl), "static long count = sizeof(char *);");
+    // All spans are undefined, defined ones would show as `start…end`
+    let debug = format!("{:?}", decl);
+    assert_eq!(debug.matches('…').count(), debug.matches("span: …").count());
+
+    let body = c_stmt!(
+        "{ $decl; while ($name--) $step; }",
+        decl = decl,
+        name = name,
+        step = c_stmt!("f();"),
+    );
+    assert_eq!(
+        to_string(&body),
+        "{\n    static long count = sizeof(char *);\n    while (count--)\n        f();\n}"
+    );
+
+    // Snippets may be indented or span several lines
+    let stmt = c_stmt!("\n    return 1;\n");
+    assert_eq!(to_string(&stmt), "return 1;");
+    let decl = c_decl!(
+        "
+        static int x;
+        "
+    );
+    assert_eq!(to_string(&decl), "static int x;");
+
+    let config = Config::default();
+    let splices = [("s", Splice::from(c_stmt!("return;")))];
+    match quote::expression(&config, "$s + $t", &splices) {
+        Err(Error::MisplacedSplice(ref name)) => assert_eq!(name, "s"),
+        r => panic!("unexpected result: {:?}", r),
+    }
+    // Errors refer to the snippet as written
+    match quote::statement(&config, "{\n  $t + ;\n}", &splices) {
+        Err(Error::SyntaxError(ref e)) => {
+            assert_eq!(e.source, "{\n  $t + ;\n}");
+            assert_eq!((e.line, e.column, e.offset), (2, 8, 9));
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+    match quote::statement(&config, "$s; $t;", &splices) {
+        Err(Error::SyntaxError(ref e)) => assert_eq!((e.line, e.column, e.offset), (1, 3, 2)),
+        r => panic!("unexpected result: {:?}", r),
+    }
+    match quote::statement(&config, "puts(\"$x\", $t +);", &splices) {
+        Err(Error::SyntaxError(ref e)) => assert_eq!((e.line, e.column, e.offset), (1, 16, 15)),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    // Dollar signs in literals are not metavariables
+    assert_eq!(to_string(&c_expr!("puts(\"$5\")")), "puts(\"$5\")");
+    let n: Identifier = ident("n");
+    let call = c_expr!("printf(\"$x %c\", '$', $x)", x = n);
+    assert_eq!(to_string(&call), "printf(\"$x %c\", '$', n)");
+    match quote::statement(&config, "if (x) $s; else $t;", &splices) {
+        Err(Error::UnknownPlaceholder(ref name)) => assert_eq!(name, "t"),
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_parse_fragments() {
+    use driver::{parse_declaration, parse_expression, parse_statement, parse_type_name};
+    use driver::{Config, Flavor};
+    use print::to_string;
+
+    let std = |typedefs: &[&str]| {
+        let mut config = Config::default().flavor(Flavor::StdC11);
+        config.typedefs = typedefs.iter().cloned().collect();
+        config
+    };
+
+    let ty = parse_type_name(&std(&[]), "const char *[4]").unwrap();
+    assert_eq!(ty.span, Span::span(0, 15));
+    assert_eq!(ty.node.specifiers.len(), 2);
+    assert_eq!(to_string(&ty), "const char *[4]");
+
+    // Typedef names change how fragments parse
+    let cast = parse_expression(&std(&["T"]), "(T) * p").unwrap();
+    assert!(matches!(cast.node, Expression::Cast(_)));
+    let product = parse_expression(&std(&[]), "(T) * p").unwrap();
+    assert!(matches!(product.node, Expression::BinaryOperator(_)));
+
+    assert!(parse_declaration(&std(&[]), "size_t n = 0;").is_err());
+    let decl = parse_declaration(&std(&["size_t"]), "size_t n = 0;").unwrap();
+    assert_eq!(to_string(&decl), "size_t n = 0;");
+    assert!(parse_type_name(&Config::with_gcc(), "__builtin_va_list").is_ok());
+
+    let stmt = parse_statement(&std(&["T"]), "{ T x; f(x); }").unwrap();
+    assert!(matches!(stmt.node, Statement::Compound(ref items) if items.len() == 2));
+
+    let err = parse_expression(&std(&[]), "a +").unwrap_err();
+    assert_eq!((err.line, err.column, err.offset), (1, 4, 3));
+
+    // Surrounding whitespace and comments
+    assert!(parse_statement(&std(&[]), "x;\n").is_ok());
+    assert!(parse_statement(&std(&[]), "  x; ").is_ok());
+    assert!(parse_declaration(&std(&["size_t"]), "size_t n;\n").is_ok());
+    assert!(parse_expression(&std(&[]), " a").is_ok());
+    let a = parse_expression(&std(&[]), "/* b */ a /* c */ // d\n").unwrap();
+    assert_eq!(a.span, Span::span(8, 10));
+
+    // Extensions of the configuration apply
+    let embedded = std(&[]).embedded(true);
+    assert!(parse_declaration(&std(&[]), "_Sat _Fract x;").is_err());
+    assert!(parse_declaration(&embedded, "_Sat _Fract x;").is_ok());
+}
+
+#[test]
+fn test_typedef_seeding() {
+    use driver::{parse_preprocessed, Config, Flavor, Typedefs};
+
+    let mut config = Config {
+        flavor: Flavor::StdC11,
+        ..Config::default()
+    };
+    let source = "uint32_t x; FILE *f;";
+    assert!(parse_preprocessed(&config, source.to_string()).is_err());
+
+    config.typedefs = ["uint32_t", "FILE"].iter().cloned().collect();
+    let parse = parse_preprocessed(&config, source.to_string()).unwrap();
+    assert_eq!(parse.typedefs, config.typedefs);
+
+    // Snapshot of file-scope typedefs after parsing a header
+    let header = "typedef int T; void f(void) { typedef int local; } typedef struct s S; int FILE;";
+    let parse = parse_preprocessed(&config, header.to_string()).unwrap();
+    let mut names = parse.typedefs.iter().collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, ["S", "T", "uint32_t"]);
+
+    // Built-in types are not part of the snapshot
+    let gnu = parse_preprocessed(&Config::with_gcc(), "typedef int T;".to_string()).unwrap();
+    assert_eq!(gnu.typedefs.iter().collect::<Vec<_>>(), ["T"]);
+
+    let config = Config {
+        typedefs: parse.typedefs,
+        ..config
+    };
+    let parse = parse_preprocessed(&config, "S s; T t; uint32_t u;".to_string()).unwrap();
+    assert_eq!(parse.unit.0.len(), 3);
+    assert!(parse_preprocessed(&config, "FILE *f;".to_string()).is_err());
+
+    let mut typedefs = Typedefs::new();
+    assert!(typedefs.insert("size_t"));
+    assert!(!typedefs.insert("size_t"));
+    assert!(typedefs.contains("size_t"));
+    assert!(typedefs.remove("size_t"));
+    assert!(typedefs.is_empty());
+}
+
+#[test]
+fn test_infer_typedefs() {
+    use driver::{
+        parse_declaration, parse_expression, parse_preprocessed, parse_statement, Config, Flavor,
+    };
+    use env::Symbol;
+    use print;
+    use span::Structural;
+
+    let mut config = Config {
+        flavor: Flavor::StdC11,
+        ..Config::default()
+    };
+    let source = concat!(
+        "uint32_t x; FILE *open(const char *name, mode_t mode);\n",
+        "int f(size_t n) { buf_t *b = (buf_t *) n; off_t o; int c; c * o; a * p; g(q); n = (len_t) o; }",
+    );
+    assert!(parse_preprocessed(&config, source.to_string()).is_err());
+
+    config.infer_typedefs = true;
+    let parse = parse_preprocessed(&config, source.to_string()).unwrap();
+    let assumed = parse.assumed_typedefs.iter();
+    let assumed = assumed.map(|i| &parse.source[i.span.start..i.span.end]);
+    assert_eq!(
+        assumed.collect::<Vec<_>>(),
+        ["uint32_t", "FILE", "mode_t", "size_t", "buf_t", "off_t", "len_t"]
+    );
+    assert!(parse.typedefs.contains("buf_t"));
+    assert!(!parse.typedefs.contains("a"));
+
+    let body = match parse.unit.0[2].node {
+        ExternalDeclaration::FunctionDefinition(ref f) => &f.node.statement.node,
+        ref other => panic!("expected a function definition, got {:?}", other),
+    };
+    let items = match *body {
+        Statement::Compound(ref items) => items,
+        ref other => panic!("expected a compound statement, got {:?}", other),
+    };
+    let ambiguous = items.iter().map(|item| match item.node {
+        BlockItem::Ambiguous(ref a) => Some(&a.node),
+        _ => None,
+    });
+    let ambiguous = ambiguous.collect::<Vec<_>>();
+    assert_eq!(ambiguous.iter().filter(|a| a.is_some()).count(), 2);
+
+    // Both interpretations are what the parser would produce knowing what the identifiers are
+    for (a, text, ty) in [(ambiguous[4], "a * p;", "a"), (ambiguous[5], "g(q);", "g")] {
+        let a = a.unwrap();
+        let mut typedefs = Config::default().flavor(Flavor::StdC11);
+        typedefs.typedefs = [ty].iter().cloned().collect();
+        let declaration = parse_declaration(&typedefs, text).unwrap();
+        assert_eq!(Structural(&a.declaration), Structural(&declaration));
+        let statement = parse_statement(&Config::default().flavor(Flavor::StdC11), text).unwrap();
+        assert_eq!(Structural(&a.statement), Structural(&*statement));
+    }
+
+    // A parenthesized unknown identifier before a unary operator may be a cast
+    let source = "void f(int y) { y = (T) -1; (T)(y); y = (T)&y; y = (T)*p; }";
+    let parse = parse_preprocessed(&config, source.to_string()).unwrap();
+    assert!(parse.assumed_typedefs.is_empty());
+    assert_eq!(format!("{:?}", parse.unit).matches("Ambiguous(").count(), 4);
+    let mut typedefs = Config::default().flavor(Flavor::StdC11);
+    typedefs.typedefs = ["T"].iter().cloned().collect();
+    for text in ["(T) -1", "(T)(y)", "(T)&y", "(T)*p", "(T) +y * 2"] {
+        let e = parse_expression(&config, text).unwrap();
+        let a = match e.node {
+            Expression::Ambiguous(ref a) => &a.node,
+            ref other => panic!("{}: expected an ambiguous expression, got {:?}", text, other),
+        };
+        assert_eq!(e.span, Span::span(0, text.len()));
+        let cast = parse_expression(&typedefs, text).unwrap();
+        assert_eq!(Structural(&a.cast), Structural(&*cast));
+        let expression = parse_expression(&Config::default().flavor(Flavor::StdC11), text).unwrap();
+        assert_eq!(Structural(&a.expression), Structural(&*expression));
+    }
+    let e = parse_expression(&config, "(T)-1").unwrap();
+    assert_eq!(print::to_string(&e.node), "(T)-1");
+    // Not when the identifier is known, or the operand could not be cast
+    let e = parse_statement(&config, "{ int T; T = (T) -1; }").unwrap();
+    assert!(!format!("{:?}", e).contains("Ambiguous"));
+    let e = parse_expression(&config, "(T) && y").unwrap();
+    assert!(matches!(e.node, Expression::BinaryOperator(_)));
+
+    // Identifiers declared as something else are never assumed to be types
+    let source = "int size_t; void f(void) { size_t * n; }";
+    let parse = parse_preprocessed(&config, source.to_string()).unwrap();
+    assert!(parse.assumed_typedefs.is_empty());
+
+    // Alternatives that fail forget the names they declared and the types they assumed
+    let env = &mut Env::new();
+    env.infer_typedefs = true;
+    env.add_typename("x");
+    env.begin_attempt();
+    env.add_symbol("x", Symbol::Identifier);
+    env.enter_scope();
+    env.assume_typedefs(vec![Node::new(ident("U"), Span::span(0, 1))]);
+    env.leave_scope();
+    env.end_attempt(false);
+    assert!(env.is_typename("x"));
+    assert!(!env.is_typename("U"));
+    assert!(env.assumed_typedefs().is_empty());
+
+    // Every level of nesting is read a bounded number of times
+    let start = ::std::time::Instant::now();
+    for error in ["", "@"] {
+        let mut source = "void f(void) ".to_string() + &"{ if (x) ".repeat(40);
+        source += &format!("{{ T y; y = (T) -y; {} }}", error);
+        source += &" }".repeat(40);
+        let parse = parse_preprocessed(&config, source);
+        assert_eq!(parse.is_ok(), error.is_empty());
+    }
+    let source = "(a)(".repeat(40) + "1" + &")".repeat(40);
+    let e = parse_expression(&config, &source).unwrap();
+    assert_eq!(format!("{:?}", e).matches("Ambiguous(").count(), 40);
+    assert!(start.elapsed() < ::std::time::Duration::from_secs(5));
+}
+
+#[cfg(feature = "serde")]
+fn json_round_trip<T>(value: &T)
+where
+    T: ::serde::Serialize + ::serde::de::DeserializeOwned + PartialEq + ::std::fmt::Debug,
+{
+    use serde_json;
+
+    let json = serde_json::to_string(value).unwrap();
+    let mut de = serde_json::Deserializer::from_str(&json);
+    de.disable_recursion_limit();
+    let back = T::deserialize(&mut de).unwrap();
+    assert_eq!(&back, value, "JSON round trip changed the value: {}", json);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    use parser::{constant, expression, statement, translation_unit};
+    use serde_json;
+
+    let corpus = [
+        "int a, *b = 0, c[10][20], (*f)(int, char *, ...);",
+        "typedef struct s { int x : 3; union { float f; } u; } S; enum e { A = 1, B } v;",
+        "static _Thread_local const volatile unsigned long long int x = 18446744073709551615ull;",
+        "_Alignas(16) char buf[]; _Static_assert(sizeof(int) == 4, \"int\");",
+        "int f(int n) { int i; for (i = 0; i < n; i++) { if (i % 2) continue; else break; } }",
+        "void g(void) { a: switch (x) { case 1: goto a; default: do x--; while (x); } }",
+        "double h(void) { return (double) x ? -1.5e3 : _Generic(x, int: 0x1p-2, default: 'c'); }",
+        "char *s = \"a\" \"b\"; struct s t = { .x = 1, [2] = { 3 } }; int l = (int []){ 1, 2 }[0];",
+        "int __attribute__((noreturn)) k(void) { asm volatile (\"nop\" : : \"r\" (x)); }",
+        "int m(void) { return ({ int y = x; y; }) + __builtin_offsetof(struct s, x); }",
+        "void n(void) __attribute__((availability(macos, introduced = 10.4, deprecated = 10.6)));",
+        "_Float128 q; __typeof__(q) r; struct { int n; int a[]; } *p, **pp;",
+    ];
+    for source in corpus.iter() {
+        let env = &mut Env::with_clang();
+        let unit = translation_unit(source, env);
+        json_round_trip(&unit.unwrap_or_else(|e| panic!("{}: {:?}", source, e)));
+    }
+
+    let env = &mut Env::new();
+    json_round_trip(&constant("0x1.8p1f", env).unwrap());
+    json_round_trip(&statement("while (1) ;", env).unwrap());
+
+    // Externally tagged enums, nodes as `node` and `span` fields
+    let x = expression("x", env).unwrap();
+    assert_eq!(
+        serde_json::to_string(&x.node).unwrap(),
+        match x.node {
+            Expression::Identifier(ref i) => format!(
+                r#"{{"Identifier":{{"node":{{"name":"x"}},"span":{{"start":0,"end":1}},"id":{}}}}}"#,
+                serde_json::to_string(&i.id).unwrap()
+            ),
+            _ => panic!("not an identifier"),
+        }
+    );
+}
+
+#[test]
+fn test_debug_without_spans() {
+    use self::parser::{expression, statement};
+    use span::Compact;
+
+    let e = expression("a + 1", &mut Env::new()).unwrap();
+    let compact = format!("{:?}", Compact(&e));
+    assert!(!compact.contains("span"), "{}", compact);
+    let lhs = "lhs: Identifier(Identifier { name: \"a\" })";
+    assert!(compact.starts_with("BinaryOperator(BinaryOperatorExpression { operator: Plus, "));
+    assert!(compact.contains(lhs), "{}", compact);
+
+    // Nodes in standard containers are compact too, and only inside of the wrapper
+    let s = statement("{ f(a, b); }", &mut Env::new()).unwrap();
+    let items = match s.node {
+        Statement::Compound(ref items) => items,
+        _ => panic!("expected a compound statement"),
+    };
+    let compact = format!("{:#?}", Compact(&Some(items)));
+    assert!(!compact.contains("span"), "{}", compact);
+    assert!(compact.contains("arguments: [\n"), "{}", compact);
+    assert!(format!("{:?}", items).contains("span"));
+}
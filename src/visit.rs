@@ -182,6 +182,14 @@ pub trait Visit<'ast> {
         visit_offset_member(self, offset_member, span)
     }
 
+    fn visit_ambiguous_expression(
+        &mut self,
+        ambiguous_expression: &'ast AmbiguousExpression,
+        span: &'ast Span,
+    ) {
+        visit_ambiguous_expression(self, ambiguous_expression, span)
+    }
+
     fn visit_declaration(&mut self, declaration: &'ast Declaration, span: &'ast Span) {
         visit_declaration(self, declaration, span)
     }
@@ -430,6 +438,14 @@ pub trait Visit<'ast> {
         visit_block_item(self, block_item, span)
     }
 
+    fn visit_ambiguous_block_item(
+        &mut self,
+        ambiguous_block_item: &'ast AmbiguousBlockItem,
+        span: &'ast Span,
+    ) {
+        visit_ambiguous_block_item(self, ambiguous_block_item, span)
+    }
+
     fn visit_translation_unit(&mut self, translation_unit: &'ast TranslationUnit) {
         visit_translation_unit(self, translation_unit)
     }
@@ -458,6 +474,14 @@ pub trait Visit<'ast> {
         visit_attribute(self, attribute, span)
     }
 
+    fn visit_vendor_qualifier(
+        &mut self,
+        vendor_qualifier: &'ast VendorQualifier,
+        span: &'ast Span,
+    ) {
+        visit_vendor_qualifier(self, vendor_qualifier, span)
+    }
+
     fn visit_asm_statement(&mut self, asm_statement: &'ast AsmStatement, span: &'ast Span) {
         visit_asm_statement(self, asm_statement, span)
     }
@@ -568,9 +592,8 @@ pub fn visit_float_format<'ast, V: Visit<'ast> + ?Sized>(
     float_format: &'ast FloatFormat,
     span: &'ast Span,
 ) {
-    match *float_format {
-        FloatFormat::TS18661Format(ref f) => visitor.visit_ts18661_float_type(f, span),
-        _ => {}
+    if let FloatFormat::TS18661Format(ref f) = *float_format {
+        visitor.visit_ts18661_float_type(f, span)
     }
 }
 
@@ -612,6 +635,7 @@ pub fn visit_expression<'ast, V: Visit<'ast> + ?Sized>(
         Expression::OffsetOf(ref o) => visitor.visit_offset_of_expression(&o.node, &o.span),
         Expression::VaArg(ref v) => visitor.visit_va_arg_expression(&v.node, &v.span),
         Expression::Statement(ref s) => visitor.visit_statement(&s.node, &s.span),
+        Expression::Ambiguous(ref a) => visitor.visit_ambiguous_expression(&a.node, &a.span),
     }
 }
 
@@ -855,6 +879,17 @@ pub fn visit_offset_member<'ast, V: Visit<'ast> + ?Sized>(
     }
 }
 
+pub fn visit_ambiguous_expression<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    ambiguous_expression: &'ast AmbiguousExpression,
+    _span: &'ast Span,
+) {
+    let cast = &ambiguous_expression.cast;
+    visitor.visit_expression(&cast.node, &cast.span);
+    let expression = &ambiguous_expression.expression;
+    visitor.visit_expression(&expression.node, &expression.span);
+}
+
 pub fn visit_declaration<'ast, V: Visit<'ast> + ?Sized>(
     visitor: &mut V,
     declaration: &'ast Declaration,
@@ -1003,6 +1038,11 @@ pub fn visit_specifier_qualifier<'ast, V: Visit<'ast> + ?Sized>(
     match *specifier_qualifier {
         SpecifierQualifier::TypeSpecifier(ref t) => visitor.visit_type_specifier(&t.node, &t.span),
         SpecifierQualifier::TypeQualifier(ref t) => visitor.visit_type_qualifier(&t.node, &t.span),
+        SpecifierQualifier::Extension(ref e) => {
+            for extension in e {
+                visitor.visit_extension(&extension.node, &extension.span);
+            }
+        }
     }
 }
 
@@ -1415,9 +1455,21 @@ pub fn visit_block_item<'ast, V: Visit<'ast> + ?Sized>(
         BlockItem::Declaration(ref d) => visitor.visit_declaration(&d.node, &d.span),
         BlockItem::StaticAssert(ref s) => visitor.visit_static_assert(&s.node, &s.span),
         BlockItem::Statement(ref s) => visitor.visit_statement(&s.node, &s.span),
+        BlockItem::Ambiguous(ref a) => visitor.visit_ambiguous_block_item(&a.node, &a.span),
     }
 }
 
+pub fn visit_ambiguous_block_item<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    ambiguous_block_item: &'ast AmbiguousBlockItem,
+    _span: &'ast Span,
+) {
+    let declaration = &ambiguous_block_item.declaration;
+    visitor.visit_declaration(&declaration.node, &declaration.span);
+    let statement = &ambiguous_block_item.statement;
+    visitor.visit_statement(&statement.node, &statement.span);
+}
+
 pub fn visit_translation_unit<'ast, V: Visit<'ast> + ?Sized>(
     visitor: &mut V,
     translation_unit: &'ast TranslationUnit,
@@ -1473,6 +1525,7 @@ pub fn visit_extension<'ast, V: Visit<'ast> + ?Sized>(
         Extension::AvailabilityAttribute(ref a) => {
             visitor.visit_availability_attribute(&a.node, &a.span)
         }
+        Extension::VendorQualifier(ref v) => visitor.visit_vendor_qualifier(v, span),
     }
 }
 
@@ -1486,6 +1539,16 @@ pub fn visit_attribute<'ast, V: Visit<'ast> + ?Sized>(
     }
 }
 
+pub fn visit_vendor_qualifier<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    vendor_qualifier: &'ast VendorQualifier,
+    _span: &'ast Span,
+) {
+    for argument in &vendor_qualifier.arguments {
+        visitor.visit_expression(&argument.node, &argument.span);
+    }
+}
+
 pub fn visit_asm_statement<'ast, V: Visit<'ast> + ?Sized>(
     visitor: &mut V,
     asm_statement: &'ast AsmStatement,
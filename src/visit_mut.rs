@@ -0,0 +1,1729 @@
+//! Recursive abstract syntax tree traversal with mutable access
+//!
+//! ```rust
+//! # use lang_c::{ast, span, visit_mut};
+//! struct Rename;
+//!
+//! impl visit_mut::VisitMut for Rename {
+//!     fn visit_identifier_mut(&mut self, identifier: &mut ast::Identifier, span: &mut span::Span) {
+//!         if identifier.name == "foo" {
+//!             identifier.name = "bar".into();
+//!         }
+//!         visit_mut::visit_identifier_mut(self, identifier, span);
+//!     }
+//! }
+//! ```
+//!
+//! The `VisitMut` trait mirrors `visit::Visit`: there is a hook for each type of node in the AST
+//! and default implementations recursively visit the sub-nodes through a corresponding free
+//! function in this module. Hooks receive mutable references, so a node can be modified or
+//! replaced in place by assigning to it.
+
+use ast::*;
+use span::Span;
+
+pub trait VisitMut {
+    fn visit_identifier_mut(&mut self, identifier: &mut Identifier, span: &mut Span) {
+        visit_identifier_mut(self, identifier, span)
+    }
+
+    fn visit_constant_mut(&mut self, constant: &mut Constant, span: &mut Span) {
+        visit_constant_mut(self, constant, span)
+    }
+
+    fn visit_integer_mut(&mut self, integer: &mut Integer, span: &mut Span) {
+        visit_integer_mut(self, integer, span)
+    }
+
+    fn visit_integer_base_mut(&mut self, integer_base: &mut IntegerBase, span: &mut Span) {
+        visit_integer_base_mut(self, integer_base, span)
+    }
+
+    fn visit_integer_suffix_mut(&mut self, integer_suffix: &mut IntegerSuffix, span: &mut Span) {
+        visit_integer_suffix_mut(self, integer_suffix, span)
+    }
+
+    fn visit_integer_size_mut(&mut self, integer_size: &mut IntegerSize, span: &mut Span) {
+        visit_integer_size_mut(self, integer_size, span)
+    }
+
+    fn visit_float_mut(&mut self, float: &mut Float, span: &mut Span) {
+        visit_float_mut(self, float, span)
+    }
+
+    fn visit_float_base_mut(&mut self, float_base: &mut FloatBase, span: &mut Span) {
+        visit_float_base_mut(self, float_base, span)
+    }
+
+    fn visit_float_suffix_mut(&mut self, float_suffix: &mut FloatSuffix, span: &mut Span) {
+        visit_float_suffix_mut(self, float_suffix, span)
+    }
+
+    fn visit_float_format_mut(&mut self, float_format: &mut FloatFormat, span: &mut Span) {
+        visit_float_format_mut(self, float_format, span)
+    }
+
+    fn visit_string_literal_mut(&mut self, string_literal: &mut StringLiteral, span: &mut Span) {
+        visit_string_literal_mut(self, string_literal, span)
+    }
+
+    fn visit_expression_mut(&mut self, expression: &mut Expression, span: &mut Span) {
+        visit_expression_mut(self, expression, span)
+    }
+
+    fn visit_member_operator_mut(&mut self, member_operator: &mut MemberOperator, span: &mut Span) {
+        visit_member_operator_mut(self, member_operator, span)
+    }
+
+    fn visit_generic_selection_mut(
+        &mut self,
+        generic_selection: &mut GenericSelection,
+        span: &mut Span,
+    ) {
+        visit_generic_selection_mut(self, generic_selection, span)
+    }
+
+    fn visit_generic_association_mut(
+        &mut self,
+        generic_association: &mut GenericAssociation,
+        span: &mut Span,
+    ) {
+        visit_generic_association_mut(self, generic_association, span)
+    }
+
+    fn visit_generic_association_type_mut(
+        &mut self,
+        generic_association_type: &mut GenericAssociationType,
+        span: &mut Span,
+    ) {
+        visit_generic_association_type_mut(self, generic_association_type, span)
+    }
+
+    fn visit_member_expression_mut(
+        &mut self,
+        member_expression: &mut MemberExpression,
+        span: &mut Span,
+    ) {
+        visit_member_expression_mut(self, member_expression, span)
+    }
+
+    fn visit_call_expression_mut(&mut self, call_expression: &mut CallExpression, span: &mut Span) {
+        visit_call_expression_mut(self, call_expression, span)
+    }
+
+    fn visit_compound_literal_mut(
+        &mut self,
+        compound_literal: &mut CompoundLiteral,
+        span: &mut Span,
+    ) {
+        visit_compound_literal_mut(self, compound_literal, span)
+    }
+
+    fn visit_unary_operator_mut(&mut self, unary_operator: &mut UnaryOperator, span: &mut Span) {
+        visit_unary_operator_mut(self, unary_operator, span)
+    }
+
+    fn visit_unary_operator_expression_mut(
+        &mut self,
+        unary_operator_expression: &mut UnaryOperatorExpression,
+        span: &mut Span,
+    ) {
+        visit_unary_operator_expression_mut(self, unary_operator_expression, span)
+    }
+
+    fn visit_cast_expression_mut(&mut self, cast_expression: &mut CastExpression, span: &mut Span) {
+        visit_cast_expression_mut(self, cast_expression, span)
+    }
+
+    fn visit_binary_operator_mut(&mut self, binary_operator: &mut BinaryOperator, span: &mut Span) {
+        visit_binary_operator_mut(self, binary_operator, span)
+    }
+
+    fn visit_binary_operator_expression_mut(
+        &mut self,
+        binary_operator_expression: &mut BinaryOperatorExpression,
+        span: &mut Span,
+    ) {
+        visit_binary_operator_expression_mut(self, binary_operator_expression, span)
+    }
+
+    fn visit_conditional_expression_mut(
+        &mut self,
+        conditional_expression: &mut ConditionalExpression,
+        span: &mut Span,
+    ) {
+        visit_conditional_expression_mut(self, conditional_expression, span)
+    }
+
+    fn visit_va_arg_expression_mut(
+        &mut self,
+        va_arg_expression: &mut VaArgExpression,
+        span: &mut Span,
+    ) {
+        visit_va_arg_expression_mut(self, va_arg_expression, span)
+    }
+
+    fn visit_offset_of_expression_mut(
+        &mut self,
+        offset_of_expression: &mut OffsetOfExpression,
+        span: &mut Span,
+    ) {
+        visit_offset_of_expression_mut(self, offset_of_expression, span)
+    }
+
+    fn visit_offset_designator_mut(
+        &mut self,
+        offset_designator: &mut OffsetDesignator,
+        span: &mut Span,
+    ) {
+        visit_offset_designator_mut(self, offset_designator, span)
+    }
+
+    fn visit_offset_member_mut(&mut self, offset_member: &mut OffsetMember, span: &mut Span) {
+        visit_offset_member_mut(self, offset_member, span)
+    }
+
+    fn visit_ambiguous_expression_mut(
+        &mut self,
+        ambiguous_expression: &mut AmbiguousExpression,
+        span: &mut Span,
+    ) {
+        visit_ambiguous_expression_mut(self, ambiguous_expression, span)
+    }
+
+    fn visit_declaration_mut(&mut self, declaration: &mut Declaration, span: &mut Span) {
+        visit_declaration_mut(self, declaration, span)
+    }
+
+    fn visit_declaration_specifier_mut(
+        &mut self,
+        declaration_specifier: &mut DeclarationSpecifier,
+        span: &mut Span,
+    ) {
+        visit_declaration_specifier_mut(self, declaration_specifier, span)
+    }
+
+    fn visit_init_declarator_mut(&mut self, init_declarator: &mut InitDeclarator, span: &mut Span) {
+        visit_init_declarator_mut(self, init_declarator, span)
+    }
+
+    fn visit_storage_class_specifier_mut(
+        &mut self,
+        storage_class_specifier: &mut StorageClassSpecifier,
+        span: &mut Span,
+    ) {
+        visit_storage_class_specifier_mut(self, storage_class_specifier, span)
+    }
+
+    fn visit_type_specifier_mut(&mut self, type_specifier: &mut TypeSpecifier, span: &mut Span) {
+        visit_type_specifier_mut(self, type_specifier, span)
+    }
+
+    fn visit_ts18661_float_type_mut(
+        &mut self,
+        ts18661_float_type: &mut TS18661FloatType,
+        span: &mut Span,
+    ) {
+        visit_ts18661_float_type_mut(self, ts18661_float_type, span)
+    }
+
+    fn visit_ts18661_float_format_mut(
+        &mut self,
+        ts18661_float_format: &mut TS18661FloatFormat,
+        span: &mut Span,
+    ) {
+        visit_ts18661_float_format_mut(self, ts18661_float_format, span)
+    }
+
+    fn visit_struct_type_mut(&mut self, struct_type: &mut StructType, span: &mut Span) {
+        visit_struct_type_mut(self, struct_type, span)
+    }
+
+    fn visit_struct_kind_mut(&mut self, struct_kind: &mut StructKind, span: &mut Span) {
+        visit_struct_kind_mut(self, struct_kind, span)
+    }
+
+    fn visit_struct_declaration_mut(
+        &mut self,
+        struct_declaration: &mut StructDeclaration,
+        span: &mut Span,
+    ) {
+        visit_struct_declaration_mut(self, struct_declaration, span)
+    }
+
+    fn visit_struct_field_mut(&mut self, struct_field: &mut StructField, span: &mut Span) {
+        visit_struct_field_mut(self, struct_field, span)
+    }
+
+    fn visit_specifier_qualifier_mut(
+        &mut self,
+        specifier_qualifier: &mut SpecifierQualifier,
+        span: &mut Span,
+    ) {
+        visit_specifier_qualifier_mut(self, specifier_qualifier, span)
+    }
+
+    fn visit_struct_declarator_mut(
+        &mut self,
+        struct_declarator: &mut StructDeclarator,
+        span: &mut Span,
+    ) {
+        visit_struct_declarator_mut(self, struct_declarator, span)
+    }
+
+    fn visit_enum_type_mut(&mut self, enum_type: &mut EnumType, span: &mut Span) {
+        visit_enum_type_mut(self, enum_type, span)
+    }
+
+    fn visit_enumerator_mut(&mut self, enumerator: &mut Enumerator, span: &mut Span) {
+        visit_enumerator_mut(self, enumerator, span)
+    }
+
+    fn visit_type_qualifier_mut(&mut self, type_qualifier: &mut TypeQualifier, span: &mut Span) {
+        visit_type_qualifier_mut(self, type_qualifier, span)
+    }
+
+    fn visit_function_specifier_mut(
+        &mut self,
+        function_specifier: &mut FunctionSpecifier,
+        span: &mut Span,
+    ) {
+        visit_function_specifier_mut(self, function_specifier, span)
+    }
+
+    fn visit_alignment_specifier_mut(
+        &mut self,
+        alignment_specifier: &mut AlignmentSpecifier,
+        span: &mut Span,
+    ) {
+        visit_alignment_specifier_mut(self, alignment_specifier, span)
+    }
+
+    fn visit_declarator_mut(&mut self, declarator: &mut Declarator, span: &mut Span) {
+        visit_declarator_mut(self, declarator, span)
+    }
+
+    fn visit_declarator_kind_mut(&mut self, declarator_kind: &mut DeclaratorKind, span: &mut Span) {
+        visit_declarator_kind_mut(self, declarator_kind, span)
+    }
+
+    fn visit_derived_declarator_mut(
+        &mut self,
+        derived_declarator: &mut DerivedDeclarator,
+        span: &mut Span,
+    ) {
+        visit_derived_declarator_mut(self, derived_declarator, span)
+    }
+
+    fn visit_array_declarator_mut(
+        &mut self,
+        array_declarator: &mut ArrayDeclarator,
+        span: &mut Span,
+    ) {
+        visit_array_declarator_mut(self, array_declarator, span)
+    }
+
+    fn visit_function_declarator_mut(
+        &mut self,
+        function_declarator: &mut FunctionDeclarator,
+        span: &mut Span,
+    ) {
+        visit_function_declarator_mut(self, function_declarator, span)
+    }
+
+    fn visit_pointer_qualifier_mut(
+        &mut self,
+        pointer_qualifier: &mut PointerQualifier,
+        span: &mut Span,
+    ) {
+        visit_pointer_qualifier_mut(self, pointer_qualifier, span)
+    }
+
+    fn visit_array_size_mut(&mut self, array_size: &mut ArraySize, span: &mut Span) {
+        visit_array_size_mut(self, array_size, span)
+    }
+
+    fn visit_parameter_declaration_mut(
+        &mut self,
+        parameter_declaration: &mut ParameterDeclaration,
+        span: &mut Span,
+    ) {
+        visit_parameter_declaration_mut(self, parameter_declaration, span)
+    }
+
+    fn visit_ellipsis_mut(&mut self, ellipsis: &mut Ellipsis, span: &mut Span) {
+        visit_ellipsis_mut(self, ellipsis, span)
+    }
+
+    fn visit_type_name_mut(&mut self, type_name: &mut TypeName, span: &mut Span) {
+        visit_type_name_mut(self, type_name, span)
+    }
+
+    fn visit_initializer_mut(&mut self, initializer: &mut Initializer, span: &mut Span) {
+        visit_initializer_mut(self, initializer, span)
+    }
+
+    fn visit_initializer_list_item_mut(
+        &mut self,
+        initializer_list_item: &mut InitializerListItem,
+        span: &mut Span,
+    ) {
+        visit_initializer_list_item_mut(self, initializer_list_item, span)
+    }
+
+    fn visit_designator_mut(&mut self, designator: &mut Designator, span: &mut Span) {
+        visit_designator_mut(self, designator, span)
+    }
+
+    fn visit_range_designator_mut(
+        &mut self,
+        range_designator: &mut RangeDesignator,
+        span: &mut Span,
+    ) {
+        visit_range_designator_mut(self, range_designator, span)
+    }
+
+    fn visit_static_assert_mut(&mut self, static_assert: &mut StaticAssert, span: &mut Span) {
+        visit_static_assert_mut(self, static_assert, span)
+    }
+
+    fn visit_statement_mut(&mut self, statement: &mut Statement, span: &mut Span) {
+        visit_statement_mut(self, statement, span)
+    }
+
+    fn visit_labeled_statement_mut(
+        &mut self,
+        labeled_statement: &mut LabeledStatement,
+        span: &mut Span,
+    ) {
+        visit_labeled_statement_mut(self, labeled_statement, span)
+    }
+
+    fn visit_if_statement_mut(&mut self, if_statement: &mut IfStatement, span: &mut Span) {
+        visit_if_statement_mut(self, if_statement, span)
+    }
+
+    fn visit_switch_statement_mut(
+        &mut self,
+        switch_statement: &mut SwitchStatement,
+        span: &mut Span,
+    ) {
+        visit_switch_statement_mut(self, switch_statement, span)
+    }
+
+    fn visit_while_statement_mut(&mut self, while_statement: &mut WhileStatement, span: &mut Span) {
+        visit_while_statement_mut(self, while_statement, span)
+    }
+
+    fn visit_do_while_statement_mut(
+        &mut self,
+        do_while_statement: &mut DoWhileStatement,
+        span: &mut Span,
+    ) {
+        visit_do_while_statement_mut(self, do_while_statement, span)
+    }
+
+    fn visit_for_statement_mut(&mut self, for_statement: &mut ForStatement, span: &mut Span) {
+        visit_for_statement_mut(self, for_statement, span)
+    }
+
+    fn visit_label_mut(&mut self, label: &mut Label, span: &mut Span) {
+        visit_label_mut(self, label, span)
+    }
+
+    fn visit_for_initializer_mut(&mut self, for_initializer: &mut ForInitializer, span: &mut Span) {
+        visit_for_initializer_mut(self, for_initializer, span)
+    }
+
+    fn visit_block_item_mut(&mut self, block_item: &mut BlockItem, span: &mut Span) {
+        visit_block_item_mut(self, block_item, span)
+    }
+
+    fn visit_ambiguous_block_item_mut(
+        &mut self,
+        ambiguous_block_item: &mut AmbiguousBlockItem,
+        span: &mut Span,
+    ) {
+        visit_ambiguous_block_item_mut(self, ambiguous_block_item, span)
+    }
+
+    fn visit_translation_unit_mut(&mut self, translation_unit: &mut TranslationUnit) {
+        visit_translation_unit_mut(self, translation_unit)
+    }
+
+    fn visit_external_declaration_mut(
+        &mut self,
+        external_declaration: &mut ExternalDeclaration,
+        span: &mut Span,
+    ) {
+        visit_external_declaration_mut(self, external_declaration, span)
+    }
+
+    fn visit_function_definition_mut(
+        &mut self,
+        function_definition: &mut FunctionDefinition,
+        span: &mut Span,
+    ) {
+        visit_function_definition_mut(self, function_definition, span)
+    }
+
+    fn visit_extension_mut(&mut self, extension: &mut Extension, span: &mut Span) {
+        visit_extension_mut(self, extension, span)
+    }
+
+    fn visit_attribute_mut(&mut self, attribute: &mut Attribute, span: &mut Span) {
+        visit_attribute_mut(self, attribute, span)
+    }
+
+    fn visit_vendor_qualifier_mut(
+        &mut self,
+        vendor_qualifier: &mut VendorQualifier,
+        span: &mut Span,
+    ) {
+        visit_vendor_qualifier_mut(self, vendor_qualifier, span)
+    }
+
+    fn visit_asm_statement_mut(&mut self, asm_statement: &mut AsmStatement, span: &mut Span) {
+        visit_asm_statement_mut(self, asm_statement, span)
+    }
+
+    fn visit_availability_attribute_mut(
+        &mut self,
+        availability: &mut AvailabilityAttribute,
+        span: &mut Span,
+    ) {
+        visit_availability_attribute_mut(self, availability, span)
+    }
+
+    fn visit_availability_clause_mut(
+        &mut self,
+        _clause: &mut AvailabilityClause,
+        _span: &mut Span,
+    ) {
+    }
+
+    fn visit_gnu_extended_asm_statement_mut(
+        &mut self,
+        gnu_extended_asm_statement: &mut GnuExtendedAsmStatement,
+        span: &mut Span,
+    ) {
+        visit_gnu_extended_asm_statement_mut(self, gnu_extended_asm_statement, span)
+    }
+
+    fn visit_gnu_asm_operand_mut(&mut self, gnu_asm_operand: &mut GnuAsmOperand, span: &mut Span) {
+        visit_gnu_asm_operand_mut(self, gnu_asm_operand, span)
+    }
+
+    fn visit_type_of_mut(&mut self, type_of: &mut TypeOf, span: &mut Span) {
+        visit_type_of_mut(self, type_of, span)
+    }
+}
+
+pub fn visit_identifier_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _identifier: &mut Identifier,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_constant_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    constant: &mut Constant,
+    span: &mut Span,
+) {
+    match *constant {
+        Constant::Integer(ref mut i) => visitor.visit_integer_mut(i, span),
+        Constant::Float(ref mut f) => visitor.visit_float_mut(f, span),
+        Constant::Character(_) => {}
+    }
+}
+
+pub fn visit_integer_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    integer: &mut Integer,
+    span: &mut Span,
+) {
+    visitor.visit_integer_base_mut(&mut integer.base, span);
+    visitor.visit_integer_suffix_mut(&mut integer.suffix, span);
+}
+
+pub fn visit_integer_base_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _integer_base: &mut IntegerBase,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_integer_suffix_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    integer_suffix: &mut IntegerSuffix,
+    span: &mut Span,
+) {
+    visitor.visit_integer_size_mut(&mut integer_suffix.size, span);
+}
+
+pub fn visit_integer_size_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _integer_size: &mut IntegerSize,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_float_mut<V: VisitMut + ?Sized>(visitor: &mut V, float: &mut Float, span: &mut Span) {
+    visitor.visit_float_base_mut(&mut float.base, span);
+    visitor.visit_float_suffix_mut(&mut float.suffix, span);
+}
+
+pub fn visit_float_base_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _float_base: &mut FloatBase,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_float_suffix_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    float_suffix: &mut FloatSuffix,
+    span: &mut Span,
+) {
+    visitor.visit_float_format_mut(&mut float_suffix.format, span);
+}
+
+pub fn visit_float_format_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    float_format: &mut FloatFormat,
+    span: &mut Span,
+) {
+    if let FloatFormat::TS18661Format(ref mut f) = *float_format {
+        visitor.visit_ts18661_float_type_mut(f, span)
+    }
+}
+
+pub fn visit_string_literal_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _string_literal: &mut StringLiteral,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_expression_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    expression: &mut Expression,
+    _span: &mut Span,
+) {
+    match *expression {
+        Expression::Identifier(ref mut i) => visitor.visit_identifier_mut(&mut i.node, &mut i.span),
+        Expression::Constant(ref mut c) => visitor.visit_constant_mut(&mut c.node, &mut c.span),
+        Expression::StringLiteral(ref mut s) => {
+            visitor.visit_string_literal_mut(&mut s.node, &mut s.span)
+        }
+        Expression::GenericSelection(ref mut g) => {
+            visitor.visit_generic_selection_mut(&mut g.node, &mut g.span)
+        }
+        Expression::Member(ref mut m) => {
+            visitor.visit_member_expression_mut(&mut m.node, &mut m.span)
+        }
+        Expression::Call(ref mut c) => visitor.visit_call_expression_mut(&mut c.node, &mut c.span),
+        Expression::CompoundLiteral(ref mut c) => {
+            visitor.visit_compound_literal_mut(&mut c.node, &mut c.span)
+        }
+        Expression::SizeOf(ref mut s) => visitor.visit_type_name_mut(&mut s.node, &mut s.span),
+        Expression::AlignOf(ref mut a) => visitor.visit_type_name_mut(&mut a.node, &mut a.span),
+        Expression::UnaryOperator(ref mut u) => {
+            visitor.visit_unary_operator_expression_mut(&mut u.node, &mut u.span)
+        }
+        Expression::Cast(ref mut c) => visitor.visit_cast_expression_mut(&mut c.node, &mut c.span),
+        Expression::BinaryOperator(ref mut b) => {
+            visitor.visit_binary_operator_expression_mut(&mut b.node, &mut b.span)
+        }
+        Expression::Conditional(ref mut c) => {
+            visitor.visit_conditional_expression_mut(&mut c.node, &mut c.span)
+        }
+        Expression::Comma(ref mut comma) => {
+            for c in comma.iter_mut() {
+                visitor.visit_expression_mut(&mut c.node, &mut c.span);
+            }
+        }
+        Expression::OffsetOf(ref mut o) => {
+            visitor.visit_offset_of_expression_mut(&mut o.node, &mut o.span)
+        }
+        Expression::VaArg(ref mut v) => {
+            visitor.visit_va_arg_expression_mut(&mut v.node, &mut v.span)
+        }
+        Expression::Statement(ref mut s) => visitor.visit_statement_mut(&mut s.node, &mut s.span),
+        Expression::Ambiguous(ref mut a) => {
+            visitor.visit_ambiguous_expression_mut(&mut a.node, &mut a.span)
+        }
+    }
+}
+
+pub fn visit_member_operator_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _member_operator: &mut MemberOperator,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_generic_selection_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    generic_selection: &mut GenericSelection,
+    _span: &mut Span,
+) {
+    visitor.visit_expression_mut(
+        &mut generic_selection.expression.node,
+        &mut generic_selection.expression.span,
+    );
+    for association in &mut generic_selection.associations {
+        visitor.visit_generic_association_mut(&mut association.node, &mut association.span);
+    }
+}
+
+pub fn visit_generic_association_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    generic_association: &mut GenericAssociation,
+    _span: &mut Span,
+) {
+    match *generic_association {
+        GenericAssociation::Type(ref mut t) => {
+            visitor.visit_generic_association_type_mut(&mut t.node, &mut t.span)
+        }
+        GenericAssociation::Default(ref mut d) => {
+            visitor.visit_expression_mut(&mut d.node, &mut d.span)
+        }
+    }
+}
+
+pub fn visit_generic_association_type_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    generic_association_type: &mut GenericAssociationType,
+    _span: &mut Span,
+) {
+    visitor.visit_type_name_mut(
+        &mut generic_association_type.type_name.node,
+        &mut generic_association_type.type_name.span,
+    );
+    visitor.visit_expression_mut(
+        &mut generic_association_type.expression.node,
+        &mut generic_association_type.expression.span,
+    );
+}
+
+pub fn visit_member_expression_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    member_expression: &mut MemberExpression,
+    _span: &mut Span,
+) {
+    visitor.visit_member_operator_mut(
+        &mut member_expression.operator.node,
+        &mut member_expression.operator.span,
+    );
+    visitor.visit_expression_mut(
+        &mut member_expression.expression.node,
+        &mut member_expression.expression.span,
+    );
+    visitor.visit_identifier_mut(
+        &mut member_expression.identifier.node,
+        &mut member_expression.identifier.span,
+    );
+}
+
+pub fn visit_call_expression_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    call_expression: &mut CallExpression,
+    _span: &mut Span,
+) {
+    visitor.visit_expression_mut(
+        &mut call_expression.callee.node,
+        &mut call_expression.callee.span,
+    );
+    for argument in &mut call_expression.arguments {
+        visitor.visit_expression_mut(&mut argument.node, &mut argument.span);
+    }
+}
+
+pub fn visit_compound_literal_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    compound_literal: &mut CompoundLiteral,
+    _span: &mut Span,
+) {
+    visitor.visit_type_name_mut(
+        &mut compound_literal.type_name.node,
+        &mut compound_literal.type_name.span,
+    );
+    for initializer in &mut compound_literal.initializer_list {
+        visitor.visit_initializer_list_item_mut(&mut initializer.node, &mut initializer.span);
+    }
+}
+
+pub fn visit_unary_operator_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _unary_operator: &mut UnaryOperator,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_unary_operator_expression_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    unary_operator_expression: &mut UnaryOperatorExpression,
+    _span: &mut Span,
+) {
+    match unary_operator_expression.operator.node {
+        UnaryOperator::PostIncrement | UnaryOperator::PostDecrement => {
+            visitor.visit_expression_mut(
+                &mut unary_operator_expression.operand.node,
+                &mut unary_operator_expression.operand.span,
+            );
+            visitor.visit_unary_operator_mut(
+                &mut unary_operator_expression.operator.node,
+                &mut unary_operator_expression.operator.span,
+            );
+        }
+        _ => {
+            visitor.visit_unary_operator_mut(
+                &mut unary_operator_expression.operator.node,
+                &mut unary_operator_expression.operator.span,
+            );
+            visitor.visit_expression_mut(
+                &mut unary_operator_expression.operand.node,
+                &mut unary_operator_expression.operand.span,
+            );
+        }
+    }
+}
+
+pub fn visit_cast_expression_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    cast_expression: &mut CastExpression,
+    _span: &mut Span,
+) {
+    visitor.visit_type_name_mut(
+        &mut cast_expression.type_name.node,
+        &mut cast_expression.type_name.span,
+    );
+    visitor.visit_expression_mut(
+        &mut cast_expression.expression.node,
+        &mut cast_expression.expression.span,
+    );
+}
+
+pub fn visit_binary_operator_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _binary_operator: &mut BinaryOperator,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_binary_operator_expression_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    binary_operator_expression: &mut BinaryOperatorExpression,
+    _span: &mut Span,
+) {
+    visitor.visit_expression_mut(
+        &mut binary_operator_expression.lhs.node,
+        &mut binary_operator_expression.lhs.span,
+    );
+    visitor.visit_expression_mut(
+        &mut binary_operator_expression.rhs.node,
+        &mut binary_operator_expression.rhs.span,
+    );
+    visitor.visit_binary_operator_mut(
+        &mut binary_operator_expression.operator.node,
+        &mut binary_operator_expression.operator.span,
+    );
+}
+
+pub fn visit_conditional_expression_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    conditional_expression: &mut ConditionalExpression,
+    _span: &mut Span,
+) {
+    visitor.visit_expression_mut(
+        &mut conditional_expression.condition.node,
+        &mut conditional_expression.condition.span,
+    );
+    visitor.visit_expression_mut(
+        &mut conditional_expression.then_expression.node,
+        &mut conditional_expression.then_expression.span,
+    );
+    visitor.visit_expression_mut(
+        &mut conditional_expression.else_expression.node,
+        &mut conditional_expression.else_expression.span,
+    );
+}
+
+pub fn visit_va_arg_expression_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    va_arg_expression: &mut VaArgExpression,
+    _span: &mut Span,
+) {
+    visitor.visit_expression_mut(
+        &mut va_arg_expression.va_list.node,
+        &mut va_arg_expression.va_list.span,
+    );
+    visitor.visit_type_name_mut(
+        &mut va_arg_expression.type_name.node,
+        &mut va_arg_expression.type_name.span,
+    );
+}
+
+pub fn visit_offset_of_expression_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    offset_of_expression: &mut OffsetOfExpression,
+    _span: &mut Span,
+) {
+    visitor.visit_type_name_mut(
+        &mut offset_of_expression.type_name.node,
+        &mut offset_of_expression.type_name.span,
+    );
+    visitor.visit_offset_designator_mut(
+        &mut offset_of_expression.designator.node,
+        &mut offset_of_expression.designator.span,
+    );
+}
+
+pub fn visit_offset_designator_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    offset_designator: &mut OffsetDesignator,
+    _span: &mut Span,
+) {
+    visitor.visit_identifier_mut(
+        &mut offset_designator.base.node,
+        &mut offset_designator.base.span,
+    );
+    for member in &mut offset_designator.members {
+        visitor.visit_offset_member_mut(&mut member.node, &mut member.span);
+    }
+}
+
+pub fn visit_offset_member_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    offset_member: &mut OffsetMember,
+    _span: &mut Span,
+) {
+    match *offset_member {
+        OffsetMember::Member(ref mut m) => visitor.visit_identifier_mut(&mut m.node, &mut m.span),
+        OffsetMember::IndirectMember(ref mut m) => {
+            visitor.visit_identifier_mut(&mut m.node, &mut m.span)
+        }
+        OffsetMember::Index(ref mut i) => visitor.visit_expression_mut(&mut i.node, &mut i.span),
+    }
+}
+
+pub fn visit_ambiguous_expression_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    ambiguous_expression: &mut AmbiguousExpression,
+    _span: &mut Span,
+) {
+    let cast = &mut ambiguous_expression.cast;
+    visitor.visit_expression_mut(&mut cast.node, &mut cast.span);
+    let expression = &mut ambiguous_expression.expression;
+    visitor.visit_expression_mut(&mut expression.node, &mut expression.span);
+}
+
+pub fn visit_declaration_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    declaration: &mut Declaration,
+    _span: &mut Span,
+) {
+    for specifier in &mut declaration.specifiers {
+        visitor.visit_declaration_specifier_mut(&mut specifier.node, &mut specifier.span);
+    }
+
+    for declarator in &mut declaration.declarators {
+        visitor.visit_init_declarator_mut(&mut declarator.node, &mut declarator.span);
+    }
+}
+
+pub fn visit_declaration_specifier_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    declaration_specifier: &mut DeclarationSpecifier,
+    _span: &mut Span,
+) {
+    match *declaration_specifier {
+        DeclarationSpecifier::StorageClass(ref mut s) => {
+            visitor.visit_storage_class_specifier_mut(&mut s.node, &mut s.span)
+        }
+        DeclarationSpecifier::TypeSpecifier(ref mut t) => {
+            visitor.visit_type_specifier_mut(&mut t.node, &mut t.span)
+        }
+        DeclarationSpecifier::TypeQualifier(ref mut t) => {
+            visitor.visit_type_qualifier_mut(&mut t.node, &mut t.span)
+        }
+        DeclarationSpecifier::Function(ref mut f) => {
+            visitor.visit_function_specifier_mut(&mut f.node, &mut f.span)
+        }
+        DeclarationSpecifier::Alignment(ref mut a) => {
+            visitor.visit_alignment_specifier_mut(&mut a.node, &mut a.span)
+        }
+        DeclarationSpecifier::Extension(ref mut e) => {
+            for extension in e {
+                visitor.visit_extension_mut(&mut extension.node, &mut extension.span);
+            }
+        }
+    }
+}
+
+pub fn visit_init_declarator_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    init_declarator: &mut InitDeclarator,
+    _span: &mut Span,
+) {
+    visitor.visit_declarator_mut(
+        &mut init_declarator.declarator.node,
+        &mut init_declarator.declarator.span,
+    );
+    if let Some(ref mut initializer) = init_declarator.initializer {
+        visitor.visit_initializer_mut(&mut initializer.node, &mut initializer.span);
+    }
+}
+
+pub fn visit_storage_class_specifier_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _storage_class_specifier: &mut StorageClassSpecifier,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_type_specifier_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    type_specifier: &mut TypeSpecifier,
+    span: &mut Span,
+) {
+    match *type_specifier {
+        TypeSpecifier::Atomic(ref mut a) => visitor.visit_type_name_mut(&mut a.node, &mut a.span),
+        TypeSpecifier::Struct(ref mut s) => visitor.visit_struct_type_mut(&mut s.node, &mut s.span),
+        TypeSpecifier::Enum(ref mut e) => visitor.visit_enum_type_mut(&mut e.node, &mut e.span),
+        TypeSpecifier::TypedefName(ref mut t) => {
+            visitor.visit_identifier_mut(&mut t.node, &mut t.span)
+        }
+        TypeSpecifier::TypeOf(ref mut t) => visitor.visit_type_of_mut(&mut t.node, &mut t.span),
+        TypeSpecifier::TS18661Float(ref mut t) => visitor.visit_ts18661_float_type_mut(t, span),
+        _ => {}
+    }
+}
+
+pub fn visit_ts18661_float_type_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    ts18661_float_type: &mut TS18661FloatType,
+    span: &mut Span,
+) {
+    visitor.visit_ts18661_float_format_mut(&mut ts18661_float_type.format, span);
+}
+
+pub fn visit_ts18661_float_format_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _ts18661_float_format: &mut TS18661FloatFormat,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_struct_type_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    struct_type: &mut StructType,
+    _span: &mut Span,
+) {
+    visitor.visit_struct_kind_mut(&mut struct_type.kind.node, &mut struct_type.kind.span);
+    if let Some(ref mut identifier) = struct_type.identifier {
+        visitor.visit_identifier_mut(&mut identifier.node, &mut identifier.span);
+    }
+    if let Some(ref mut declarations) = struct_type.declarations {
+        for declaration in declarations {
+            visitor.visit_struct_declaration_mut(&mut declaration.node, &mut declaration.span);
+        }
+    }
+}
+
+pub fn visit_struct_kind_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _struct_kind: &mut StructKind,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_struct_declaration_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    struct_declaration: &mut StructDeclaration,
+    _span: &mut Span,
+) {
+    match *struct_declaration {
+        StructDeclaration::Field(ref mut f) => {
+            visitor.visit_struct_field_mut(&mut f.node, &mut f.span)
+        }
+        StructDeclaration::StaticAssert(ref mut s) => {
+            visitor.visit_static_assert_mut(&mut s.node, &mut s.span)
+        }
+    }
+}
+
+pub fn visit_struct_field_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    struct_field: &mut StructField,
+    _span: &mut Span,
+) {
+    for specifier in &mut struct_field.specifiers {
+        visitor.visit_specifier_qualifier_mut(&mut specifier.node, &mut specifier.span);
+    }
+    for declarator in &mut struct_field.declarators {
+        visitor.visit_struct_declarator_mut(&mut declarator.node, &mut declarator.span);
+    }
+}
+
+pub fn visit_specifier_qualifier_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    specifier_qualifier: &mut SpecifierQualifier,
+    _span: &mut Span,
+) {
+    match *specifier_qualifier {
+        SpecifierQualifier::TypeSpecifier(ref mut t) => {
+            visitor.visit_type_specifier_mut(&mut t.node, &mut t.span)
+        }
+        SpecifierQualifier::TypeQualifier(ref mut t) => {
+            visitor.visit_type_qualifier_mut(&mut t.node, &mut t.span)
+        }
+        SpecifierQualifier::Extension(ref mut e) => {
+            for extension in e {
+                visitor.visit_extension_mut(&mut extension.node, &mut extension.span);
+            }
+        }
+    }
+}
+
+pub fn visit_struct_declarator_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    struct_declarator: &mut StructDeclarator,
+    _span: &mut Span,
+) {
+    if let Some(ref mut declarator) = struct_declarator.declarator {
+        visitor.visit_declarator_mut(&mut declarator.node, &mut declarator.span);
+    }
+    if let Some(ref mut bit_width) = struct_declarator.bit_width {
+        visitor.visit_expression_mut(&mut bit_width.node, &mut bit_width.span);
+    }
+}
+
+pub fn visit_enum_type_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    enum_type: &mut EnumType,
+    _span: &mut Span,
+) {
+    if let Some(ref mut identifier) = enum_type.identifier {
+        visitor.visit_identifier_mut(&mut identifier.node, &mut identifier.span);
+    }
+    for enumerator in &mut enum_type.enumerators {
+        visitor.visit_enumerator_mut(&mut enumerator.node, &mut enumerator.span);
+    }
+}
+
+pub fn visit_enumerator_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    enumerator: &mut Enumerator,
+    _span: &mut Span,
+) {
+    visitor.visit_identifier_mut(
+        &mut enumerator.identifier.node,
+        &mut enumerator.identifier.span,
+    );
+    if let Some(ref mut expression) = enumerator.expression {
+        visitor.visit_expression_mut(&mut expression.node, &mut expression.span);
+    }
+}
+
+pub fn visit_type_qualifier_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _type_qualifier: &mut TypeQualifier,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_function_specifier_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _function_specifier: &mut FunctionSpecifier,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_alignment_specifier_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    alignment_specifier: &mut AlignmentSpecifier,
+    _span: &mut Span,
+) {
+    match *alignment_specifier {
+        AlignmentSpecifier::Type(ref mut t) => {
+            visitor.visit_type_name_mut(&mut t.node, &mut t.span)
+        }
+        AlignmentSpecifier::Constant(ref mut c) => {
+            visitor.visit_expression_mut(&mut c.node, &mut c.span)
+        }
+    }
+}
+
+pub fn visit_declarator_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    declarator: &mut Declarator,
+    _span: &mut Span,
+) {
+    visitor.visit_declarator_kind_mut(&mut declarator.kind.node, &mut declarator.kind.span);
+    for derived in &mut declarator.derived {
+        visitor.visit_derived_declarator_mut(&mut derived.node, &mut derived.span);
+    }
+    for extension in &mut declarator.extensions {
+        visitor.visit_extension_mut(&mut extension.node, &mut extension.span);
+    }
+}
+
+pub fn visit_declarator_kind_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    declarator_kind: &mut DeclaratorKind,
+    _span: &mut Span,
+) {
+    match *declarator_kind {
+        DeclaratorKind::Identifier(ref mut i) => {
+            visitor.visit_identifier_mut(&mut i.node, &mut i.span)
+        }
+        DeclaratorKind::Declarator(ref mut d) => {
+            visitor.visit_declarator_mut(&mut d.node, &mut d.span)
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_derived_declarator_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    derived_declarator: &mut DerivedDeclarator,
+    _span: &mut Span,
+) {
+    match *derived_declarator {
+        DerivedDeclarator::Pointer(ref mut p) => {
+            for pointer in p {
+                visitor.visit_pointer_qualifier_mut(&mut pointer.node, &mut pointer.span);
+            }
+        }
+        DerivedDeclarator::Array(ref mut a) => {
+            visitor.visit_array_declarator_mut(&mut a.node, &mut a.span)
+        }
+        DerivedDeclarator::Function(ref mut f) => {
+            visitor.visit_function_declarator_mut(&mut f.node, &mut f.span)
+        }
+        DerivedDeclarator::KRFunction(ref mut k) => {
+            for identifier in k {
+                visitor.visit_identifier_mut(&mut identifier.node, &mut identifier.span);
+            }
+        }
+    }
+}
+
+pub fn visit_array_declarator_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    array_declarator: &mut ArrayDeclarator,
+    span: &mut Span,
+) {
+    for qualifier in &mut array_declarator.qualifiers {
+        visitor.visit_type_qualifier_mut(&mut qualifier.node, &mut qualifier.span);
+    }
+    visitor.visit_array_size_mut(&mut array_declarator.size, span)
+}
+
+pub fn visit_function_declarator_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    function_declarator: &mut FunctionDeclarator,
+    span: &mut Span,
+) {
+    for parameter in &mut function_declarator.parameters {
+        visitor.visit_parameter_declaration_mut(&mut parameter.node, &mut parameter.span);
+    }
+    visitor.visit_ellipsis_mut(&mut function_declarator.ellipsis, span);
+}
+
+pub fn visit_pointer_qualifier_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    pointer_qualifier: &mut PointerQualifier,
+    _span: &mut Span,
+) {
+    match *pointer_qualifier {
+        PointerQualifier::TypeQualifier(ref mut t) => {
+            visitor.visit_type_qualifier_mut(&mut t.node, &mut t.span)
+        }
+        PointerQualifier::Extension(ref mut e) => {
+            for extension in e {
+                visitor.visit_extension_mut(&mut extension.node, &mut extension.span);
+            }
+        }
+    }
+}
+
+pub fn visit_array_size_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    array_size: &mut ArraySize,
+    _span: &mut Span,
+) {
+    match *array_size {
+        ArraySize::VariableExpression(ref mut e) => {
+            visitor.visit_expression_mut(&mut e.node, &mut e.span)
+        }
+        ArraySize::StaticExpression(ref mut s) => {
+            visitor.visit_expression_mut(&mut s.node, &mut s.span)
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_parameter_declaration_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    parameter_declaration: &mut ParameterDeclaration,
+    _span: &mut Span,
+) {
+    for specifier in &mut parameter_declaration.specifiers {
+        visitor.visit_declaration_specifier_mut(&mut specifier.node, &mut specifier.span);
+    }
+    if let Some(ref mut declarator) = parameter_declaration.declarator {
+        visitor.visit_declarator_mut(&mut declarator.node, &mut declarator.span);
+    }
+    for extension in &mut parameter_declaration.extensions {
+        visitor.visit_extension_mut(&mut extension.node, &mut extension.span);
+    }
+}
+
+pub fn visit_ellipsis_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _ellipsis: &mut Ellipsis,
+    _span: &mut Span,
+) {
+}
+
+pub fn visit_type_name_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    type_name: &mut TypeName,
+    _span: &mut Span,
+) {
+    for specifier in &mut type_name.specifiers {
+        visitor.visit_specifier_qualifier_mut(&mut specifier.node, &mut specifier.span);
+    }
+    if let Some(ref mut declarator) = type_name.declarator {
+        visitor.visit_declarator_mut(&mut declarator.node, &mut declarator.span);
+    }
+}
+
+pub fn visit_initializer_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    initializer: &mut Initializer,
+    _span: &mut Span,
+) {
+    match *initializer {
+        Initializer::Expression(ref mut e) => {
+            visitor.visit_expression_mut(&mut e.node, &mut e.span)
+        }
+        Initializer::List(ref mut l) => {
+            for item in l {
+                visitor.visit_initializer_list_item_mut(&mut item.node, &mut item.span);
+            }
+        }
+    }
+}
+
+pub fn visit_initializer_list_item_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    initializer_list_item: &mut InitializerListItem,
+    _span: &mut Span,
+) {
+    for designation in &mut initializer_list_item.designation {
+        visitor.visit_designator_mut(&mut designation.node, &mut designation.span);
+    }
+    visitor.visit_initializer_mut(
+        &mut initializer_list_item.initializer.node,
+        &mut initializer_list_item.initializer.span,
+    );
+}
+
+pub fn visit_designator_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    designator: &mut Designator,
+    _span: &mut Span,
+) {
+    match *designator {
+        Designator::Index(ref mut i) => visitor.visit_expression_mut(&mut i.node, &mut i.span),
+        Designator::Member(ref mut m) => visitor.visit_identifier_mut(&mut m.node, &mut m.span),
+        Designator::Range(ref mut r) => {
+            visitor.visit_range_designator_mut(&mut r.node, &mut r.span)
+        }
+    }
+}
+
+pub fn visit_range_designator_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    range_designator: &mut RangeDesignator,
+    _span: &mut Span,
+) {
+    visitor.visit_expression_mut(
+        &mut range_designator.from.node,
+        &mut range_designator.from.span,
+    );
+    visitor.visit_expression_mut(&mut range_designator.to.node, &mut range_designator.to.span);
+}
+
+pub fn visit_static_assert_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    static_assert: &mut StaticAssert,
+    _span: &mut Span,
+) {
+    visitor.visit_expression_mut(
+        &mut static_assert.expression.node,
+        &mut static_assert.expression.span,
+    );
+    visitor.visit_string_literal_mut(
+        &mut static_assert.message.node,
+        &mut static_assert.message.span,
+    );
+}
+
+pub fn visit_statement_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    statement: &mut Statement,
+    _span: &mut Span,
+) {
+    match *statement {
+        Statement::Labeled(ref mut l) => {
+            visitor.visit_labeled_statement_mut(&mut l.node, &mut l.span)
+        }
+        Statement::Compound(ref mut c) => {
+            for item in c {
+                visitor.visit_block_item_mut(&mut item.node, &mut item.span);
+            }
+        }
+        Statement::Expression(Some(ref mut e)) => {
+            visitor.visit_expression_mut(&mut e.node, &mut e.span);
+        }
+        Statement::If(ref mut i) => visitor.visit_if_statement_mut(&mut i.node, &mut i.span),
+        Statement::Switch(ref mut s) => {
+            visitor.visit_switch_statement_mut(&mut s.node, &mut s.span)
+        }
+        Statement::While(ref mut w) => visitor.visit_while_statement_mut(&mut w.node, &mut w.span),
+        Statement::DoWhile(ref mut d) => {
+            visitor.visit_do_while_statement_mut(&mut d.node, &mut d.span)
+        }
+        Statement::For(ref mut f) => visitor.visit_for_statement_mut(&mut f.node, &mut f.span),
+        Statement::Goto(ref mut g) => visitor.visit_identifier_mut(&mut g.node, &mut g.span),
+        Statement::Return(Some(ref mut r)) => {
+            visitor.visit_expression_mut(&mut r.node, &mut r.span);
+        }
+        Statement::Asm(ref mut a) => visitor.visit_asm_statement_mut(&mut a.node, &mut a.span),
+        _ => {}
+    }
+}
+
+pub fn visit_labeled_statement_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    labeled_statement: &mut LabeledStatement,
+    _span: &mut Span,
+) {
+    visitor.visit_label_mut(
+        &mut labeled_statement.label.node,
+        &mut labeled_statement.label.span,
+    );
+    visitor.visit_statement_mut(
+        &mut labeled_statement.statement.node,
+        &mut labeled_statement.statement.span,
+    );
+}
+
+pub fn visit_if_statement_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    if_statement: &mut IfStatement,
+    _span: &mut Span,
+) {
+    visitor.visit_expression_mut(
+        &mut if_statement.condition.node,
+        &mut if_statement.condition.span,
+    );
+    visitor.visit_statement_mut(
+        &mut if_statement.then_statement.node,
+        &mut if_statement.then_statement.span,
+    );
+    if let Some(ref mut e) = if_statement.else_statement {
+        visitor.visit_statement_mut(&mut e.node, &mut e.span);
+    }
+}
+
+pub fn visit_switch_statement_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    switch_statement: &mut SwitchStatement,
+    _span: &mut Span,
+) {
+    visitor.visit_expression_mut(
+        &mut switch_statement.expression.node,
+        &mut switch_statement.expression.span,
+    );
+    visitor.visit_statement_mut(
+        &mut switch_statement.statement.node,
+        &mut switch_statement.statement.span,
+    );
+}
+
+pub fn visit_while_statement_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    while_statement: &mut WhileStatement,
+    _span: &mut Span,
+) {
+    visitor.visit_expression_mut(
+        &mut while_statement.expression.node,
+        &mut while_statement.expression.span,
+    );
+    visitor.visit_statement_mut(
+        &mut while_statement.statement.node,
+        &mut while_statement.statement.span,
+    );
+}
+
+pub fn visit_do_while_statement_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    do_while_statement: &mut DoWhileStatement,
+    _span: &mut Span,
+) {
+    visitor.visit_statement_mut(
+        &mut do_while_statement.statement.node,
+        &mut do_while_statement.statement.span,
+    );
+    visitor.visit_expression_mut(
+        &mut do_while_statement.expression.node,
+        &mut do_while_statement.expression.span,
+    );
+}
+
+pub fn visit_for_statement_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    for_statement: &mut ForStatement,
+    _span: &mut Span,
+) {
+    visitor.visit_for_initializer_mut(
+        &mut for_statement.initializer.node,
+        &mut for_statement.initializer.span,
+    );
+    if let Some(ref mut c) = for_statement.condition {
+        visitor.visit_expression_mut(&mut c.node, &mut c.span);
+    }
+    if let Some(ref mut s) = for_statement.step {
+        visitor.visit_expression_mut(&mut s.node, &mut s.span);
+    }
+    visitor.visit_statement_mut(
+        &mut for_statement.statement.node,
+        &mut for_statement.statement.span,
+    );
+}
+
+pub fn visit_label_mut<V: VisitMut + ?Sized>(visitor: &mut V, label: &mut Label, _span: &mut Span) {
+    match *label {
+        Label::Identifier(ref mut i) => visitor.visit_identifier_mut(&mut i.node, &mut i.span),
+        Label::Case(ref mut c) => visitor.visit_expression_mut(&mut c.node, &mut c.span),
+        Label::Default => {}
+    }
+}
+
+pub fn visit_for_initializer_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    for_initializer: &mut ForInitializer,
+    _span: &mut Span,
+) {
+    match *for_initializer {
+        ForInitializer::Empty => {}
+        ForInitializer::Expression(ref mut e) => {
+            visitor.visit_expression_mut(&mut e.node, &mut e.span)
+        }
+        ForInitializer::Declaration(ref mut d) => {
+            visitor.visit_declaration_mut(&mut d.node, &mut d.span)
+        }
+        ForInitializer::StaticAssert(ref mut s) => {
+            visitor.visit_static_assert_mut(&mut s.node, &mut s.span)
+        }
+    }
+}
+
+pub fn visit_block_item_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    block_item: &mut BlockItem,
+    _span: &mut Span,
+) {
+    match *block_item {
+        BlockItem::Declaration(ref mut d) => {
+            visitor.visit_declaration_mut(&mut d.node, &mut d.span)
+        }
+        BlockItem::StaticAssert(ref mut s) => {
+            visitor.visit_static_assert_mut(&mut s.node, &mut s.span)
+        }
+        BlockItem::Statement(ref mut s) => visitor.visit_statement_mut(&mut s.node, &mut s.span),
+        BlockItem::Ambiguous(ref mut a) => {
+            visitor.visit_ambiguous_block_item_mut(&mut a.node, &mut a.span)
+        }
+    }
+}
+
+pub fn visit_ambiguous_block_item_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    ambiguous_block_item: &mut AmbiguousBlockItem,
+    _span: &mut Span,
+) {
+    let declaration = &mut ambiguous_block_item.declaration;
+    visitor.visit_declaration_mut(&mut declaration.node, &mut declaration.span);
+    let statement = &mut ambiguous_block_item.statement;
+    visitor.visit_statement_mut(&mut statement.node, &mut statement.span);
+}
+
+pub fn visit_translation_unit_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    translation_unit: &mut TranslationUnit,
+) {
+    for element in &mut translation_unit.0 {
+        visitor.visit_external_declaration_mut(&mut element.node, &mut element.span);
+    }
+}
+
+pub fn visit_external_declaration_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    external_declaration: &mut ExternalDeclaration,
+    _span: &mut Span,
+) {
+    match *external_declaration {
+        ExternalDeclaration::Declaration(ref mut d) => {
+            visitor.visit_declaration_mut(&mut d.node, &mut d.span)
+        }
+        ExternalDeclaration::StaticAssert(ref mut s) => {
+            visitor.visit_static_assert_mut(&mut s.node, &mut s.span)
+        }
+        ExternalDeclaration::FunctionDefinition(ref mut f) => {
+            visitor.visit_function_definition_mut(&mut f.node, &mut f.span)
+        }
+    }
+}
+
+pub fn visit_function_definition_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    function_definition: &mut FunctionDefinition,
+    _span: &mut Span,
+) {
+    for specifier in &mut function_definition.specifiers {
+        visitor.visit_declaration_specifier_mut(&mut specifier.node, &mut specifier.span);
+    }
+    visitor.visit_declarator_mut(
+        &mut function_definition.declarator.node,
+        &mut function_definition.declarator.span,
+    );
+    for declaration in &mut function_definition.declarations {
+        visitor.visit_declaration_mut(&mut declaration.node, &mut declaration.span);
+    }
+    visitor.visit_statement_mut(
+        &mut function_definition.statement.node,
+        &mut function_definition.statement.span,
+    );
+}
+
+pub fn visit_extension_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    extension: &mut Extension,
+    span: &mut Span,
+) {
+    match *extension {
+        Extension::Attribute(ref mut a) => visitor.visit_attribute_mut(a, span),
+        Extension::AsmLabel(ref mut a) => {
+            visitor.visit_string_literal_mut(&mut a.node, &mut a.span)
+        }
+        Extension::AvailabilityAttribute(ref mut a) => {
+            visitor.visit_availability_attribute_mut(&mut a.node, &mut a.span)
+        }
+        Extension::VendorQualifier(ref mut v) => visitor.visit_vendor_qualifier_mut(v, span),
+    }
+}
+
+pub fn visit_attribute_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    attribute: &mut Attribute,
+    _span: &mut Span,
+) {
+    for argument in &mut attribute.arguments {
+        visitor.visit_expression_mut(&mut argument.node, &mut argument.span);
+    }
+}
+
+pub fn visit_vendor_qualifier_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    vendor_qualifier: &mut VendorQualifier,
+    _span: &mut Span,
+) {
+    for argument in &mut vendor_qualifier.arguments {
+        visitor.visit_expression_mut(&mut argument.node, &mut argument.span);
+    }
+}
+
+pub fn visit_asm_statement_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    asm_statement: &mut AsmStatement,
+    span: &mut Span,
+) {
+    match *asm_statement {
+        AsmStatement::GnuBasic(ref mut g) => {
+            visitor.visit_string_literal_mut(&mut g.node, &mut g.span)
+        }
+        AsmStatement::GnuExtended(ref mut g) => {
+            visitor.visit_gnu_extended_asm_statement_mut(g, span)
+        }
+    }
+}
+
+pub fn visit_availability_attribute_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    availability: &mut AvailabilityAttribute,
+    _span: &mut Span,
+) {
+    for clause in &mut availability.clauses {
+        visitor.visit_availability_clause_mut(&mut clause.node, &mut clause.span);
+    }
+}
+
+pub fn visit_gnu_extended_asm_statement_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    gnu_extended_asm_statement: &mut GnuExtendedAsmStatement,
+    _span: &mut Span,
+) {
+    if let Some(ref mut qualifier) = gnu_extended_asm_statement.qualifier {
+        visitor.visit_type_qualifier_mut(&mut qualifier.node, &mut qualifier.span);
+    }
+    visitor.visit_string_literal_mut(
+        &mut gnu_extended_asm_statement.template.node,
+        &mut gnu_extended_asm_statement.template.span,
+    );
+    for output in &mut gnu_extended_asm_statement.outputs {
+        visitor.visit_gnu_asm_operand_mut(&mut output.node, &mut output.span);
+    }
+    for input in &mut gnu_extended_asm_statement.inputs {
+        visitor.visit_gnu_asm_operand_mut(&mut input.node, &mut input.span);
+    }
+    for clobber in &mut gnu_extended_asm_statement.clobbers {
+        visitor.visit_string_literal_mut(&mut clobber.node, &mut clobber.span);
+    }
+}
+
+pub fn visit_gnu_asm_operand_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    gnu_asm_operand: &mut GnuAsmOperand,
+    _span: &mut Span,
+) {
+    if let Some(ref mut name) = gnu_asm_operand.symbolic_name {
+        visitor.visit_identifier_mut(&mut name.node, &mut name.span);
+    }
+    visitor.visit_string_literal_mut(
+        &mut gnu_asm_operand.constraints.node,
+        &mut gnu_asm_operand.constraints.span,
+    );
+    visitor.visit_expression_mut(
+        &mut gnu_asm_operand.variable_name.node,
+        &mut gnu_asm_operand.variable_name.span,
+    );
+}
+
+pub fn visit_type_of_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    type_of: &mut TypeOf,
+    _span: &mut Span,
+) {
+    match *type_of {
+        TypeOf::Expression(ref mut e) => visitor.visit_expression_mut(&mut e.node, &mut e.span),
+        TypeOf::Type(ref mut t) => visitor.visit_type_name_mut(&mut t.node, &mut t.span),
+    }
+}
@@ -0,0 +1,2796 @@
+//! Abstract syntax tree traversal with ancestor tracking
+//!
+//! ```rust
+//! # use lang_c::{ast, span, visit_path};
+//! # use lang_c::visit_path::{NodeRef, Path};
+//! // Count assignments used as `if` conditions
+//! struct AssignInCondition(usize);
+//!
+//! impl<'ast> visit_path::VisitPath<'ast> for AssignInCondition {
+//!     fn visit_binary_operator_expression(
+//!         &mut self,
+//!         expr: &'ast ast::BinaryOperatorExpression,
+//!         span: &'ast span::Span,
+//!         path: &mut Path<'ast>,
+//!     ) {
+//!         let in_condition = path.ancestors().iter().rev().nth(1).map_or(false, |a| {
+//!             a.role.name == "condition" && matches!(a.node, NodeRef::IfStatement(_))
+//!         });
+//!         if expr.operator.node == ast::BinaryOperator::Assign && in_condition {
+//!             self.0 += 1;
+//!         }
+//!         visit_path::visit_binary_operator_expression(self, expr, span, path);
+//!     }
+//! }
+//! ```
+//!
+//! The `VisitPath` trait mirrors `visit::Visit`, but each hook also receives a `Path`: the chain
+//! of nodes enclosing the visited one, starting from the outermost. Each entry records the role
+//! of the next node on the path in its parent, so a hook can tell whether its expression is, for
+//! example, the `rhs` of a `BinaryOperatorExpression` or the `condition` of an `IfStatement`.
+//!
+//! Free functions in this module visit sub-nodes and maintain the path. Hooks that do not call
+//! them should leave the path as they found it.
+
+use std::fmt;
+
+use ast::*;
+use span::Span;
+
+macro_rules! node_ref {
+    ($($ty:ident: $_method:ident, $_method_mut:ident;)*) => {
+        /// Reference to an AST node of any type
+        #[derive(Debug, Clone, Copy)]
+        pub enum NodeRef<'ast> {
+            $($ty(&'ast $ty),)*
+            TranslationUnit(&'ast TranslationUnit),
+            AvailabilityClause(&'ast AvailabilityClause),
+        }
+
+        impl<'ast> NodeRef<'ast> {
+            /// Name of the node type
+            pub fn kind(&self) -> &'static str {
+                match *self {
+                    $(NodeRef::$ty(_) => stringify!($ty),)*
+                    NodeRef::TranslationUnit(_) => "TranslationUnit",
+                    NodeRef::AvailabilityClause(_) => "AvailabilityClause",
+                }
+            }
+        }
+    };
+}
+
+for_each_node_type!(node_ref);
+
+/// Position of a node in its parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Role {
+    /// Name of the field, or of the enum variant, holding the node
+    pub name: &'static str,
+    /// Position of the node if the field holds a list
+    pub index: Option<usize>,
+}
+
+impl Role {
+    pub fn field(name: &'static str) -> Role {
+        Role { name, index: None }
+    }
+
+    pub fn index(name: &'static str, index: usize) -> Role {
+        Role {
+            name,
+            index: Some(index),
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(fmt, "{}[{}]", self.name, index),
+            None => write!(fmt, "{}", self.name),
+        }
+    }
+}
+
+/// Node enclosing the visited one
+#[derive(Debug, Clone, Copy)]
+pub struct Ancestor<'ast> {
+    pub node: NodeRef<'ast>,
+    /// Span of the node, undefined for the translation unit
+    pub span: Span,
+    /// Role of the next node on the path in this one
+    pub role: Role,
+}
+
+impl<'ast> fmt::Display for Ancestor<'ast> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{} of {}", self.role, self.node.kind())
+    }
+}
+
+/// Chain of nodes enclosing the visited one
+#[derive(Debug, Clone, Default)]
+pub struct Path<'ast> {
+    ancestors: Vec<Ancestor<'ast>>,
+}
+
+impl<'ast> Path<'ast> {
+    pub fn new() -> Path<'ast> {
+        Path::default()
+    }
+
+    /// Enclosing nodes, starting from the outermost
+    pub fn ancestors(&self) -> &[Ancestor<'ast>] {
+        &self.ancestors
+    }
+
+    /// Immediately enclosing node
+    pub fn parent(&self) -> Option<&Ancestor<'ast>> {
+        self.ancestors.last()
+    }
+
+    /// Add a node to the path before visiting its child with the given role
+    pub fn enter(&mut self, node: NodeRef<'ast>, span: Span, role: Role) {
+        self.ancestors.push(Ancestor { node, span, role });
+    }
+
+    /// Remove the innermost node from the path
+    pub fn leave(&mut self) {
+        self.ancestors.pop();
+    }
+}
+
+pub trait VisitPath<'ast> {
+    fn visit_identifier(
+        &mut self,
+        identifier: &'ast Identifier,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_identifier(self, identifier, span, path)
+    }
+
+    fn visit_constant(
+        &mut self,
+        constant: &'ast Constant,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_constant(self, constant, span, path)
+    }
+
+    fn visit_integer(&mut self, integer: &'ast Integer, span: &'ast Span, path: &mut Path<'ast>) {
+        visit_integer(self, integer, span, path)
+    }
+
+    fn visit_integer_base(
+        &mut self,
+        integer_base: &'ast IntegerBase,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_integer_base(self, integer_base, span, path)
+    }
+
+    fn visit_integer_suffix(
+        &mut self,
+        integer_suffix: &'ast IntegerSuffix,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_integer_suffix(self, integer_suffix, span, path)
+    }
+
+    fn visit_integer_size(
+        &mut self,
+        integer_size: &'ast IntegerSize,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_integer_size(self, integer_size, span, path)
+    }
+
+    fn visit_float(&mut self, float: &'ast Float, span: &'ast Span, path: &mut Path<'ast>) {
+        visit_float(self, float, span, path)
+    }
+
+    fn visit_float_base(
+        &mut self,
+        float_base: &'ast FloatBase,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_float_base(self, float_base, span, path)
+    }
+
+    fn visit_float_suffix(
+        &mut self,
+        float_suffix: &'ast FloatSuffix,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_float_suffix(self, float_suffix, span, path)
+    }
+
+    fn visit_float_format(
+        &mut self,
+        float_format: &'ast FloatFormat,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_float_format(self, float_format, span, path)
+    }
+
+    fn visit_string_literal(
+        &mut self,
+        string_literal: &'ast StringLiteral,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_string_literal(self, string_literal, span, path)
+    }
+
+    fn visit_expression(
+        &mut self,
+        expression: &'ast Expression,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_expression(self, expression, span, path)
+    }
+
+    fn visit_member_operator(
+        &mut self,
+        member_operator: &'ast MemberOperator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_member_operator(self, member_operator, span, path)
+    }
+
+    fn visit_generic_selection(
+        &mut self,
+        generic_selection: &'ast GenericSelection,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_generic_selection(self, generic_selection, span, path)
+    }
+
+    fn visit_generic_association(
+        &mut self,
+        generic_association: &'ast GenericAssociation,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_generic_association(self, generic_association, span, path)
+    }
+
+    fn visit_generic_association_type(
+        &mut self,
+        generic_association_type: &'ast GenericAssociationType,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_generic_association_type(self, generic_association_type, span, path)
+    }
+
+    fn visit_member_expression(
+        &mut self,
+        member_expression: &'ast MemberExpression,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_member_expression(self, member_expression, span, path)
+    }
+
+    fn visit_call_expression(
+        &mut self,
+        call_expression: &'ast CallExpression,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_call_expression(self, call_expression, span, path)
+    }
+
+    fn visit_compound_literal(
+        &mut self,
+        compound_literal: &'ast CompoundLiteral,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_compound_literal(self, compound_literal, span, path)
+    }
+
+    fn visit_unary_operator(
+        &mut self,
+        unary_operator: &'ast UnaryOperator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_unary_operator(self, unary_operator, span, path)
+    }
+
+    fn visit_unary_operator_expression(
+        &mut self,
+        unary_operator_expression: &'ast UnaryOperatorExpression,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_unary_operator_expression(self, unary_operator_expression, span, path)
+    }
+
+    fn visit_cast_expression(
+        &mut self,
+        cast_expression: &'ast CastExpression,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_cast_expression(self, cast_expression, span, path)
+    }
+
+    fn visit_binary_operator(
+        &mut self,
+        binary_operator: &'ast BinaryOperator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_binary_operator(self, binary_operator, span, path)
+    }
+
+    fn visit_binary_operator_expression(
+        &mut self,
+        binary_operator_expression: &'ast BinaryOperatorExpression,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_binary_operator_expression(self, binary_operator_expression, span, path)
+    }
+
+    fn visit_conditional_expression(
+        &mut self,
+        conditional_expression: &'ast ConditionalExpression,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_conditional_expression(self, conditional_expression, span, path)
+    }
+
+    fn visit_va_arg_expression(
+        &mut self,
+        va_arg_expression: &'ast VaArgExpression,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_va_arg_expression(self, va_arg_expression, span, path)
+    }
+
+    fn visit_offset_of_expression(
+        &mut self,
+        offset_of_expression: &'ast OffsetOfExpression,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_offset_of_expression(self, offset_of_expression, span, path)
+    }
+
+    fn visit_offset_designator(
+        &mut self,
+        offset_designator: &'ast OffsetDesignator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_offset_designator(self, offset_designator, span, path)
+    }
+
+    fn visit_offset_member(
+        &mut self,
+        offset_member: &'ast OffsetMember,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_offset_member(self, offset_member, span, path)
+    }
+
+    fn visit_ambiguous_expression(
+        &mut self,
+        ambiguous_expression: &'ast AmbiguousExpression,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_ambiguous_expression(self, ambiguous_expression, span, path)
+    }
+
+    fn visit_declaration(
+        &mut self,
+        declaration: &'ast Declaration,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_declaration(self, declaration, span, path)
+    }
+
+    fn visit_declaration_specifier(
+        &mut self,
+        declaration_specifier: &'ast DeclarationSpecifier,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_declaration_specifier(self, declaration_specifier, span, path)
+    }
+
+    fn visit_init_declarator(
+        &mut self,
+        init_declarator: &'ast InitDeclarator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_init_declarator(self, init_declarator, span, path)
+    }
+
+    fn visit_storage_class_specifier(
+        &mut self,
+        storage_class_specifier: &'ast StorageClassSpecifier,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_storage_class_specifier(self, storage_class_specifier, span, path)
+    }
+
+    fn visit_type_specifier(
+        &mut self,
+        type_specifier: &'ast TypeSpecifier,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_type_specifier(self, type_specifier, span, path)
+    }
+
+    fn visit_ts18661_float_type(
+        &mut self,
+        ts18661_float_type: &'ast TS18661FloatType,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_ts18661_float_type(self, ts18661_float_type, span, path)
+    }
+
+    fn visit_ts18661_float_format(
+        &mut self,
+        ts18661_float_format: &'ast TS18661FloatFormat,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_ts18661_float_format(self, ts18661_float_format, span, path)
+    }
+
+    fn visit_struct_type(
+        &mut self,
+        struct_type: &'ast StructType,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_struct_type(self, struct_type, span, path)
+    }
+
+    fn visit_struct_kind(
+        &mut self,
+        struct_kind: &'ast StructKind,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_struct_kind(self, struct_kind, span, path)
+    }
+
+    fn visit_struct_declaration(
+        &mut self,
+        struct_declaration: &'ast StructDeclaration,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_struct_declaration(self, struct_declaration, span, path)
+    }
+
+    fn visit_struct_field(
+        &mut self,
+        struct_field: &'ast StructField,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_struct_field(self, struct_field, span, path)
+    }
+
+    fn visit_specifier_qualifier(
+        &mut self,
+        specifier_qualifier: &'ast SpecifierQualifier,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_specifier_qualifier(self, specifier_qualifier, span, path)
+    }
+
+    fn visit_struct_declarator(
+        &mut self,
+        struct_declarator: &'ast StructDeclarator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_struct_declarator(self, struct_declarator, span, path)
+    }
+
+    fn visit_enum_type(
+        &mut self,
+        enum_type: &'ast EnumType,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_enum_type(self, enum_type, span, path)
+    }
+
+    fn visit_enumerator(
+        &mut self,
+        enumerator: &'ast Enumerator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_enumerator(self, enumerator, span, path)
+    }
+
+    fn visit_type_qualifier(
+        &mut self,
+        type_qualifier: &'ast TypeQualifier,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_type_qualifier(self, type_qualifier, span, path)
+    }
+
+    fn visit_function_specifier(
+        &mut self,
+        function_specifier: &'ast FunctionSpecifier,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_function_specifier(self, function_specifier, span, path)
+    }
+
+    fn visit_alignment_specifier(
+        &mut self,
+        alignment_specifier: &'ast AlignmentSpecifier,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_alignment_specifier(self, alignment_specifier, span, path)
+    }
+
+    fn visit_declarator(
+        &mut self,
+        declarator: &'ast Declarator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_declarator(self, declarator, span, path)
+    }
+
+    fn visit_declarator_kind(
+        &mut self,
+        declarator_kind: &'ast DeclaratorKind,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_declarator_kind(self, declarator_kind, span, path)
+    }
+
+    fn visit_derived_declarator(
+        &mut self,
+        derived_declarator: &'ast DerivedDeclarator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_derived_declarator(self, derived_declarator, span, path)
+    }
+
+    fn visit_array_declarator(
+        &mut self,
+        array_declarator: &'ast ArrayDeclarator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_array_declarator(self, array_declarator, span, path)
+    }
+
+    fn visit_function_declarator(
+        &mut self,
+        function_declarator: &'ast FunctionDeclarator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_function_declarator(self, function_declarator, span, path)
+    }
+
+    fn visit_pointer_qualifier(
+        &mut self,
+        pointer_qualifier: &'ast PointerQualifier,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_pointer_qualifier(self, pointer_qualifier, span, path)
+    }
+
+    fn visit_array_size(
+        &mut self,
+        array_size: &'ast ArraySize,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_array_size(self, array_size, span, path)
+    }
+
+    fn visit_parameter_declaration(
+        &mut self,
+        parameter_declaration: &'ast ParameterDeclaration,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_parameter_declaration(self, parameter_declaration, span, path)
+    }
+
+    fn visit_ellipsis(
+        &mut self,
+        ellipsis: &'ast Ellipsis,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_ellipsis(self, ellipsis, span, path)
+    }
+
+    fn visit_type_name(
+        &mut self,
+        type_name: &'ast TypeName,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_type_name(self, type_name, span, path)
+    }
+
+    fn visit_initializer(
+        &mut self,
+        initializer: &'ast Initializer,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_initializer(self, initializer, span, path)
+    }
+
+    fn visit_initializer_list_item(
+        &mut self,
+        initializer_list_item: &'ast InitializerListItem,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_initializer_list_item(self, initializer_list_item, span, path)
+    }
+
+    fn visit_designator(
+        &mut self,
+        designator: &'ast Designator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_designator(self, designator, span, path)
+    }
+
+    fn visit_range_designator(
+        &mut self,
+        range_designator: &'ast RangeDesignator,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_range_designator(self, range_designator, span, path)
+    }
+
+    fn visit_static_assert(
+        &mut self,
+        static_assert: &'ast StaticAssert,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_static_assert(self, static_assert, span, path)
+    }
+
+    fn visit_statement(
+        &mut self,
+        statement: &'ast Statement,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_statement(self, statement, span, path)
+    }
+
+    fn visit_labeled_statement(
+        &mut self,
+        labeled_statement: &'ast LabeledStatement,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_labeled_statement(self, labeled_statement, span, path)
+    }
+
+    fn visit_if_statement(
+        &mut self,
+        if_statement: &'ast IfStatement,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_if_statement(self, if_statement, span, path)
+    }
+
+    fn visit_switch_statement(
+        &mut self,
+        switch_statement: &'ast SwitchStatement,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_switch_statement(self, switch_statement, span, path)
+    }
+
+    fn visit_while_statement(
+        &mut self,
+        while_statement: &'ast WhileStatement,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_while_statement(self, while_statement, span, path)
+    }
+
+    fn visit_do_while_statement(
+        &mut self,
+        do_while_statement: &'ast DoWhileStatement,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_do_while_statement(self, do_while_statement, span, path)
+    }
+
+    fn visit_for_statement(
+        &mut self,
+        for_statement: &'ast ForStatement,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_for_statement(self, for_statement, span, path)
+    }
+
+    fn visit_label(&mut self, label: &'ast Label, span: &'ast Span, path: &mut Path<'ast>) {
+        visit_label(self, label, span, path)
+    }
+
+    fn visit_for_initializer(
+        &mut self,
+        for_initializer: &'ast ForInitializer,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_for_initializer(self, for_initializer, span, path)
+    }
+
+    fn visit_block_item(
+        &mut self,
+        block_item: &'ast BlockItem,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_block_item(self, block_item, span, path)
+    }
+
+    fn visit_ambiguous_block_item(
+        &mut self,
+        ambiguous_block_item: &'ast AmbiguousBlockItem,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_ambiguous_block_item(self, ambiguous_block_item, span, path)
+    }
+
+    fn visit_translation_unit(
+        &mut self,
+        translation_unit: &'ast TranslationUnit,
+        path: &mut Path<'ast>,
+    ) {
+        visit_translation_unit(self, translation_unit, path)
+    }
+
+    fn visit_external_declaration(
+        &mut self,
+        external_declaration: &'ast ExternalDeclaration,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_external_declaration(self, external_declaration, span, path)
+    }
+
+    fn visit_function_definition(
+        &mut self,
+        function_definition: &'ast FunctionDefinition,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_function_definition(self, function_definition, span, path)
+    }
+
+    fn visit_extension(
+        &mut self,
+        extension: &'ast Extension,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_extension(self, extension, span, path)
+    }
+
+    fn visit_attribute(
+        &mut self,
+        attribute: &'ast Attribute,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_attribute(self, attribute, span, path)
+    }
+
+    fn visit_vendor_qualifier(
+        &mut self,
+        vendor_qualifier: &'ast VendorQualifier,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_vendor_qualifier(self, vendor_qualifier, span, path)
+    }
+
+    fn visit_asm_statement(
+        &mut self,
+        asm_statement: &'ast AsmStatement,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_asm_statement(self, asm_statement, span, path)
+    }
+
+    fn visit_availability_attribute(
+        &mut self,
+        availability_attribute: &'ast AvailabilityAttribute,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_availability_attribute(self, availability_attribute, span, path)
+    }
+
+    fn visit_availability_clause(
+        &mut self,
+        availability_clause: &'ast AvailabilityClause,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_availability_clause(self, availability_clause, span, path)
+    }
+
+    fn visit_gnu_extended_asm_statement(
+        &mut self,
+        gnu_extended_asm_statement: &'ast GnuExtendedAsmStatement,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_gnu_extended_asm_statement(self, gnu_extended_asm_statement, span, path)
+    }
+
+    fn visit_gnu_asm_operand(
+        &mut self,
+        gnu_asm_operand: &'ast GnuAsmOperand,
+        span: &'ast Span,
+        path: &mut Path<'ast>,
+    ) {
+        visit_gnu_asm_operand(self, gnu_asm_operand, span, path)
+    }
+
+    fn visit_type_of(&mut self, type_of: &'ast TypeOf, span: &'ast Span, path: &mut Path<'ast>) {
+        visit_type_of(self, type_of, span, path)
+    }
+}
+
+pub fn visit_identifier<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _identifier: &'ast Identifier,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_constant<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    constant: &'ast Constant,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Constant(constant);
+    match *constant {
+        Constant::Integer(ref x) => {
+            path.enter(node, *span, Role::field("Integer"));
+            visitor.visit_integer(x, span, path);
+            path.leave();
+        }
+        Constant::Float(ref x) => {
+            path.enter(node, *span, Role::field("Float"));
+            visitor.visit_float(x, span, path);
+            path.leave();
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_integer<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    integer: &'ast Integer,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Integer(integer);
+    path.enter(node, *span, Role::field("base"));
+    visitor.visit_integer_base(&integer.base, span, path);
+    path.leave();
+    path.enter(node, *span, Role::field("suffix"));
+    visitor.visit_integer_suffix(&integer.suffix, span, path);
+    path.leave();
+}
+
+pub fn visit_integer_base<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _integer_base: &'ast IntegerBase,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_integer_suffix<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    integer_suffix: &'ast IntegerSuffix,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::IntegerSuffix(integer_suffix);
+    path.enter(node, *span, Role::field("size"));
+    visitor.visit_integer_size(&integer_suffix.size, span, path);
+    path.leave();
+}
+
+pub fn visit_integer_size<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _integer_size: &'ast IntegerSize,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_float<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    float: &'ast Float,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Float(float);
+    path.enter(node, *span, Role::field("base"));
+    visitor.visit_float_base(&float.base, span, path);
+    path.leave();
+    path.enter(node, *span, Role::field("suffix"));
+    visitor.visit_float_suffix(&float.suffix, span, path);
+    path.leave();
+}
+
+pub fn visit_float_base<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _float_base: &'ast FloatBase,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_float_suffix<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    float_suffix: &'ast FloatSuffix,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::FloatSuffix(float_suffix);
+    path.enter(node, *span, Role::field("format"));
+    visitor.visit_float_format(&float_suffix.format, span, path);
+    path.leave();
+}
+
+pub fn visit_float_format<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    float_format: &'ast FloatFormat,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    if let FloatFormat::TS18661Format(ref x) = *float_format {
+        path.enter(
+            NodeRef::FloatFormat(float_format),
+            *span,
+            Role::field("TS18661Format"),
+        );
+        visitor.visit_ts18661_float_type(x, span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_string_literal<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _string_literal: &'ast StringLiteral,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_expression<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    expression: &'ast Expression,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Expression(expression);
+    match *expression {
+        Expression::Identifier(ref x) => {
+            path.enter(node, *span, Role::field("Identifier"));
+            visitor.visit_identifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::Constant(ref x) => {
+            path.enter(node, *span, Role::field("Constant"));
+            visitor.visit_constant(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::StringLiteral(ref x) => {
+            path.enter(node, *span, Role::field("StringLiteral"));
+            visitor.visit_string_literal(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::GenericSelection(ref x) => {
+            path.enter(node, *span, Role::field("GenericSelection"));
+            visitor.visit_generic_selection(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::Member(ref x) => {
+            path.enter(node, *span, Role::field("Member"));
+            visitor.visit_member_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::Call(ref x) => {
+            path.enter(node, *span, Role::field("Call"));
+            visitor.visit_call_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::CompoundLiteral(ref x) => {
+            path.enter(node, *span, Role::field("CompoundLiteral"));
+            visitor.visit_compound_literal(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::SizeOf(ref x) => {
+            path.enter(node, *span, Role::field("SizeOf"));
+            visitor.visit_type_name(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::AlignOf(ref x) => {
+            path.enter(node, *span, Role::field("AlignOf"));
+            visitor.visit_type_name(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::UnaryOperator(ref x) => {
+            path.enter(node, *span, Role::field("UnaryOperator"));
+            visitor.visit_unary_operator_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::Cast(ref x) => {
+            path.enter(node, *span, Role::field("Cast"));
+            visitor.visit_cast_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::BinaryOperator(ref x) => {
+            path.enter(node, *span, Role::field("BinaryOperator"));
+            visitor.visit_binary_operator_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::Conditional(ref x) => {
+            path.enter(node, *span, Role::field("Conditional"));
+            visitor.visit_conditional_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::Comma(ref x) => {
+            for (i, item) in x.iter().enumerate() {
+                path.enter(node, *span, Role::index("Comma", i));
+                visitor.visit_expression(&item.node, &item.span, path);
+                path.leave();
+            }
+        }
+        Expression::OffsetOf(ref x) => {
+            path.enter(node, *span, Role::field("OffsetOf"));
+            visitor.visit_offset_of_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::VaArg(ref x) => {
+            path.enter(node, *span, Role::field("VaArg"));
+            visitor.visit_va_arg_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::Statement(ref x) => {
+            path.enter(node, *span, Role::field("Statement"));
+            visitor.visit_statement(&x.node, &x.span, path);
+            path.leave();
+        }
+        Expression::Ambiguous(ref x) => {
+            path.enter(node, *span, Role::field("Ambiguous"));
+            visitor.visit_ambiguous_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_member_operator<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _member_operator: &'ast MemberOperator,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_generic_selection<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    generic_selection: &'ast GenericSelection,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::GenericSelection(generic_selection);
+    path.enter(node, *span, Role::field("expression"));
+    visitor.visit_expression(
+        &generic_selection.expression.node,
+        &generic_selection.expression.span,
+        path,
+    );
+    path.leave();
+    for (i, item) in generic_selection.associations.iter().enumerate() {
+        path.enter(node, *span, Role::index("associations", i));
+        visitor.visit_generic_association(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_generic_association<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    generic_association: &'ast GenericAssociation,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::GenericAssociation(generic_association);
+    match *generic_association {
+        GenericAssociation::Type(ref x) => {
+            path.enter(node, *span, Role::field("Type"));
+            visitor.visit_generic_association_type(&x.node, &x.span, path);
+            path.leave();
+        }
+        GenericAssociation::Default(ref x) => {
+            path.enter(node, *span, Role::field("Default"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_generic_association_type<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    generic_association_type: &'ast GenericAssociationType,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::GenericAssociationType(generic_association_type);
+    path.enter(node, *span, Role::field("type_name"));
+    visitor.visit_type_name(
+        &generic_association_type.type_name.node,
+        &generic_association_type.type_name.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("expression"));
+    visitor.visit_expression(
+        &generic_association_type.expression.node,
+        &generic_association_type.expression.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_member_expression<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    member_expression: &'ast MemberExpression,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::MemberExpression(member_expression);
+    path.enter(node, *span, Role::field("operator"));
+    visitor.visit_member_operator(
+        &member_expression.operator.node,
+        &member_expression.operator.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("expression"));
+    visitor.visit_expression(
+        &member_expression.expression.node,
+        &member_expression.expression.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("identifier"));
+    visitor.visit_identifier(
+        &member_expression.identifier.node,
+        &member_expression.identifier.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_call_expression<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    call_expression: &'ast CallExpression,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::CallExpression(call_expression);
+    path.enter(node, *span, Role::field("callee"));
+    visitor.visit_expression(
+        &call_expression.callee.node,
+        &call_expression.callee.span,
+        path,
+    );
+    path.leave();
+    for (i, item) in call_expression.arguments.iter().enumerate() {
+        path.enter(node, *span, Role::index("arguments", i));
+        visitor.visit_expression(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_compound_literal<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    compound_literal: &'ast CompoundLiteral,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::CompoundLiteral(compound_literal);
+    path.enter(node, *span, Role::field("type_name"));
+    visitor.visit_type_name(
+        &compound_literal.type_name.node,
+        &compound_literal.type_name.span,
+        path,
+    );
+    path.leave();
+    for (i, item) in compound_literal.initializer_list.iter().enumerate() {
+        path.enter(node, *span, Role::index("initializer_list", i));
+        visitor.visit_initializer_list_item(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_unary_operator<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _unary_operator: &'ast UnaryOperator,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_unary_operator_expression<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    unary_operator_expression: &'ast UnaryOperatorExpression,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::UnaryOperatorExpression(unary_operator_expression);
+    let operator = &unary_operator_expression.operator;
+    let operand = &unary_operator_expression.operand;
+    let postfix = matches!(
+        operator.node,
+        UnaryOperator::PostIncrement | UnaryOperator::PostDecrement
+    );
+    if postfix {
+        path.enter(node, *span, Role::field("operand"));
+        visitor.visit_expression(&operand.node, &operand.span, path);
+        path.leave();
+    }
+    path.enter(node, *span, Role::field("operator"));
+    visitor.visit_unary_operator(&operator.node, &operator.span, path);
+    path.leave();
+    if !postfix {
+        path.enter(node, *span, Role::field("operand"));
+        visitor.visit_expression(&operand.node, &operand.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_cast_expression<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    cast_expression: &'ast CastExpression,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::CastExpression(cast_expression);
+    path.enter(node, *span, Role::field("type_name"));
+    visitor.visit_type_name(
+        &cast_expression.type_name.node,
+        &cast_expression.type_name.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("expression"));
+    visitor.visit_expression(
+        &cast_expression.expression.node,
+        &cast_expression.expression.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_binary_operator<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _binary_operator: &'ast BinaryOperator,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_binary_operator_expression<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    binary_operator_expression: &'ast BinaryOperatorExpression,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::BinaryOperatorExpression(binary_operator_expression);
+    path.enter(node, *span, Role::field("operator"));
+    visitor.visit_binary_operator(
+        &binary_operator_expression.operator.node,
+        &binary_operator_expression.operator.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("lhs"));
+    visitor.visit_expression(
+        &binary_operator_expression.lhs.node,
+        &binary_operator_expression.lhs.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("rhs"));
+    visitor.visit_expression(
+        &binary_operator_expression.rhs.node,
+        &binary_operator_expression.rhs.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_conditional_expression<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    conditional_expression: &'ast ConditionalExpression,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::ConditionalExpression(conditional_expression);
+    path.enter(node, *span, Role::field("condition"));
+    visitor.visit_expression(
+        &conditional_expression.condition.node,
+        &conditional_expression.condition.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("then_expression"));
+    visitor.visit_expression(
+        &conditional_expression.then_expression.node,
+        &conditional_expression.then_expression.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("else_expression"));
+    visitor.visit_expression(
+        &conditional_expression.else_expression.node,
+        &conditional_expression.else_expression.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_va_arg_expression<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    va_arg_expression: &'ast VaArgExpression,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::VaArgExpression(va_arg_expression);
+    path.enter(node, *span, Role::field("va_list"));
+    visitor.visit_expression(
+        &va_arg_expression.va_list.node,
+        &va_arg_expression.va_list.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("type_name"));
+    visitor.visit_type_name(
+        &va_arg_expression.type_name.node,
+        &va_arg_expression.type_name.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_offset_of_expression<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    offset_of_expression: &'ast OffsetOfExpression,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::OffsetOfExpression(offset_of_expression);
+    path.enter(node, *span, Role::field("type_name"));
+    visitor.visit_type_name(
+        &offset_of_expression.type_name.node,
+        &offset_of_expression.type_name.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("designator"));
+    visitor.visit_offset_designator(
+        &offset_of_expression.designator.node,
+        &offset_of_expression.designator.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_offset_designator<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    offset_designator: &'ast OffsetDesignator,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::OffsetDesignator(offset_designator);
+    path.enter(node, *span, Role::field("base"));
+    visitor.visit_identifier(
+        &offset_designator.base.node,
+        &offset_designator.base.span,
+        path,
+    );
+    path.leave();
+    for (i, item) in offset_designator.members.iter().enumerate() {
+        path.enter(node, *span, Role::index("members", i));
+        visitor.visit_offset_member(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_offset_member<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    offset_member: &'ast OffsetMember,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::OffsetMember(offset_member);
+    match *offset_member {
+        OffsetMember::Member(ref x) => {
+            path.enter(node, *span, Role::field("Member"));
+            visitor.visit_identifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        OffsetMember::IndirectMember(ref x) => {
+            path.enter(node, *span, Role::field("IndirectMember"));
+            visitor.visit_identifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        OffsetMember::Index(ref x) => {
+            path.enter(node, *span, Role::field("Index"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_ambiguous_expression<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    ambiguous_expression: &'ast AmbiguousExpression,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::AmbiguousExpression(ambiguous_expression);
+    path.enter(node, *span, Role::field("cast"));
+    visitor.visit_expression(
+        &ambiguous_expression.cast.node,
+        &ambiguous_expression.cast.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("expression"));
+    visitor.visit_expression(
+        &ambiguous_expression.expression.node,
+        &ambiguous_expression.expression.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_declaration<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    declaration: &'ast Declaration,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Declaration(declaration);
+    for (i, item) in declaration.specifiers.iter().enumerate() {
+        path.enter(node, *span, Role::index("specifiers", i));
+        visitor.visit_declaration_specifier(&item.node, &item.span, path);
+        path.leave();
+    }
+    for (i, item) in declaration.declarators.iter().enumerate() {
+        path.enter(node, *span, Role::index("declarators", i));
+        visitor.visit_init_declarator(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_declaration_specifier<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    declaration_specifier: &'ast DeclarationSpecifier,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::DeclarationSpecifier(declaration_specifier);
+    match *declaration_specifier {
+        DeclarationSpecifier::StorageClass(ref x) => {
+            path.enter(node, *span, Role::field("StorageClass"));
+            visitor.visit_storage_class_specifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        DeclarationSpecifier::TypeSpecifier(ref x) => {
+            path.enter(node, *span, Role::field("TypeSpecifier"));
+            visitor.visit_type_specifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        DeclarationSpecifier::TypeQualifier(ref x) => {
+            path.enter(node, *span, Role::field("TypeQualifier"));
+            visitor.visit_type_qualifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        DeclarationSpecifier::Function(ref x) => {
+            path.enter(node, *span, Role::field("Function"));
+            visitor.visit_function_specifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        DeclarationSpecifier::Alignment(ref x) => {
+            path.enter(node, *span, Role::field("Alignment"));
+            visitor.visit_alignment_specifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        DeclarationSpecifier::Extension(ref x) => {
+            for (i, item) in x.iter().enumerate() {
+                path.enter(node, *span, Role::index("Extension", i));
+                visitor.visit_extension(&item.node, &item.span, path);
+                path.leave();
+            }
+        }
+    }
+}
+
+pub fn visit_init_declarator<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    init_declarator: &'ast InitDeclarator,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::InitDeclarator(init_declarator);
+    path.enter(node, *span, Role::field("declarator"));
+    visitor.visit_declarator(
+        &init_declarator.declarator.node,
+        &init_declarator.declarator.span,
+        path,
+    );
+    path.leave();
+    if let Some(ref initializer) = init_declarator.initializer {
+        path.enter(node, *span, Role::field("initializer"));
+        visitor.visit_initializer(&initializer.node, &initializer.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_storage_class_specifier<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _storage_class_specifier: &'ast StorageClassSpecifier,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_type_specifier<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    type_specifier: &'ast TypeSpecifier,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::TypeSpecifier(type_specifier);
+    match *type_specifier {
+        TypeSpecifier::Atomic(ref x) => {
+            path.enter(node, *span, Role::field("Atomic"));
+            visitor.visit_type_name(&x.node, &x.span, path);
+            path.leave();
+        }
+        TypeSpecifier::Struct(ref x) => {
+            path.enter(node, *span, Role::field("Struct"));
+            visitor.visit_struct_type(&x.node, &x.span, path);
+            path.leave();
+        }
+        TypeSpecifier::Enum(ref x) => {
+            path.enter(node, *span, Role::field("Enum"));
+            visitor.visit_enum_type(&x.node, &x.span, path);
+            path.leave();
+        }
+        TypeSpecifier::TypedefName(ref x) => {
+            path.enter(node, *span, Role::field("TypedefName"));
+            visitor.visit_identifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        TypeSpecifier::TypeOf(ref x) => {
+            path.enter(node, *span, Role::field("TypeOf"));
+            visitor.visit_type_of(&x.node, &x.span, path);
+            path.leave();
+        }
+        TypeSpecifier::TS18661Float(ref x) => {
+            path.enter(node, *span, Role::field("TS18661Float"));
+            visitor.visit_ts18661_float_type(x, span, path);
+            path.leave();
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_ts18661_float_type<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    ts18661_float_type: &'ast TS18661FloatType,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::TS18661FloatType(ts18661_float_type);
+    path.enter(node, *span, Role::field("format"));
+    visitor.visit_ts18661_float_format(&ts18661_float_type.format, span, path);
+    path.leave();
+}
+
+pub fn visit_ts18661_float_format<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _ts18661_float_format: &'ast TS18661FloatFormat,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_struct_type<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    struct_type: &'ast StructType,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::StructType(struct_type);
+    path.enter(node, *span, Role::field("kind"));
+    visitor.visit_struct_kind(&struct_type.kind.node, &struct_type.kind.span, path);
+    path.leave();
+    if let Some(ref identifier) = struct_type.identifier {
+        path.enter(node, *span, Role::field("identifier"));
+        visitor.visit_identifier(&identifier.node, &identifier.span, path);
+        path.leave();
+    }
+    if let Some(ref declarations) = struct_type.declarations {
+        for (i, item) in declarations.iter().enumerate() {
+            path.enter(node, *span, Role::index("declarations", i));
+            visitor.visit_struct_declaration(&item.node, &item.span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_struct_kind<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _struct_kind: &'ast StructKind,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_struct_declaration<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    struct_declaration: &'ast StructDeclaration,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::StructDeclaration(struct_declaration);
+    match *struct_declaration {
+        StructDeclaration::Field(ref x) => {
+            path.enter(node, *span, Role::field("Field"));
+            visitor.visit_struct_field(&x.node, &x.span, path);
+            path.leave();
+        }
+        StructDeclaration::StaticAssert(ref x) => {
+            path.enter(node, *span, Role::field("StaticAssert"));
+            visitor.visit_static_assert(&x.node, &x.span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_struct_field<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    struct_field: &'ast StructField,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::StructField(struct_field);
+    for (i, item) in struct_field.specifiers.iter().enumerate() {
+        path.enter(node, *span, Role::index("specifiers", i));
+        visitor.visit_specifier_qualifier(&item.node, &item.span, path);
+        path.leave();
+    }
+    for (i, item) in struct_field.declarators.iter().enumerate() {
+        path.enter(node, *span, Role::index("declarators", i));
+        visitor.visit_struct_declarator(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_specifier_qualifier<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    specifier_qualifier: &'ast SpecifierQualifier,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::SpecifierQualifier(specifier_qualifier);
+    match *specifier_qualifier {
+        SpecifierQualifier::TypeSpecifier(ref x) => {
+            path.enter(node, *span, Role::field("TypeSpecifier"));
+            visitor.visit_type_specifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        SpecifierQualifier::TypeQualifier(ref x) => {
+            path.enter(node, *span, Role::field("TypeQualifier"));
+            visitor.visit_type_qualifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        SpecifierQualifier::Extension(ref x) => {
+            for (i, item) in x.iter().enumerate() {
+                path.enter(node, *span, Role::index("Extension", i));
+                visitor.visit_extension(&item.node, &item.span, path);
+                path.leave();
+            }
+        }
+    }
+}
+
+pub fn visit_struct_declarator<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    struct_declarator: &'ast StructDeclarator,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::StructDeclarator(struct_declarator);
+    if let Some(ref declarator) = struct_declarator.declarator {
+        path.enter(node, *span, Role::field("declarator"));
+        visitor.visit_declarator(&declarator.node, &declarator.span, path);
+        path.leave();
+    }
+    if let Some(ref bit_width) = struct_declarator.bit_width {
+        path.enter(node, *span, Role::field("bit_width"));
+        visitor.visit_expression(&bit_width.node, &bit_width.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_enum_type<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    enum_type: &'ast EnumType,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::EnumType(enum_type);
+    if let Some(ref identifier) = enum_type.identifier {
+        path.enter(node, *span, Role::field("identifier"));
+        visitor.visit_identifier(&identifier.node, &identifier.span, path);
+        path.leave();
+    }
+    for (i, item) in enum_type.enumerators.iter().enumerate() {
+        path.enter(node, *span, Role::index("enumerators", i));
+        visitor.visit_enumerator(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_enumerator<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    enumerator: &'ast Enumerator,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Enumerator(enumerator);
+    path.enter(node, *span, Role::field("identifier"));
+    visitor.visit_identifier(
+        &enumerator.identifier.node,
+        &enumerator.identifier.span,
+        path,
+    );
+    path.leave();
+    if let Some(ref expression) = enumerator.expression {
+        path.enter(node, *span, Role::field("expression"));
+        visitor.visit_expression(&expression.node, &expression.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_type_qualifier<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _type_qualifier: &'ast TypeQualifier,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_function_specifier<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _function_specifier: &'ast FunctionSpecifier,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_alignment_specifier<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    alignment_specifier: &'ast AlignmentSpecifier,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::AlignmentSpecifier(alignment_specifier);
+    match *alignment_specifier {
+        AlignmentSpecifier::Type(ref x) => {
+            path.enter(node, *span, Role::field("Type"));
+            visitor.visit_type_name(&x.node, &x.span, path);
+            path.leave();
+        }
+        AlignmentSpecifier::Constant(ref x) => {
+            path.enter(node, *span, Role::field("Constant"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_declarator<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    declarator: &'ast Declarator,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Declarator(declarator);
+    path.enter(node, *span, Role::field("kind"));
+    visitor.visit_declarator_kind(&declarator.kind.node, &declarator.kind.span, path);
+    path.leave();
+    for (i, item) in declarator.derived.iter().enumerate() {
+        path.enter(node, *span, Role::index("derived", i));
+        visitor.visit_derived_declarator(&item.node, &item.span, path);
+        path.leave();
+    }
+    for (i, item) in declarator.extensions.iter().enumerate() {
+        path.enter(node, *span, Role::index("extensions", i));
+        visitor.visit_extension(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_declarator_kind<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    declarator_kind: &'ast DeclaratorKind,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::DeclaratorKind(declarator_kind);
+    match *declarator_kind {
+        DeclaratorKind::Identifier(ref x) => {
+            path.enter(node, *span, Role::field("Identifier"));
+            visitor.visit_identifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        DeclaratorKind::Declarator(ref x) => {
+            path.enter(node, *span, Role::field("Declarator"));
+            visitor.visit_declarator(&x.node, &x.span, path);
+            path.leave();
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_derived_declarator<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    derived_declarator: &'ast DerivedDeclarator,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::DerivedDeclarator(derived_declarator);
+    match *derived_declarator {
+        DerivedDeclarator::Pointer(ref x) => {
+            for (i, item) in x.iter().enumerate() {
+                path.enter(node, *span, Role::index("Pointer", i));
+                visitor.visit_pointer_qualifier(&item.node, &item.span, path);
+                path.leave();
+            }
+        }
+        DerivedDeclarator::Array(ref x) => {
+            path.enter(node, *span, Role::field("Array"));
+            visitor.visit_array_declarator(&x.node, &x.span, path);
+            path.leave();
+        }
+        DerivedDeclarator::Function(ref x) => {
+            path.enter(node, *span, Role::field("Function"));
+            visitor.visit_function_declarator(&x.node, &x.span, path);
+            path.leave();
+        }
+        DerivedDeclarator::KRFunction(ref x) => {
+            for (i, item) in x.iter().enumerate() {
+                path.enter(node, *span, Role::index("KRFunction", i));
+                visitor.visit_identifier(&item.node, &item.span, path);
+                path.leave();
+            }
+        }
+    }
+}
+
+pub fn visit_array_declarator<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    array_declarator: &'ast ArrayDeclarator,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::ArrayDeclarator(array_declarator);
+    for (i, item) in array_declarator.qualifiers.iter().enumerate() {
+        path.enter(node, *span, Role::index("qualifiers", i));
+        visitor.visit_type_qualifier(&item.node, &item.span, path);
+        path.leave();
+    }
+    path.enter(node, *span, Role::field("size"));
+    visitor.visit_array_size(&array_declarator.size, span, path);
+    path.leave();
+}
+
+pub fn visit_function_declarator<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    function_declarator: &'ast FunctionDeclarator,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::FunctionDeclarator(function_declarator);
+    for (i, item) in function_declarator.parameters.iter().enumerate() {
+        path.enter(node, *span, Role::index("parameters", i));
+        visitor.visit_parameter_declaration(&item.node, &item.span, path);
+        path.leave();
+    }
+    path.enter(node, *span, Role::field("ellipsis"));
+    visitor.visit_ellipsis(&function_declarator.ellipsis, span, path);
+    path.leave();
+}
+
+pub fn visit_pointer_qualifier<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    pointer_qualifier: &'ast PointerQualifier,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::PointerQualifier(pointer_qualifier);
+    match *pointer_qualifier {
+        PointerQualifier::TypeQualifier(ref x) => {
+            path.enter(node, *span, Role::field("TypeQualifier"));
+            visitor.visit_type_qualifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        PointerQualifier::Extension(ref x) => {
+            for (i, item) in x.iter().enumerate() {
+                path.enter(node, *span, Role::index("Extension", i));
+                visitor.visit_extension(&item.node, &item.span, path);
+                path.leave();
+            }
+        }
+    }
+}
+
+pub fn visit_array_size<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    array_size: &'ast ArraySize,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::ArraySize(array_size);
+    match *array_size {
+        ArraySize::VariableExpression(ref x) => {
+            path.enter(node, *span, Role::field("VariableExpression"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        ArraySize::StaticExpression(ref x) => {
+            path.enter(node, *span, Role::field("StaticExpression"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_parameter_declaration<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    parameter_declaration: &'ast ParameterDeclaration,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::ParameterDeclaration(parameter_declaration);
+    for (i, item) in parameter_declaration.specifiers.iter().enumerate() {
+        path.enter(node, *span, Role::index("specifiers", i));
+        visitor.visit_declaration_specifier(&item.node, &item.span, path);
+        path.leave();
+    }
+    if let Some(ref declarator) = parameter_declaration.declarator {
+        path.enter(node, *span, Role::field("declarator"));
+        visitor.visit_declarator(&declarator.node, &declarator.span, path);
+        path.leave();
+    }
+    for (i, item) in parameter_declaration.extensions.iter().enumerate() {
+        path.enter(node, *span, Role::index("extensions", i));
+        visitor.visit_extension(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_ellipsis<'ast, V: VisitPath<'ast> + ?Sized>(
+    _visitor: &mut V,
+    _ellipsis: &'ast Ellipsis,
+    _span: &'ast Span,
+    _path: &mut Path<'ast>,
+) {
+}
+
+pub fn visit_type_name<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    type_name: &'ast TypeName,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::TypeName(type_name);
+    for (i, item) in type_name.specifiers.iter().enumerate() {
+        path.enter(node, *span, Role::index("specifiers", i));
+        visitor.visit_specifier_qualifier(&item.node, &item.span, path);
+        path.leave();
+    }
+    if let Some(ref declarator) = type_name.declarator {
+        path.enter(node, *span, Role::field("declarator"));
+        visitor.visit_declarator(&declarator.node, &declarator.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_initializer<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    initializer: &'ast Initializer,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Initializer(initializer);
+    match *initializer {
+        Initializer::Expression(ref x) => {
+            path.enter(node, *span, Role::field("Expression"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Initializer::List(ref x) => {
+            for (i, item) in x.iter().enumerate() {
+                path.enter(node, *span, Role::index("List", i));
+                visitor.visit_initializer_list_item(&item.node, &item.span, path);
+                path.leave();
+            }
+        }
+    }
+}
+
+pub fn visit_initializer_list_item<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    initializer_list_item: &'ast InitializerListItem,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::InitializerListItem(initializer_list_item);
+    for (i, item) in initializer_list_item.designation.iter().enumerate() {
+        path.enter(node, *span, Role::index("designation", i));
+        visitor.visit_designator(&item.node, &item.span, path);
+        path.leave();
+    }
+    path.enter(node, *span, Role::field("initializer"));
+    visitor.visit_initializer(
+        &initializer_list_item.initializer.node,
+        &initializer_list_item.initializer.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_designator<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    designator: &'ast Designator,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Designator(designator);
+    match *designator {
+        Designator::Index(ref x) => {
+            path.enter(node, *span, Role::field("Index"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Designator::Member(ref x) => {
+            path.enter(node, *span, Role::field("Member"));
+            visitor.visit_identifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        Designator::Range(ref x) => {
+            path.enter(node, *span, Role::field("Range"));
+            visitor.visit_range_designator(&x.node, &x.span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_range_designator<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    range_designator: &'ast RangeDesignator,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::RangeDesignator(range_designator);
+    path.enter(node, *span, Role::field("from"));
+    visitor.visit_expression(
+        &range_designator.from.node,
+        &range_designator.from.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("to"));
+    visitor.visit_expression(&range_designator.to.node, &range_designator.to.span, path);
+    path.leave();
+}
+
+pub fn visit_static_assert<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    static_assert: &'ast StaticAssert,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::StaticAssert(static_assert);
+    path.enter(node, *span, Role::field("expression"));
+    visitor.visit_expression(
+        &static_assert.expression.node,
+        &static_assert.expression.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("message"));
+    visitor.visit_string_literal(
+        &static_assert.message.node,
+        &static_assert.message.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_statement<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    statement: &'ast Statement,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Statement(statement);
+    match *statement {
+        Statement::Labeled(ref x) => {
+            path.enter(node, *span, Role::field("Labeled"));
+            visitor.visit_labeled_statement(&x.node, &x.span, path);
+            path.leave();
+        }
+        Statement::Compound(ref x) => {
+            for (i, item) in x.iter().enumerate() {
+                path.enter(node, *span, Role::index("Compound", i));
+                visitor.visit_block_item(&item.node, &item.span, path);
+                path.leave();
+            }
+        }
+        Statement::Expression(Some(ref x)) => {
+            path.enter(node, *span, Role::field("Expression"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Statement::If(ref x) => {
+            path.enter(node, *span, Role::field("If"));
+            visitor.visit_if_statement(&x.node, &x.span, path);
+            path.leave();
+        }
+        Statement::Switch(ref x) => {
+            path.enter(node, *span, Role::field("Switch"));
+            visitor.visit_switch_statement(&x.node, &x.span, path);
+            path.leave();
+        }
+        Statement::While(ref x) => {
+            path.enter(node, *span, Role::field("While"));
+            visitor.visit_while_statement(&x.node, &x.span, path);
+            path.leave();
+        }
+        Statement::DoWhile(ref x) => {
+            path.enter(node, *span, Role::field("DoWhile"));
+            visitor.visit_do_while_statement(&x.node, &x.span, path);
+            path.leave();
+        }
+        Statement::For(ref x) => {
+            path.enter(node, *span, Role::field("For"));
+            visitor.visit_for_statement(&x.node, &x.span, path);
+            path.leave();
+        }
+        Statement::Goto(ref x) => {
+            path.enter(node, *span, Role::field("Goto"));
+            visitor.visit_identifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        Statement::Return(Some(ref x)) => {
+            path.enter(node, *span, Role::field("Return"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        Statement::Asm(ref x) => {
+            path.enter(node, *span, Role::field("Asm"));
+            visitor.visit_asm_statement(&x.node, &x.span, path);
+            path.leave();
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_labeled_statement<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    labeled_statement: &'ast LabeledStatement,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::LabeledStatement(labeled_statement);
+    path.enter(node, *span, Role::field("label"));
+    visitor.visit_label(
+        &labeled_statement.label.node,
+        &labeled_statement.label.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("statement"));
+    visitor.visit_statement(
+        &labeled_statement.statement.node,
+        &labeled_statement.statement.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_if_statement<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    if_statement: &'ast IfStatement,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::IfStatement(if_statement);
+    path.enter(node, *span, Role::field("condition"));
+    visitor.visit_expression(
+        &if_statement.condition.node,
+        &if_statement.condition.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("then_statement"));
+    visitor.visit_statement(
+        &if_statement.then_statement.node,
+        &if_statement.then_statement.span,
+        path,
+    );
+    path.leave();
+    if let Some(ref else_statement) = if_statement.else_statement {
+        path.enter(node, *span, Role::field("else_statement"));
+        visitor.visit_statement(&else_statement.node, &else_statement.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_switch_statement<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    switch_statement: &'ast SwitchStatement,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::SwitchStatement(switch_statement);
+    path.enter(node, *span, Role::field("expression"));
+    visitor.visit_expression(
+        &switch_statement.expression.node,
+        &switch_statement.expression.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("statement"));
+    visitor.visit_statement(
+        &switch_statement.statement.node,
+        &switch_statement.statement.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_while_statement<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    while_statement: &'ast WhileStatement,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::WhileStatement(while_statement);
+    path.enter(node, *span, Role::field("expression"));
+    visitor.visit_expression(
+        &while_statement.expression.node,
+        &while_statement.expression.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("statement"));
+    visitor.visit_statement(
+        &while_statement.statement.node,
+        &while_statement.statement.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_do_while_statement<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    do_while_statement: &'ast DoWhileStatement,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::DoWhileStatement(do_while_statement);
+    path.enter(node, *span, Role::field("statement"));
+    visitor.visit_statement(
+        &do_while_statement.statement.node,
+        &do_while_statement.statement.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("expression"));
+    visitor.visit_expression(
+        &do_while_statement.expression.node,
+        &do_while_statement.expression.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_for_statement<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    for_statement: &'ast ForStatement,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::ForStatement(for_statement);
+    path.enter(node, *span, Role::field("initializer"));
+    visitor.visit_for_initializer(
+        &for_statement.initializer.node,
+        &for_statement.initializer.span,
+        path,
+    );
+    path.leave();
+    if let Some(ref condition) = for_statement.condition {
+        path.enter(node, *span, Role::field("condition"));
+        visitor.visit_expression(&condition.node, &condition.span, path);
+        path.leave();
+    }
+    if let Some(ref step) = for_statement.step {
+        path.enter(node, *span, Role::field("step"));
+        visitor.visit_expression(&step.node, &step.span, path);
+        path.leave();
+    }
+    path.enter(node, *span, Role::field("statement"));
+    visitor.visit_statement(
+        &for_statement.statement.node,
+        &for_statement.statement.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_label<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    label: &'ast Label,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Label(label);
+    match *label {
+        Label::Identifier(ref x) => {
+            path.enter(node, *span, Role::field("Identifier"));
+            visitor.visit_identifier(&x.node, &x.span, path);
+            path.leave();
+        }
+        Label::Case(ref x) => {
+            path.enter(node, *span, Role::field("Case"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_for_initializer<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    for_initializer: &'ast ForInitializer,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::ForInitializer(for_initializer);
+    match *for_initializer {
+        ForInitializer::Expression(ref x) => {
+            path.enter(node, *span, Role::field("Expression"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        ForInitializer::Declaration(ref x) => {
+            path.enter(node, *span, Role::field("Declaration"));
+            visitor.visit_declaration(&x.node, &x.span, path);
+            path.leave();
+        }
+        ForInitializer::StaticAssert(ref x) => {
+            path.enter(node, *span, Role::field("StaticAssert"));
+            visitor.visit_static_assert(&x.node, &x.span, path);
+            path.leave();
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_block_item<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    block_item: &'ast BlockItem,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::BlockItem(block_item);
+    match *block_item {
+        BlockItem::Declaration(ref x) => {
+            path.enter(node, *span, Role::field("Declaration"));
+            visitor.visit_declaration(&x.node, &x.span, path);
+            path.leave();
+        }
+        BlockItem::StaticAssert(ref x) => {
+            path.enter(node, *span, Role::field("StaticAssert"));
+            visitor.visit_static_assert(&x.node, &x.span, path);
+            path.leave();
+        }
+        BlockItem::Statement(ref x) => {
+            path.enter(node, *span, Role::field("Statement"));
+            visitor.visit_statement(&x.node, &x.span, path);
+            path.leave();
+        }
+        BlockItem::Ambiguous(ref x) => {
+            path.enter(node, *span, Role::field("Ambiguous"));
+            visitor.visit_ambiguous_block_item(&x.node, &x.span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_ambiguous_block_item<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    ambiguous_block_item: &'ast AmbiguousBlockItem,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::AmbiguousBlockItem(ambiguous_block_item);
+    path.enter(node, *span, Role::field("declaration"));
+    visitor.visit_declaration(
+        &ambiguous_block_item.declaration.node,
+        &ambiguous_block_item.declaration.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("statement"));
+    visitor.visit_statement(
+        &ambiguous_block_item.statement.node,
+        &ambiguous_block_item.statement.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_translation_unit<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    translation_unit: &'ast TranslationUnit,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::TranslationUnit(translation_unit);
+    for (i, item) in translation_unit.0.iter().enumerate() {
+        path.enter(node, Span::none(), Role::index("0", i));
+        visitor.visit_external_declaration(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_external_declaration<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    external_declaration: &'ast ExternalDeclaration,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::ExternalDeclaration(external_declaration);
+    match *external_declaration {
+        ExternalDeclaration::Declaration(ref x) => {
+            path.enter(node, *span, Role::field("Declaration"));
+            visitor.visit_declaration(&x.node, &x.span, path);
+            path.leave();
+        }
+        ExternalDeclaration::StaticAssert(ref x) => {
+            path.enter(node, *span, Role::field("StaticAssert"));
+            visitor.visit_static_assert(&x.node, &x.span, path);
+            path.leave();
+        }
+        ExternalDeclaration::FunctionDefinition(ref x) => {
+            path.enter(node, *span, Role::field("FunctionDefinition"));
+            visitor.visit_function_definition(&x.node, &x.span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_function_definition<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    function_definition: &'ast FunctionDefinition,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::FunctionDefinition(function_definition);
+    for (i, item) in function_definition.specifiers.iter().enumerate() {
+        path.enter(node, *span, Role::index("specifiers", i));
+        visitor.visit_declaration_specifier(&item.node, &item.span, path);
+        path.leave();
+    }
+    path.enter(node, *span, Role::field("declarator"));
+    visitor.visit_declarator(
+        &function_definition.declarator.node,
+        &function_definition.declarator.span,
+        path,
+    );
+    path.leave();
+    for (i, item) in function_definition.declarations.iter().enumerate() {
+        path.enter(node, *span, Role::index("declarations", i));
+        visitor.visit_declaration(&item.node, &item.span, path);
+        path.leave();
+    }
+    path.enter(node, *span, Role::field("statement"));
+    visitor.visit_statement(
+        &function_definition.statement.node,
+        &function_definition.statement.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_extension<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    extension: &'ast Extension,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Extension(extension);
+    match *extension {
+        Extension::Attribute(ref x) => {
+            path.enter(node, *span, Role::field("Attribute"));
+            visitor.visit_attribute(x, span, path);
+            path.leave();
+        }
+        Extension::AsmLabel(ref x) => {
+            path.enter(node, *span, Role::field("AsmLabel"));
+            visitor.visit_string_literal(&x.node, &x.span, path);
+            path.leave();
+        }
+        Extension::AvailabilityAttribute(ref x) => {
+            path.enter(node, *span, Role::field("AvailabilityAttribute"));
+            visitor.visit_availability_attribute(&x.node, &x.span, path);
+            path.leave();
+        }
+        Extension::VendorQualifier(ref x) => {
+            path.enter(node, *span, Role::field("VendorQualifier"));
+            visitor.visit_vendor_qualifier(x, span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_attribute<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    attribute: &'ast Attribute,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::Attribute(attribute);
+    for (i, item) in attribute.arguments.iter().enumerate() {
+        path.enter(node, *span, Role::index("arguments", i));
+        visitor.visit_expression(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_vendor_qualifier<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    vendor_qualifier: &'ast VendorQualifier,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::VendorQualifier(vendor_qualifier);
+    for (i, item) in vendor_qualifier.arguments.iter().enumerate() {
+        path.enter(node, *span, Role::index("arguments", i));
+        visitor.visit_expression(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_asm_statement<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    asm_statement: &'ast AsmStatement,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::AsmStatement(asm_statement);
+    match *asm_statement {
+        AsmStatement::GnuBasic(ref x) => {
+            path.enter(node, *span, Role::field("GnuBasic"));
+            visitor.visit_string_literal(&x.node, &x.span, path);
+            path.leave();
+        }
+        AsmStatement::GnuExtended(ref x) => {
+            path.enter(node, *span, Role::field("GnuExtended"));
+            visitor.visit_gnu_extended_asm_statement(x, span, path);
+            path.leave();
+        }
+    }
+}
+
+pub fn visit_availability_attribute<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    availability_attribute: &'ast AvailabilityAttribute,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::AvailabilityAttribute(availability_attribute);
+    path.enter(node, *span, Role::field("platform"));
+    visitor.visit_identifier(
+        &availability_attribute.platform.node,
+        &availability_attribute.platform.span,
+        path,
+    );
+    path.leave();
+    for (i, item) in availability_attribute.clauses.iter().enumerate() {
+        path.enter(node, *span, Role::index("clauses", i));
+        visitor.visit_availability_clause(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_availability_clause<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    availability_clause: &'ast AvailabilityClause,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::AvailabilityClause(availability_clause);
+    match *availability_clause {
+        AvailabilityClause::Message(ref x) => {
+            path.enter(node, *span, Role::field("Message"));
+            visitor.visit_string_literal(&x.node, &x.span, path);
+            path.leave();
+        }
+        AvailabilityClause::Replacement(ref x) => {
+            path.enter(node, *span, Role::field("Replacement"));
+            visitor.visit_string_literal(&x.node, &x.span, path);
+            path.leave();
+        }
+        _ => {}
+    }
+}
+
+pub fn visit_gnu_extended_asm_statement<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    gnu_extended_asm_statement: &'ast GnuExtendedAsmStatement,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::GnuExtendedAsmStatement(gnu_extended_asm_statement);
+    if let Some(ref qualifier) = gnu_extended_asm_statement.qualifier {
+        path.enter(node, *span, Role::field("qualifier"));
+        visitor.visit_type_qualifier(&qualifier.node, &qualifier.span, path);
+        path.leave();
+    }
+    path.enter(node, *span, Role::field("template"));
+    visitor.visit_string_literal(
+        &gnu_extended_asm_statement.template.node,
+        &gnu_extended_asm_statement.template.span,
+        path,
+    );
+    path.leave();
+    for (i, item) in gnu_extended_asm_statement.outputs.iter().enumerate() {
+        path.enter(node, *span, Role::index("outputs", i));
+        visitor.visit_gnu_asm_operand(&item.node, &item.span, path);
+        path.leave();
+    }
+    for (i, item) in gnu_extended_asm_statement.inputs.iter().enumerate() {
+        path.enter(node, *span, Role::index("inputs", i));
+        visitor.visit_gnu_asm_operand(&item.node, &item.span, path);
+        path.leave();
+    }
+    for (i, item) in gnu_extended_asm_statement.clobbers.iter().enumerate() {
+        path.enter(node, *span, Role::index("clobbers", i));
+        visitor.visit_string_literal(&item.node, &item.span, path);
+        path.leave();
+    }
+}
+
+pub fn visit_gnu_asm_operand<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    gnu_asm_operand: &'ast GnuAsmOperand,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::GnuAsmOperand(gnu_asm_operand);
+    if let Some(ref symbolic_name) = gnu_asm_operand.symbolic_name {
+        path.enter(node, *span, Role::field("symbolic_name"));
+        visitor.visit_identifier(&symbolic_name.node, &symbolic_name.span, path);
+        path.leave();
+    }
+    path.enter(node, *span, Role::field("constraints"));
+    visitor.visit_string_literal(
+        &gnu_asm_operand.constraints.node,
+        &gnu_asm_operand.constraints.span,
+        path,
+    );
+    path.leave();
+    path.enter(node, *span, Role::field("variable_name"));
+    visitor.visit_expression(
+        &gnu_asm_operand.variable_name.node,
+        &gnu_asm_operand.variable_name.span,
+        path,
+    );
+    path.leave();
+}
+
+pub fn visit_type_of<'ast, V: VisitPath<'ast> + ?Sized>(
+    visitor: &mut V,
+    type_of: &'ast TypeOf,
+    span: &'ast Span,
+    path: &mut Path<'ast>,
+) {
+    let node = NodeRef::TypeOf(type_of);
+    match *type_of {
+        TypeOf::Expression(ref x) => {
+            path.enter(node, *span, Role::field("Expression"));
+            visitor.visit_expression(&x.node, &x.span, path);
+            path.leave();
+        }
+        TypeOf::Type(ref x) => {
+            path.enter(node, *span, Role::field("Type"));
+            visitor.visit_type_name(&x.node, &x.span, path);
+            path.leave();
+        }
+    }
+}